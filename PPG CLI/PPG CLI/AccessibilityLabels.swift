@@ -0,0 +1,46 @@
+import Foundation
+
+/// Spoken descriptions for rows, cards and panes whose visible text leaves things out,
+/// usually a status shown only as a colored dot. VoiceOver reads these in place of
+/// the view's children, so each one carries the element's name, kind and state.
+nonisolated enum AccessibilityLabels {
+    /// "Project app, 2 worktrees"
+    static func project(name: String, worktreeCount: Int) -> String {
+        "Project \(name), \(count(worktreeCount, "worktree"))"
+    }
+
+    /// "Worktree auth-fix, active, 3 agents"
+    static func worktree(_ worktree: WorktreeModel) -> String {
+        "Worktree \(worktree.name), \(worktree.status), \(count(worktree.agents.count, "agent"))"
+    }
+
+    /// "Agent claude-1, claude, running" (", stalled" when it has gone quiet)
+    static func agent(_ agent: AgentModel, stalled: Bool = false) -> String {
+        "Agent \(agent.name.isEmpty ? agent.id : agent.name), \(agent.agentType), \(agent.statusLabel)" + (stalled ? ", stalled" : "")
+    }
+
+    /// "Split of 2 agents: claude-1, running; codex-1, failed"
+    static func agentGroup(_ agents: [AgentModel]) -> String {
+        let members = agents.map { "\($0.name.isEmpty ? $0.id : $0.name), \($0.statusLabel)" }
+        return "Split of \(count(agents.count, "agent")): " + members.joined(separator: "; ")
+    }
+
+    /// "Terminal build" or "Agent session claude"
+    static func sessionEntry(label: String, isAgent: Bool) -> String {
+        "\(isAgent ? "Agent session" : "Terminal") \(label)"
+    }
+
+    /// "Agent claude-1 terminal, failed" for a pane showing an agent.
+    static func agentPane(name: String, status: String) -> String {
+        "Agent \(name) terminal, \(status)"
+    }
+
+    /// "2 running, 1 failed" (or "No agents") for per-status counts drawn as dots.
+    static func agentCounts(_ counts: AgentStatusCounts) -> String {
+        counts.parts.isEmpty ? "No agents" : counts.parts.joined(separator: ", ")
+    }
+
+    private static func count(_ n: Int, _ noun: String) -> String {
+        "\(n) \(noun)\(n == 1 ? "" : "s")"
+    }
+}
@@ -0,0 +1,91 @@
+import Foundation
+
+/// Derives a "stalled" marker for running agents that have gone quiet. ppg only knows
+/// whether an agent is running or has exited, but a running agent with no output for a
+/// long time is usually stuck or waiting for input. The marker is shown alongside the
+/// manifest status and never replaces it.
+nonisolated struct StallDetector {
+    /// Latest output time seen per agent ID.
+    private(set) var lastOutput: [String: Date] = [:]
+
+    /// Note output from `agentId` at `date`. Older times than the one already known are ignored.
+    mutating func recordOutput(agentId: String, at date: Date) {
+        if let known = lastOutput[agentId], known >= date { return }
+        lastOutput[agentId] = date
+    }
+
+    /// Take each agent's output time from its tmux window, and forget agents no longer listed.
+    mutating func record(_ activity: TmuxWindowActivity, agents: [AgentModel]) {
+        let ids = Set(agents.map(\.id))
+        lastOutput = lastOutput.filter { ids.contains($0.key) }
+        recordOutput(activity, agents: agents)
+    }
+
+    /// Take output times for just these agents, leaving everyone else's as they are.
+    mutating func recordOutput(_ activity: TmuxWindowActivity, agents: [AgentModel]) {
+        for agent in agents {
+            if let date = activity.lastOutput(of: TmuxTarget(parsing: agent.tmuxTarget)) {
+                recordOutput(agentId: agent.id, at: date)
+            }
+        }
+    }
+
+    /// When a running agent last printed anything, if that was at least `threshold` before `now`.
+    /// Nil for agents that aren't running or whose output time isn't known.
+    func stalledSince(agentId: String, status: AgentStatus, threshold: TimeInterval, now: Date) -> Date? {
+        guard status == .running, let last = lastOutput[agentId] else { return nil }
+        return now.timeIntervalSince(last) >= threshold ? last : nil
+    }
+}
+
+/// The app's stall detector. The sidebar feeds it from tmux as part of each refresh,
+/// and every view that shows agent status reads from it.
+final class AgentActivity {
+    static let shared = AgentActivity()
+
+    /// How often window activity is polled while nothing is stalled. A stalled agent is
+    /// checked on every refresh instead, so new output clears its marker promptly.
+    static let pollInterval: TimeInterval = 30
+
+    private(set) var detector = StallDetector()
+    private var lastPoll: Date?
+    private var hasStalledAgents = false
+
+    private init() {}
+
+    var threshold: TimeInterval {
+        TimeInterval(AppSettingsManager.shared.stalledAgentMinutes) * 60
+    }
+
+    /// Whether this refresh should ask tmux for window activity.
+    func needsPoll(now: Date = Date()) -> Bool {
+        guard !hasStalledAgents, let lastPoll else { return true }
+        return now.timeIntervalSince(lastPoll) >= Self.pollInterval
+    }
+
+    /// Apply a poll's results; `agents` is every agent in the open projects.
+    func record(_ activity: TmuxWindowActivity, agents: [AgentModel], now: Date = Date()) {
+        lastPoll = now
+        detector.record(activity, agents: agents)
+        hasStalledAgents = agents.contains { stalledSince($0, now: now) != nil }
+    }
+
+    /// Apply a poll made outside the refresh for some of the agents, e.g. the pane
+    /// grid's auto-follow, which needs fresher output times than the refresh keeps.
+    func recordOutput(_ activity: TmuxWindowActivity, agents: [AgentModel]) {
+        detector.recordOutput(activity, agents: agents)
+    }
+
+    /// Latest output time known for an agent.
+    func lastOutput(agentId: String) -> Date? {
+        detector.lastOutput[agentId]
+    }
+
+    func stalledSince(_ agent: AgentModel, now: Date = Date()) -> Date? {
+        detector.stalledSince(agentId: agent.id, status: agent.status, threshold: threshold, now: now)
+    }
+
+    func stalledSince(agentId: String, status: AgentStatus, now: Date = Date()) -> Date? {
+        detector.stalledSince(agentId: agentId, status: status, threshold: threshold, now: now)
+    }
+}
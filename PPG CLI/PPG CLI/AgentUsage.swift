@@ -0,0 +1,94 @@
+import AppKit
+
+/// Tokens and cost a newer ppg reports on agent entries (`tokensUsed`, `costUsd`).
+/// Either may be missing; a missing value is left out of the text rather than shown as 0.
+nonisolated struct AgentUsage: Equatable {
+    /// The agent entry fields this is read from; not repeated among the raw extra fields.
+    static let manifestKeys: Set<String> = ["tokensUsed", "costUsd"]
+
+    var tokens: Int?
+    var costUsd: Double?
+
+    /// Sum over the agents that report usage, field by field. Nil when none do.
+    static func total(of usages: [AgentUsage?]) -> AgentUsage? {
+        var total = AgentUsage()
+        for usage in usages.compactMap({ $0 }) {
+            if let tokens = usage.tokens {
+                let (sum, overflow) = (total.tokens ?? 0).addingReportingOverflow(tokens)
+                total.tokens = overflow ? .max : sum
+            }
+            if let cost = usage.costUsd { total.costUsd = (total.costUsd ?? 0) + cost }
+        }
+        return total.tokens == nil && total.costUsd == nil ? nil : total
+    }
+
+    /// "$1.24 · 18,204 tokens", or just the part that's known.
+    func text(locale: Locale = .current) -> String {
+        var parts: [String] = []
+        if let costUsd { parts.append(formatCost(costUsd, locale: locale)) }
+        if let tokens { parts.append(formatTokenCount(tokens, locale: locale)) }
+        return parts.joined(separator: " · ")
+    }
+}
+
+/// US dollars to the cent: "$1,234.56". Amounts under a cent read "<$0.01" so a cheap
+/// agent doesn't look free.
+nonisolated func formatCost(_ usd: Double, locale: Locale = .current) -> String {
+    let formatter = NumberFormatter()
+    formatter.numberStyle = .currency
+    formatter.currencyCode = "USD"
+    formatter.locale = locale
+    formatter.minimumFractionDigits = 2
+    formatter.maximumFractionDigits = 2
+    if usd > 0 && usd < 0.01 {
+        return "<" + (formatter.string(from: 0.01) ?? "$0.01")
+    }
+    return formatter.string(from: NSNumber(value: usd)) ?? String(format: "$%.2f", usd)
+}
+
+/// "1 token", "18,204 tokens".
+nonisolated func formatTokenCount(_ tokens: Int, locale: Locale = .current) -> String {
+    let formatter = NumberFormatter()
+    formatter.numberStyle = .decimal
+    formatter.locale = locale
+    let number = formatter.string(from: NSNumber(value: tokens)) ?? String(tokens)
+    return "\(number) \(tokens == 1 ? "token" : "tokens")"
+}
+
+/// Small rounded badge showing an agent's usage, e.g. in the worktree detail rows.
+final class UsageChip: NSView {
+    private let label: NSTextField
+
+    init(usage: AgentUsage) {
+        label = NSTextField(labelWithString: usage.text())
+        super.init(frame: .zero)
+        wantsLayer = true
+        layer?.cornerRadius = 4
+        applyColors()
+
+        label.font = .monospacedDigitSystemFont(ofSize: 11, weight: .regular)
+        label.textColor = .secondaryLabelColor
+        label.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(label)
+        NSLayoutConstraint.activate([
+            label.topAnchor.constraint(equalTo: topAnchor, constant: 2),
+            label.bottomAnchor.constraint(equalTo: bottomAnchor, constant: -2),
+            label.leadingAnchor.constraint(equalTo: leadingAnchor, constant: 6),
+            label.trailingAnchor.constraint(equalTo: trailingAnchor, constant: -6),
+        ])
+        setAccessibilityElement(true)
+        setAccessibilityRole(.staticText)
+        setAccessibilityLabel("Usage: \(usage.text())")
+    }
+
+    required init?(coder: NSCoder) { fatalError() }
+
+    private func applyColors() {
+        layer?.backgroundColor = NSColor.quaternaryLabelColor.resolvedCGColor(for: effectiveAppearance)
+    }
+
+    override func viewDidChangeEffectiveAppearance() {
+        super.viewDidChangeEffectiveAppearance()
+        applyColors()
+    }
+}
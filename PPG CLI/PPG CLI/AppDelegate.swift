@@ -2,12 +2,40 @@ import AppKit
 
 class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
     var window: NSWindow!
+    private var menuBarController: MenuBarController?
+    private var shortcutsWindowController: ShortcutsWindowController?
+    /// Save panel shown by App ▸ Export Report…, while it's open.
+    private weak var reportSavePanel: NSSavePanel?
 
     func applicationDidFinishLaunching(_ notification: Notification) {
         AppSettingsManager.shared.applyAppearance()
+        UserTheme.shared.onLoadError = { [weak self] message in
+            self?.showThemeError(message)
+        }
+        UserTheme.shared.start()
         setupMainMenu()
         KeybindingManager.shared.applyBindings(to: NSApp.mainMenu!)
 
+        DistributedNotificationCenter.default().addObserver(
+            forName: SingleInstance.openProjectNotification, object: nil, queue: .main
+        ) { [weak self] notification in
+            guard let root = notification.object as? String else { return }
+            MainActor.assumeIsolated {
+                self?.openForwardedProject(root)
+            }
+        }
+
+        NotificationCenter.default.addObserver(
+            forName: .appSettingsDidChange, object: nil, queue: .main
+        ) { [weak self] notification in
+            guard let key = notification.userInfo?[AppSettingsManager.changedKeyUserInfoKey] as? AppSettingsKey,
+                  key == .keepRunningInMenuBar else { return }
+            MainActor.assumeIsolated {
+                self?.updateMenuBarItem()
+            }
+        }
+        updateMenuBarItem()
+
         let screenFrame = NSScreen.main?.visibleFrame ?? NSRect(x: 100, y: 100, width: 1400, height: 900)
 
         window = NSWindow(
@@ -20,6 +48,8 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         window.backgroundColor = Theme.chromeBackground
         window.isOpaque = false
         window.isRestorable = false
+        // Closing only hides the window when the app keeps running in the menu bar
+        window.isReleasedWhenClosed = false
 
         let toolbar = NSToolbar(identifier: "MainToolbar")
         toolbar.displayMode = .iconOnly
@@ -27,10 +57,32 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         window.toolbarStyle = .unified
 
         // Launch flow: check prerequisites, then restore projects or show picker
+        if AppSettingsManager.shared.skipSetupChecks {
+            // The setup checks would have read tmux's version; attach commands depend on it
+            DispatchQueue.global(qos: .utility).async {
+                _ = PPGService.shared.tmuxVersion()
+            }
+            proceedToProjects()
+        } else {
+            checkPrerequisitesAndProceed()
+        }
+
+        window.setFrame(screenFrame, display: true)
+        window.makeKeyAndOrderFront(nil)
+        NSApp.activate(ignoringOtherApps: true)
+
+        if let report = CrashReporter.takePendingCrashReport() {
+            DispatchQueue.main.async { [weak self] in
+                self?.showPreviousCrash(report)
+            }
+        }
+    }
+
+    private func checkPrerequisitesAndProceed() {
         let cli = PPGService.shared.checkCLIAvailable()
         let tmux = PPGService.shared.checkTmuxAvailable()
 
-        if cli.available && tmux.available && tmux.supportsCodexInputTheme {
+        if cli.available && cli.supported && tmux.available && tmux.supportsCodexInputTheme {
             proceedToProjects()
             if let version = cli.version {
                 checkCLIVersion(installedVersion: version)
@@ -38,13 +90,15 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         } else {
             showSetup()
         }
-
-        window.setFrame(screenFrame, display: true)
-        window.makeKeyAndOrderFront(nil)
-        NSApp.activate(ignoringOtherApps: true)
     }
 
     private func proceedToProjects() {
+        // A project passed on the command line (`ppg ui`) opens alongside any already open
+        let launchRoot = ProjectState.shared.projectRoot
+        if ProjectState.shared.isConfigured, RecentProjects.shared.isValidProject(launchRoot) {
+            OpenProjects.shared.add(root: launchRoot)
+        }
+
         if !OpenProjects.shared.projects.isEmpty {
             showDashboard()
         } else if let lastProject = RecentProjects.shared.lastOpened,
@@ -61,6 +115,11 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         window.title = "ppg — Setup"
         let setup = SetupViewController()
         setup.onReady = { [weak self] in
+            // Checks pass again, so go back to running them at launch
+            AppSettingsManager.shared.skipSetupChecks = false
+            self?.proceedToProjects()
+        }
+        setup.onSkip = { [weak self] in
             self?.proceedToProjects()
         }
         window.contentViewController = setup
@@ -110,6 +169,26 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
 
         appMenu.addItem(.separator())
 
+        let settingsItem = NSMenuItem(title: "Settings…", action: #selector(showSettings), keyEquivalent: ",")
+        settingsItem.target = self
+        settingsItem.tag = kMenuTagSettings
+        appMenu.addItem(settingsItem)
+
+        let setupChecksItem = NSMenuItem(title: "Run Setup Checks…", action: #selector(runSetupChecks), keyEquivalent: "")
+        setupChecksItem.target = self
+        appMenu.addItem(setupChecksItem)
+
+        let diagnosticsItem = NSMenuItem(title: "Diagnostics…", action: #selector(showDiagnostics), keyEquivalent: "")
+        diagnosticsItem.target = self
+        appMenu.addItem(diagnosticsItem)
+
+        let exportReportItem = NSMenuItem(title: "Export Report…", action: #selector(exportReport), keyEquivalent: "")
+        exportReportItem.target = self
+        exportReportItem.tag = kMenuTagExportReport
+        appMenu.addItem(exportReportItem)
+
+        appMenu.addItem(.separator())
+
         let quitItem = appMenu.addItem(withTitle: "Quit PPG CLI",
             action: #selector(NSApplication.terminate(_:)),
             keyEquivalent: "q")
@@ -197,6 +276,21 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         refreshItem.tag = kMenuTagRefresh
         viewMenu.addItem(refreshItem)
 
+        let quickSwitcherItem = NSMenuItem(title: "Go to Worktree or Agent…", action: #selector(showQuickSwitcher), keyEquivalent: "k")
+        quickSwitcherItem.target = self
+        quickSwitcherItem.tag = kMenuTagQuickSwitcher
+        viewMenu.addItem(quickSwitcherItem)
+
+        let backItem = NSMenuItem(title: "Back", action: #selector(navigateBack), keyEquivalent: "[")
+        backItem.target = self
+        backItem.tag = kMenuTagNavigateBack
+        viewMenu.addItem(backItem)
+
+        let forwardItem = NSMenuItem(title: "Forward", action: #selector(navigateForward), keyEquivalent: "]")
+        forwardItem.target = self
+        forwardItem.tag = kMenuTagNavigateForward
+        viewMenu.addItem(forwardItem)
+
         viewMenu.addItem(.separator())
 
         let splitBelowItem = NSMenuItem(title: "Split Pane Below", action: #selector(splitPaneBelow), keyEquivalent: "d")
@@ -216,6 +310,12 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         closePaneItem.tag = kMenuTagClosePane
         viewMenu.addItem(closePaneItem)
 
+        let zoomPaneItem = NSMenuItem(title: "Maximize/Restore Pane", action: #selector(togglePaneZoom), keyEquivalent: "\r")
+        zoomPaneItem.keyEquivalentModifierMask = [.command, .shift]
+        zoomPaneItem.target = self
+        zoomPaneItem.tag = kMenuTagTogglePaneZoom
+        viewMenu.addItem(zoomPaneItem)
+
         viewMenu.addItem(.separator())
 
         let focusUpItem = NSMenuItem(title: "Focus Pane Above", action: #selector(focusPaneUp), keyEquivalent: String(Character(UnicodeScalar(NSUpArrowFunctionKey)!)))
@@ -242,9 +342,62 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         focusRightItem.tag = kMenuTagFocusPaneRight
         viewMenu.addItem(focusRightItem)
 
+        // Cmd+Opt+1-6 — focus pane 1-6 in the grid
+        let paneMenuTags = [
+            kMenuTagFocusPane1, kMenuTagFocusPane2, kMenuTagFocusPane3,
+            kMenuTagFocusPane4, kMenuTagFocusPane5, kMenuTagFocusPane6,
+        ]
+        for i in 1...paneMenuTags.count {
+            let paneItem = NSMenuItem(title: "Focus Pane \(i)", action: #selector(focusPaneByIndex(_:)), keyEquivalent: "\(i)")
+            paneItem.keyEquivalentModifierMask = [.command, .option]
+            paneItem.target = self
+            paneItem.tag = paneMenuTags[i - 1]
+            paneItem.representedObject = i  // store the pane number
+            viewMenu.addItem(paneItem)
+        }
+
         viewMenuItem.submenu = viewMenu
         mainMenu.addItem(viewMenuItem)
 
+        // Agent menu — acts on the agent in the focused pane
+        let agentMenuItem = NSMenuItem()
+        let agentMenu = NSMenu(title: "Agent")
+
+        let restartAgentItem = NSMenuItem(title: "Restart Agent", action: #selector(restartFocusedAgent), keyEquivalent: "r")
+        restartAgentItem.keyEquivalentModifierMask = [.command, .shift]
+        restartAgentItem.target = self
+        restartAgentItem.tag = kMenuTagRestartAgent
+        agentMenu.addItem(restartAgentItem)
+
+        let killAgentItem = NSMenuItem(title: "Kill Agent…", action: #selector(killFocusedAgent), keyEquivalent: "k")
+        killAgentItem.keyEquivalentModifierMask = [.command, .shift]
+        killAgentItem.target = self
+        killAgentItem.tag = kMenuTagKillAgent
+        agentMenu.addItem(killAgentItem)
+
+        agentMenuItem.submenu = agentMenu
+        mainMenu.addItem(agentMenuItem)
+
+        // Help menu
+        let helpMenuItem = NSMenuItem()
+        let helpMenu = NSMenu(title: "Help")
+
+        let shortcutsItem = NSMenuItem(title: "Keyboard Shortcuts", action: #selector(showKeyboardShortcuts), keyEquivalent: "?")
+        shortcutsItem.target = self
+        shortcutsItem.tag = kMenuTagShowShortcuts
+        helpMenu.addItem(shortcutsItem)
+
+        #if DEBUG
+        helpMenu.addItem(.separator())
+        let testExceptionItem = NSMenuItem(title: "Raise Test Exception", action: #selector(raiseTestException), keyEquivalent: "")
+        testExceptionItem.target = self
+        helpMenu.addItem(testExceptionItem)
+        #endif
+
+        helpMenuItem.submenu = helpMenu
+        mainMenu.addItem(helpMenuItem)
+        NSApp.helpMenu = helpMenu
+
         NSApp.mainMenu = mainMenu
     }
 
@@ -264,6 +417,21 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         splitVC.sidebar.refresh()
     }
 
+    @objc private func showQuickSwitcher() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.showQuickSwitcher()
+    }
+
+    @objc private func navigateBack() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.navigateBack()
+    }
+
+    @objc private func navigateForward() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.navigateForward()
+    }
+
     @objc private func showCreationMenu(_ sender: Any) {
         guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
         splitVC.showCreationMenu()
@@ -284,6 +452,222 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         splitVC.closeFocusedPane()
     }
 
+    @objc private func focusPaneByIndex(_ sender: NSMenuItem) {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        let index = (sender.representedObject as? Int) ?? 1
+        splitVC.focusPane(at: index - 1)
+    }
+
+    @objc private func togglePaneZoom() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.togglePaneZoom()
+    }
+
+    @objc private func showKeyboardShortcuts() {
+        if shortcutsWindowController == nil {
+            shortcutsWindowController = ShortcutsWindowController()
+        }
+        shortcutsWindowController?.showWindow(nil)
+    }
+
+    /// Shown as a sheet so the dashboard underneath, with its terminals and grids, is left as it was.
+    @objc private func runSetupChecks() {
+        guard let host = window?.contentViewController, !(host is SetupViewController),
+              host.presentedViewControllers?.contains(where: { $0 is SetupViewController }) != true else { return }
+        let setup = SetupViewController()
+        setup.onReady = { [weak setup] in
+            // Checks pass again, so go back to running them at launch
+            AppSettingsManager.shared.skipSetupChecks = false
+            setup?.dismiss(nil)
+        }
+        setup.onSkip = { [weak setup] in setup?.dismiss(nil) }
+        setup.onClose = { [weak setup] in setup?.dismiss(nil) }
+        setup.view.setFrameSize(NSSize(width: 600, height: 460))
+        host.presentAsSheet(setup)
+    }
+
+    // MARK: - Crash Reports
+
+    private func showPreviousCrash(_ report: String) {
+        let alert = NSAlert()
+        alert.messageText = "PPG CLI Quit Unexpectedly"
+        alert.informativeText = "The last session ended because of an internal error. The report is also in \(CrashReporter.errorLogURL.path)."
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "OK")
+        alert.addButton(withTitle: "Copy Report")
+        if alert.runModal() == .alertSecondButtonReturn {
+            NSPasteboard.general.clearContents()
+            NSPasteboard.general.setString(report, forType: .string)
+        }
+    }
+
+    #if DEBUG
+    @objc private func raiseTestException() {
+        NSException(name: .internalInconsistencyException, reason: "Test exception from Help ▸ Raise Test Exception", userInfo: nil).raise()
+    }
+    #endif
+
+    // MARK: - Theme
+
+    private func showThemeError(_ message: String) {
+        let alert = NSAlert()
+        alert.messageText = "Theme Not Applied"
+        alert.informativeText = message + "\n\nThe previous colors stay in effect until the file is fixed."
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "Open theme.json")
+        alert.addButton(withTitle: "OK")
+        let openFile: (NSApplication.ModalResponse) -> Void = { response in
+            if response == .alertFirstButtonReturn {
+                NSWorkspace.shared.open(UserTheme.fileURL)
+            }
+        }
+        if let window, window.isVisible {
+            alert.beginSheetModal(for: window, completionHandler: openFile)
+        } else {
+            openFile(alert.runModal())
+        }
+    }
+
+    // MARK: - Diagnostics
+
+    @objc private func showDiagnostics() {
+        let settings = AppSettingsManager.shared
+        let info = Bundle.main.infoDictionary
+        var base = DiagnosticsReport()
+        base.appVersion = info?["CFBundleShortVersionString"] as? String
+        base.appBuild = info?["CFBundleVersion"] as? String
+        base.osVersion = ProcessInfo.processInfo.operatingSystemVersionString
+        base.architecture = DiagnosticsReport.machineArchitecture()
+        base.appearance = settings.appearanceMode.rawValue
+        base.terminalFont = "\(settings.terminalFontName) \(Int(settings.terminalFontSize))pt"
+        base.themePath = UserTheme.fileURL.path
+        base.themeFileExists = FileManager.default.fileExists(atPath: UserTheme.fileURL.path)
+        base.themeError = UserTheme.shared.loadError
+        base.openProjects = OpenProjects.shared.projects.count
+        var projectWorktrees: [String: [WorktreeModel]] = [:]
+        if let splitVC = window?.contentViewController as? DashboardSplitViewController {
+            base.runningAgents = runningAgentCounts().values.reduce(0, +)
+            projectWorktrees = splitVC.sidebar.projectWorktrees
+        }
+
+        DiagnosticsReport.collect(base: base, projectWorktrees: projectWorktrees) { [weak self] report in
+            self?.presentDiagnostics(report.markdown())
+        }
+    }
+
+    private func presentDiagnostics(_ markdown: String) {
+        let alert = NSAlert()
+        alert.messageText = "Diagnostics"
+        alert.informativeText = "Paste this into bug reports."
+        alert.addButton(withTitle: "Copy to Clipboard")
+        alert.addButton(withTitle: "Close")
+
+        let scrollView = NSScrollView(frame: NSRect(x: 0, y: 0, width: 480, height: 240))
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .bezelBorder
+        let textView = NSTextView(frame: scrollView.bounds)
+        textView.isEditable = false
+        textView.isSelectable = true
+        textView.font = .monospacedSystemFont(ofSize: 11, weight: .regular)
+        textView.string = markdown
+        textView.autoresizingMask = [.width]
+        scrollView.documentView = textView
+        alert.accessoryView = scrollView
+
+        if alert.runModal() == .alertFirstButtonReturn {
+            NSPasteboard.general.clearContents()
+            NSPasteboard.general.setString(markdown, forType: .string)
+        }
+    }
+
+    // MARK: - Status Report
+
+    @objc private func exportReport() {
+        let projects = OpenProjects.shared.projects.map { (root: $0.projectRoot, manifestPath: $0.manifestPath) }
+        // Later records win, e.g. an agent ID that somehow came up twice
+        var spawns: [String: SpawnRecord] = [:]
+        for ctx in OpenProjects.shared.projects {
+            for spawn in ctx.statusHistory.spawns {
+                spawns[spawn.agentId] = spawn
+            }
+        }
+        StatusReport.collect(projects: projects, spawns: spawns) { [weak self] report in
+            self?.saveReport(report)
+        }
+    }
+
+    private func saveReport(_ report: StatusReport) {
+        let formatPopup = NSPopUpButton(frame: .zero, pullsDown: false)
+        formatPopup.addItems(withTitles: StatusReport.Format.allCases.map(\.displayName))
+        formatPopup.target = self
+        formatPopup.action = #selector(reportFormatChanged(_:))
+
+        let label = NSTextField(labelWithString: "Format:")
+        let accessory = NSStackView(views: [label, formatPopup])
+        accessory.edgeInsets = NSEdgeInsets(top: 8, left: 8, bottom: 8, right: 8)
+
+        let stamp = ISO8601DateFormatter.string(from: Date(), timeZone: .current, formatOptions: [.withFullDate])
+        let panel = NSSavePanel()
+        panel.nameFieldStringValue = "ppg-report-\(stamp).md"
+        panel.allowedContentTypes = [StatusReport.Format.markdown.contentType]
+        panel.accessoryView = accessory
+        reportSavePanel = panel
+        defer { reportSavePanel = nil }
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+
+        let format = StatusReport.Format.allCases[max(0, formatPopup.indexOfSelectedItem)]
+        do {
+            try Data(report.rendered(as: format).utf8).write(to: url, options: .atomic)
+        } catch {
+            let alert = NSAlert()
+            alert.messageText = "Couldn't Export Report"
+            alert.informativeText = error.localizedDescription
+            alert.alertStyle = .warning
+            alert.runModal()
+            return
+        }
+
+        let alert = NSAlert()
+        alert.messageText = "Report Exported"
+        alert.informativeText = url.lastPathComponent
+        alert.addButton(withTitle: "Open")
+        alert.addButton(withTitle: "Done")
+        let openReport: (NSApplication.ModalResponse) -> Void = { response in
+            if response == .alertFirstButtonReturn {
+                NSWorkspace.shared.open(url)
+            }
+        }
+        if let window, window.isVisible {
+            alert.beginSheetModal(for: window, completionHandler: openReport)
+        } else {
+            openReport(alert.runModal())
+        }
+    }
+
+    /// Keep the save panel's file type and extension in step with the format popup.
+    @objc private func reportFormatChanged(_ sender: NSPopUpButton) {
+        guard let panel = reportSavePanel, sender.indexOfSelectedItem >= 0 else { return }
+        let format = StatusReport.Format.allCases[sender.indexOfSelectedItem]
+        panel.allowedContentTypes = [format.contentType]
+        let base = (panel.nameFieldStringValue as NSString).deletingPathExtension
+        panel.nameFieldStringValue = "\(base).\(format.fileExtension)"
+    }
+
+    @objc private func showSettings() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.showSettings()
+    }
+
+    @objc private func restartFocusedAgent() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.restartFocusedAgent()
+    }
+
+    @objc private func killFocusedAgent() {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
+        splitVC.killFocusedAgent()
+    }
+
     @objc private func focusPaneUp() {
         guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return }
         splitVC.movePaneFocus(direction: .horizontal, forward: false)
@@ -361,6 +745,20 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         }
     }
 
+    /// Project handed over by a second launch of the app.
+    private func openForwardedProject(_ root: String) {
+        guard RecentProjects.shared.isValidProject(root) else { return }
+        OpenProjects.shared.add(root: root)
+
+        // Setup picks the project up from OpenProjects once it continues
+        if window?.contentViewController is ProjectPickerViewController {
+            showDashboard()
+        } else if let splitVC = window?.contentViewController as? DashboardSplitViewController {
+            splitVC.sidebar.refresh()
+        }
+        window?.makeKeyAndOrderFront(nil)
+    }
+
     @objc private func openRecentProject(_ sender: NSMenuItem) {
         guard let path = sender.representedObject as? String else { return }
         OpenProjects.shared.add(root: path)
@@ -452,10 +850,112 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         }
     }
 
+    func applicationShouldTerminate(_ sender: NSApplication) -> NSApplication.TerminateReply {
+        let counts = runningAgentCounts()
+        let total = counts.values.reduce(0, +)
+        guard total > 0, AppSettingsManager.shared.confirmQuitWithRunningAgents else { return .terminateNow }
+
+        let alert = NSAlert()
+        alert.messageText = total == 1 ? "1 agent is still running" : "\(total) agents are still running"
+        alert.informativeText = "They keep running in tmux after PPG CLI quits. Open the dashboard again any time to check on them."
+        alert.addButton(withTitle: "Quit")
+        alert.addButton(withTitle: "Quit and Kill All Agents")
+        alert.addButton(withTitle: "Cancel")
+        alert.showsSuppressionButton = true
+        alert.suppressionButton?.title = "Don't ask again"
+
+        let response = alert.runModal()
+        if response != .alertThirdButtonReturn, alert.suppressionButton?.state == .on {
+            AppSettingsManager.shared.confirmQuitWithRunningAgents = false
+        }
+
+        switch response {
+        case .alertFirstButtonReturn:
+            return .terminateNow
+        case .alertSecondButtonReturn:
+            killAllAgentsThenQuit(projectRoots: Array(counts.keys))
+            return .terminateLater
+        default:
+            return .terminateCancel
+        }
+    }
+
+    /// Running agents per open project root, from the sidebar's last refresh.
+    private func runningAgentCounts() -> [String: Int] {
+        guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return [:] }
+        return splitVC.sidebar.projectWorktrees.compactMapValues { worktrees in
+            let count = worktrees.flatMap(\.agents).filter { $0.status == .running }.count
+            return count > 0 ? count : nil
+        }
+    }
+
+    /// Kill every agent in `projectRoots`, then finish the pending quit.
+    /// Failures are reported but don't block quitting.
+    private func killAllAgentsThenQuit(projectRoots: [String]) {
+        window?.title = "ppg — Stopping agents…"
+        DispatchQueue.global(qos: .userInitiated).async {
+            var failures: [String] = []
+            for root in projectRoots {
+                let args = "kill --all --json"
+                let result = PPGService.shared.runPPGCommand(args, projectRoot: root)
+                if result.exitCode != 0 {
+                    let failure = CommandFailure(args: args, result: result)
+                    failures.append("\(URL(fileURLWithPath: root).lastPathComponent): \(failure.summary)")
+                }
+            }
+
+            let report = failures.joined(separator: "\n")
+            DispatchQueue.main.async {
+                if !report.isEmpty {
+                    let alert = NSAlert()
+                    alert.messageText = "Some Agents Couldn't Be Killed"
+                    alert.informativeText = report
+                    alert.alertStyle = .warning
+                    alert.runModal()
+                }
+                NSApp.reply(toApplicationShouldTerminate: true)
+            }
+        }
+    }
+
     func applicationShouldTerminateAfterLastWindowClosed(_ sender: NSApplication) -> Bool {
+        return !AppSettingsManager.shared.keepRunningInMenuBar
+    }
+
+    func applicationShouldHandleReopen(_ sender: NSApplication, hasVisibleWindows flag: Bool) -> Bool {
+        if !flag { showMainWindow() }
         return true
     }
 
+    // MARK: - Menu Bar Item
+
+    private func updateMenuBarItem() {
+        guard AppSettingsManager.shared.keepRunningInMenuBar else {
+            menuBarController?.remove()
+            menuBarController = nil
+            return
+        }
+        guard menuBarController == nil else { return }
+
+        let controller = MenuBarController()
+        controller.onShowWindow = { [weak self] in
+            self?.showMainWindow()
+        }
+        controller.onNewAgent = { [weak self] in
+            self?.showMainWindow()
+            (self?.window?.contentViewController as? DashboardSplitViewController)?.showCreationMenu()
+        }
+        controller.runningAgentCount = { [weak self] in
+            self?.runningAgentCounts().values.reduce(0, +) ?? 0
+        }
+        menuBarController = controller
+    }
+
+    private func showMainWindow() {
+        window?.makeKeyAndOrderFront(nil)
+        NSApp.activate(ignoringOtherApps: true)
+    }
+
     func applicationSupportsSecureRestorableState(_ app: NSApplication) -> Bool {
         return false
     }
@@ -470,6 +970,24 @@ class AppDelegate: NSObject, NSApplicationDelegate, NSMenuItemValidation {
         case kMenuTagSplitRight:
             guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return false }
             return splitVC.canSplitFocusedPane(direction: .vertical)
+        case kMenuTagTogglePaneZoom:
+            guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return false }
+            return splitVC.content.isGridMode
+        case kMenuTagFocusPane1...kMenuTagFocusPane6:
+            guard let splitVC = window?.contentViewController as? DashboardSplitViewController,
+                  let grid = splitVC.content.paneGrid, splitVC.content.isGridMode else { return false }
+            return menuItem.tag - kMenuTagFocusPane1 < grid.root.leafCount
+        case kMenuTagSettings, kMenuTagQuickSwitcher:
+            return window?.contentViewController is DashboardSplitViewController
+        case kMenuTagExportReport:
+            return !OpenProjects.shared.projects.isEmpty
+        case kMenuTagNavigateBack:
+            return (window?.contentViewController as? DashboardSplitViewController)?.canNavigateBack ?? false
+        case kMenuTagNavigateForward:
+            return (window?.contentViewController as? DashboardSplitViewController)?.canNavigateForward ?? false
+        case kMenuTagRestartAgent, kMenuTagKillAgent:
+            guard let splitVC = window?.contentViewController as? DashboardSplitViewController else { return false }
+            return splitVC.focusedManifestAgent != nil
         default:
             return true
         }
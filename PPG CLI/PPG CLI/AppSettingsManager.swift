@@ -4,10 +4,14 @@ extension Notification.Name {
     static let appSettingsDidChange = Notification.Name("PPGAppSettingsDidChange")
 }
 
-enum AppSettingsKey: String {
+enum AppSettingsKey: String, CaseIterable {
     case refreshInterval
-    case terminalFont, terminalFontSize, shell, historyLimit
-    case appearanceMode
+    case terminalFont, terminalFontSize, shell, historyLimit, maxVisiblePanes, minPaneWidth, autoOpenSpawnedAgents
+    case stalledAgentMinutes
+    case appearanceMode, compactSidebarRows
+    case restoreLastSelection, autoContinueSetup, keepRunningInMenuBar, confirmQuitWithRunningAgents
+    case agentCommands
+    case preferredTerminal
 }
 
 enum AppearanceMode: String {
@@ -25,7 +29,7 @@ enum AppearanceMode: String {
 final class AppSettingsManager {
     static let shared = AppSettingsManager()
 
-    private let defaults = UserDefaults.standard
+    private let defaults: UserDefaults
 
     // MARK: - Keys
 
@@ -35,7 +39,22 @@ final class AppSettingsManager {
         static let terminalFontSize = "PPGTerminalFontSize"
         static let shell = "PPGShell"
         static let historyLimit = "PPGHistoryLimit"
+        static let maxVisiblePanes = "PPGMaxVisiblePanes"
+        static let minPaneWidth = "PPGMinPaneWidth"
+        static let autoOpenSpawnedAgents = "PPGAutoOpenSpawnedAgents"
+        static let stalledAgentMinutes = "PPGStalledAgentMinutes"
+        static let preservedImportFields = "PPGPreservedImportFields"
+        static let restoreLastSelection = "PPGRestoreLastSelection"
+        static let lastSelectedItemId = "PPGLastSelectedItemId"
+        static let agentCommands = "PPGAgentCommands"
+        static let autoContinueSetup = "PPGAutoContinueSetup"
+        static let skipSetupChecks = "PPGSkipSetupChecks"
+        static let strictManifestParsing = "PPGStrictManifestParsing"
+        static let keepRunningInMenuBar = "PPGKeepRunningInMenuBar"
+        static let confirmQuitWithRunningAgents = "PPGConfirmQuitWithRunningAgents"
         static let appearanceMode = "PPGAppearanceMode"
+        static let compactSidebarRows = "PPGCompactSidebarRows"
+        static let preferredTerminal = "PPGPreferredTerminal"
     }
 
     // MARK: - Defaults
@@ -45,8 +64,25 @@ final class AppSettingsManager {
     static let defaultTerminalFontSize: CGFloat = 13.0
     static let defaultShell = "/bin/zsh"
     static let defaultHistoryLimit = 50000
+    static let defaultMaxVisiblePanes = PaneSplitNode.maxLeaves
+    /// The grid itself tops out at 2 × 3 panes (see `PaneSplitNode.canSplit`), so a
+    /// limit above six would have nowhere to put the extra panes.
+    static let maxVisiblePanesRange = 1...PaneSplitNode.maxLeaves
+    static let defaultMinPaneWidth = 480
+    static let minPaneWidthRange = 240...1200
+    static let defaultAutoOpenSpawnedAgents = false
+    static let defaultStalledAgentMinutes = 15
+    static let stalledAgentMinutesRange = 5...240
+    static let defaultCompactSidebarRows = false
+    static let defaultRestoreLastSelection = false
+    static let defaultAutoContinueSetup = false
+    static let defaultKeepRunningInMenuBar = false
+    static let defaultConfirmQuitWithRunningAgents = true
 
-    private init() {}
+    /// The app uses `shared`; tests pass a suite of their own.
+    init(defaults: UserDefaults = .standard) {
+        self.defaults = defaults
+    }
 
     // MARK: - Properties
 
@@ -84,6 +120,125 @@ final class AppSettingsManager {
         set { defaults.set(newValue, forKey: Key.historyLimit); notify(.historyLimit) }
     }
 
+    /// How many of a worktree's agents "Show Terminals" opens at once. The rest stay
+    /// unattached until swapped in, so each extra agent costs nothing until viewed.
+    var maxVisiblePanes: Int {
+        get {
+            let val = defaults.integer(forKey: Key.maxVisiblePanes)
+            guard val > 0 else { return Self.defaultMaxVisiblePanes }
+            return min(max(val, Self.maxVisiblePanesRange.lowerBound), Self.maxVisiblePanesRange.upperBound)
+        }
+        set { defaults.set(newValue, forKey: Key.maxVisiblePanes); notify(.maxVisiblePanes) }
+    }
+
+    /// Narrowest a grid pane gets before the grid drops to fewer columns, in points.
+    var minPaneWidth: Int {
+        get {
+            let val = defaults.integer(forKey: Key.minPaneWidth)
+            guard val > 0 else { return Self.defaultMinPaneWidth }
+            return min(max(val, Self.minPaneWidthRange.lowerBound), Self.minPaneWidthRange.upperBound)
+        }
+        set { defaults.set(newValue, forKey: Key.minPaneWidth); notify(.minPaneWidth) }
+    }
+
+    /// Minutes without output after which a running agent is marked stalled.
+    var stalledAgentMinutes: Int {
+        get {
+            let val = defaults.integer(forKey: Key.stalledAgentMinutes)
+            guard val > 0 else { return Self.defaultStalledAgentMinutes }
+            return min(max(val, Self.stalledAgentMinutesRange.lowerBound), Self.stalledAgentMinutesRange.upperBound)
+        }
+        set { defaults.set(newValue, forKey: Key.stalledAgentMinutes); notify(.stalledAgentMinutes) }
+    }
+
+    /// Whether a newly added agent is selected as soon as it appears in the sidebar.
+    var autoOpenSpawnedAgents: Bool {
+        get { defaults.object(forKey: Key.autoOpenSpawnedAgents) as? Bool ?? Self.defaultAutoOpenSpawnedAgents }
+        set { defaults.set(newValue, forKey: Key.autoOpenSpawnedAgents); notify(.autoOpenSpawnedAgents) }
+    }
+
+    /// Keep sidebar worktree rows to one line, without the branch name underneath.
+    var compactSidebarRows: Bool {
+        get { defaults.object(forKey: Key.compactSidebarRows) as? Bool ?? Self.defaultCompactSidebarRows }
+        set { defaults.set(newValue, forKey: Key.compactSidebarRows); notify(.compactSidebarRows) }
+    }
+
+    /// Reopen the last selected sidebar item on launch instead of the home dashboard.
+    var restoreLastSelection: Bool {
+        get { defaults.object(forKey: Key.restoreLastSelection) as? Bool ?? Self.defaultRestoreLastSelection }
+        set { defaults.set(newValue, forKey: Key.restoreLastSelection); notify(.restoreLastSelection) }
+    }
+
+    /// Leave the setup screen on its own a few seconds after every check passes.
+    var autoContinueSetup: Bool {
+        get { defaults.object(forKey: Key.autoContinueSetup) as? Bool ?? Self.defaultAutoContinueSetup }
+        set { defaults.set(newValue, forKey: Key.autoContinueSetup); notify(.autoContinueSetup) }
+    }
+
+    /// Closing the window hides it and leaves a menu bar item, instead of quitting.
+    var keepRunningInMenuBar: Bool {
+        get { defaults.object(forKey: Key.keepRunningInMenuBar) as? Bool ?? Self.defaultKeepRunningInMenuBar }
+        set { defaults.set(newValue, forKey: Key.keepRunningInMenuBar); notify(.keepRunningInMenuBar) }
+    }
+
+    /// Ask before quitting while agents are running, offering to kill them too.
+    var confirmQuitWithRunningAgents: Bool {
+        get { defaults.object(forKey: Key.confirmQuitWithRunningAgents) as? Bool ?? Self.defaultConfirmQuitWithRunningAgents }
+        set { defaults.set(newValue, forKey: Key.confirmQuitWithRunningAgents); notify(.confirmQuitWithRunningAgents) }
+    }
+
+    /// `ExternalTerminal` raw value for commands opened outside the app; empty means
+    /// automatic (Terminal). A terminal that isn't installed also falls back to Terminal.
+    var preferredTerminal: String {
+        get { defaults.string(forKey: Key.preferredTerminal) ?? "" }
+        set { defaults.set(newValue, forKey: Key.preferredTerminal); notify(.preferredTerminal) }
+    }
+
+    /// Set by "Skip Checks" on the setup screen: launch straight into the app without
+    /// checking for ppg and tmux. Per-machine, so it isn't part of settings export.
+    var skipSetupChecks: Bool {
+        get { defaults.bool(forKey: Key.skipSetupChecks) }
+        set { defaults.set(newValue, forKey: Key.skipSetupChecks) }
+    }
+
+    /// Development aid: drop a whole manifest on any decoding error instead of salvaging
+    /// readable entries. No UI; set with `defaults write com.2wit.PPG-CLI PPGStrictManifestParsing -bool YES`.
+    var strictManifestParsing: Bool {
+        get { defaults.bool(forKey: Key.strictManifestParsing) }
+        set { defaults.set(newValue, forKey: Key.strictManifestParsing) }
+    }
+
+    /// Sidebar item ID selected most recently; nil when the last view was a dashboard page.
+    /// Recorded regardless of `restoreLastSelection` so turning it on takes effect next launch.
+    var lastSelectedItemId: String? {
+        get { defaults.string(forKey: Key.lastSelectedItemId) }
+        set { defaults.set(newValue, forKey: Key.lastSelectedItemId) }
+    }
+
+    /// Replacement launch commands keyed by `AgentVariant.id`. Variants without an
+    /// entry use their built-in `defaultCommand`.
+    var agentCommandOverrides: [String: String] {
+        get { defaults.dictionary(forKey: Key.agentCommands) as? [String: String] ?? [:] }
+        set {
+            if newValue.isEmpty {
+                defaults.removeObject(forKey: Key.agentCommands)
+            } else {
+                defaults.set(newValue, forKey: Key.agentCommands)
+            }
+            notify(.agentCommands)
+        }
+    }
+
+    func setAgentCommand(_ command: String?, for variant: AgentVariant) {
+        var overrides = agentCommandOverrides
+        if let command, !command.isEmpty, command != variant.defaultCommand {
+            overrides[variant.id] = command
+        } else {
+            overrides.removeValue(forKey: variant.id)
+        }
+        agentCommandOverrides = overrides
+    }
+
     var appearanceMode: AppearanceMode {
         get {
             guard let raw = defaults.string(forKey: Key.appearanceMode) else { return .system }
@@ -113,6 +268,125 @@ final class AppSettingsManager {
         }
     }
 
+    // MARK: - Import / Export
+
+    /// Current values keyed by `AppSettingsKey` raw value, as written to a settings export.
+    func exportedSettings() -> [String: Any] {
+        [
+            AppSettingsKey.refreshInterval.rawValue: refreshInterval,
+            AppSettingsKey.terminalFont.rawValue: terminalFontName,
+            AppSettingsKey.terminalFontSize.rawValue: Double(terminalFontSize),
+            AppSettingsKey.shell.rawValue: shell,
+            AppSettingsKey.historyLimit.rawValue: historyLimit,
+            AppSettingsKey.appearanceMode.rawValue: appearanceMode.rawValue,
+            AppSettingsKey.compactSidebarRows.rawValue: compactSidebarRows,
+            AppSettingsKey.maxVisiblePanes.rawValue: maxVisiblePanes,
+            AppSettingsKey.minPaneWidth.rawValue: minPaneWidth,
+            AppSettingsKey.autoOpenSpawnedAgents.rawValue: autoOpenSpawnedAgents,
+            AppSettingsKey.stalledAgentMinutes.rawValue: stalledAgentMinutes,
+            AppSettingsKey.restoreLastSelection.rawValue: restoreLastSelection,
+            AppSettingsKey.autoContinueSetup.rawValue: autoContinueSetup,
+            AppSettingsKey.keepRunningInMenuBar.rawValue: keepRunningInMenuBar,
+            AppSettingsKey.confirmQuitWithRunningAgents.rawValue: confirmQuitWithRunningAgents,
+            AppSettingsKey.agentCommands.rawValue: agentCommandOverrides,
+            AppSettingsKey.preferredTerminal.rawValue: preferredTerminal,
+        ]
+    }
+
+    /// Apply values already checked by `SettingsBundle.validatedValue`. Each setter
+    /// posts its own change notification so open views update live.
+    func applyImported(_ values: [AppSettingsKey: Any]) {
+        for (key, value) in values {
+            switch key {
+            case .refreshInterval: if let v = value as? Double { refreshInterval = v }
+            case .terminalFont: if let v = value as? String { terminalFontName = v }
+            case .terminalFontSize: if let v = value as? Double { terminalFontSize = CGFloat(v) }
+            case .shell: if let v = value as? String { shell = v }
+            case .historyLimit: if let v = value as? Int { historyLimit = v }
+            case .appearanceMode: if let v = value as? AppearanceMode { appearanceMode = v }
+            case .compactSidebarRows: if let v = value as? Bool { compactSidebarRows = v }
+            case .maxVisiblePanes: if let v = value as? Int { maxVisiblePanes = v }
+            case .minPaneWidth: if let v = value as? Int { minPaneWidth = v }
+            case .autoOpenSpawnedAgents: if let v = value as? Bool { autoOpenSpawnedAgents = v }
+            case .stalledAgentMinutes: if let v = value as? Int { stalledAgentMinutes = v }
+            case .restoreLastSelection: if let v = value as? Bool { restoreLastSelection = v }
+            case .autoContinueSetup: if let v = value as? Bool { autoContinueSetup = v }
+            case .keepRunningInMenuBar: if let v = value as? Bool { keepRunningInMenuBar = v }
+            case .confirmQuitWithRunningAgents: if let v = value as? Bool { confirmQuitWithRunningAgents = v }
+            case .agentCommands: if let v = value as? [String: String] { agentCommandOverrides = v }
+            case .preferredTerminal: if let v = value as? String { preferredTerminal = v }
+            }
+        }
+    }
+
+    /// Fields from a newer app version's export, kept so re-exporting doesn't drop them.
+    var preservedImportFields: [String: Any] {
+        get {
+            guard let data = defaults.data(forKey: Key.preservedImportFields),
+                  let fields = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any] else { return [:] }
+            return fields
+        }
+        set {
+            if newValue.isEmpty {
+                defaults.removeObject(forKey: Key.preservedImportFields)
+            } else if let data = try? JSONSerialization.data(withJSONObject: newValue) {
+                defaults.set(data, forKey: Key.preservedImportFields)
+            }
+        }
+    }
+
+    // MARK: - Reset
+
+    /// The Terminal tab's terminal and pane settings: how terminals look and run, and how
+    /// many panes a grid shows at what width. Auto-open is a behavior setting and stays out.
+    static let terminalAndPaneKeys: [AppSettingsKey] = [
+        .terminalFont, .terminalFontSize, .shell, .historyLimit, .maxVisiblePanes, .minPaneWidth,
+    ]
+
+    /// Settings restored by "Reset Appearance & Terminal".
+    static let appearanceAndTerminalKeys: [AppSettingsKey] = [.appearanceMode, .compactSidebarRows] + terminalAndPaneKeys
+
+    /// Put `keys` back to their defaults, notifying for each so open views and
+    /// terminals pick up the change without a restart.
+    func reset(_ keys: [AppSettingsKey]) {
+        for key in keys {
+            defaults.removeObject(forKey: storageKey(for: key))
+            if key == .appearanceMode {
+                applyAppearance(.system)
+            }
+            notify(key)
+        }
+    }
+
+    /// Every setting, plus any fields held over from an import.
+    func resetAll() {
+        reset(AppSettingsKey.allCases)
+        preservedImportFields = [:]
+        skipSetupChecks = false
+    }
+
+    private func storageKey(for key: AppSettingsKey) -> String {
+        switch key {
+        case .refreshInterval: return Key.refreshInterval
+        case .terminalFont: return Key.terminalFont
+        case .terminalFontSize: return Key.terminalFontSize
+        case .shell: return Key.shell
+        case .historyLimit: return Key.historyLimit
+        case .maxVisiblePanes: return Key.maxVisiblePanes
+        case .minPaneWidth: return Key.minPaneWidth
+        case .autoOpenSpawnedAgents: return Key.autoOpenSpawnedAgents
+        case .stalledAgentMinutes: return Key.stalledAgentMinutes
+        case .appearanceMode: return Key.appearanceMode
+        case .compactSidebarRows: return Key.compactSidebarRows
+        case .restoreLastSelection: return Key.restoreLastSelection
+        case .autoContinueSetup: return Key.autoContinueSetup
+        case .keepRunningInMenuBar: return Key.keepRunningInMenuBar
+        case .confirmQuitWithRunningAgents: return Key.confirmQuitWithRunningAgents
+        case .agentCommands: return Key.agentCommands
+        case .preferredTerminal: return Key.preferredTerminal
+        }
+    }
+
     // MARK: - Notification
 
     static let changedKeyUserInfoKey = "PPGChangedSettingsKey"
@@ -0,0 +1,38 @@
+import Foundation
+
+/// When the pane grid's auto-follow moves to another agent. The grid polls output
+/// times; this decides whether they warrant a switch, keeping the view from jumping
+/// around faster than anyone can read it or while the user is working in a pane.
+nonisolated struct AutoFollow {
+    /// Shortest time between two switches, so a chatty fan-out doesn't flicker.
+    static let switchInterval: TimeInterval = 5
+    /// How long clicking or typing in a pane holds auto-follow back.
+    static let pauseDuration: TimeInterval = 60
+
+    private(set) var lastSwitch: Date?
+    private(set) var pausedUntil: Date?
+
+    /// Hold back after manual interaction; interacting again restarts the pause.
+    mutating func pause(at now: Date) {
+        pausedUntil = now.addingTimeInterval(Self.pauseDuration)
+    }
+
+    /// Whole seconds left on the pause, for the countdown; nil when not paused.
+    func pauseRemaining(at now: Date) -> Int? {
+        guard let pausedUntil, pausedUntil > now else { return nil }
+        return Int(pausedUntil.timeIntervalSince(now).rounded(.up))
+    }
+
+    /// The entry to switch to, given each entry's latest output time: the newest one,
+    /// unless the current entry is at least as recent or a switch isn't allowed yet.
+    /// Equal times go to the lower ID so the choice doesn't depend on dictionary order.
+    mutating func nextEntry(lastOutput: [String: Date], current: String?, now: Date) -> String? {
+        guard pauseRemaining(at: now) == nil else { return nil }
+        if let lastSwitch, now.timeIntervalSince(lastSwitch) < Self.switchInterval { return nil }
+        guard let newest = lastOutput.min(by: { $0.value != $1.value ? $0.value > $1.value : $0.key < $1.key }),
+              newest.key != current else { return nil }
+        if let current, let currentOutput = lastOutput[current], currentOutput >= newest.value { return nil }
+        lastSwitch = now
+        return newest.key
+    }
+}
@@ -0,0 +1,141 @@
+import AppKit
+
+// MARK: - CommandFailure
+
+/// A failed `ppg` command as reported to the user. With `--json`, ppg prints
+/// `{"error": …, "code": …}` as the last line of stderr; anything printed before it
+/// (hook output, git's conflict list) is kept as extra output for the details view.
+nonisolated struct CommandFailure: Equatable {
+    /// Messages longer than this, or spanning several lines, get a Details button.
+    static let summaryLimit = 120
+
+    /// The command as the user would type it, e.g. "ppg kill --agent ag-1 --json".
+    let command: String
+    let exitCode: Int32
+    let message: String
+    /// ppg's error code (e.g. "WORKTREE_NOT_FOUND"), when it printed one.
+    let code: String?
+    /// stderr lines other than the JSON error.
+    let extraOutput: String
+    let stdout: String
+    let stderr: String
+    let occurredAt: Date
+
+    init(args: String, result: PPGService.CommandResult, at date: Date = Date()) {
+        command = "ppg \(args)"
+        exitCode = result.exitCode
+        stdout = result.stdout
+        stderr = result.stderr
+        occurredAt = date
+
+        var lines = result.stderr.components(separatedBy: "\n")
+        while let last = lines.last, last.trimmingCharacters(in: .whitespaces).isEmpty {
+            lines.removeLast()
+        }
+        if let last = lines.last, let error = Self.parseJSONError(last) {
+            message = error.message
+            code = error.code == "UNKNOWN" ? nil : error.code
+            extraOutput = lines.dropLast().joined(separator: "\n").trimmingCharacters(in: .whitespacesAndNewlines)
+        } else {
+            let raw = result.stderr.isEmpty ? result.stdout : result.stderr
+            message = raw.trimmingCharacters(in: .whitespacesAndNewlines)
+            code = nil
+            extraOutput = ""
+        }
+    }
+
+    private static func parseJSONError(_ line: String) -> (message: String, code: String?)? {
+        guard let data = line.data(using: .utf8),
+              let json = try? JSONSerialization.jsonObject(with: data) as? [String: Any],
+              let message = json["error"] as? String else { return nil }
+        return (message, json["code"] as? String)
+    }
+
+    /// Whether the message is too long or too structured for an alert's text.
+    var needsDetails: Bool {
+        message.count > Self.summaryLimit || message.contains("\n") || !extraOutput.isEmpty
+    }
+
+    /// The message's first line, cut to `summaryLimit`.
+    var summary: String {
+        let firstLine = message.components(separatedBy: "\n").first ?? ""
+        if firstLine.isEmpty { return "ppg exited with code \(exitCode)." }
+        guard firstLine.count > Self.summaryLimit else { return firstLine }
+        return String(firstLine.prefix(Self.summaryLimit - 1)).trimmingCharacters(in: .whitespaces) + "…"
+    }
+
+    /// Everything the details view shows, as plain text for the clipboard.
+    func detailsText() -> String {
+        var lines = [
+            "Command: \(command)",
+            "Exit code: \(exitCode)",
+        ]
+        if let code {
+            lines.append("Error code: \(code)")
+        }
+        lines.append("Time: \(ISO8601DateFormatter().string(from: occurredAt))")
+        lines += ["", message.isEmpty ? summary : message]
+        if !extraOutput.isEmpty {
+            lines += ["", "── Output ──", extraOutput]
+        }
+        let trimmedStdout = stdout.trimmingCharacters(in: .whitespacesAndNewlines)
+        if !trimmedStdout.isEmpty, trimmedStdout != message {
+            lines += ["", "── stdout ──", trimmedStdout]
+        }
+        return lines.joined(separator: "\n") + "\n"
+    }
+}
+
+// MARK: - CommandFailureAlert
+
+/// Alerts for failed `ppg` commands. Short errors read in full in the alert; long or
+/// multi-line ones are summarized, with a Details button for the complete output.
+enum CommandFailureAlert {
+    static func show(title: String, args: String, result: PPGService.CommandResult) {
+        show(title: title, failure: CommandFailure(args: args, result: result))
+    }
+
+    static func show(title: String, failure: CommandFailure) {
+        let alert = NSAlert()
+        alert.messageText = title
+        alert.informativeText = failure.summary
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "OK")
+        if failure.needsDetails {
+            alert.addButton(withTitle: "Details…")
+        }
+        if alert.runModal() == .alertSecondButtonReturn {
+            showDetails(title: title, failure: failure)
+        }
+    }
+
+    static func showDetails(title: String, failure: CommandFailure) {
+        let formatter = DateFormatter()
+        formatter.dateStyle = .none
+        formatter.timeStyle = .medium
+
+        let alert = NSAlert()
+        alert.messageText = title
+        alert.informativeText = "\(failure.command)\nExit code \(failure.exitCode) at \(formatter.string(from: failure.occurredAt))"
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "Copy to Clipboard")
+        alert.addButton(withTitle: "Close")
+
+        let scrollView = NSScrollView(frame: NSRect(x: 0, y: 0, width: 520, height: 260))
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .bezelBorder
+        let textView = NSTextView(frame: scrollView.bounds)
+        textView.isEditable = false
+        textView.isSelectable = true
+        textView.font = .monospacedSystemFont(ofSize: 11, weight: .regular)
+        textView.string = failure.detailsText()
+        textView.autoresizingMask = [.width]
+        scrollView.documentView = textView
+        alert.accessoryView = scrollView
+
+        if alert.runModal() == .alertFirstButtonReturn {
+            NSPasteboard.general.clearContents()
+            NSPasteboard.general.setString(failure.detailsText(), forType: .string)
+        }
+    }
+}
@@ -94,12 +94,19 @@ class CommandPalettePanel: NSPanel {
     }
 
     @discardableResult
-    static func show(relativeTo window: NSWindow?, variants: [AgentVariant] = AgentVariant.allVariants, onSelect: @escaping (AgentVariant, String?) -> Void) -> CommandPalettePanel {
+    static func show(
+        relativeTo window: NSWindow?,
+        variants: [AgentVariant] = AgentVariant.allVariants,
+        preferredVariantId: String? = nil,
+        onSelect: @escaping (AgentVariant, String?) -> Void
+    ) -> CommandPalettePanel {
         let panel = CommandPalettePanel()
         guard let vc = panel.contentViewController as? CommandPaletteViewController else {
             return panel
         }
         vc.availableVariants = variants
+        vc.preferredVariantId = preferredVariantId
+        vc.reloadVariants()
         vc.onSelect = { variant, prompt in
             panel.dismiss()
             onSelect(variant, prompt)
@@ -131,6 +138,8 @@ class CommandPaletteViewController: NSViewController, NSTextFieldDelegate, NSTex
     private let scrollView = NSScrollView()
     private let tableView = NSTableView()
     var availableVariants: [AgentVariant] = AgentVariant.allVariants
+    /// Variant highlighted when the list is unfiltered, e.g. the project's `defaultAgent`.
+    var preferredVariantId: String?
     private var filteredVariants: [AgentVariant] = AgentVariant.allVariants
     private var selectedIndex = 0
 
@@ -348,10 +357,15 @@ class CommandPaletteViewController: NSViewController, NSTextFieldDelegate, NSTex
 
     // MARK: - Phase Transitions
 
+    /// Show `availableVariants` again after changing them.
+    func reloadVariants() {
+        showSelectionPhase()
+    }
+
     private func showSelectionPhase() {
         phase = .selection
         filteredVariants = availableVariants
-        selectedIndex = 0
+        selectedIndex = preferredIndex()
 
         searchField.stringValue = ""
         searchField.isHidden = false
@@ -364,6 +378,7 @@ class CommandPaletteViewController: NSViewController, NSTextFieldDelegate, NSTex
 
         tableView.reloadData()
         updateHighlight()
+        tableView.scrollRowToVisible(selectedIndex)
 
         resizePanel(rowCount: filteredVariants.count)
 
@@ -372,6 +387,10 @@ class CommandPaletteViewController: NSViewController, NSTextFieldDelegate, NSTex
         }
     }
 
+    private func preferredIndex() -> Int {
+        filteredVariants.firstIndex { $0.id == preferredVariantId } ?? 0
+    }
+
     private func showPromptPhase(variant: AgentVariant) {
         phase = .prompt(variant)
 
@@ -436,7 +455,7 @@ class CommandPaletteViewController: NSViewController, NSTextFieldDelegate, NSTex
                 $0.id.lowercased().contains(q)
             }
         }
-        selectedIndex = filteredVariants.isEmpty ? -1 : 0
+        selectedIndex = filteredVariants.isEmpty ? -1 : (q.isEmpty ? preferredIndex() : 0)
         tableView.reloadData()
         updateHighlight()
         resizePanel(rowCount: filteredVariants.count)
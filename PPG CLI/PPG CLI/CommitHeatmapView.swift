@@ -3,7 +3,7 @@ import AppKit
 /// GitHub-style commit heatmap: 7 rows (days) x 13 columns (weeks) = 91 days of history.
 class CommitHeatmapView: NSView {
 
-    struct HeatmapData {
+    nonisolated struct HeatmapData {
         /// Maps "YYYY-MM-DD" date strings to commit counts.
         let commitsByDate: [String: Int]
     }
@@ -65,11 +65,18 @@ class ContentViewController: NSViewController {
     private(set) var paneGrid: PaneGridController?  // currently visible grid
     private var gridsByEntry: [String: PaneGridController] = [:]  // all grids (active + suspended)
     private(set) var activeGridOwnerId: String?  // which entry owns the visible grid
+    private var settingsObserver: NSObjectProtocol?
     /// Called when a grid is permanently destroyed (exitGridMode or removeGrid). Parameter: owner entry ID.
     var onGridDestroyed: ((String) -> Void)?
     /// Called when a grid is suspended (navigate away). Parameters: owner entry ID, layout snapshot.
     var onGridSuspended: ((String, GridLayoutNode) -> Void)?
     var onCloseEntry: (() -> Void)?
+    /// Exit banner actions on a stopped agent's pane.
+    var onShowAgentLogs: ((AgentModel) -> Void)?
+    var onRestartAgent: ((AgentModel) -> Void)?
+    var onRestartAgentWithNewPrompt: ((AgentModel) -> Void)?
+    /// Whether an agent has a restart under way; its ended grid pane is kept for the replacement.
+    var hasPendingRestart: ((String) -> Bool)?
     var isGridMode: Bool {
         guard let grid = paneGrid else { return false }
         return grid.view.superview != nil && !grid.view.isHidden
@@ -130,10 +137,25 @@ class ContentViewController: NSViewController {
         containerView.onSplitHorizontal = { [weak self] in self?.splitPaneBelow() }
         containerView.onSplitVertical = { [weak self] in self?.splitPaneRight() }
         containerView.onClose = { [weak self] in self?.onCloseEntry?() }
+
+        // A new minimum pane width re-places the panes of every grid right away
+        settingsObserver = NotificationCenter.default.addObserver(
+            forName: .appSettingsDidChange, object: nil, queue: .main
+        ) { [weak self] notification in
+            guard let self,
+                  notification.userInfo?[AppSettingsManager.changedKeyUserInfoKey] as? AppSettingsKey == .minPaneWidth else { return }
+            let minPaneWidth = CGFloat(AppSettingsManager.shared.minPaneWidth)
+            for grid in self.gridsByEntry.values {
+                grid.minPaneWidth = minPaneWidth
+            }
+        }
     }
 
     deinit {
         evictionTimer?.invalidate()
+        if let observer = settingsObserver {
+            NotificationCenter.default.removeObserver(observer)
+        }
     }
 
     private func refreshAppearance() {
@@ -225,8 +247,16 @@ class ContentViewController: NSViewController {
             }
         }()
 
-        // Build a change fingerprint from mutable fields (status + label)
-        let changeKey = "\(agentStatus?.rawValue ?? "")-\(entry.label)"
+        let stalledSince: Date? = {
+            switch entry {
+            case .manifestAgent(let agent, _): return AgentActivity.shared.stalledSince(agent)
+            case .agentGroup(let agents, _, _): return agents.first.flatMap { AgentActivity.shared.stalledSince($0) }
+            case .sessionEntry: return nil
+            }
+        }()
+
+        // Build a change fingerprint from mutable fields (status + stalled + label)
+        let changeKey = "\(agentStatus?.rawValue ?? "")-\(stalledSince != nil)-\(entry.label)"
 
         // Always update eviction tracking regardless of dedup
         if let status = agentStatus {
@@ -255,12 +285,14 @@ class ContentViewController: NSViewController {
         switch entry {
         case .manifestAgent(let agent, _):
             if let pane = terminalViews[agent.id] as? TerminalPane {
-                pane.updateStatus(agent.status)
+                pane.updateStatus(agent.status, rawStatus: agent.rawStatus, exitCode: agent.exitCode, completedAt: agent.completedDate,
+                                  stalledSince: stalledSince)
             }
         case .agentGroup(let agents, _, _):
             if let pane = terminalViews[entry.id] as? TerminalPane {
-                let status = agents.first?.status ?? .lost
-                pane.updateStatus(status)
+                let lead = agents.first
+                pane.updateStatus(lead?.status ?? .lost, rawStatus: lead?.rawStatus, exitCode: lead?.exitCode, completedAt: lead?.completedDate,
+                                  stalledSince: stalledSince)
             }
         case .sessionEntry:
             break
@@ -283,7 +315,9 @@ class ContentViewController: NSViewController {
         }
     }
 
-    func clearStaleViews(validIds: Set<String>) {
+    /// `endedAgentIds` are agents that still exist but are gone from tmux; grid panes
+    /// showing them are retired after a short "agent ended" overlay.
+    func clearStaleViews(validIds: Set<String>, endedAgentIds: Set<String> = []) {
         let staleIds = terminalViews.keys.filter { !validIds.contains($0) }
         for id in staleIds {
             if let termView = terminalViews[id] {
@@ -298,6 +332,11 @@ class ContentViewController: NSViewController {
         for id in staleGridIds {
             removeGrid(forEntryId: id)
         }
+        // Surviving grids may still hold panes for agents that were killed or deleted
+        for grid in gridsByEntry.values {
+            grid.evictEntries(notIn: validIds)
+            grid.endEntries(agentIds: endedAgentIds)
+        }
     }
 
     // MARK: - Grid Mode
@@ -320,6 +359,8 @@ class ContentViewController: NSViewController {
                 self.terminalViews.removeValue(forKey: id)
             }
         }
+        grid.hasPendingRestart = { [weak self] agentId in self?.hasPendingRestart?(agentId) == true }
+        grid.minPaneWidth = CGFloat(AppSettingsManager.shared.minPaneWidth)
         gridsByEntry[entryId] = grid
         return grid
     }
@@ -396,15 +437,19 @@ class ContentViewController: NSViewController {
         paneGrid?.moveFocus(direction, forward: forward)
     }
 
+    func togglePaneZoom() {
+        guard isGridMode else { return }
+        paneGrid?.toggleZoom()
+    }
+
     func exitGridMode() {
         guard let grid = paneGrid else { return }
         let ownerId = activeGridOwnerId
 
         // Restore the focused entry back into single-pane mode
         if let entry = grid.focusedEntry {
-            // Extract the terminal view from the focused cell so we can reuse it
-            let focusedCell = grid.cellViews[grid.focusedLeafId]
-            let existingView = focusedCell?.detachTerminalView()
+            // Extract the focused terminal view so we can reuse it, from its own window if detached
+            let existingView = grid.takeTerminalView(leafId: grid.focusedLeafId)
 
             grid.view.removeFromSuperview()
             grid.removeFromParent()
@@ -460,7 +505,7 @@ class ContentViewController: NSViewController {
     func suspendGrid() {
         guard let grid = paneGrid, let ownerId = activeGridOwnerId else { return }
         // Save layout before detaching
-        onGridSuspended?(ownerId, grid.root.toLayoutNode())
+        onGridSuspended?(ownerId, grid.layoutNode())
         grid.view.removeFromSuperview()
         grid.removeFromParent()
         paneGrid = nil
@@ -579,10 +624,14 @@ class ContentViewController: NSViewController {
     func showWorktreeDetail(
         worktree: WorktreeModel,
         projectRoot: String,
+        sessionName: String = "",
+        notes: WorktreeNotes? = nil,
         onNewAgent: @escaping () -> Void,
         onNewTerminal: @escaping () -> Void,
         onNewWorktree: @escaping () -> Void,
-        onRenameWorktree: @escaping (String, String) -> Void
+        onRenameWorktree: @escaping (String, String) -> Bool,
+        onShowTerminals: (() -> Void)? = nil,
+        onRestartExitedAgents: (() -> Void)? = nil
     ) {
         homeDashboardView?.removeFromSuperview()
         promptsView?.removeFromSuperview()
@@ -606,11 +655,19 @@ class ContentViewController: NSViewController {
 
         detailView.configure(
             worktree: worktree,
+            sessionName: sessionName,
+            notes: notes,
             onNewAgent: onNewAgent,
             onNewTerminal: onNewTerminal,
             onNewWorktree: onNewWorktree
         )
         detailView.onRenameWorktree = onRenameWorktree
+        detailView.onShowTerminals = onShowTerminals
+        detailView.onRestartExitedAgents = onRestartExitedAgents
+        detailView.onShowAgentLogs = projectRoot.isEmpty ? nil : { agent in
+            LogViewerWindowController.show(agent: agent, projectRoot: projectRoot)
+        }
+        detailView.reloadBranchChanges()
 
         if detailView.superview != view {
             detailView.removeFromSuperview()
@@ -638,6 +695,7 @@ class ContentViewController: NSViewController {
         guard let detailView = worktreeDetailView, detailView.superview != nil else { return }
         let worktreePath = detailView.currentWorktreePath
         guard !worktreePath.isEmpty else { return }
+        detailView.reloadBranchChanges()
         DispatchQueue.global(qos: .utility).async {
             let diffData = WorktreeDetailView.fetchDiffData(worktreePath: worktreePath)
             DispatchQueue.main.async { [weak detailView] in
@@ -812,6 +870,11 @@ class ContentViewController: NSViewController {
         switch tab {
         case .manifestAgent(let agent, _):
             let pane = TerminalPane(agent: agent, sessionName: sessionName)
+            pane.onShowLogs = { [weak self] agent in self?.onShowAgentLogs?(agent) }
+            pane.onRestart = { [weak self] agent in self?.onRestartAgent?(agent) }
+            pane.onRestartWithNewPrompt = { [weak self] agent in self?.onRestartAgentWithNewPrompt?(agent) }
+            pane.updateStatus(agent.status, rawStatus: agent.rawStatus, exitCode: agent.exitCode, completedAt: agent.completedDate,
+                              stalledSince: AgentActivity.shared.stalledSince(agent))
             termView = pane
 
         case .agentGroup(let agents, let tmuxTarget, _):
@@ -826,6 +889,8 @@ class ContentViewController: NSViewController {
                 startedAt: lead.startedAt
             )
             let pane = TerminalPane(agent: groupAgent, sessionName: sessionName)
+            pane.updateStatus(lead.status, rawStatus: lead.rawStatus, exitCode: lead.exitCode, completedAt: lead.completedDate,
+                              stalledSince: AgentActivity.shared.stalledSince(lead))
             termView = pane
 
         case .sessionEntry(let entry, _):
@@ -955,6 +1020,9 @@ class HoverableContainerView: NSView {
             overlay.onSplitHorizontal = { [weak self] in self?.onSplitHorizontal?() }
             overlay.onSplitVertical = { [weak self] in self?.onSplitVertical?() }
             overlay.onClose = { [weak self] in self?.onClose?() }
+            overlay.onShowInfo = { [weak self] anchor in
+                self?.visibleAgentPane?.showInfoPopover(relativeTo: anchor)
+            }
             addSubview(overlay)
             overlay.updatePosition(in: bounds)
             hoverOverlay = overlay
@@ -962,9 +1030,16 @@ class HoverableContainerView: NSView {
         // Ensure overlay is above terminal views that may have been added after it
         hoverOverlay!.superview?.addSubview(hoverOverlay!, positioned: .above, relativeTo: nil)
         hoverOverlay?.updateSplitAvailability(canSplitH: true, canSplitV: true)
+        hoverOverlay?.updateInfo(for: visibleAgentPane)
+        hoverOverlay?.updatePosition(in: bounds)
         hoverOverlay?.animator().alphaValue = 1
     }
 
+    /// The agent terminal currently shown (cached panes for other entries stay hidden).
+    private var visibleAgentPane: TerminalPane? {
+        subviews.first { $0 is TerminalPane && !$0.isHidden } as? TerminalPane
+    }
+
     private func scheduleHide() {
         fadeOutWork?.cancel()
         let work = DispatchWorkItem { [weak self] in
@@ -1010,21 +1085,118 @@ class WorktreeDetailView: NSView {
         }
     }
 
+    /// Committed changes on the worktree branch relative to its base branch.
+    struct BranchChanges {
+        let baseBranch: String
+        let files: [FileStat]
+        /// Commits on the branch that aren't on the base, newest first.
+        var commits: [CommitInfo] = []
+        /// What git said when it couldn't compare, e.g. a base branch that was renamed.
+        var error: String?
+
+        struct FileStat {
+            let filename: String
+            let added: Int
+            let removed: Int
+        }
+
+        var totalAdded: Int { files.reduce(0) { $0 + $1.added } }
+        var totalRemoved: Int { files.reduce(0) { $0 + $1.removed } }
+
+        var summary: String {
+            if let error { return "Couldn't compare with \(baseBranch): \(error)" }
+            guard !files.isEmpty else { return "No changes vs \(baseBranch)" }
+            let fileWord = files.count == 1 ? "file" : "files"
+            return "\(files.count) \(fileWord) changed, +\(totalAdded) −\(totalRemoved) vs \(baseBranch)"
+        }
+
+        var commitsSummary: String {
+            if error != nil { return "Commits unavailable" }
+            guard !commits.isEmpty else { return "No commits yet — the agent hasn't committed anything" }
+            if commits.count >= WorktreeDetailView.maxBranchCommits {
+                return "Latest \(commits.count) commits ahead of \(baseBranch)"
+            }
+            let commitWord = commits.count == 1 ? "commit" : "commits"
+            return "\(commits.count) \(commitWord) ahead of \(baseBranch)"
+        }
+    }
+
+    /// Upper bound on commits fetched for the branch commit list.
+    static let maxBranchCommits = 50
+    /// Commits shown before the "Show more" expander is clicked.
+    static let collapsedCommitCount = 10
+
     private(set) var currentWorktreePath = ""
     private(set) var currentWorktreeId = ""
+    private(set) var currentBaseBranch = ""
+    private var currentCreatedAt = ""
+    private var currentCreatedDate: Date?
+    private var currentMergedAt: String?
+    private var currentMergedDate: Date?
+    private var currentTmuxWindow = ""
 
     // Header
     private let iconView = NSImageView()
     private let nameLabel = NSTextField()
     private let branchLabel = NSTextField(labelWithString: "")
+    private let createdLabel = NSTextField(labelWithString: "")
+    /// Keeps relative timestamps current while the view is on screen.
+    private var timestampTimer: Timer?
     private let shortcutLabel = NSTextField(labelWithString: "")
     private let agentButton = NSButton()
     private let terminalButton = NSButton()
     private let worktreeButton = NSButton()
+    private let attachButton = NSButton()
+    private let terminalsButton = NSButton()
+    private let logsButton = NSButton()
+    private let restartExitedButton = NSButton()
+    private var currentAgents: [AgentModel] = []
     private let headerStack = NSStackView()
+    private let renameButton = NSButton()
     private var nameBeforeEditing = ""
     private var isEditingName = false
 
+    // Branch changes (vs base branch)
+    private let branchChangesRow = NSStackView()
+    private let branchSummaryLabel = NSTextField(labelWithString: "")
+    private let branchFilesToggle = NSButton()
+    private let branchRefreshButton = NSButton()
+    private let branchFilesStack = NSStackView()
+    /// Per-worktree cache keyed by worktree ID; reused until HEAD moves.
+    private var branchChangesCache: [String: (head: String, changes: BranchChanges)] = [:]
+    /// Generation counter to discard fetches for a worktree that is no longer shown.
+    private var branchChangesGeneration = 0
+
+    // Branch commits (base..HEAD)
+    private let commitsRow = NSStackView()
+    private let commitsSummaryLabel = NSTextField(labelWithString: "")
+    private let commitsToggle = NSButton()
+    private let commitsStack = NSStackView()
+    private let showMoreCommitsButton = NSButton()
+    private var showAllCommits = false
+    private var currentCommits: [CommitInfo] = []
+
+    // Notes, kept locally per worktree and saved a second after typing stops
+    private let notesRow = NSStackView()
+    private let notesEditedLabel = NSTextField(labelWithString: "")
+    private let notesScrollView = NSScrollView()
+    private let notesTextView = NSTextView()
+    private var notesStore: WorktreeNotes?
+    private lazy var notesSaver = Debouncer(delay: 1, maxDelay: 10) { [weak self] in
+        self?.saveNote()
+    }
+
+    // Token and cost usage, when the CLI reports it
+    private let usageLabel = NSTextField(labelWithString: "")
+    private let usageStack = NSStackView()
+
+    // Advanced (raw tmux identifiers)
+    private let advancedRow = NSStackView()
+    private let advancedToggle = NSButton()
+    private let advancedStack = NSStackView()
+    /// Values behind each copy button, indexed by the button's tag.
+    private var advancedValues: [String] = []
+
     // Diff area
     private let scrollView = NSScrollView()
     private let diffStackView = NSStackView()
@@ -1033,7 +1205,10 @@ class WorktreeDetailView: NSView {
     var onNewAgent: (() -> Void)?
     var onNewTerminal: (() -> Void)?
     var onNewWorktree: (() -> Void)?
-    var onRenameWorktree: ((String, String) -> Void)?  // (worktreeId, newName)
+    var onRenameWorktree: ((String, String) -> Bool)?  // (worktreeId, newName) -> accepted
+    var onShowTerminals: (() -> Void)?
+    var onShowAgentLogs: ((AgentModel) -> Void)?
+    var onRestartExitedAgents: (() -> Void)?
 
     override init(frame frameRect: NSRect) {
         super.init(frame: frameRect)
@@ -1047,14 +1222,48 @@ class WorktreeDetailView: NSView {
 
     func configure(
         worktree: WorktreeModel,
+        sessionName: String = "",
+        notes: WorktreeNotes? = nil,
         onNewAgent: @escaping () -> Void,
         onNewTerminal: @escaping () -> Void,
         onNewWorktree: @escaping () -> Void
     ) {
+        if worktree.id != currentWorktreeId || notes !== notesStore {
+            saveNote()
+            notesStore = notes
+            notesTextView.string = notes?.note(for: worktree.id)?.text ?? ""
+        }
+        if worktree.id != currentWorktreeId {
+            branchFilesToggle.state = .off
+            branchFilesStack.isHidden = true
+            commitsToggle.state = .off
+            commitsStack.isHidden = true
+            showAllCommits = false
+        }
         currentWorktreePath = worktree.path
         currentWorktreeId = worktree.id
+        currentBaseBranch = worktree.baseBranch
         nameLabel.stringValue = worktree.name
         branchLabel.stringValue = worktree.branch
+        // The header shows no status; give VoiceOver the sidebar row's full description
+        iconView.setAccessibilityLabel(AccessibilityLabels.worktree(worktree))
+        currentCreatedAt = worktree.createdAt
+        currentCreatedDate = worktree.createdDate
+        currentMergedAt = worktree.mergedAt
+        currentMergedDate = worktree.mergedDate
+        currentTmuxWindow = worktree.tmuxWindow
+        attachButton.isHidden = worktree.tmuxWindow.isEmpty
+        terminalsButton.isHidden = worktree.agents.isEmpty
+        currentAgents = worktree.agents
+        logsButton.isHidden = worktree.agents.isEmpty
+        let exitedCount = BatchRestart.restartable(worktree.agents).count
+        restartExitedButton.isHidden = exitedCount == 0
+        restartExitedButton.toolTip = "Restart \(exitedCount) exited agent\(exitedCount == 1 ? "" : "s") with their original prompts"
+        notesRow.isHidden = notes == nil
+        notesScrollView.isHidden = notes == nil
+        updateUsage(worktree.agents)
+        updateAdvanced(Self.advancedEntries(for: worktree, sessionName: sessionName))
+        refreshTimestamps()
         shortcutLabel.stringValue = "Press \(KeybindingManager.shared.displayString(for: .newItem)) or:"
         self.onNewAgent = onNewAgent
         self.onNewTerminal = onNewTerminal
@@ -1086,8 +1295,306 @@ class WorktreeDetailView: NSView {
         }
     }
 
+    override func viewDidMoveToWindow() {
+        super.viewDidMoveToWindow()
+        timestampTimer?.invalidate()
+        timestampTimer = nil
+        guard window != nil else {
+            saveNote()
+            return
+        }
+        refreshTimestamps()
+        timestampTimer = Timer.scheduledTimer(withTimeInterval: 60, repeats: true) { [weak self] _ in
+            self?.refreshTimestamps()
+        }
+    }
+
+    private func refreshTimestamps() {
+        if let editedAt = notesStore?.note(for: currentWorktreeId)?.editedAt {
+            notesEditedLabel.stringValue = "Edited \(humanizeRelative(editedAt))"
+            notesEditedLabel.isHidden = false
+        } else {
+            notesEditedLabel.isHidden = true
+        }
+        guard let relative = currentCreatedDate.map({ humanizeRelative($0) }) else {
+            createdLabel.isHidden = true
+            return
+        }
+        createdLabel.isHidden = false
+        createdLabel.stringValue = "Created \(relative)"
+        createdLabel.toolTip = currentCreatedAt
+        if let mergedDate = currentMergedDate, let mergedAt = currentMergedAt {
+            createdLabel.stringValue += " · Merged \(humanizeRelative(mergedDate))"
+            createdLabel.toolTip = "Created \(currentCreatedAt)\nMerged \(mergedAt)"
+        }
+    }
+
+    /// Recompute the branch-vs-base summary off the main thread. Cached results are
+    /// shown immediately and only recomputed when the worktree's HEAD has moved.
+    func reloadBranchChanges(force: Bool = false) {
+        branchChangesGeneration += 1
+        let generation = branchChangesGeneration
+
+        guard !currentBaseBranch.isEmpty else {
+            branchChangesRow.isHidden = true
+            branchFilesStack.isHidden = true
+            commitsRow.isHidden = true
+            commitsStack.isHidden = true
+            return
+        }
+        branchChangesRow.isHidden = false
+        commitsRow.isHidden = false
+
+        let worktreeId = currentWorktreeId
+        let worktreePath = currentWorktreePath
+        let baseBranch = currentBaseBranch
+        let cached = force ? nil : branchChangesCache[worktreeId]
+        if let cached {
+            updateBranchChanges(cached.changes)
+        } else {
+            branchSummaryLabel.stringValue = "Computing changes vs \(baseBranch)…"
+            commitsSummaryLabel.stringValue = "Loading commits…"
+            commitsToggle.isHidden = true
+        }
+
+        DispatchQueue.global(qos: .utility).async { [weak self] in
+            let head = PPGService.shared.runGitCommand(["rev-parse", "HEAD"], cwd: worktreePath)
+                .stdout.trimmingCharacters(in: .whitespacesAndNewlines)
+            let changes: BranchChanges
+            if let cached, !head.isEmpty, cached.head == head {
+                changes = cached.changes
+            } else {
+                changes = Self.fetchBranchChanges(worktreePath: worktreePath, baseBranch: baseBranch)
+            }
+            DispatchQueue.main.async {
+                guard let self = self, self.branchChangesGeneration == generation else { return }
+                // An error may clear without HEAD moving (the base branch comes back), so don't keep it
+                if changes.error == nil {
+                    self.branchChangesCache[worktreeId] = (head, changes)
+                } else {
+                    self.branchChangesCache[worktreeId] = nil
+                }
+                self.updateBranchChanges(changes)
+            }
+        }
+    }
+
+    private func updateBranchChanges(_ changes: BranchChanges) {
+        branchSummaryLabel.stringValue = changes.summary
+        branchFilesToggle.isHidden = changes.files.isEmpty
+
+        for view in branchFilesStack.arrangedSubviews {
+            branchFilesStack.removeArrangedSubview(view)
+            view.removeFromSuperview()
+        }
+        for file in changes.files {
+            branchFilesStack.addArrangedSubview(makeFileStatRow(file))
+        }
+        branchFilesStack.isHidden = changes.files.isEmpty || branchFilesToggle.state == .off
+
+        currentCommits = changes.commits
+        commitsSummaryLabel.stringValue = changes.commitsSummary
+        commitsToggle.isHidden = changes.commits.isEmpty
+        rebuildCommitRows()
+    }
+
+    /// Label/value pairs for the Advanced section; empty values are omitted.
+    static func advancedEntries(for worktree: WorktreeModel, sessionName: String) -> [(label: String, value: String)] {
+        var entries: [(label: String, value: String)] = [
+            ("Session", sessionName),
+            ("Window", worktree.tmuxWindow),
+        ]
+        // Fields from a newer CLI that this build has no UI for yet
+        for (name, value) in worktree.extra.sorted(by: { $0.key < $1.key }) {
+            entries.append((name, value.displayString))
+        }
+        for agent in worktree.agents {
+            entries.append(("\(agent.name) target", agent.tmuxTarget))
+            entries.append(("\(agent.name) session ID", agent.sessionId ?? ""))
+            if !agent.tmuxTarget.isEmpty {
+                entries.append(("\(agent.name) attach", tmuxAttachPaneShellCommand(target: agent.tmuxTarget, unsynchronizePanes: true)))
+            }
+            for (name, value) in agent.extra.sorted(by: { $0.key < $1.key }) where !AgentUsage.manifestKeys.contains(name) {
+                entries.append(("\(agent.name) \(name)", value.displayString))
+            }
+        }
+        if !worktree.tmuxWindow.isEmpty {
+            entries.append(("Attach", tmuxAttachShellCommand(target: worktree.tmuxWindow)))
+        }
+        return entries.filter { !$0.value.isEmpty }
+    }
+
+    /// Write any pending edit to the note of the worktree shown.
+    private func saveNote() {
+        notesSaver.cancel()
+        guard let notesStore, !currentWorktreeId.isEmpty else { return }
+        notesStore.setText(notesTextView.string, for: currentWorktreeId)
+        refreshTimestamps()
+    }
+
+    /// Subtotal for the worktree plus a chip per agent that reports usage; hidden when none do.
+    private func updateUsage(_ agents: [AgentModel]) {
+        for view in usageStack.arrangedSubviews {
+            usageStack.removeArrangedSubview(view)
+            view.removeFromSuperview()
+        }
+        let total = AgentUsage.total(of: agents.map(\.usage))
+        usageLabel.stringValue = total.map { "Usage: \($0.text())" } ?? ""
+        usageLabel.isHidden = total == nil
+        for agent in agents {
+            guard let usage = agent.usage else { continue }
+            let nameField = NSTextField(labelWithString: agent.name.isEmpty ? agent.id : agent.name)
+            nameField.font = .systemFont(ofSize: 11)
+            nameField.textColor = .secondaryLabelColor
+            nameField.lineBreakMode = .byTruncatingTail
+            let row = NSStackView(views: [nameField, UsageChip(usage: usage)])
+            row.orientation = .horizontal
+            row.spacing = 8
+            row.alignment = .centerY
+            usageStack.addArrangedSubview(row)
+        }
+        usageStack.isHidden = usageStack.arrangedSubviews.isEmpty
+    }
+
+    private func updateAdvanced(_ entries: [(label: String, value: String)]) {
+        for view in advancedStack.arrangedSubviews {
+            advancedStack.removeArrangedSubview(view)
+            view.removeFromSuperview()
+        }
+        advancedValues = entries.map(\.value)
+        for (index, entry) in entries.enumerated() {
+            advancedStack.addArrangedSubview(makeAdvancedRow(label: entry.label, value: entry.value, index: index))
+        }
+        advancedRow.isHidden = entries.isEmpty
+        advancedStack.isHidden = entries.isEmpty || advancedToggle.state == .off
+    }
+
+    private func makeAdvancedRow(label: String, value: String, index: Int) -> NSView {
+        let monoFont = NSFont.monospacedSystemFont(ofSize: 11, weight: .regular)
+
+        let keyLabel = NSTextField(labelWithString: label)
+        keyLabel.font = monoFont
+        keyLabel.textColor = .secondaryLabelColor
+        keyLabel.lineBreakMode = .byTruncatingTail
+        keyLabel.isSelectable = true
+        keyLabel.widthAnchor.constraint(equalToConstant: 140).isActive = true
+
+        let valueLabel = NSTextField(labelWithString: value)
+        valueLabel.font = monoFont
+        valueLabel.textColor = Theme.primaryText
+        valueLabel.lineBreakMode = .byTruncatingMiddle
+        valueLabel.isSelectable = true
+        valueLabel.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        let copyButton = NSButton()
+        copyButton.bezelStyle = .accessoryBarAction
+        copyButton.isBordered = false
+        copyButton.image = NSImage(systemSymbolName: "doc.on.doc", accessibilityDescription: "Copy \(label)")
+        copyButton.imagePosition = .imageOnly
+        copyButton.contentTintColor = .secondaryLabelColor
+        copyButton.toolTip = "Copy"
+        copyButton.tag = index
+        copyButton.target = self
+        copyButton.action = #selector(copyAdvancedValue(_:))
+
+        let row = NSStackView(views: [keyLabel, valueLabel, copyButton])
+        row.orientation = .horizontal
+        row.spacing = 8
+        row.alignment = .centerY
+        return row
+    }
+
+    private func rebuildCommitRows() {
+        for view in commitsStack.arrangedSubviews {
+            commitsStack.removeArrangedSubview(view)
+            view.removeFromSuperview()
+        }
+        let visible = showAllCommits ? currentCommits : Array(currentCommits.prefix(Self.collapsedCommitCount))
+        for commit in visible {
+            commitsStack.addArrangedSubview(HomeDashboardView.makeCommitRow(commit))
+        }
+        let hiddenCount = currentCommits.count - visible.count
+        if hiddenCount > 0 {
+            showMoreCommitsButton.title = "Show \(hiddenCount) more"
+            commitsStack.addArrangedSubview(showMoreCommitsButton)
+        }
+        commitsStack.isHidden = currentCommits.isEmpty || commitsToggle.state == .off
+    }
+
+    private func makeFileStatRow(_ file: BranchChanges.FileStat) -> NSView {
+        let monoFont = NSFont.monospacedSystemFont(ofSize: 11, weight: .regular)
+
+        let addedLabel = NSTextField(labelWithString: "+\(file.added)")
+        addedLabel.font = monoFont
+        addedLabel.textColor = Theme.additionText
+        addedLabel.alignment = .right
+        addedLabel.widthAnchor.constraint(equalToConstant: 44).isActive = true
+
+        let removedLabel = NSTextField(labelWithString: "−\(file.removed)")
+        removedLabel.font = monoFont
+        removedLabel.textColor = Theme.deletionText
+        removedLabel.alignment = .right
+        removedLabel.widthAnchor.constraint(equalToConstant: 44).isActive = true
+
+        let nameField = NSTextField(labelWithString: file.filename)
+        nameField.font = monoFont
+        nameField.textColor = Theme.primaryText
+        nameField.lineBreakMode = .byTruncatingMiddle
+        nameField.isSelectable = true
+        nameField.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        let row = NSStackView(views: [addedLabel, removedLabel, nameField])
+        row.orientation = .horizontal
+        row.spacing = 8
+        row.alignment = .firstBaseline
+        return row
+    }
+
     // MARK: - Static Helpers
 
+    static func fetchBranchChanges(worktreePath: String, baseBranch: String) -> BranchChanges {
+        // Three-dot form diffs against the merge base, so commits landing on the
+        // base branch after the worktree was created don't show up as changes.
+        let result = PPGService.shared.runGitCommand(
+            ["diff", "--numstat", "\(baseBranch)...HEAD"], cwd: worktreePath
+        )
+        let log = PPGService.shared.runGitCommand(
+            ["log", "-\(maxBranchCommits)", HomeDashboardView.commitLogFormat, "\(baseBranch)..HEAD"],
+            cwd: worktreePath
+        )
+        return branchChanges(baseBranch: baseBranch, diff: result, log: log)
+    }
+
+    /// Build the summary from the diff and log results. Either failing (a missing or
+    /// renamed base branch, usually) is an error rather than an empty branch.
+    static func branchChanges(baseBranch: String, diff: PPGService.CommandResult, log: PPGService.CommandResult) -> BranchChanges {
+        for result in [diff, log] where result.exitCode != 0 {
+            let stderr = result.stderr.trimmingCharacters(in: .whitespacesAndNewlines)
+            let message = stderr.components(separatedBy: "\n").first { !$0.isEmpty }
+            return BranchChanges(baseBranch: baseBranch, files: [], error: message ?? "git exited with code \(result.exitCode)")
+        }
+        return BranchChanges(
+            baseBranch: baseBranch,
+            files: parseNumstat(diff.stdout),
+            commits: HomeDashboardView.parseCommitLog(log.stdout)
+        )
+    }
+
+    /// Parse `git diff --numstat` output. Binary files report "-" counts, which map to 0.
+    static func parseNumstat(_ output: String) -> [BranchChanges.FileStat] {
+        var files: [BranchChanges.FileStat] = []
+        for line in output.components(separatedBy: "\n") where !line.isEmpty {
+            let parts = line.split(separator: "\t", maxSplits: 2)
+            guard parts.count >= 3 else { continue }
+            files.append(BranchChanges.FileStat(
+                filename: String(parts[2]),
+                added: Int(parts[0]) ?? 0,
+                removed: Int(parts[1]) ?? 0
+            ))
+        }
+        return files
+    }
+
     static func fetchDiffData(worktreePath: String) -> DiffData {
         let service = PPGService.shared
 
@@ -1280,12 +1787,25 @@ class WorktreeDetailView: NSView {
         let clickGesture = NSClickGestureRecognizer(target: self, action: #selector(nameLabelClicked))
         nameLabel.addGestureRecognizer(clickGesture)
 
+        renameButton.bezelStyle = .accessoryBarAction
+        renameButton.isBordered = false
+        renameButton.image = NSImage(systemSymbolName: "pencil", accessibilityDescription: "Rename worktree")
+        renameButton.imagePosition = .imageOnly
+        renameButton.contentTintColor = .secondaryLabelColor
+        renameButton.toolTip = "Rename worktree"
+        renameButton.target = self
+        renameButton.action = #selector(nameLabelClicked)
+
         // Branch
         branchLabel.font = .systemFont(ofSize: 13)
         branchLabel.textColor = .secondaryLabelColor
         branchLabel.lineBreakMode = .byTruncatingTail
         branchLabel.translatesAutoresizingMaskIntoConstraints = false
 
+        createdLabel.font = .systemFont(ofSize: 11)
+        createdLabel.textColor = .tertiaryLabelColor
+        createdLabel.isHidden = true
+
         // Shortcut hint
         shortcutLabel.font = .systemFont(ofSize: 12)
         shortcutLabel.textColor = .tertiaryLabelColor
@@ -1303,8 +1823,32 @@ class WorktreeDetailView: NSView {
         worktreeButton.target = self
         worktreeButton.action = #selector(worktreeButtonClicked)
 
+        configureButton(terminalsButton, title: "Terminals", icon: "rectangle.split.3x1")
+        terminalsButton.toolTip = "Show this worktree's agents side by side"
+        terminalsButton.target = self
+        terminalsButton.action = #selector(terminalsButtonClicked)
+
+        configureButton(logsButton, title: "Logs", icon: "doc.text.magnifyingglass")
+        logsButton.toolTip = "View an agent's recent output"
+        logsButton.target = self
+        logsButton.action = #selector(logsButtonClicked(_:))
+
+        configureButton(restartExitedButton, title: "Restart Exited", icon: "arrow.clockwise")
+        restartExitedButton.target = self
+        restartExitedButton.action = #selector(restartExitedButtonClicked)
+
+        configureButton(attachButton, title: "Attach", icon: "arrow.up.forward.app")
+        attachButton.toolTip = "Open this worktree's tmux window in Terminal"
+        attachButton.target = self
+        attachButton.action = #selector(attachButtonClicked)
+
         // Header layout
-        let titleStack = NSStackView(views: [nameLabel, branchLabel])
+        let nameRow = NSStackView(views: [nameLabel, renameButton])
+        nameRow.orientation = .horizontal
+        nameRow.spacing = 4
+        nameRow.alignment = .centerY
+
+        let titleStack = NSStackView(views: [nameRow, branchLabel, createdLabel])
         titleStack.orientation = .vertical
         titleStack.alignment = .leading
         titleStack.spacing = 2
@@ -1314,17 +1858,161 @@ class WorktreeDetailView: NSView {
         buttonStack.spacing = 8
         buttonStack.alignment = .centerY
 
-        let topRow = NSStackView(views: [iconView, titleStack])
+        let topRow = NSStackView(views: [iconView, titleStack, terminalsButton, logsButton, restartExitedButton, attachButton])
         topRow.orientation = .horizontal
         topRow.spacing = 10
         topRow.alignment = .centerY
 
+        // Branch changes summary
+        branchSummaryLabel.font = .systemFont(ofSize: 12, weight: .medium)
+        branchSummaryLabel.textColor = .secondaryLabelColor
+        branchSummaryLabel.lineBreakMode = .byTruncatingTail
+
+        branchFilesToggle.bezelStyle = .disclosure
+        branchFilesToggle.setButtonType(.pushOnPushOff)
+        branchFilesToggle.title = ""
+        branchFilesToggle.state = .off
+        branchFilesToggle.toolTip = "Show changed files"
+        branchFilesToggle.setAccessibilityLabel("Show changed files")
+        branchFilesToggle.target = self
+        branchFilesToggle.action = #selector(branchFilesToggled)
+
+        branchRefreshButton.bezelStyle = .accessoryBarAction
+        branchRefreshButton.isBordered = false
+        branchRefreshButton.image = NSImage(systemSymbolName: "arrow.clockwise", accessibilityDescription: "Refresh changes")
+        branchRefreshButton.imagePosition = .imageOnly
+        branchRefreshButton.contentTintColor = .secondaryLabelColor
+        branchRefreshButton.toolTip = "Recompute changes"
+        branchRefreshButton.target = self
+        branchRefreshButton.action = #selector(branchRefreshClicked)
+
+        for view in [branchFilesToggle, branchSummaryLabel, branchRefreshButton] as [NSView] {
+            branchChangesRow.addArrangedSubview(view)
+        }
+        branchChangesRow.orientation = .horizontal
+        branchChangesRow.spacing = 6
+        branchChangesRow.alignment = .centerY
+        branchChangesRow.isHidden = true
+
+        branchFilesStack.orientation = .vertical
+        branchFilesStack.alignment = .leading
+        branchFilesStack.spacing = 2
+        branchFilesStack.isHidden = true
+
+        // Branch commits
+        commitsSummaryLabel.font = .systemFont(ofSize: 12, weight: .medium)
+        commitsSummaryLabel.textColor = .secondaryLabelColor
+        commitsSummaryLabel.lineBreakMode = .byTruncatingTail
+
+        commitsToggle.bezelStyle = .disclosure
+        commitsToggle.setButtonType(.pushOnPushOff)
+        commitsToggle.title = ""
+        commitsToggle.state = .off
+        commitsToggle.toolTip = "Show commits"
+        commitsToggle.setAccessibilityLabel("Show commits")
+        commitsToggle.target = self
+        commitsToggle.action = #selector(commitsToggled)
+
+        commitsRow.addArrangedSubview(commitsToggle)
+        commitsRow.addArrangedSubview(commitsSummaryLabel)
+        commitsRow.orientation = .horizontal
+        commitsRow.spacing = 6
+        commitsRow.alignment = .centerY
+        commitsRow.isHidden = true
+
+        showMoreCommitsButton.bezelStyle = .inline
+        showMoreCommitsButton.controlSize = .small
+        showMoreCommitsButton.target = self
+        showMoreCommitsButton.action = #selector(showMoreCommitsClicked)
+
+        commitsStack.orientation = .vertical
+        commitsStack.alignment = .leading
+        commitsStack.spacing = 4
+        commitsStack.isHidden = true
+
+        // Advanced
+        let advancedLabel = NSTextField(labelWithString: "Advanced")
+        advancedLabel.font = .systemFont(ofSize: 12, weight: .medium)
+        advancedLabel.textColor = .secondaryLabelColor
+
+        advancedToggle.bezelStyle = .disclosure
+        advancedToggle.setButtonType(.pushOnPushOff)
+        advancedToggle.title = ""
+        advancedToggle.state = .off
+        advancedToggle.toolTip = "Show tmux targets"
+        advancedToggle.setAccessibilityLabel("Show tmux targets")
+        advancedToggle.target = self
+        advancedToggle.action = #selector(advancedToggled)
+
+        advancedRow.addArrangedSubview(advancedToggle)
+        advancedRow.addArrangedSubview(advancedLabel)
+        advancedRow.orientation = .horizontal
+        advancedRow.spacing = 6
+        advancedRow.alignment = .centerY
+        advancedRow.isHidden = true
+
+        advancedStack.orientation = .vertical
+        advancedStack.alignment = .leading
+        advancedStack.spacing = 2
+        advancedStack.isHidden = true
+
+        // Notes
+        let notesLabel = NSTextField(labelWithString: "Notes")
+        notesLabel.font = .systemFont(ofSize: 12, weight: .medium)
+        notesLabel.textColor = .secondaryLabelColor
+
+        notesEditedLabel.font = .systemFont(ofSize: 11)
+        notesEditedLabel.textColor = .tertiaryLabelColor
+        notesEditedLabel.isHidden = true
+
+        notesRow.addArrangedSubview(notesLabel)
+        notesRow.addArrangedSubview(notesEditedLabel)
+        notesRow.orientation = .horizontal
+        notesRow.spacing = 8
+        notesRow.alignment = .firstBaseline
+        notesRow.isHidden = true
+
+        notesTextView.isRichText = false
+        notesTextView.allowsUndo = true
+        notesTextView.font = .systemFont(ofSize: 12)
+        notesTextView.textColor = Theme.primaryText
+        notesTextView.isVerticallyResizable = true
+        notesTextView.autoresizingMask = [.width]
+        notesTextView.textContainer?.widthTracksTextView = true
+        notesTextView.delegate = self
+        notesTextView.setAccessibilityLabel("Worktree notes")
+
+        notesScrollView.documentView = notesTextView
+        notesScrollView.hasVerticalScroller = true
+        notesScrollView.borderType = .bezelBorder
+        notesScrollView.isHidden = true
+        notesScrollView.translatesAutoresizingMaskIntoConstraints = false
+
+        usageLabel.font = .systemFont(ofSize: 12)
+        usageLabel.textColor = .secondaryLabelColor
+        usageLabel.isHidden = true
+
+        usageStack.orientation = .vertical
+        usageStack.alignment = .leading
+        usageStack.spacing = 4
+        usageStack.isHidden = true
+
         headerStack.orientation = .vertical
         headerStack.alignment = .leading
         headerStack.spacing = 8
         headerStack.edgeInsets = NSEdgeInsets(top: 16, left: 16, bottom: 12, right: 16)
         headerStack.addArrangedSubview(topRow)
         headerStack.addArrangedSubview(buttonStack)
+        headerStack.addArrangedSubview(branchChangesRow)
+        headerStack.addArrangedSubview(branchFilesStack)
+        headerStack.addArrangedSubview(commitsRow)
+        headerStack.addArrangedSubview(commitsStack)
+        headerStack.addArrangedSubview(notesRow)
+        headerStack.addArrangedSubview(notesScrollView)
+        headerStack.addArrangedSubview(usageLabel)
+        headerStack.addArrangedSubview(usageStack)
+        headerStack.addArrangedSubview(advancedRow)
+        headerStack.addArrangedSubview(advancedStack)
         headerStack.translatesAutoresizingMaskIntoConstraints = false
         addSubview(headerStack)
 
@@ -1380,7 +2068,12 @@ class WorktreeDetailView: NSView {
 
             iconView.widthAnchor.constraint(equalToConstant: 32),
             iconView.heightAnchor.constraint(equalToConstant: 32),
+
+            notesScrollView.leadingAnchor.constraint(equalTo: headerStack.leadingAnchor, constant: 16),
+            notesScrollView.trailingAnchor.constraint(equalTo: headerStack.trailingAnchor, constant: -16),
+            notesScrollView.heightAnchor.constraint(equalToConstant: 60),
         ])
+        notesTextView.frame = NSRect(origin: .zero, size: notesScrollView.contentSize)
     }
 
     private func configureButton(_ button: NSButton, title: String, icon: String) {
@@ -1397,6 +2090,110 @@ class WorktreeDetailView: NSView {
     @objc private func agentButtonClicked() { onNewAgent?() }
     @objc private func terminalButtonClicked() { onNewTerminal?() }
     @objc private func worktreeButtonClicked() { onNewWorktree?() }
+    @objc private func terminalsButtonClicked() { onShowTerminals?() }
+    @objc private func restartExitedButtonClicked() { onRestartExitedAgents?() }
+
+    /// Open the only agent's logs directly; with several, pick one from a menu.
+    @objc private func logsButtonClicked(_ sender: NSButton) {
+        if currentAgents.count == 1 {
+            onShowAgentLogs?(currentAgents[0])
+            return
+        }
+        let menu = NSMenu()
+        for (index, agent) in currentAgents.enumerated() {
+            let title = agent.name.isEmpty ? agent.id : agent.name
+            let item = menu.addItem(withTitle: "\(title) — \(agent.statusLabel)", action: #selector(logsMenuItemClicked(_:)), keyEquivalent: "")
+            item.target = self
+            item.tag = index
+        }
+        menu.popUp(positioning: nil, at: NSPoint(x: 0, y: sender.bounds.height + 4), in: sender)
+    }
+
+    @objc private func logsMenuItemClicked(_ sender: NSMenuItem) {
+        guard currentAgents.indices.contains(sender.tag) else { return }
+        onShowAgentLogs?(currentAgents[sender.tag])
+    }
+
+    @objc private func attachButtonClicked() {
+        guard !currentTmuxWindow.isEmpty else { return }
+        let target = currentTmuxWindow
+        let command = tmuxAttachShellCommand(target: target)
+        let terminal = ExternalTerminal.detect(preferred: AppSettingsManager.shared.preferredTerminal)
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            // Attaching to a window that's gone just flashes a tmux error in Terminal
+            if PPGService.shared.tmuxSnapshot()?.contains(TmuxTarget(parsing: target)) == false {
+                DispatchQueue.main.async {
+                    self?.showMissingWindowAlert(target: target)
+                }
+                return
+            }
+            let failure: String
+            do {
+                try terminal.open(command: command)
+                return
+            } catch {
+                failure = error.localizedDescription
+            }
+            DispatchQueue.main.async {
+                NSPasteboard.general.clearContents()
+                NSPasteboard.general.setString(command, forType: .string)
+
+                let alert = NSAlert()
+                alert.messageText = "Attach Command Copied"
+                alert.informativeText = "\(failure) Paste the copied command into any terminal to attach:\n\n\(command)"
+                alert.alertStyle = .informational
+                alert.addButton(withTitle: "OK")
+                if let window = self?.window {
+                    alert.beginSheetModal(for: window)
+                } else {
+                    alert.runModal()
+                }
+            }
+        }
+    }
+
+    private func showMissingWindowAlert(target: String) {
+        let alert = NSAlert()
+        alert.messageText = "tmux Window Missing"
+        alert.informativeText = "This worktree's tmux window (\(target)) no longer exists. tmux may have restarted or the session was killed outside ppg."
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "OK")
+        if let window {
+            alert.beginSheetModal(for: window)
+        } else {
+            alert.runModal()
+        }
+    }
+
+    @objc private func branchFilesToggled() {
+        branchFilesStack.isHidden = branchFilesToggle.state == .off || branchFilesStack.arrangedSubviews.isEmpty
+        branchFilesToggle.toolTip = branchFilesToggle.state == .on ? "Hide changed files" : "Show changed files"
+    }
+
+    @objc private func commitsToggled() {
+        commitsStack.isHidden = commitsToggle.state == .off || currentCommits.isEmpty
+        commitsToggle.toolTip = commitsToggle.state == .on ? "Hide commits" : "Show commits"
+    }
+
+    @objc private func advancedToggled() {
+        advancedStack.isHidden = advancedToggle.state == .off || advancedValues.isEmpty
+        advancedToggle.toolTip = advancedToggle.state == .on ? "Hide tmux targets" : "Show tmux targets"
+    }
+
+    @objc private func copyAdvancedValue(_ sender: NSButton) {
+        guard advancedValues.indices.contains(sender.tag) else { return }
+        NSPasteboard.general.clearContents()
+        NSPasteboard.general.setString(advancedValues[sender.tag], forType: .string)
+    }
+
+    @objc private func showMoreCommitsClicked() {
+        showAllCommits = true
+        rebuildCommitRows()
+    }
+
+    @objc private func branchRefreshClicked() {
+        reloadBranchChanges(force: true)
+    }
 
     // MARK: - Inline Name Editing
 
@@ -1422,11 +2219,40 @@ class WorktreeDetailView: NSView {
         nameLabel.drawsBackground = false
         window?.makeFirstResponder(nil)
 
-        if let newName = commitName, newName != nameBeforeEditing {
-            nameLabel.stringValue = newName
-            onRenameWorktree?(currentWorktreeId, newName)
-        } else {
+        guard let newName = commitName?.trimmingCharacters(in: .whitespaces), newName != nameBeforeEditing else {
+            nameLabel.stringValue = nameBeforeEditing
+            return
+        }
+        if let problem = Self.validateWorktreeName(newName) {
             nameLabel.stringValue = nameBeforeEditing
+            showRenameError(problem)
+            return
+        }
+        // Show the new name immediately; revert if the rename is rejected.
+        nameLabel.stringValue = newName
+        if onRenameWorktree?(currentWorktreeId, newName) == false {
+            nameLabel.stringValue = nameBeforeEditing
+        }
+    }
+
+    /// Returns a user-facing reason the name is unusable, or nil if it's acceptable.
+    static func validateWorktreeName(_ name: String) -> String? {
+        let trimmed = name.trimmingCharacters(in: .whitespaces)
+        if trimmed.isEmpty { return "The name can't be empty." }
+        if trimmed.count > 64 { return "The name can be at most 64 characters." }
+        if trimmed.contains("/") { return "The name can't contain slashes." }
+        return nil
+    }
+
+    private func showRenameError(_ message: String) {
+        let alert = NSAlert()
+        alert.messageText = "Can't Rename Worktree"
+        alert.informativeText = message
+        alert.alertStyle = .warning
+        if let window {
+            alert.beginSheetModal(for: window)
+        } else {
+            alert.runModal()
         }
     }
 
@@ -1459,6 +2285,18 @@ extension WorktreeDetailView: NSTextFieldDelegate {
     }
 }
 
+// MARK: - WorktreeDetailView + NSTextViewDelegate
+
+extension WorktreeDetailView: NSTextViewDelegate {
+    func textDidChange(_ notification: Notification) {
+        notesSaver.schedule()
+    }
+
+    func textDidEndEditing(_ notification: Notification) {
+        saveNote()
+    }
+}
+
 // MARK: - DiffCardView
 
 class DiffCardView: NSView {
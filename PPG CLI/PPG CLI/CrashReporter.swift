@@ -0,0 +1,112 @@
+import AppKit
+
+/// Saves Objective-C exceptions to ~/Library/Logs/PPG CLI so they leave a trace
+/// instead of the app vanishing or carrying on half-broken without a word.
+///
+/// Exceptions AppKit catches during event handling go through `PPGApplication`,
+/// which logs them and offers to continue or quit. Anything uncaught terminates the
+/// process; the report is kept as pending and shown on the next launch.
+nonisolated enum CrashReporter {
+    static let logDirectory = FileManager.default.homeDirectoryForCurrentUser
+        .appendingPathComponent("Library/Logs/PPG CLI", isDirectory: true)
+    /// Appended to for every recorded exception.
+    static let errorLogURL = logDirectory.appendingPathComponent("errors.log")
+    /// Written only for exceptions that took the app down; removed once shown.
+    static let pendingCrashURL = logDirectory.appendingPathComponent("last-crash.log")
+
+    static func install() {
+        NSSetUncaughtExceptionHandler { exception in
+            CrashReporter.record(CrashReporter.report(for: exception), fatal: true)
+        }
+    }
+
+    static func report(for exception: NSException) -> String {
+        report(
+            name: exception.name.rawValue,
+            reason: exception.reason,
+            callStack: exception.callStackSymbols,
+            date: Date()
+        )
+    }
+
+    static func report(name: String, reason: String?, callStack: [String], date: Date) -> String {
+        let info = Bundle.main.infoDictionary
+        let version = info?["CFBundleShortVersionString"] as? String ?? "unknown"
+        let build = info?["CFBundleVersion"] as? String ?? "unknown"
+        var lines = [
+            "Date: \(ISO8601DateFormatter().string(from: date))",
+            "Version: \(version) (\(build))",
+            "macOS: \(ProcessInfo.processInfo.operatingSystemVersionString)",
+            "Exception: \(name)",
+            "Reason: \(reason ?? "none")",
+            "",
+            "Backtrace:",
+        ]
+        lines += callStack.isEmpty ? ["  unavailable"] : callStack.map { "  \($0)" }
+        return lines.joined(separator: "\n") + "\n"
+    }
+
+    /// Append `report` to the error log; `fatal` reports are also kept for the next launch.
+    static func record(_ report: String, fatal: Bool) {
+        let fm = FileManager.default
+        try? fm.createDirectory(at: logDirectory, withIntermediateDirectories: true)
+        let entry = Data((report + "\n").utf8)
+        if let handle = try? FileHandle(forWritingTo: errorLogURL) {
+            handle.seekToEndOfFile()
+            handle.write(entry)
+            handle.closeFile()
+        } else {
+            try? entry.write(to: errorLogURL)
+        }
+        if fatal {
+            try? Data(report.utf8).write(to: pendingCrashURL)
+        }
+    }
+
+    /// The report from a crash in the previous run, if any. Removes it so it's shown once.
+    static func takePendingCrashReport() -> String? {
+        guard let data = try? Data(contentsOf: pendingCrashURL) else { return nil }
+        try? FileManager.default.removeItem(at: pendingCrashURL)
+        return String(data: data, encoding: .utf8)
+    }
+}
+
+/// Application subclass that turns exceptions AppKit would silently log into a
+/// visible error with the option to keep going.
+final class PPGApplication: NSApplication {
+    private var isShowingInternalError = false
+
+    override func reportException(_ exception: NSException) {
+        super.reportException(exception)
+        let report = CrashReporter.report(for: exception)
+        CrashReporter.record(report, fatal: false)
+
+        // Repeated exceptions from the same broken state shouldn't stack alerts
+        guard !isShowingInternalError else { return }
+        isShowingInternalError = true
+        DispatchQueue.main.async { [weak self] in
+            self?.presentInternalError(report)
+        }
+    }
+
+    private func presentInternalError(_ report: String) {
+        defer { isShowingInternalError = false }
+        let alert = NSAlert()
+        alert.messageText = "PPG CLI Hit an Internal Error"
+        alert.informativeText = "The last action didn't finish. A report was saved to \(CrashReporter.errorLogURL.path). You can keep working, but quit and reopen if anything looks wrong."
+        alert.alertStyle = .critical
+        alert.addButton(withTitle: "Continue")
+        alert.addButton(withTitle: "Copy Report")
+        alert.addButton(withTitle: "Quit")
+
+        switch alert.runModal() {
+        case .alertSecondButtonReturn:
+            NSPasteboard.general.clearContents()
+            NSPasteboard.general.setString(report, forType: .string)
+        case .alertThirdButtonReturn:
+            terminate(nil)
+        default:
+            break
+        }
+    }
+}
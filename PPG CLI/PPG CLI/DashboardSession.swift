@@ -9,6 +9,9 @@ struct GridLayoutNode: Codable {
     var direction: String?
     var ratio: CGFloat?
     var children: [GridLayoutNode]?
+    /// Root only: entries held back by the visible-pane limit, in "+N more" order.
+    /// Absent in layouts saved before the limit existed.
+    var overflowEntryIds: [String]?
 
     var isLeaf: Bool { children == nil }
 
@@ -313,14 +316,14 @@ class DashboardSession {
     // MARK: - Tmux helpers
 
     @discardableResult
-    private func runTmux(_ args: String) -> (exitCode: Int32, stdout: String) {
+    private func runTmux(_ command: TmuxCommand) -> (exitCode: Int32, stdout: String) {
         let task = Process()
         task.executableURL = URL(fileURLWithPath: "/bin/zsh")
         let cmd = """
         if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; \
         [ -f ~/.zprofile ] && source ~/.zprofile; \
         [ -f ~/.zshrc ] && source ~/.zshrc; \
-        tmux \(args)
+        \(command.shellString)
         """
         task.arguments = ["-c", cmd]
         let outPipe = Pipe()
@@ -338,29 +341,30 @@ class DashboardSession {
     }
 
     private func createTmuxWindow(sessionName: String, windowName: String, cwd: String) -> String? {
-        let escapedSession = shellEscape(sessionName)
-        let escapedName = shellEscape(windowName)
-        let escapedCwd = shellEscape(cwd)
-
-        let hasResult = runTmux("has-session -t \(escapedSession)")
+        let hasResult = runTmux(TmuxCommand("has-session").option("-t", sessionName))
         if hasResult.exitCode != 0 {
-            let createResult = runTmux("new-session -d -s \(escapedSession) -x 220 -y 50")
+            let createResult = runTmux(TmuxCommand("new-session").flag("-d").option("-s", sessionName).option("-x", "220").option("-y", "50"))
             guard createResult.exitCode == 0 else { return nil }
         }
-        runTmux("set-option -t \(escapedSession) mouse on")
-        runTmux("set-option -t \(escapedSession) history-limit \(AppSettingsManager.shared.historyLimit)")
+        runTmux(
+            TmuxCommand("set-option").option("-t", sessionName).arguments("history-limit", String(AppSettingsManager.shared.historyLimit))
+                .thenEnablingMouse(target: sessionName, features: PPGService.shared.tmuxFeatures)
+        )
 
-        let result = runTmux("new-window -t \(escapedSession) -n \(escapedName) -c \(escapedCwd) -P -F '#{window_index}'")
+        let result = runTmux(
+            TmuxCommand("new-window").option("-t", sessionName).option("-n", windowName).option("-c", cwd)
+                .flag("-P").option("-F", "#{window_index}")
+        )
         guard result.exitCode == 0, !result.stdout.isEmpty else { return nil }
         return "\(sessionName):\(result.stdout)"
     }
 
     func killTmuxWindow(target: String) {
-        runTmux("kill-window -t \(shellEscape(target))")
+        runTmux(TmuxCommand("kill-window").option("-t", target))
     }
 
     func sendTmuxKeys(target: String, command: String) {
-        runTmux("send-keys -t \(shellEscape(target)) -l \(shellEscape(command + "\n"))")
+        runTmux(TmuxCommand("send-keys").option("-t", target).flag("-l").arguments(command + "\n"))
     }
 
     private func generateId(_ length: Int) -> String {
@@ -10,6 +10,22 @@ class DashboardSplitViewController: NSSplitViewController {
 
     /// Entry ID to navigate to after the next sidebar data refresh.
     private var pendingNavigationEntryId: String?
+    /// When to stop waiting for `pendingNavigationEntryId` to show up (e.g. an agent that
+    /// failed to start), so it can't yank the selection away much later.
+    private var pendingNavigationDeadline: Date?
+    private static let pendingNavigationTimeout: TimeInterval = 30
+
+    /// Sidebar item to reselect on the first data refresh after launch (tried once).
+    private var pendingRestoredSelectionId: String?
+
+    /// Restarted agent ID → replacement agent ID. The pane showing the old agent is
+    /// switched over once a sidebar refresh picks up the replacement.
+    private var pendingAgentReplacements: [String: String] = [:]
+    /// Agents whose `ppg restart` hasn't returned yet.
+    private var restartingAgentIds: Set<String> = []
+
+    /// Worktree ID → its "restart exited agents" batch while one is running.
+    private var worktreeRestarts: [String: BatchRestart] = [:]
 
     /// Editable title in the window titlebar.
     private var titleAccessory: EditableTitleBarAccessory?
@@ -17,6 +33,19 @@ class DashboardSplitViewController: NSSplitViewController {
     /// The currently displayed sidebar item (for rename routing).
     private var currentSidebarItem: SidebarItem?
 
+    /// Back/forward history of sidebar rows and tabs shown.
+    private var navigationHistory = NavigationHistory()
+    /// Set while showing a history entry, so the selection it causes isn't visited again.
+    private var isWalkingHistory = false
+    /// Mouse back/forward buttons over the window.
+    private var mouseButtonMonitor: Any?
+
+    /// Counts behind the titlebar summary; nil until the sidebar's first refresh.
+    private var fleetSummary: FleetSummary?
+
+    /// "root@version" pairs already shown in the unsupported-manifest alert this session.
+    private var reportedManifestVersions: Set<String> = []
+
     override func viewDidLoad() {
         super.viewDidLoad()
 
@@ -47,11 +76,23 @@ class DashboardSplitViewController: NSSplitViewController {
 
         sidebar.onDataRefreshed = { [weak self] _ in
             guard let self = self else { return }
-            if let pendingId = self.pendingNavigationEntryId {
-                if self.sidebar.selectItem(byId: pendingId) {
-                    self.pendingNavigationEntryId = nil
-                    return
-                }
+            self.checkManifestVersions()
+            self.updateFleetSummary()
+            self.applyPendingAgentReplacements()
+            self.updateLogViewers()
+            if let restoreId = self.pendingRestoredSelectionId {
+                self.pendingRestoredSelectionId = nil
+                if self.sidebar.selectItem(byId: restoreId) { return }
+            }
+            // Past the deadline the user has moved on; a late arrival mustn't pull them back
+            if let deadline = self.pendingNavigationDeadline, Date() > deadline {
+                self.pendingNavigationEntryId = nil
+                self.pendingNavigationDeadline = nil
+            }
+            if let pendingId = self.pendingNavigationEntryId, self.sidebar.selectItem(byId: pendingId) {
+                self.pendingNavigationEntryId = nil
+                self.pendingNavigationDeadline = nil
+                return
             }
             self.handleRefresh()
         }
@@ -83,6 +124,24 @@ class DashboardSplitViewController: NSSplitViewController {
             self.deleteManifestAgent(project: project, agentId: agentId)
         }
 
+        sidebar.onShowAgentLogs = { project, agent in
+            guard !project.projectRoot.isEmpty else { return }
+            LogViewerWindowController.show(agent: agent, projectRoot: project.projectRoot)
+        }
+
+        sidebar.onRestartAgentWithNewPrompt = { [weak self] _, agent in
+            self?.restartAgentWithNewPrompt(agent)
+        }
+
+        sidebar.onRestartExitedAgents = { [weak self] project, worktree in
+            self?.restartExitedAgents(project: project, worktree: worktree)
+        }
+
+        sidebar.onKillWorktreeAgents = { [weak self] project, worktreeId in
+            guard let self = self else { return }
+            self.killWorktreeAgents(project: project, worktreeId: worktreeId)
+        }
+
         sidebar.onDeleteWorktree = { [weak self] project, worktreeId in
             guard let self = self else { return }
             self.deleteWorktree(project: project, worktreeId: worktreeId)
@@ -101,24 +160,28 @@ class DashboardSplitViewController: NSSplitViewController {
             self?.showCreationMenuForProject(ctx)
         }
 
+        sidebar.onProjectReinitialized = { [weak self] ctx in
+            self?.projectReinitialized(ctx)
+        }
+
         sidebar.onDashboardClicked = { [weak self] in
-            self?.showHomeDashboard()
+            self?.openTab(.dashboard)
         }
 
         sidebar.onSwarmsClicked = { [weak self] in
-            self?.showSwarmsView()
+            self?.openTab(.swarms)
         }
 
         sidebar.onPromptsClicked = { [weak self] in
-            self?.showPromptsView()
+            self?.openTab(.prompts)
         }
 
         sidebar.onSchedulesClicked = { [weak self] in
-            self?.showSchedulesView()
+            self?.openTab(.schedules)
         }
 
         sidebar.onAgentConfigClicked = { [weak self] in
-            self?.showAgentConfigView()
+            self?.openTab(.agentConfig)
         }
 
         // Save grid layout when a grid is suspended (navigate away)
@@ -134,6 +197,13 @@ class DashboardSplitViewController: NSSplitViewController {
         }
 
         content.onCloseEntry = { [weak self] in self?.closeCurrentEntry() }
+        content.onShowAgentLogs = { [weak self] agent in self?.showAgentLogs(agent) }
+        content.onRestartAgent = { [weak self] agent in self?.restartAgent(agent) }
+        content.onRestartAgentWithNewPrompt = { [weak self] agent in self?.restartAgentWithNewPrompt(agent) }
+        content.hasPendingRestart = { [weak self] agentId in
+            guard let self = self else { return false }
+            return self.restartingAgentIds.contains(agentId) || self.pendingAgentReplacements[agentId] != nil
+        }
 
         // Clean up persisted grid-owned session entries when a grid is destroyed
         content.onGridDestroyed = { [weak self] ownerEntryId in
@@ -151,15 +221,27 @@ class DashboardSplitViewController: NSSplitViewController {
             }
         }
 
-        // Auto-show dashboard on launch
+        // Auto-show dashboard on launch; optionally jump back to the last selection
+        // once the sidebar has loaded (falls back to the dashboard if it's gone).
+        let settings = AppSettingsManager.shared
+        if settings.restoreLastSelection {
+            pendingRestoredSelectionId = settings.lastSelectedItemId
+        }
         DispatchQueue.main.async { [weak self] in
-            self?.showHomeDashboard()
+            self?.openTab(.dashboard)
         }
     }
 
     override func viewDidAppear() {
         super.viewDidAppear()
         installTitleAccessory()
+        installMouseButtonMonitor()
+    }
+
+    deinit {
+        if let mouseButtonMonitor {
+            NSEvent.removeMonitor(mouseButtonMonitor)
+        }
     }
 
     private func installTitleAccessory() {
@@ -171,8 +253,44 @@ class DashboardSplitViewController: NSSplitViewController {
         accessory.onRename = { [weak self] newName in
             self?.handleTitleRename(newName)
         }
+        accessory.onFleetClicked = { [weak self] button in
+            self?.showFailedAgentsMenu(from: button)
+        }
         window.addTitlebarAccessoryViewController(accessory)
         titleAccessory = accessory
+        if let fleetSummary {
+            accessory.setFleetSummary(fleetSummary)
+        }
+    }
+
+    private func updateFleetSummary() {
+        let summary = FleetSummary(projectWorktrees: sidebar.projectWorktrees)
+        fleetSummary = summary
+        titleAccessory?.setFleetSummary(summary)
+    }
+
+    /// List failed agents under the titlebar summary; picking one reveals it in the sidebar.
+    private func showFailedAgentsMenu(from button: NSButton) {
+        let menu = NSMenu()
+        let failed = fleetSummary?.failedAgents ?? []
+        if failed.isEmpty {
+            let item = menu.addItem(withTitle: "No failed agents", action: nil, keyEquivalent: "")
+            item.isEnabled = false
+        }
+        for entry in failed {
+            let name = entry.agent.name.isEmpty ? entry.agent.id : entry.agent.name
+            let project = URL(fileURLWithPath: entry.projectRoot).lastPathComponent
+            let item = menu.addItem(withTitle: "\(name) — \(entry.worktree.name) (\(project))",
+                                    action: #selector(failedAgentMenuItemClicked(_:)), keyEquivalent: "")
+            item.target = self
+            item.representedObject = entry.agent.id
+        }
+        menu.popUp(positioning: nil, at: NSPoint(x: 0, y: button.bounds.height + 4), in: button)
+    }
+
+    @objc private func failedAgentMenuItemClicked(_ sender: NSMenuItem) {
+        guard let agentId = sender.representedObject as? String else { return }
+        sidebar.revealItem(byId: agentId)
     }
 
     private func handleTitleRename(_ newName: String) {
@@ -194,7 +312,7 @@ class DashboardSplitViewController: NSSplitViewController {
         }
     }
 
-    private func showSettings() {
+    func showSettings() {
         let settingsVC = SettingsViewController()
         presentAsSheet(settingsVC)
     }
@@ -212,8 +330,7 @@ class DashboardSplitViewController: NSSplitViewController {
 
         case .agent(let ag):
             // Check if this agent shares a tmux window with others
-            let worktrees = sidebar.worktrees(for: ctx)
-            if let wt = worktrees.first(where: { $0.agents.contains(where: { a in a.id == ag.id }) }) {
+            if let wt = sidebar.agentIndex.worktree(ofAgent: ag.id) {
                 let target = ag.tmuxTarget
                 let windowKey: String
                 if let dotIndex = target.lastIndex(of: ".") {
@@ -242,6 +359,20 @@ class DashboardSplitViewController: NSSplitViewController {
         }
     }
 
+    /// Agents whose tmux pane is gone, for retiring their grid panes.
+    private func collectEndedAgentIds() -> Set<String> {
+        var ids = Set<String>()
+        for projectNode in sidebar.projectNodes {
+            guard case .project(let ctx) = projectNode.item else { continue }
+            for wt in sidebar.worktrees(for: ctx) {
+                for agent in wt.agents where agent.status == .lost {
+                    ids.insert(agent.id)
+                }
+            }
+        }
+        return ids
+    }
+
     /// Collect all terminal/agent IDs across the sidebar tree for cache cleanup.
     /// Includes compound agentGroup IDs ("id1+id2") for agents sharing a tmux window.
     private func collectAllTerminalIds() -> Set<String> {
@@ -304,12 +435,9 @@ class DashboardSplitViewController: NSSplitViewController {
             }
 
             // Check manifest agents
-            let worktrees = sidebar.worktrees(for: ctx)
-            for wt in worktrees {
-                if wt.agents.contains(where: { $0.id == entryId }) {
-                    deleteManifestAgent(project: ctx, agentId: entryId)
-                    return
-                }
+            if sidebar.agentIndex.find(entryId)?.projectRoot == ctx.projectRoot {
+                deleteManifestAgent(project: ctx, agentId: entryId)
+                return
             }
         }
 
@@ -317,16 +445,102 @@ class DashboardSplitViewController: NSSplitViewController {
         content.removeEntry(byId: entryId)
     }
 
+    // MARK: - Worktree Terminals
+
+    /// One tab entry per sidebar row for the worktree's agents, in sidebar order.
+    /// Agents sharing a tmux window collapse into a single group entry.
+    private func agentTabEntries(for wt: WorktreeModel, sessionName: String) -> [TabEntry] {
+        var windowOrder: [String] = []
+        var windowAgents: [String: [AgentModel]] = [:]
+        for agent in wt.agents {
+            let target = agent.tmuxTarget
+            let windowKey: String
+            if let dotIndex = target.lastIndex(of: ".") {
+                windowKey = String(target[target.startIndex..<dotIndex])
+            } else {
+                windowKey = target
+            }
+            if windowAgents[windowKey] == nil { windowOrder.append(windowKey) }
+            windowAgents[windowKey, default: []].append(agent)
+        }
+        return windowOrder.compactMap { key in
+            guard let agents = windowAgents[key] else { return nil }
+            if agents.count > 1 {
+                return .agentGroup(agents, key, sessionName: sessionName)
+            }
+            return .manifestAgent(agents[0], sessionName: sessionName)
+        }
+    }
+
+    /// Open the worktree's agents together in a pane grid. The first agent is selected
+    /// in the sidebar (so the grid is owned by it), and the rest fill additional panes.
+    private func showWorktreeTerminals(project: ProjectContext, worktreeId: String) {
+        guard let wt = sidebar.worktrees(for: project).first(where: { $0.id == worktreeId }) else { return }
+        let entries = agentTabEntries(for: wt, sessionName: project.sessionName)
+        guard let first = entries.first, sidebar.selectItem(byId: first.id) else { return }
+
+        // The first agent already owns a grid (restored on selection) — leave it alone.
+        guard !content.isGridMode, entries.count > 1 else { return }
+
+        // Pick rows/columns from the space available, then fill each row left to right.
+        // Agents past the visible-pane limit get no terminal until swapped in.
+        let settings = AppSettingsManager.shared
+        let visible = Array(entries.prefix(settings.maxVisiblePanes))
+        let shape = PaneGridController.gridShape(paneCount: visible.count, availableWidth: content.view.bounds.width,
+                                                 minPaneWidth: CGFloat(settings.minPaneWidth))
+        let topRow = Array(visible.prefix(shape.columns))
+        let bottomRow = Array(visible.dropFirst(shape.columns))
+
+        wireGridCallbacks()
+        var shownCount = 1
+        if !bottomRow.isEmpty, content.splitPaneBelow(), let grid = content.paneGrid {
+            grid.fillFocusedPane(with: bottomRow[0])
+            shownCount += 1
+            shownCount += fillGridRow(grid, from: grid.focusedLeafId, with: bottomRow.dropFirst())
+            if let topLeaf = grid.root.allLeafIds().first {
+                shownCount += fillGridRow(grid, from: topLeaf, with: topRow.dropFirst())
+            }
+        } else if topRow.count > 1, content.splitPaneRight(), let grid = content.paneGrid {
+            grid.fillFocusedPane(with: topRow[1])
+            shownCount += 1
+            shownCount += fillGridRow(grid, from: grid.focusedLeafId, with: topRow.dropFirst(2))
+        }
+
+        if let grid = content.paneGrid {
+            if let firstLeaf = grid.root.allLeafIds().first {
+                grid.setFocus(firstLeaf)
+            }
+            grid.reflowsOnResize = true
+            // Agents that didn't get a pane are offered from the grid's "+N more" chip.
+            if shownCount < entries.count {
+                grid.setOverflowEntries(entries.filter { !grid.containsEntry(id: $0.id) })
+            }
+        }
+        persistGridLayout()
+    }
+
+    /// Add panes to the right of `leafId` for each entry. Returns how many were placed.
+    private func fillGridRow(_ grid: PaneGridController, from leafId: String, with entries: ArraySlice<TabEntry>) -> Int {
+        grid.setFocus(leafId)
+        var placed = 0
+        for entry in entries {
+            guard grid.splitFocusedPane(direction: .vertical) else { break }
+            grid.fillFocusedPane(with: entry)
+            placed += 1
+        }
+        return placed
+    }
+
     // MARK: - Grid Restoration
 
     /// Rebuild a grid from persisted session entries after a reboot.
     /// Uses the saved layout tree to preserve split directions and ratios.
     private func rebuildGridFromSession(entry: TabEntry, project: ProjectContext) {
         let gridEntries = project.dashboardSession.entriesForGrid(ownerEntryId: entry.id)
-        guard !gridEntries.isEmpty else { return }
+        let savedLayout = project.dashboardSession.gridLayout(forOwnerEntryId: entry.id)
+        guard !gridEntries.isEmpty || savedLayout != nil else { return }
 
         let sessionName = project.sessionName
-        let savedLayout = project.dashboardSession.gridLayout(forOwnerEntryId: entry.id)
 
         wireGridCallbacks()
         content.splitPaneRight()
@@ -341,12 +555,14 @@ class DashboardSplitViewController: NSSplitViewController {
 
             grid.replaceRoot(restoredRoot)
 
-            // Build a lookup from entry ID -> session entry
-            var entryById: [String: DashboardSession.TerminalEntry] = [:]
-            for ge in gridEntries { entryById[ge.id] = ge }
+            let entryById = restorableEntries(project: project, gridEntries: gridEntries)
 
             // Walk the layout and restored tree in parallel to fill entries
-            fillLeavesFromLayout(grid: grid, layout: layout, leafIds: restoredRoot.allLeafIds(), sessionName: sessionName, ownerEntry: entry, entryById: entryById)
+            fillLeavesFromLayout(grid: grid, layout: layout, leafIds: restoredRoot.allLeafIds(), ownerEntry: entry, entryById: entryById)
+            let overflow = (layout.overflowEntryIds ?? []).compactMap { entryById[$0] }
+            if !overflow.isEmpty {
+                grid.setOverflowEntries(overflow)
+            }
         } else {
             // No saved layout — fall back to simple vertical splits
             content.paneGrid?.fillFocusedPane(with: .sessionEntry(gridEntries[0], sessionName: sessionName))
@@ -362,14 +578,28 @@ class DashboardSplitViewController: NSSplitViewController {
         }
     }
 
+    /// Entries a restored grid can show, by ID: the project's agents and the grid's own
+    /// session entries. Agents that have since gone are left out.
+    private func restorableEntries(project: ProjectContext, gridEntries: [DashboardSession.TerminalEntry]) -> [String: TabEntry] {
+        var entryById: [String: TabEntry] = [:]
+        for wt in sidebar.worktrees(for: project) {
+            for agentEntry in agentTabEntries(for: wt, sessionName: project.sessionName) {
+                entryById[agentEntry.id] = agentEntry
+            }
+        }
+        for ge in gridEntries {
+            entryById[ge.id] = .sessionEntry(ge, sessionName: project.sessionName)
+        }
+        return entryById
+    }
+
     /// Walk the layout tree and the matching leaf IDs to fill each pane with the right entry.
     private func fillLeavesFromLayout(
         grid: PaneGridController,
         layout: GridLayoutNode,
         leafIds: [String],
-        sessionName: String,
         ownerEntry: TabEntry,
-        entryById: [String: DashboardSession.TerminalEntry]
+        entryById: [String: TabEntry]
     ) {
         // Collect entry IDs from the layout in tree order (depth-first)
         let layoutEntryIds = collectLeafEntryIds(from: layout)
@@ -383,9 +613,9 @@ class DashboardSplitViewController: NSSplitViewController {
                     // This is the grid owner — show it directly
                     grid.setFocus(leafId)
                     grid.fillFocusedPane(with: ownerEntry)
-                } else if let sessionEntry = entryById[entryId] {
+                } else if let restored = entryById[entryId] {
                     grid.setFocus(leafId)
-                    grid.fillFocusedPane(with: .sessionEntry(sessionEntry, sessionName: sessionName))
+                    grid.fillFocusedPane(with: restored)
                 }
             }
         }
@@ -403,7 +633,7 @@ class DashboardSplitViewController: NSSplitViewController {
     /// Persist the current grid layout to the dashboard session on disk.
     private func persistGridLayout() {
         guard let grid = content.paneGrid, let ownerId = content.activeGridOwnerId else { return }
-        let layout = grid.root.toLayoutNode()
+        let layout = grid.layoutNode()
         if let ctx = projectContextForGridOwner(ownerId) {
             ctx.dashboardSession.saveGridLayout(ownerEntryId: ownerId, layout: layout)
         }
@@ -416,10 +646,7 @@ class DashboardSplitViewController: NSSplitViewController {
             // Check dashboard session entries
             if ctx.dashboardSession.entry(byId: entryId) != nil { return ctx }
             // Check manifest agents
-            let worktrees = sidebar.worktrees(for: ctx)
-            for wt in worktrees {
-                if wt.agents.contains(where: { $0.id == entryId }) { return ctx }
-            }
+            if sidebar.agentIndex.find(entryId)?.projectRoot == ctx.projectRoot { return ctx }
             // Check if this entry has grid children in this project
             if !ctx.dashboardSession.entriesForGrid(ownerEntryId: entryId).isEmpty { return ctx }
         }
@@ -459,6 +686,16 @@ class DashboardSplitViewController: NSSplitViewController {
         content.movePaneFocus(direction: direction, forward: forward)
     }
 
+    func togglePaneZoom() {
+        content.togglePaneZoom()
+    }
+
+    /// Jump to the pane at `index` in reading order (0-based).
+    func focusPane(at index: Int) {
+        guard content.isGridMode else { return }
+        content.paneGrid?.focusPane(at: index)
+    }
+
     /// Whether the focused pane can be split in the given direction.
     /// Used by menu validation to disable split items at the grid limit.
     func canSplitFocusedPane(direction: SplitDirection) -> Bool {
@@ -501,6 +738,13 @@ class DashboardSplitViewController: NSSplitViewController {
             }
         }
 
+        grid.onPanesSwapped = { [weak self] in
+            self?.persistGridLayout()
+        }
+        grid.onReflowed = { [weak self] in
+            self?.persistGridLayout()
+        }
+
         grid.onClosePane = { [weak self] leafId in
             guard let self = self, let grid = self.content.paneGrid else { return }
             grid.setFocus(leafId)
@@ -561,6 +805,7 @@ class DashboardSplitViewController: NSSplitViewController {
         let projects = OpenProjects.shared.projects
         content.showHomeDashboard(projects: projects, worktreesByProject: sidebar.projectWorktrees)
         currentSidebarItem = nil
+        AppSettingsManager.shared.lastSelectedItemId = nil
         view.window?.title = "ppg"
         titleAccessory?.setTitle("ppg", editable: false)
     }
@@ -597,9 +842,98 @@ class DashboardSplitViewController: NSSplitViewController {
         titleAccessory?.setTitle("ppg - Agent Config", editable: false)
     }
 
+    /// A new manifest replaced the project's worktrees and agents, which may now run in
+    /// another tmux session. Panes for the old agents are cleared by the refresh like any
+    /// removed agent. A selected worktree or agent would point at nothing, so the window
+    /// goes back to the dashboard; the project row and the user's own terminals stay.
+    private func projectReinitialized(_ ctx: ProjectContext) {
+        guard let item = currentSidebarItem else { return }
+        switch item {
+        case .worktree, .agent, .agentGroup:
+            guard sidebar.projectContext(for: item) === ctx else { return }
+            sidebar.showTab(.dashboard)
+            openTab(.dashboard)
+        case .project, .terminal:
+            return
+        }
+    }
+
+    // MARK: - Back / Forward
+
+    var canNavigateBack: Bool { navigationHistory.canGoBack }
+    var canNavigateForward: Bool { navigationHistory.canGoForward }
+
+    func navigateBack() {
+        guard let target = navigationHistory.goBack(where: { [sidebar] in Self.isAvailable($0, in: sidebar) }) else { return }
+        showHistoryEntry(target)
+    }
+
+    func navigateForward() {
+        guard let target = navigationHistory.goForward(where: { [sidebar] in Self.isAvailable($0, in: sidebar) }) else { return }
+        showHistoryEntry(target)
+    }
+
+    /// Rows that are gone (a cleaned-up worktree, a closed terminal) are skipped.
+    private static func isAvailable(_ target: NavigationTarget, in sidebar: SidebarViewController) -> Bool {
+        guard case .item(let id) = target else { return true }
+        return sidebar.containsItem(byId: id)
+    }
+
+    /// Show a history entry and highlight it in the sidebar without visiting it again.
+    private func showHistoryEntry(_ target: NavigationTarget) {
+        isWalkingHistory = true
+        defer { isWalkingHistory = false }
+        switch target {
+        case .item(let id):
+            sidebar.revealItem(byId: id)
+        case .tab(let tab):
+            sidebar.showTab(tab)
+            showView(for: tab)
+        }
+    }
+
+    /// Show a sidebar tab's view as a new history entry.
+    private func openTab(_ tab: SidebarTab) {
+        navigationHistory.visit(.tab(tab))
+        showView(for: tab)
+    }
+
+    private func showView(for tab: SidebarTab) {
+        switch tab {
+        case .dashboard: showHomeDashboard()
+        case .swarms: showSwarmsView()
+        case .prompts: showPromptsView()
+        case .schedules: showSchedulesView()
+        case .agentConfig: showAgentConfigView()
+        }
+    }
+
+    /// Mouse buttons 4 and 5 (numbered 3 and 4 by AppKit) go back and forward, as in Finder and Safari.
+    private func installMouseButtonMonitor() {
+        guard mouseButtonMonitor == nil else { return }
+        mouseButtonMonitor = NSEvent.addLocalMonitorForEvents(matching: .otherMouseDown) { [weak self] event in
+            guard let self = self, event.window === self.view.window else { return event }
+            switch event.buttonNumber {
+            case 3:
+                self.navigateBack()
+                return nil
+            case 4:
+                self.navigateForward()
+                return nil
+            default:
+                return event
+            }
+        }
+    }
+
     // MARK: - Selection & Refresh
 
     private func handleSelection(_ item: SidebarItem) {
+        AppSettingsManager.shared.lastSelectedItemId = item.id
+        // Clicks, the quick switcher and notification jumps all select through the sidebar
+        if !isWalkingHistory {
+            navigationHistory.visit(.item(item.id))
+        }
         switch item {
         case .project(let ctx):
             showProjectDetail(ctx: ctx, worktreeId: nil)
@@ -616,8 +950,9 @@ class DashboardSplitViewController: NSSplitViewController {
             if content.restoreGrid(forEntryId: entry.id) {
                 // Grid restored — done
             } else if let ctx = sidebar.projectContext(for: item),
-                      !ctx.dashboardSession.entriesForGrid(ownerEntryId: entry.id).isEmpty {
-                // Persisted grid children exist (e.g. after reboot) — rebuild the grid
+                      !ctx.dashboardSession.entriesForGrid(ownerEntryId: entry.id).isEmpty
+                        || ctx.dashboardSession.gridLayout(forOwnerEntryId: entry.id) != nil {
+                // Persisted grid children or layout exist (e.g. after reboot) — rebuild the grid
                 content.showEntry(entry)
                 rebuildGridFromSession(entry: entry, project: ctx)
             } else {
@@ -631,8 +966,11 @@ class DashboardSplitViewController: NSSplitViewController {
     private func showProjectDetail(ctx: ProjectContext, worktreeId: String?) {
         let worktrees = sidebar.worktrees(for: ctx)
         let wt: WorktreeModel
+        // Notes belong to ppg worktrees; the project root has no ID to keep them under
+        var notes: WorktreeNotes?
         if let wtId = worktreeId, let found = worktrees.first(where: { $0.id == wtId }) {
             wt = found
+            notes = ctx.worktreeNotes
         } else {
             // Synthetic worktree model for the project root
             wt = WorktreeModel(
@@ -648,11 +986,23 @@ class DashboardSplitViewController: NSSplitViewController {
         content.showWorktreeDetail(
             worktree: wt,
             projectRoot: ctx.projectRoot,
+            sessionName: ctx.sessionName,
+            notes: notes,
             onNewAgent: { [weak self] in self?.addAgent(project: ctx, parentWorktreeId: worktreeId) },
             onNewTerminal: { [weak self] in self?.addTerminal(project: ctx, parentWorktreeId: worktreeId) },
             onNewWorktree: { [weak self] in self?.createWorktree(project: ctx) },
             onRenameWorktree: { [weak self] worktreeId, newName in
-                self?.renameManifestWorktree(project: ctx, worktreeId: worktreeId, newName: newName)
+                self?.renameManifestWorktree(project: ctx, worktreeId: worktreeId, newName: newName) ?? false
+            },
+            onShowTerminals: { [weak self] in
+                guard let worktreeId else { return }
+                self?.showWorktreeTerminals(project: ctx, worktreeId: worktreeId)
+            },
+            onRestartExitedAgents: { [weak self] in
+                // Look the worktree up again; its agents may have changed since this was shown
+                guard let self, let worktreeId,
+                      let current = self.sidebar.worktrees(for: ctx).first(where: { $0.id == worktreeId }) else { return }
+                self.restartExitedAgents(project: ctx, worktree: current)
             }
         )
     }
@@ -707,7 +1057,7 @@ class DashboardSplitViewController: NSSplitViewController {
         // If prompts, swarms, or schedules view is visible, just clean stale views
         if content.isShowingPromptsView || content.isShowingSwarmsView || content.isShowingSchedulesView || content.isShowingAgentConfigView {
             let validIds = collectAllTerminalIds()
-            content.clearStaleViews(validIds: validIds)
+            content.clearStaleViews(validIds: validIds, endedAgentIds: collectEndedAgentIds())
             return
         }
 
@@ -716,7 +1066,7 @@ class DashboardSplitViewController: NSSplitViewController {
             let projects = OpenProjects.shared.projects
             content.refreshHomeDashboard(projects: projects, worktreesByProject: sidebar.projectWorktrees)
             let validIds = collectAllTerminalIds()
-            content.clearStaleViews(validIds: validIds)
+            content.clearStaleViews(validIds: validIds, endedAgentIds: collectEndedAgentIds())
             return
         }
 
@@ -731,7 +1081,7 @@ class DashboardSplitViewController: NSSplitViewController {
         if isDetailItem, content.isShowingWorktreeDetail {
             content.refreshWorktreeDetail()
             let validIds = collectAllTerminalIds()
-            content.clearStaleViews(validIds: validIds)
+            content.clearStaleViews(validIds: validIds, endedAgentIds: collectEndedAgentIds())
             return
         }
 
@@ -756,7 +1106,7 @@ class DashboardSplitViewController: NSSplitViewController {
 
         // Clean stale cached views
         let validIds = collectAllTerminalIds()
-        content.clearStaleViews(validIds: validIds)
+        content.clearStaleViews(validIds: validIds, endedAgentIds: collectEndedAgentIds())
     }
 
     // MARK: - Add Agent / Terminal / Worktree
@@ -774,7 +1124,9 @@ class DashboardSplitViewController: NSSplitViewController {
             workingDir: workingDir,
             initialPrompt: initialPrompt
         )
-        pendingNavigationEntryId = entry.id
+        if AppSettingsManager.shared.autoOpenSpawnedAgents {
+            navigateWhenAvailable(entry.id)
+        }
         sidebar.refresh()
     }
 
@@ -791,10 +1143,16 @@ class DashboardSplitViewController: NSSplitViewController {
                 project.dashboardSession.sendTmuxKeys(target: target, command: cmd)
             }
         }
-        pendingNavigationEntryId = entry.id
+        navigateWhenAvailable(entry.id)
         sidebar.refresh()
     }
 
+    /// Select `entryId` once a sidebar refresh picks it up, giving up after a timeout.
+    private func navigateWhenAvailable(_ entryId: String) {
+        pendingNavigationEntryId = entryId
+        pendingNavigationDeadline = Date().addingTimeInterval(Self.pendingNavigationTimeout)
+    }
+
     private func createWorktree(project: ProjectContext) {
         let alert = NSAlert()
         alert.messageText = "New Worktree"
@@ -819,24 +1177,65 @@ class DashboardSplitViewController: NSSplitViewController {
         guard !projectRoot.isEmpty else { return }
 
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
-            let result = PPGService.shared.runPPGCommand("worktree create --name \(shellEscape(name)) --json", projectRoot: projectRoot)
+            let args = "worktree create --name \(shellEscape(name)) --json"
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
 
             DispatchQueue.main.async {
                 guard let self = self else { return }
                 if result.exitCode == 0 {
                     self.sidebar.refresh()
                 } else {
-                    let errAlert = NSAlert()
-                    errAlert.messageText = "Failed to Create Worktree"
-                    errAlert.informativeText = result.stderr.isEmpty ? result.stdout : result.stderr
-                    errAlert.alertStyle = .warning
-                    errAlert.runModal()
+                    CommandFailureAlert.show(title: "Failed to Create Worktree", args: args, result: result)
                 }
             }
         }
     }
 
+    // MARK: - Manifest Version
+
+    /// Tell the user once per project and version when a manifest is too new to read.
+    private func checkManifestVersions() {
+        let blocked = sidebar.blockedManifestVersions
+        let keys = Set(blocked.map { "\($0.key)@\($0.value)" })
+        guard !keys.isSubset(of: reportedManifestVersions),
+              let version = blocked.values.max() else { return }
+        reportedManifestVersions.formUnion(keys)
+
+        let supported = PPGService.supportedManifestVersions
+        let range = supported.count == 1 ? "v\(supported.lowerBound)" : "v\(supported.lowerBound)–v\(supported.upperBound)"
+        let names = blocked.keys.sorted().map { URL(fileURLWithPath: $0).lastPathComponent }
+        let alert = NSAlert()
+        alert.messageText = "Manifest Version Not Supported"
+        alert.informativeText = "This app reads manifest \(range), but a newer ppg wrote v\(version) in \(names.joined(separator: ", ")). Updates for \(names.count == 1 ? "that project" : "those projects") are paused until you update the app.\n\nIgnoring reads the manifest anyway; some details may be missing or wrong."
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "Check for Updates")
+        alert.addButton(withTitle: "Ignore and Continue")
+        alert.addButton(withTitle: "Not Now")
+
+        switch alert.runModal() {
+        case .alertFirstButtonReturn:
+            UpdaterManager.shared.updaterController.checkForUpdates(nil)
+        case .alertSecondButtonReturn:
+            PPGService.shared.ignoresManifestVersion = true
+            sidebar.refresh()
+        default:
+            break
+        }
+    }
+
+    /// False (after telling the user why) if the manifest is a version this build can't safely rewrite.
+    private func canEditManifest(_ project: ProjectContext) -> Bool {
+        guard let version = PPGService.shared.blockingManifestVersion(at: project.manifestPath) else { return true }
+        let alert = NSAlert()
+        alert.messageText = "Can't Edit Manifest"
+        alert.informativeText = "\(project.projectName) has manifest v\(version), which this version of the app doesn't support. Update the app to make changes."
+        alert.alertStyle = .warning
+        alert.runModal()
+        return false
+    }
+
     private func renameManifestAgent(project: ProjectContext, agentId: String, newName: String) {
+        guard canEditManifest(project) else { return }
         let manifestPath = project.manifestPath
         guard let data = FileManager.default.contents(atPath: manifestPath),
               var json = try? JSONSerialization.jsonObject(with: data) as? [String: Any],
@@ -862,23 +1261,49 @@ class DashboardSplitViewController: NSSplitViewController {
         sidebar.refresh()
     }
 
-    private func renameManifestWorktree(project: ProjectContext, worktreeId: String, newName: String) {
+    /// Returns false (after telling the user why) if the rename was rejected.
+    @discardableResult
+    private func renameManifestWorktree(project: ProjectContext, worktreeId: String, newName: String) -> Bool {
+        // The same trimmed name is checked for duplicates and written
+        let newName = newName.trimmingCharacters(in: .whitespaces)
+        guard !newName.isEmpty, canEditManifest(project) else { return false }
         let manifestPath = project.manifestPath
         guard let data = FileManager.default.contents(atPath: manifestPath),
               var json = try? JSONSerialization.jsonObject(with: data) as? [String: Any],
               var worktrees = json["worktrees"] as? [String: Any],
-              var wt = worktrees[worktreeId] as? [String: Any] else { return }
+              var wt = worktrees[worktreeId] as? [String: Any] else { return false }
+
+        let duplicate = worktrees.contains { id, value in
+            id != worktreeId && (value as? [String: Any])?["name"] as? String == newName
+        }
+        if duplicate {
+            let alert = NSAlert()
+            alert.messageText = "Can't Rename Worktree"
+            alert.informativeText = "Another worktree is already named \"\(newName)\"."
+            alert.alertStyle = .warning
+            alert.runModal()
+            return false
+        }
 
         wt["name"] = newName
         worktrees[worktreeId] = wt
         json["worktrees"] = worktrees
         json["updatedAt"] = ISO8601DateFormatter().string(from: Date())
 
-        if let updatedData = try? JSONSerialization.data(withJSONObject: json, options: [.prettyPrinted, .sortedKeys]) {
-            try? updatedData.write(to: URL(fileURLWithPath: manifestPath))
+        do {
+            let updatedData = try JSONSerialization.data(withJSONObject: json, options: [.prettyPrinted, .sortedKeys])
+            try updatedData.write(to: URL(fileURLWithPath: manifestPath))
+        } catch {
+            let alert = NSAlert()
+            alert.messageText = "Can't Rename Worktree"
+            alert.informativeText = "The manifest couldn't be saved: \(error.localizedDescription)"
+            alert.alertStyle = .warning
+            alert.runModal()
+            return false
         }
 
         sidebar.refresh()
+        return true
     }
 
     private func deleteManifestAgent(project: ProjectContext, agentId: String) {
@@ -886,15 +1311,12 @@ class DashboardSplitViewController: NSSplitViewController {
         guard !projectRoot.isEmpty else { return }
 
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
-            let result = PPGService.shared.runPPGCommand("kill --agent \(shellEscape(agentId)) --delete --json", projectRoot: projectRoot)
+            let args = "kill --agent \(shellEscape(agentId)) --delete --json"
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
             DispatchQueue.main.async {
                 guard let self = self else { return }
                 if result.exitCode != 0 {
-                    let alert = NSAlert()
-                    alert.messageText = "Failed to Delete Agent"
-                    alert.informativeText = result.stderr.isEmpty ? result.stdout : result.stderr
-                    alert.alertStyle = .warning
-                    alert.runModal()
+                    CommandFailureAlert.show(title: "Failed to Delete Agent", args: args, result: result)
                 }
                 self.content.removeEntry(byId: agentId)
                 self.sidebar.refresh()
@@ -902,6 +1324,232 @@ class DashboardSplitViewController: NSSplitViewController {
         }
     }
 
+    // MARK: - Exited Agents
+
+    /// Let open log viewers stop following agents that exited or left the manifest.
+    /// Projects whose read came back blocked or empty (possibly mid-write) don't count
+    /// as evidence that an agent is gone.
+    private func updateLogViewers() {
+        let loadedRoots = sidebar.projectWorktrees
+            .filter { !$0.value.isEmpty && sidebar.blockedManifestVersions[$0.key] == nil }
+            .keys
+        LogViewerWindowController.agentsRefreshed(sidebar.agentIndex, loadedRoots: Set(loadedRoots))
+    }
+
+    /// Open the agent's log viewer window (`ppg logs`).
+    private func showAgentLogs(_ agent: AgentModel) {
+        guard let project = projectContextForGridOwner(agent.id), !project.projectRoot.isEmpty else { return }
+        LogViewerWindowController.show(agent: agent, projectRoot: project.projectRoot)
+    }
+
+    /// Respawn an agent (`ppg restart`), with its original prompt unless `request` overrides
+    /// it. The pane that showed the old agent switches to the replacement once it appears
+    /// in the manifest.
+    private func restartAgent(_ agent: AgentModel, request: RestartRequest = RestartRequest()) {
+        guard let project = projectContextForGridOwner(agent.id), !project.projectRoot.isEmpty else { return }
+        let projectRoot = project.projectRoot
+        restartingAgentIds.insert(agent.id)
+
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let args = request.arguments(agentId: agent.id)
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
+            DispatchQueue.main.async {
+                guard let self = self else { return }
+                self.restartingAgentIds.remove(agent.id)
+                guard result.exitCode == 0, let response = RestartRequest.response(fromJSON: result.stdout) else {
+                    CommandFailureAlert.show(title: "Failed to Restart Agent", args: args, result: result)
+                    return
+                }
+                let newId = response.newAgentId
+                self.recordRestart(of: agent, request: request, args: args, response: response)
+                self.pendingAgentReplacements[agent.id] = newId
+                // An edited restart is a new spawn as far as the user is concerned, so it
+                // follows the palette's auto-open setting. A visible old agent is swapped
+                // in place instead.
+                let oldAgentVisible = self.content.currentEntryId == agent.id
+                    || self.content.paneGrid?.containsEntry(id: agent.id) == true
+                if request.prompt != nil, !oldAgentVisible, AppSettingsManager.shared.autoOpenSpawnedAgents {
+                    self.navigateWhenAvailable(newId)
+                }
+                self.sidebar.refresh()
+            }
+        }
+    }
+
+    /// Ask for an edited prompt (and optionally another agent type), then restart with it.
+    private func restartAgentWithNewPrompt(_ agent: AgentModel) {
+        guard let project = projectContextForGridOwner(agent.id), !project.projectRoot.isEmpty,
+              let request = RestartDialog(agent: agent, projectRoot: project.projectRoot).run() else { return }
+        restartAgent(agent, request: request)
+    }
+
+    /// Respawn every exited agent in a worktree with its original prompt, a few at a
+    /// time. Panes showing them switch over as replacements appear; failures are
+    /// reported together once the batch is done.
+    private func restartExitedAgents(project: ProjectContext, worktree: WorktreeModel) {
+        let agents = BatchRestart.restartable(worktree.agents)
+        let projectRoot = project.projectRoot
+        guard !agents.isEmpty, !projectRoot.isEmpty, worktreeRestarts[worktree.id] == nil else { return }
+
+        let count = agents.count
+        let alert = NSAlert()
+        alert.messageText = "Restart \(count) exited agent\(count == 1 ? "" : "s") in \"\(worktree.name)\"?"
+        alert.informativeText = "Each starts again with its original prompt in a new tmux window. Running agents are left alone."
+        alert.addButton(withTitle: "Restart")
+        alert.addButton(withTitle: "Cancel")
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        worktreeRestarts[worktree.id] = BatchRestart(total: count)
+        restartingAgentIds.formUnion(agents.map(\.id))
+        let worktreeId = worktree.id
+        let limiter = DispatchSemaphore(value: BatchRestart.maxConcurrent)
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            for agent in agents {
+                limiter.wait()
+                DispatchQueue.global(qos: .userInitiated).async {
+                    let args = RestartRequest().arguments(agentId: agent.id)
+                    let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
+                    limiter.signal()
+                    DispatchQueue.main.async {
+                        self?.recordBatchRestart(worktreeId: worktreeId, agent: agent, args: args, result: result)
+                    }
+                }
+            }
+        }
+    }
+
+    private func recordBatchRestart(worktreeId: String, agent: AgentModel, args: String, result: PPGService.CommandResult) {
+        restartingAgentIds.remove(agent.id)
+        guard var batch = worktreeRestarts[worktreeId] else { return }
+        if result.exitCode == 0, let response = RestartRequest.response(fromJSON: result.stdout) {
+            batch.recordSuccess()
+            recordRestart(of: agent, request: RestartRequest(), args: args, response: response, batchSize: batch.total)
+            pendingAgentReplacements[agent.id] = response.newAgentId
+        } else {
+            batch.recordFailure(name: agent.name.isEmpty ? agent.id : agent.name, CommandFailure(args: args, result: result))
+        }
+        sidebar.refresh()
+
+        guard batch.isFinished else {
+            worktreeRestarts[worktreeId] = batch
+            return
+        }
+        worktreeRestarts.removeValue(forKey: worktreeId)
+        guard !batch.failures.isEmpty else { return }
+        if batch.total == 1, let only = batch.failures.first {
+            CommandFailureAlert.show(title: "Failed to Restart Agent", failure: only.failure)
+            return
+        }
+        let alert = NSAlert()
+        alert.messageText = batch.summary
+        alert.informativeText = batch.failures.map { "\($0.name): \($0.failure.summary)" }.joined(separator: "\n")
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "OK")
+        alert.runModal()
+    }
+
+    /// Keep what a restart asked for and what ppg reported back in the project's history,
+    /// for the new agent's info popover and the status report.
+    private func recordRestart(of agent: AgentModel, request: RestartRequest, args: String,
+                               response: RestartRequest.Response, batchSize: Int = 1) {
+        guard let location = sidebar.agentIndex.find(agent.id),
+              let project = OpenProjects.shared.projects.first(where: { $0.projectRoot == location.projectRoot }) else { return }
+        project.statusHistory.recordSpawn(SpawnRecord(
+            restarting: agent, worktreeId: location.worktree.id, request: request,
+            args: args, response: response, batchSize: batchSize
+        ))
+    }
+
+    /// The manifest agent in the focused pane (or the single visible pane), if any.
+    var focusedManifestAgent: AgentModel? {
+        guard let id = content.currentEntryId else { return nil }
+        return manifestAgent(byId: id)?.agent
+    }
+
+    /// `ppg restart` kills an agent that is still alive, so that case asks first like Kill does.
+    func restartFocusedAgent() {
+        guard let agent = focusedManifestAgent else { return }
+        if BatchRestart.restartable([agent]).isEmpty {
+            let name = agent.name.isEmpty ? agent.id : agent.name
+            let alert = NSAlert()
+            alert.messageText = "Restart \(name)?"
+            alert.informativeText = "The agent is still running. Restarting kills it and starts it again with its original prompt."
+            alert.addButton(withTitle: "Restart")
+            alert.addButton(withTitle: "Cancel")
+            alert.alertStyle = .warning
+            guard alert.runModal() == .alertFirstButtonReturn else { return }
+        }
+        restartAgent(agent)
+    }
+
+    func killFocusedAgent() {
+        guard let agent = focusedManifestAgent,
+              let project = projectContextForGridOwner(agent.id), !project.projectRoot.isEmpty else { return }
+        let name = agent.name.isEmpty ? agent.id : agent.name
+
+        let alert = NSAlert()
+        alert.messageText = "Kill \(name)?"
+        alert.informativeText = "The agent's process is stopped. Its pane keeps the scrollback and can be restarted."
+        alert.addButton(withTitle: "Kill")
+        alert.addButton(withTitle: "Cancel")
+        alert.alertStyle = .warning
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        let projectRoot = project.projectRoot
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let args = "kill --agent \(shellEscape(agent.id)) --json"
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
+            DispatchQueue.main.async {
+                guard let self = self else { return }
+                if result.exitCode != 0 {
+                    CommandFailureAlert.show(title: "Failed to Kill Agent", args: args, result: result)
+                }
+                self.sidebar.refresh()
+            }
+        }
+    }
+
+    private func applyPendingAgentReplacements() {
+        for (oldId, newId) in pendingAgentReplacements {
+            guard let found = manifestAgent(byId: newId) else { continue }
+            pendingAgentReplacements.removeValue(forKey: oldId)
+
+            let entry = TabEntry.manifestAgent(found.agent, sessionName: found.project.sessionName)
+            if let grid = content.paneGrid, grid.replaceEntry(id: oldId, with: entry) {
+                persistGridLayout()
+            } else if content.currentEntryId == oldId {
+                _ = sidebar.selectItem(byId: newId)
+            }
+        }
+    }
+
+    private func manifestAgent(byId id: String) -> (project: ProjectContext, agent: AgentModel)? {
+        guard let location = sidebar.agentIndex.find(id) else { return nil }
+        for projectNode in sidebar.projectNodes {
+            guard case .project(let ctx) = projectNode.item, ctx.projectRoot == location.projectRoot else { continue }
+            return (ctx, location.agent)
+        }
+        return nil
+    }
+
+    private func killWorktreeAgents(project: ProjectContext, worktreeId: String) {
+        let projectRoot = project.projectRoot
+        guard !projectRoot.isEmpty else { return }
+
+        // Without --remove/--delete, `ppg kill --worktree` only stops the agents.
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let args = "kill --worktree \(shellEscape(worktreeId)) --json"
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
+            DispatchQueue.main.async {
+                guard let self = self else { return }
+                if result.exitCode != 0 {
+                    CommandFailureAlert.show(title: "Failed to Kill Agents", args: args, result: result)
+                }
+                self.sidebar.refresh()
+            }
+        }
+    }
+
     private func deleteWorktree(project: ProjectContext, worktreeId: String) {
         let projectRoot = project.projectRoot
         guard !projectRoot.isEmpty else { return }
@@ -914,15 +1562,12 @@ class DashboardSplitViewController: NSSplitViewController {
         let sessionEntryIds = project.dashboardSession.entriesForWorktree(worktreeId).map(\.id)
 
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
-            let result = PPGService.shared.runPPGCommand("kill --worktree \(shellEscape(worktreeId)) --delete --json", projectRoot: projectRoot)
+            let args = "kill --worktree \(shellEscape(worktreeId)) --delete --json"
+            let result = PPGService.shared.runPPGCommand(args, projectRoot: projectRoot)
             DispatchQueue.main.async {
                 guard let self = self else { return }
                 if result.exitCode != 0 {
-                    let alert = NSAlert()
-                    alert.messageText = "Failed to Delete Worktree"
-                    alert.informativeText = result.stderr.isEmpty ? result.stdout : result.stderr
-                    alert.alertStyle = .warning
-                    alert.runModal()
+                    CommandFailureAlert.show(title: "Failed to Delete Worktree", args: args, result: result)
                 }
                 // Remove content views for all agents in the worktree
                 for id in agentIds {
@@ -954,13 +1599,25 @@ class DashboardSplitViewController: NSSplitViewController {
         return FileManager.default.homeDirectoryForCurrentUser.path
     }
 
+    /// Jump to any worktree or agent in the open projects (⌘K). Selection goes through
+    /// the sidebar, so it works the same with the sidebar collapsed.
+    func showQuickSwitcher() {
+        let projectWorktrees = OpenProjects.shared.projects.map {
+            (root: $0.projectRoot, worktrees: sidebar.projectWorktrees[$0.projectRoot] ?? [])
+        }
+        let items = QuickSwitcherItem.items(projectWorktrees: projectWorktrees)
+        QuickSwitcherPanel.show(relativeTo: view.window, items: items) { [weak self] item in
+            self?.sidebar.revealItem(byId: item.id)
+        }
+    }
+
     /// Show Raycast-style command palette scoped to current sidebar selection's project.
     /// Always creates a sidebar entry (Cmd+N). Grid pane filling uses `showCreationMenuForGrid()`.
     func showCreationMenu() {
         guard let ctx = sidebar.selectedProjectContext() else { return }
         let worktreeId = sidebar.selectedWorktreeId()
 
-        CommandPalettePanel.show(relativeTo: view.window) { [weak self] variant, prompt in
+        CommandPalettePanel.show(relativeTo: view.window, preferredVariantId: ctx.configuredDefaultAgent) { [weak self] variant, prompt in
             self?.handlePaletteSelection(variant: variant, prompt: prompt,
                                           project: ctx, worktreeId: worktreeId, isGrid: false)
         }
@@ -968,7 +1625,7 @@ class DashboardSplitViewController: NSSplitViewController {
 
     /// Show command palette scoped to a specific project (per-project + button).
     func showCreationMenuForProject(_ ctx: ProjectContext) {
-        CommandPalettePanel.show(relativeTo: view.window) { [weak self] variant, prompt in
+        CommandPalettePanel.show(relativeTo: view.window, preferredVariantId: ctx.configuredDefaultAgent) { [weak self] variant, prompt in
             self?.handlePaletteSelection(variant: variant, prompt: prompt,
                                           project: ctx, worktreeId: nil, isGrid: false)
         }
@@ -979,7 +1636,8 @@ class DashboardSplitViewController: NSSplitViewController {
         guard let ctx = sidebar.selectedProjectContext() else { return }
         let worktreeId = sidebar.selectedWorktreeId()
 
-        CommandPalettePanel.show(relativeTo: view.window, variants: AgentVariant.paneVariants) { [weak self] variant, prompt in
+        CommandPalettePanel.show(relativeTo: view.window, variants: AgentVariant.paneVariants,
+                                 preferredVariantId: ctx.configuredDefaultAgent) { [weak self] variant, prompt in
             self?.handlePaletteSelection(variant: variant, prompt: prompt,
                                           project: ctx, worktreeId: worktreeId, isGrid: true)
         }
@@ -1016,11 +1674,13 @@ class DashboardSplitViewController: NSSplitViewController {
 
 class EditableTitleBarAccessory: NSTitlebarAccessoryViewController, NSTextFieldDelegate {
     private let titleField = NSTextField()
+    private let fleetButton = NSButton()
     private var nameBeforeEditing = ""
     private var isEditing = false
     private var isEditableItem = false
 
     var onRename: ((String) -> Void)?
+    var onFleetClicked: ((NSButton) -> Void)?
 
     override func loadView() {
         let container = NSView()
@@ -1042,12 +1702,25 @@ class EditableTitleBarAccessory: NSTitlebarAccessoryViewController, NSTextFieldD
         let click = NSClickGestureRecognizer(target: self, action: #selector(titleClicked))
         titleField.addGestureRecognizer(click)
 
+        // Hidden until the first sidebar refresh so it never shows made-up zeros
+        fleetButton.bezelStyle = .accessoryBarAction
+        fleetButton.isBordered = false
+        fleetButton.font = .systemFont(ofSize: 11)
+        fleetButton.target = self
+        fleetButton.action = #selector(fleetButtonClicked)
+        fleetButton.isHidden = true
+        fleetButton.translatesAutoresizingMaskIntoConstraints = false
+
         container.addSubview(titleField)
+        container.addSubview(fleetButton)
         NSLayoutConstraint.activate([
             container.heightAnchor.constraint(equalToConstant: 22),
             titleField.centerXAnchor.constraint(equalTo: container.centerXAnchor),
             titleField.centerYAnchor.constraint(equalTo: container.centerYAnchor),
             titleField.widthAnchor.constraint(lessThanOrEqualTo: container.widthAnchor, constant: -32),
+            fleetButton.trailingAnchor.constraint(equalTo: container.trailingAnchor, constant: -12),
+            fleetButton.centerYAnchor.constraint(equalTo: container.centerYAnchor),
+            fleetButton.leadingAnchor.constraint(greaterThanOrEqualTo: titleField.trailingAnchor, constant: 12),
         ])
 
         view = container
@@ -1059,6 +1732,22 @@ class EditableTitleBarAccessory: NSTitlebarAccessoryViewController, NSTextFieldD
         isEditableItem = editable
     }
 
+    /// Show fleet-wide agent counts at the trailing edge of the titlebar.
+    func setFleetSummary(_ summary: FleetSummary) {
+        let color: NSColor = summary.counts.failed > 0 ? .systemRed : .secondaryLabelColor
+        fleetButton.attributedTitle = NSAttributedString(string: summary.text, attributes: [
+            .font: NSFont.systemFont(ofSize: 11),
+            .foregroundColor: color,
+        ])
+        fleetButton.toolTip = summary.counts.failed > 0 ? "Show failed agents" : nil
+        fleetButton.setAccessibilityLabel(summary.accessibilityLabel)
+        fleetButton.isHidden = false
+    }
+
+    @objc private func fleetButtonClicked(_ sender: NSButton) {
+        onFleetClicked?(sender)
+    }
+
     @objc private func titleClicked() {
         guard isEditableItem, !isEditing else { return }
         isEditing = true
@@ -0,0 +1,57 @@
+import Foundation
+
+/// Trailing-edge debounce on the main queue: a burst of `schedule` calls runs the
+/// action once, `delay` after the last call. A steady stream of calls still runs it
+/// at least every `maxDelay`, so continuous writes can't starve the UI.
+final class Debouncer {
+    /// Runs `item` after the given number of seconds.
+    typealias Scheduler = (TimeInterval, DispatchWorkItem) -> Void
+
+    let delay: TimeInterval
+    let maxDelay: TimeInterval
+    private let action: () -> Void
+    private let now: () -> Date
+    private let scheduler: Scheduler
+    private var workItem: DispatchWorkItem?
+    /// When the first call of the pending burst arrived.
+    private var burstStart: Date?
+
+    /// The app uses the defaults; tests pass a clock and scheduler they drive by hand.
+    init(delay: TimeInterval, maxDelay: TimeInterval,
+         now: @escaping () -> Date = Date.init,
+         scheduler: @escaping Scheduler = { wait, item in DispatchQueue.main.asyncAfter(deadline: .now() + wait, execute: item) },
+         action: @escaping () -> Void) {
+        self.delay = delay
+        self.maxDelay = max(delay, maxDelay)
+        self.now = now
+        self.scheduler = scheduler
+        self.action = action
+    }
+
+    func schedule() {
+        let time = now()
+        let start = burstStart ?? time
+        burstStart = start
+        let wait = min(delay, max(0, start.addingTimeInterval(maxDelay).timeIntervalSince(time)))
+
+        workItem?.cancel()
+        let item = DispatchWorkItem { [weak self] in
+            self?.fire()
+        }
+        workItem = item
+        scheduler(wait, item)
+    }
+
+    /// Drop the pending run, if any.
+    func cancel() {
+        workItem?.cancel()
+        workItem = nil
+        burstStart = nil
+    }
+
+    private func fire() {
+        workItem = nil
+        burstStart = nil
+        action()
+    }
+}
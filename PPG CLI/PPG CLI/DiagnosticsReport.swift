@@ -0,0 +1,128 @@
+import Foundation
+
+/// Environment facts for bug reports, rendered as Markdown by App ▸ Diagnostics.
+/// Every field is optional; anything that couldn't be determined reads "unknown"
+/// instead of failing the whole report.
+nonisolated struct DiagnosticsReport {
+    var appVersion: String?
+    var appBuild: String?
+    var osVersion: String?
+    var architecture: String?
+    var ppgVersion: String?
+    var ppgSupported: Bool?
+    var tmuxVersion: String?
+    var appearance: String?
+    var terminalFont: String?
+    var openProjects: Int?
+    var runningAgents: Int?
+    /// Doctor check: manifest entries whose tmux window is gone. Nil if tmux couldn't be queried.
+    var orphanedEntries: [String]?
+    /// Where the user's theme.json is read from, whether it's there, and why it was rejected.
+    var themePath: String?
+    var themeFileExists: Bool?
+    var themeError: String?
+
+    func markdown() -> String {
+        var ppg = Self.value(ppgVersion)
+        if ppgSupported == false {
+            ppg += " (needs ≥ \(PPGService.minimumCLIVersion))"
+        }
+        let rows: [(String, String)] = [
+            ("App version", Self.value(appVersion)),
+            ("Build", Self.value(appBuild)),
+            ("macOS", Self.value(osVersion)),
+            ("Architecture", Self.value(architecture)),
+            ("ppg", ppg),
+            ("tmux", Self.value(tmuxVersion)),
+            ("Appearance", Self.value(appearance)),
+            ("Terminal font", Self.value(terminalFont)),
+            ("Open projects", Self.value(openProjects.map(String.init))),
+            ("Running agents", Self.value(runningAgents.map(String.init))),
+        ]
+        var lines = ["## PPG CLI Diagnostics", ""]
+        lines += rows.map { "- **\($0.0):** \($0.1)" }
+        lines += ["", "### Doctor", ""]
+        switch orphanedEntries {
+        case nil:
+            lines.append("- **Orphaned manifest entries:** unknown")
+        case let entries? where entries.isEmpty:
+            lines.append("- **Orphaned manifest entries:** none")
+        case let entries?:
+            lines.append("- **Orphaned manifest entries:**")
+            lines += entries.map { "  - \($0)" }
+        }
+        lines += ["", "### Theme", ""]
+        var theme = Self.value(themePath)
+        switch (themeFileExists, themeError) {
+        case (_, let error?): theme += " (not applied: \(error))"
+        case (true?, nil): theme += " (loaded)"
+        case (false?, nil): theme += " (not present)"
+        case (nil, nil): break
+        }
+        lines.append("- **File:** \(theme)")
+        lines.append("- **Keys:** " + ThemeOverrides.keys.map { "`\($0)`" }.joined(separator: ", "))
+        return lines.joined(separator: "\n") + "\n"
+    }
+
+    private static func value(_ text: String?) -> String {
+        guard let text, !text.isEmpty else { return "unknown" }
+        return text
+    }
+}
+
+extension DiagnosticsReport {
+    /// Fill in the tool versions and the doctor check off the main thread, then call
+    /// `completion` on main. `base` carries the fields that have to be read on the main
+    /// actor; `projectWorktrees` is the sidebar's view of each open project's manifest.
+    static func collect(base: DiagnosticsReport, projectWorktrees: [String: [WorktreeModel]] = [:], completion: @escaping @MainActor (DiagnosticsReport) -> Void) {
+        DispatchQueue.global(qos: .userInitiated).async {
+            var report = base
+            let cli = PPGService.shared.checkCLIAvailable()
+            if cli.available {
+                report.ppgVersion = cli.version
+                report.ppgSupported = cli.supported
+            } else {
+                report.ppgVersion = "not found"
+            }
+            let tmux = PPGService.shared.checkTmuxAvailable()
+            report.tmuxVersion = tmux.available ? tmux.version : "not found"
+            if tmux.available, let snapshot = PPGService.shared.tmuxSnapshot() {
+                report.orphanedEntries = orphanedEntries(in: projectWorktrees, snapshot: snapshot)
+            }
+
+            let finished = report
+            DispatchQueue.main.async {
+                completion(finished)
+            }
+        }
+    }
+
+    /// Worktrees and live agents whose tmux window no longer exists, e.g. after tmux
+    /// restarted. Agents that already exited are expected to have lost theirs.
+    static func orphanedEntries(in projectWorktrees: [String: [WorktreeModel]], snapshot: TmuxSnapshot) -> [String] {
+        let liveStatuses: Set<AgentStatus> = [.spawning, .running, .waiting]
+        var entries: [String] = []
+        for root in projectWorktrees.keys.sorted() {
+            let project = URL(fileURLWithPath: root).lastPathComponent
+            for worktree in projectWorktrees[root] ?? [] {
+                if !worktree.tmuxWindow.isEmpty, !snapshot.contains(TmuxTarget(parsing: worktree.tmuxWindow)) {
+                    entries.append("\(project) / \(worktree.name): window \(worktree.tmuxWindow) is missing")
+                }
+                for agent in worktree.agents where liveStatuses.contains(agent.status) {
+                    guard !agent.tmuxTarget.isEmpty, !snapshot.contains(TmuxTarget(parsing: agent.tmuxTarget)) else { continue }
+                    entries.append("\(project) / \(worktree.name) / \(agent.name): \(agent.status.rawValue) agent's window \(agent.tmuxTarget) is missing")
+                }
+            }
+        }
+        return entries
+    }
+
+    /// Machine architecture as reported by `uname`, e.g. "arm64".
+    static func machineArchitecture() -> String? {
+        var info = utsname()
+        guard uname(&info) == 0 else { return nil }
+        return withUnsafeBytes(of: &info.machine) { bytes in
+            bytes.split(separator: 0).first.map { String(decoding: $0, as: UTF8.self) }
+        }
+    }
+}
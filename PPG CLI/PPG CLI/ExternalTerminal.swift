@@ -0,0 +1,143 @@
+import AppKit
+
+/// Terminal apps that "Attach in Terminal" and the setup view's Run button can open a
+/// command in. Each has its own way of being told to run something, captured by `launchArguments`.
+nonisolated enum ExternalTerminal: String, CaseIterable {
+    case terminal
+    case iTerm
+    case ghostty
+    case kitty
+    case alacritty
+    case wezTerm
+
+    var displayName: String {
+        switch self {
+        case .terminal: return "Terminal"
+        case .iTerm: return "iTerm2"
+        case .ghostty: return "Ghostty"
+        case .kitty: return "kitty"
+        case .alacritty: return "Alacritty"
+        case .wezTerm: return "WezTerm"
+        }
+    }
+
+    var bundleIdentifier: String {
+        switch self {
+        case .terminal: return "com.apple.Terminal"
+        case .iTerm: return "com.googlecode.iterm2"
+        case .ghostty: return "com.mitchellh.ghostty"
+        case .kitty: return "net.kovidgoyal.kitty"
+        case .alacritty: return "org.alacritty"
+        case .wezTerm: return "com.github.wez.wezterm"
+        }
+    }
+
+    /// Executable and arguments that open a new window running `command` (a Bourne shell
+    /// command line). Terminal and iTerm2 are scripted with AppleScript; the others are
+    /// launched as a new instance with their own "run this program" flag, and hand over to
+    /// a login shell afterwards so the window and the command's output stay open, as they
+    /// do in Terminal and iTerm2.
+    func launchArguments(command: String, appPath: String) -> (executable: String, arguments: [String]) {
+        let shell = ["/bin/zsh", "-lc", "\(command); exec /bin/zsh -l"]
+        switch self {
+        case .terminal:
+            let script = Self.appleScriptString(command)
+            return ("/usr/bin/osascript", [
+                "-e", "tell application \"Terminal\" to do script \(script)",
+                "-e", "tell application \"Terminal\" to activate",
+            ])
+        case .iTerm:
+            let script = Self.appleScriptString(command)
+            return ("/usr/bin/osascript", [
+                "-e", "tell application \"iTerm\"",
+                "-e", "activate",
+                "-e", "set newWindow to (create window with default profile)",
+                "-e", "tell current session of newWindow to write text \(script)",
+                "-e", "end tell",
+            ])
+        case .ghostty, .alacritty:
+            return ("/usr/bin/open", ["-na", appPath, "--args", "-e"] + shell)
+        case .kitty:
+            return ("/usr/bin/open", ["-na", appPath, "--args"] + shell)
+        case .wezTerm:
+            return ("/usr/bin/open", ["-na", appPath, "--args", "start", "--"] + shell)
+        }
+    }
+
+    /// `text` as a double-quoted AppleScript string literal.
+    static func appleScriptString(_ text: String) -> String {
+        let escaped = text
+            .replacingOccurrences(of: "\\", with: "\\\\")
+            .replacingOccurrences(of: "\"", with: "\\\"")
+        return "\"\(escaped)\""
+    }
+
+    // MARK: - Detection
+
+    /// Where the app is installed, or nil if Launch Services doesn't know it.
+    var applicationURL: URL? {
+        NSWorkspace.shared.urlForApplication(withBundleIdentifier: bundleIdentifier)
+    }
+
+    static func installed() -> [ExternalTerminal] {
+        allCases.filter { $0.applicationURL != nil }
+    }
+
+    /// The terminal to use: the preferred one from Settings if it's still installed,
+    /// otherwise Terminal, which ships with macOS.
+    static func detect(preferred: String?) -> ExternalTerminal {
+        if let preferred, let terminal = ExternalTerminal(rawValue: preferred), terminal.applicationURL != nil {
+            return terminal
+        }
+        return .terminal
+    }
+
+    /// True when running inside the App Sandbox, where other apps can't be launched or scripted.
+    static var isSandboxed: Bool {
+        ProcessInfo.processInfo.environment["APP_SANDBOX_CONTAINER_ID"] != nil
+    }
+
+    /// Open a new window running `command`, detached from this app. Blocks until the
+    /// launcher (osascript or open) exits; call off the main thread.
+    func open(command: String) throws {
+        guard !Self.isSandboxed else { throw ExternalTerminalError.sandboxed }
+        guard let appURL = applicationURL else { throw ExternalTerminalError.notInstalled(displayName) }
+
+        let launch = launchArguments(command: command, appPath: appURL.path)
+        let task = Process()
+        task.executableURL = URL(fileURLWithPath: launch.executable)
+        task.arguments = launch.arguments
+        task.standardOutput = FileHandle.nullDevice
+        let errPipe = Pipe()
+        task.standardError = errPipe
+
+        do {
+            try task.run()
+            task.waitUntilExit()
+        } catch {
+            throw ExternalTerminalError.launchFailed(displayName, error.localizedDescription)
+        }
+        guard task.terminationStatus == 0 else {
+            let data = errPipe.fileHandleForReading.readDataToEndOfFile()
+            let message = String(data: data, encoding: .utf8)?.trimmingCharacters(in: .whitespacesAndNewlines) ?? ""
+            throw ExternalTerminalError.launchFailed(displayName, message.isEmpty ? "exit code \(task.terminationStatus)" : message)
+        }
+    }
+}
+
+nonisolated enum ExternalTerminalError: LocalizedError {
+    case sandboxed
+    case notInstalled(String)
+    case launchFailed(String, String)
+
+    var errorDescription: String? {
+        switch self {
+        case .sandboxed:
+            return "This copy of PPG CLI runs in the App Sandbox, which can't open other apps."
+        case .notInstalled(let name):
+            return "\(name) isn't installed."
+        case .launchFailed(let name, let message):
+            return "\(name) couldn't be opened: \(message)"
+        }
+    }
+}
@@ -0,0 +1,40 @@
+import Foundation
+
+/// Agent counts across every open project, shown in the titlebar as "4 running · 1 failed".
+/// Built from the full set of manifests on each sidebar refresh, so it always agrees
+/// with the rows below it.
+nonisolated struct FleetSummary {
+    struct FailedAgent {
+        let projectRoot: String
+        let worktree: WorktreeModel
+        let agent: AgentModel
+    }
+
+    private(set) var counts = AgentStatusCounts()
+    /// Failed agents by project root, then in manifest order: those the CLI marks
+    /// exited with a non-zero code.
+    private(set) var failedAgents: [FailedAgent] = []
+
+    init(projectWorktrees: [String: [WorktreeModel]]) {
+        for root in projectWorktrees.keys.sorted() {
+            for worktree in projectWorktrees[root] ?? [] {
+                for agent in worktree.agents {
+                    counts.add(agent.status)
+                    if agent.status == .failed {
+                        failedAgents.append(FailedAgent(projectRoot: root, worktree: worktree, agent: agent))
+                    }
+                }
+            }
+        }
+    }
+
+    /// "4 running · 1 failed"; the failed part is left out while nothing has failed.
+    var text: String {
+        counts.failed > 0 ? "\(counts.running) running · \(counts.failed) failed" : "\(counts.running) running"
+    }
+
+    /// "Agents: 4 running, 1 failed", or "No agents".
+    var accessibilityLabel: String {
+        counts.total == 0 ? "No agents" : "Agents: " + AccessibilityLabels.agentCounts(counts)
+    }
+}
@@ -2,14 +2,38 @@ import AppKit
 
 // MARK: - Data Models
 
-struct AgentStatusCounts {
+nonisolated struct AgentStatusCounts: Equatable {
     var running = 0
     var completed = 0
     var failed = 0
     var killed = 0
     var other = 0
+    /// Running agents with no recent output. They're also counted in `running`.
+    var stalled = 0
 
     var total: Int { running + completed + failed + killed + other }
+
+    /// Count one agent under its status bucket. Spawning agents count as running.
+    mutating func add(_ status: AgentStatus) {
+        switch status {
+        case .running, .spawning: running += 1
+        case .completed: completed += 1
+        case .failed: failed += 1
+        case .killed: killed += 1
+        case .lost, .waiting, .unknown: other += 1
+        }
+    }
+
+    /// Non-zero counts as text: ["2 running", "1 stalled", "1 failed"].
+    var parts: [String] {
+        var parts: [String] = []
+        if running > 0 { parts.append("\(running) running") }
+        if stalled > 0 { parts.append("\(stalled) stalled") }
+        if completed > 0 { parts.append("\(completed) completed") }
+        if failed > 0 { parts.append("\(failed) failed") }
+        if killed > 0 { parts.append("\(killed) killed") }
+        return parts
+    }
 }
 
 struct CommitInfo {
@@ -40,6 +64,8 @@ class HomeDashboardView: NSView {
     // Aggregate stats bar
     private let projectCountLabel = NSTextField(labelWithString: "")
     private let agentStatsLabel = NSTextField(labelWithString: "")
+    private let usageCard = NSStackView()
+    private let usageValueLabel = NSTextField(labelWithString: "")
 
     // Per-project card views (reused on refresh)
     private var projectCards: [String: ProjectCardView] = [:]
@@ -48,6 +74,12 @@ class HomeDashboardView: NSView {
     private var heatmapCache: [String: CommitHeatmapView.HeatmapData] = [:]
     private var lastHeatmapFetch: Date?
 
+    // Branch and recent commits, refetched at most every `gitFetchInterval`: manifest
+    // refreshes come in bursts while agents spawn, and git doesn't change with them.
+    private static let gitFetchInterval: TimeInterval = 30
+    private var gitCache: [String: (branch: String, commits: [CommitInfo])] = [:]
+    private var lastGitFetch: Date?
+
     // Generation counter to discard stale background fetches
     private var fetchGeneration = 0
 
@@ -111,12 +143,39 @@ class HomeDashboardView: NSView {
         statsBar.addArrangedSubview(agentStatsLabel)
         statsBar.addArrangedSubview(NSView())  // spacer
         outerStack.addArrangedSubview(statsBar)
+
+        // Session usage, only when the CLI reports it
+        let usageTitle = NSTextField(labelWithString: "Session usage")
+        usageTitle.font = .systemFont(ofSize: 11, weight: .medium)
+        usageTitle.textColor = .secondaryLabelColor
+        usageValueLabel.font = .monospacedDigitSystemFont(ofSize: 15, weight: .semibold)
+        usageValueLabel.textColor = Theme.primaryText
+
+        usageCard.orientation = .vertical
+        usageCard.alignment = .leading
+        usageCard.spacing = 4
+        usageCard.edgeInsets = NSEdgeInsets(top: 10, left: 12, bottom: 10, right: 12)
+        usageCard.wantsLayer = true
+        usageCard.layer?.cornerRadius = 8
+        usageCard.layer?.borderWidth = 1
+        usageCard.addArrangedSubview(usageTitle)
+        usageCard.addArrangedSubview(usageValueLabel)
+        usageCard.isHidden = true
+        outerStack.addArrangedSubview(usageCard)
+        usageCard.leadingAnchor.constraint(equalTo: outerStack.leadingAnchor, constant: 20).isActive = true
+        applyUsageCardColors()
     }
 
     override func viewDidChangeEffectiveAppearance() {
         super.viewDidChangeEffectiveAppearance()
         layer?.backgroundColor = Theme.contentBackground.resolvedCGColor(for: effectiveAppearance)
         scrollView.backgroundColor = Theme.contentBackground
+        applyUsageCardColors()
+    }
+
+    private func applyUsageCardColors() {
+        usageCard.layer?.backgroundColor = Theme.cardBackground.resolvedCGColor(for: effectiveAppearance)
+        usageCard.layer?.borderColor = NSColor.separatorColor.resolvedCGColor(for: effectiveAppearance)
     }
 
     // MARK: - Visibility
@@ -146,6 +205,7 @@ class HomeDashboardView: NSView {
         // Compute agent counts immediately (cheap)
         var totalAgentCounts = AgentStatusCounts()
         var totalWorktrees = 0
+        var usages: [AgentUsage?] = []
 
         // Snapshot data we need for background work
         var projectSnapshots: [(root: String, name: String, manifestPath: String, worktreeCount: Int, agentCounts: AgentStatusCounts)] = []
@@ -157,13 +217,9 @@ class HomeDashboardView: NSView {
             var counts = AgentStatusCounts()
             for wt in worktrees {
                 for agent in wt.agents {
-                    switch agent.status {
-                    case .running, .spawning: counts.running += 1
-                    case .completed: counts.completed += 1
-                    case .failed: counts.failed += 1
-                    case .killed: counts.killed += 1
-                    case .lost, .waiting: counts.other += 1
-                    }
+                    counts.add(agent.status)
+                    usages.append(agent.usage)
+                    if AgentActivity.shared.stalledSince(agent) != nil { counts.stalled += 1 }
                 }
             }
             totalAgentCounts.running += counts.running
@@ -171,6 +227,7 @@ class HomeDashboardView: NSView {
             totalAgentCounts.failed += counts.failed
             totalAgentCounts.killed += counts.killed
             totalAgentCounts.other += counts.other
+            totalAgentCounts.stalled += counts.stalled
 
             projectSnapshots.append((
                 root: ctx.projectRoot,
@@ -184,21 +241,44 @@ class HomeDashboardView: NSView {
         // Update aggregate stats bar immediately
         projectCountLabel.stringValue = "\(projects.count) project\(projects.count == 1 ? "" : "s"), \(totalWorktrees) worktree\(totalWorktrees == 1 ? "" : "s")"
         updateAgentStatsLabel(totalAgentCounts)
+        updateUsageCard(AgentUsage.total(of: usages))
 
         // Skip expensive background git fetches when the dashboard is not visible.
         // The aggregate stats bar (above) is still updated from cached worktree data.
         guard isVisible else { return }
 
-        // Background: fetch git data per project
+        // Background: fetch git data per project. Within the rate limit only projects
+        // without cached data (newly opened) are fetched.
         let fetchHeatmap = shouldFetchHeatmap
+        let gitIsFresh = lastGitFetch.map { Date().timeIntervalSince($0) < Self.gitFetchInterval } ?? false
+        let rootsToFetch = Set(projectSnapshots.map(\.root).filter { !gitIsFresh || gitCache[$0] == nil })
+        if !gitIsFresh { lastGitFetch = Date() }
         fetchGeneration += 1
         let generation = fetchGeneration
         let cachedHeatmaps = heatmapCache
+        let cachedGit = gitCache
+
+        if rootsToFetch.isEmpty && !fetchHeatmap {
+            // Nothing to ask git; refresh the cards' counts from cached git data
+            updateCards(projectSnapshots.map { snap in
+                ProjectDashboardData(
+                    projectRoot: snap.root,
+                    projectName: snap.name,
+                    branch: cachedGit[snap.root]?.branch ?? "",
+                    worktreeCount: snap.worktreeCount,
+                    agentCounts: snap.agentCounts,
+                    heatmap: cachedHeatmaps[snap.root] ?? CommitHeatmapView.HeatmapData(commitsByDate: [:]),
+                    recentCommits: cachedGit[snap.root]?.commits ?? []
+                )
+            }, skipHeatmap: true)
+            return
+        }
 
         DispatchQueue.global(qos: .utility).async { [weak self] in
             var results: [ProjectDashboardData] = []
             for snap in projectSnapshots {
-                let branch = Self.fetchBranch(projectRoot: snap.root)
+                let fetchGit = rootsToFetch.contains(snap.root)
+                let branch = fetchGit ? Self.fetchBranch(projectRoot: snap.root) : cachedGit[snap.root]?.branch ?? ""
                 let heatmap: CommitHeatmapView.HeatmapData
                 if fetchHeatmap {
                     heatmap = Self.fetchHeatmapData(projectRoot: snap.root)
@@ -206,7 +286,7 @@ class HomeDashboardView: NSView {
                     // Use cached heatmap so new cards don't start empty
                     heatmap = cachedHeatmaps[snap.root] ?? CommitHeatmapView.HeatmapData(commitsByDate: [:])
                 }
-                let commits = Self.fetchRecentCommits(projectRoot: snap.root)
+                let commits = fetchGit ? Self.fetchRecentCommits(projectRoot: snap.root) : cachedGit[snap.root]?.commits ?? []
 
                 results.append(ProjectDashboardData(
                     projectRoot: snap.root,
@@ -227,6 +307,9 @@ class HomeDashboardView: NSView {
                         self.heatmapCache[result.projectRoot] = result.heatmap
                     }
                 }
+                for result in results where rootsToFetch.contains(result.projectRoot) {
+                    self.gitCache[result.projectRoot] = (result.branch, result.recentCommits)
+                }
                 self.updateCards(results, skipHeatmap: !fetchHeatmap)
             }
         }
@@ -235,12 +318,12 @@ class HomeDashboardView: NSView {
     // MARK: - Update UI
 
     private func updateAgentStatsLabel(_ counts: AgentStatusCounts) {
-        var parts: [String] = []
-        if counts.running > 0 { parts.append("\(counts.running) running") }
-        if counts.completed > 0 { parts.append("\(counts.completed) completed") }
-        if counts.failed > 0 { parts.append("\(counts.failed) failed") }
-        if counts.killed > 0 { parts.append("\(counts.killed) killed") }
-        agentStatsLabel.stringValue = parts.isEmpty ? "No agents" : parts.joined(separator: " · ")
+        agentStatsLabel.stringValue = counts.parts.isEmpty ? "No agents" : counts.parts.joined(separator: " · ")
+    }
+
+    private func updateUsageCard(_ usage: AgentUsage?) {
+        usageValueLabel.stringValue = usage?.text() ?? ""
+        usageCard.isHidden = usage == nil
     }
 
     private func updateCards(_ data: [ProjectDashboardData], skipHeatmap: Bool) {
@@ -273,7 +356,7 @@ class HomeDashboardView: NSView {
         PPGService.shared.currentBranch(at: projectRoot)
     }
 
-    static func fetchHeatmapData(projectRoot: String) -> CommitHeatmapView.HeatmapData {
+    nonisolated static func fetchHeatmapData(projectRoot: String) -> CommitHeatmapView.HeatmapData {
         let result = PPGService.shared.runGitCommand([
             "log", "--format=%ad", "--date=format:%Y-%m-%d", "--since=91 days ago", "--all"
         ], cwd: projectRoot)
@@ -287,14 +370,19 @@ class HomeDashboardView: NSView {
         return CommitHeatmapView.HeatmapData(commitsByDate: commitsByDate)
     }
 
+    /// `git log` format consumed by `parseCommitLog` (null-byte delimited for safety).
+    static let commitLogFormat = "--format=%h%x00%s%x00%an%x00%ar%x00%at"
+
     static func fetchRecentCommits(projectRoot: String) -> [CommitInfo] {
-        // Use null-byte delimiter for safety
         let result = PPGService.shared.runGitCommand([
-            "log", "-10", "--format=%h%x00%s%x00%an%x00%ar%x00%at"
+            "log", "-10", commitLogFormat
         ], cwd: projectRoot)
+        return parseCommitLog(result.stdout)
+    }
 
+    static func parseCommitLog(_ output: String) -> [CommitInfo] {
         var commits: [CommitInfo] = []
-        for line in result.stdout.components(separatedBy: "\n") {
+        for line in output.components(separatedBy: "\n") {
             let parts = line.components(separatedBy: "\0")
             guard parts.count >= 5 else { continue }
             commits.append(CommitInfo(
@@ -307,6 +395,50 @@ class HomeDashboardView: NSView {
         }
         return commits
     }
+
+    // MARK: - Shared Rows
+
+    static func makeCommitRow(_ commit: CommitInfo) -> NSView {
+        let row = NSStackView()
+        row.orientation = .horizontal
+        row.spacing = 8
+        row.alignment = .firstBaseline
+
+        let monoFont = NSFont.monospacedSystemFont(ofSize: 11, weight: .regular)
+
+        let hashLabel = NSTextField(labelWithString: commit.hash)
+        hashLabel.font = monoFont
+        hashLabel.textColor = .controlAccentColor
+        hashLabel.setContentHuggingPriority(.required, for: .horizontal)
+
+        let msgLabel = NSTextField(labelWithString: commit.message)
+        msgLabel.font = .systemFont(ofSize: 11)
+        msgLabel.textColor = Theme.primaryText
+        msgLabel.lineBreakMode = .byTruncatingTail
+        msgLabel.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        let authorLabel = NSTextField(labelWithString: commit.author)
+        authorLabel.font = .systemFont(ofSize: 10)
+        authorLabel.textColor = .tertiaryLabelColor
+        authorLabel.setContentHuggingPriority(.required, for: .horizontal)
+
+        let timeLabel = NSTextField(labelWithString: commit.relativeTime)
+        if commit.timestamp > 0 {
+            let date = Date(timeIntervalSince1970: TimeInterval(commit.timestamp))
+            timeLabel.stringValue = humanizeRelative(date)
+            timeLabel.toolTip = ISO8601DateFormatter().string(from: date)
+        }
+        timeLabel.font = .systemFont(ofSize: 10)
+        timeLabel.textColor = .tertiaryLabelColor
+        timeLabel.setContentHuggingPriority(.required, for: .horizontal)
+
+        row.addArrangedSubview(hashLabel)
+        row.addArrangedSubview(msgLabel)
+        row.addArrangedSubview(authorLabel)
+        row.addArrangedSubview(timeLabel)
+
+        return row
+    }
 }
 
 // MARK: - ProjectCardView
@@ -465,6 +597,12 @@ private class ProjectCardView: NSView {
         addDots(count: data.agentCounts.completed, color: Theme.statusColor(for: .completed))
         addDots(count: data.agentCounts.failed, color: Theme.statusColor(for: .failed))
         addDots(count: data.agentCounts.killed, color: Theme.statusColor(for: .killed))
+        // The dots say which statuses only by color
+        let countsText = AccessibilityLabels.agentCounts(data.agentCounts)
+        agentDotsStack.toolTip = countsText
+        agentDotsStack.setAccessibilityElement(true)
+        agentDotsStack.setAccessibilityRole(.staticText)
+        agentDotsStack.setAccessibilityLabel("Agents: \(countsText)")
 
         // Heatmap
         if !skipHeatmap {
@@ -479,7 +617,7 @@ private class ProjectCardView: NSView {
         } else {
             emptyCommitsLabel.isHidden = true
             for commit in data.recentCommits {
-                let row = makeCommitRow(commit)
+                let row = HomeDashboardView.makeCommitRow(commit)
                 commitsStack.addArrangedSubview(row)
             }
         }
@@ -507,41 +645,4 @@ private class ProjectCardView: NSView {
             agentDotsStack.addArrangedSubview(overflow)
         }
     }
-
-    private func makeCommitRow(_ commit: CommitInfo) -> NSView {
-        let row = NSStackView()
-        row.orientation = .horizontal
-        row.spacing = 8
-        row.alignment = .firstBaseline
-
-        let monoFont = NSFont.monospacedSystemFont(ofSize: 11, weight: .regular)
-
-        let hashLabel = NSTextField(labelWithString: commit.hash)
-        hashLabel.font = monoFont
-        hashLabel.textColor = .controlAccentColor
-        hashLabel.setContentHuggingPriority(.required, for: .horizontal)
-
-        let msgLabel = NSTextField(labelWithString: commit.message)
-        msgLabel.font = .systemFont(ofSize: 11)
-        msgLabel.textColor = Theme.primaryText
-        msgLabel.lineBreakMode = .byTruncatingTail
-        msgLabel.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
-
-        let authorLabel = NSTextField(labelWithString: commit.author)
-        authorLabel.font = .systemFont(ofSize: 10)
-        authorLabel.textColor = .tertiaryLabelColor
-        authorLabel.setContentHuggingPriority(.required, for: .horizontal)
-
-        let timeLabel = NSTextField(labelWithString: commit.relativeTime)
-        timeLabel.font = .systemFont(ofSize: 10)
-        timeLabel.textColor = .tertiaryLabelColor
-        timeLabel.setContentHuggingPriority(.required, for: .horizontal)
-
-        row.addArrangedSubview(hashLabel)
-        row.addArrangedSubview(msgLabel)
-        row.addArrangedSubview(authorLabel)
-        row.addArrangedSubview(timeLabel)
-
-        return row
-    }
 }
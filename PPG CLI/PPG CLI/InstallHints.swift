@@ -0,0 +1,34 @@
+import Foundation
+
+/// Package managers the setup screen can suggest install commands for.
+nonisolated enum PackageManager: String, CaseIterable {
+    case homebrew = "brew"
+    case macports = "port"
+    case npm
+    case pnpm
+
+    /// Executable looked up on the user's PATH to detect this manager.
+    var command: String { rawValue }
+}
+
+/// Commands that install tmux with the detected managers, preferred first.
+/// When neither Homebrew nor MacPorts is found, both are listed.
+nonisolated func tmuxInstallHints(available: Set<PackageManager>) -> [String] {
+    let all: [(PackageManager, String)] = [
+        (.homebrew, "brew install tmux"),
+        (.macports, "sudo port install tmux"),
+    ]
+    let detected = all.filter { available.contains($0.0) }
+    return (detected.isEmpty ? all : detected).map(\.1)
+}
+
+/// Commands that install the ppg CLI with the detected Node package managers, npm first.
+/// With neither found, both are listed.
+nonisolated func ppgInstallHints(available: Set<PackageManager>) -> [String] {
+    let all: [(PackageManager, String)] = [
+        (.npm, "npm install -g pure-point-guard"),
+        (.pnpm, "pnpm add -g pure-point-guard"),
+    ]
+    let detected = all.filter { available.contains($0.0) }
+    return (detected.isEmpty ? all : detected).map(\.1)
+}
@@ -24,6 +24,45 @@ enum BindableAction: String, CaseIterable {
     case focusPaneDown
     case focusPaneLeft
     case focusPaneRight
+    case togglePaneZoom
+    case focusPane1
+    case focusPane2
+    case focusPane3
+    case focusPane4
+    case focusPane5
+    case focusPane6
+    case openSettings
+    case restartFocusedAgent
+    case killFocusedAgent
+    case showShortcuts
+    case quickSwitcher
+    case navigateBack
+    case navigateForward
+
+    /// Section the action is listed under in the Keyboard Shortcuts window.
+    enum Category: String, CaseIterable {
+        case general = "General"
+        case projects = "Projects"
+        case panes = "Terminal Panes"
+        case agents = "Agents"
+    }
+
+    var category: Category {
+        switch self {
+        case .quit, .newItem, .openProject, .closeEntry, .refresh, .openSettings, .showShortcuts, .quickSwitcher,
+             .navigateBack, .navigateForward:
+            return .general
+        case .switchProject1, .switchProject2, .switchProject3, .switchProject4, .switchProject5,
+             .switchProject6, .switchProject7, .switchProject8, .switchProject9:
+            return .projects
+        case .splitBelow, .splitRight, .closePane, .focusPaneUp, .focusPaneDown, .focusPaneLeft,
+             .focusPaneRight, .togglePaneZoom, .focusPane1, .focusPane2, .focusPane3, .focusPane4,
+             .focusPane5, .focusPane6:
+            return .panes
+        case .restartFocusedAgent, .killFocusedAgent:
+            return .agents
+        }
+    }
 
     var displayName: String {
         switch self {
@@ -48,6 +87,20 @@ enum BindableAction: String, CaseIterable {
         case .focusPaneDown: return "Focus Pane Below"
         case .focusPaneLeft: return "Focus Pane Left"
         case .focusPaneRight: return "Focus Pane Right"
+        case .togglePaneZoom: return "Maximize/Restore Pane"
+        case .focusPane1: return "Focus Pane 1"
+        case .focusPane2: return "Focus Pane 2"
+        case .focusPane3: return "Focus Pane 3"
+        case .focusPane4: return "Focus Pane 4"
+        case .focusPane5: return "Focus Pane 5"
+        case .focusPane6: return "Focus Pane 6"
+        case .openSettings: return "Settings"
+        case .restartFocusedAgent: return "Restart Agent"
+        case .killFocusedAgent: return "Kill Agent"
+        case .showShortcuts: return "Keyboard Shortcuts"
+        case .quickSwitcher: return "Go to Worktree or Agent"
+        case .navigateBack: return "Back"
+        case .navigateForward: return "Forward"
         }
     }
 
@@ -74,6 +127,20 @@ enum BindableAction: String, CaseIterable {
         case .focusPaneDown: return String(Character(UnicodeScalar(NSDownArrowFunctionKey)!))
         case .focusPaneLeft: return String(Character(UnicodeScalar(NSLeftArrowFunctionKey)!))
         case .focusPaneRight: return String(Character(UnicodeScalar(NSRightArrowFunctionKey)!))
+        case .togglePaneZoom: return "\r"
+        case .focusPane1: return "1"
+        case .focusPane2: return "2"
+        case .focusPane3: return "3"
+        case .focusPane4: return "4"
+        case .focusPane5: return "5"
+        case .focusPane6: return "6"
+        case .openSettings: return ","
+        case .restartFocusedAgent: return "r"
+        case .killFocusedAgent: return "k"
+        case .showShortcuts: return "?"
+        case .quickSwitcher: return "k"
+        case .navigateBack: return "["
+        case .navigateForward: return "]"
         }
     }
 
@@ -82,8 +149,12 @@ enum BindableAction: String, CaseIterable {
         case .splitBelow: return .command
         case .splitRight: return [.command, .shift]
         case .closePane: return [.command, .shift]
+        case .togglePaneZoom: return [.command, .shift]
+        case .restartFocusedAgent, .killFocusedAgent: return [.command, .shift]
         case .focusPaneUp, .focusPaneDown, .focusPaneLeft, .focusPaneRight:
             return [.command, .option]
+        case .focusPane1, .focusPane2, .focusPane3, .focusPane4, .focusPane5, .focusPane6:
+            return [.command, .option]
         default: return .command
         }
     }
@@ -124,7 +195,8 @@ final class KeybindingManager {
               let decoded = try? JSONDecoder().decode([String: StoredBinding].self, from: data) else {
             return
         }
-        customBindings = decoded
+        // Drop entries for actions that no longer exist or that have no key
+        customBindings = decoded.filter { BindableAction(rawValue: $0.key) != nil && !$0.value.keyEquivalent.isEmpty }
     }
 
     private func saveBindings() {
@@ -164,6 +236,15 @@ final class KeybindingManager {
         return nil
     }
 
+    /// Custom bindings keyed by action raw value, for settings export.
+    var exportedBindings: [String: StoredBinding] { customBindings }
+
+    /// Replace every custom binding (settings import). Actions not listed revert to defaults.
+    func replaceAll(with bindings: [BindableAction: StoredBinding]) {
+        customBindings = Dictionary(uniqueKeysWithValues: bindings.map { ($0.key.rawValue, $0.value) })
+        saveBindings()
+    }
+
     func resetAll() {
         customBindings.removeAll()
         saveBindings()
@@ -232,7 +313,7 @@ final class KeybindingManager {
         if modifiers.contains(.option) { parts.append("⌥") }
         if modifiers.contains(.shift) { parts.append("⇧") }
         if modifiers.contains(.command) { parts.append("⌘") }
-        parts.append(keyEquivalent.uppercased())
+        parts.append(keyEquivalent == "\r" ? "↩" : keyEquivalent.uppercased())
         return parts.joined()
     }
 
@@ -269,6 +350,21 @@ let kMenuTagFocusPaneUp    = 133
 let kMenuTagFocusPaneDown  = 134
 let kMenuTagFocusPaneLeft  = 135
 let kMenuTagFocusPaneRight = 136
+let kMenuTagTogglePaneZoom = 137
+let kMenuTagFocusPane1     = 141
+let kMenuTagFocusPane2     = 142
+let kMenuTagFocusPane3     = 143
+let kMenuTagFocusPane4     = 144
+let kMenuTagFocusPane5     = 145
+let kMenuTagFocusPane6     = 146
+let kMenuTagSettings       = 150
+let kMenuTagRestartAgent   = 151
+let kMenuTagKillAgent      = 152
+let kMenuTagShowShortcuts  = 153
+let kMenuTagQuickSwitcher  = 154
+let kMenuTagExportReport   = 155
+let kMenuTagNavigateBack   = 156
+let kMenuTagNavigateForward = 157
 
 func menuTagToActionId(_ tag: Int) -> String {
     switch tag {
@@ -293,6 +389,20 @@ func menuTagToActionId(_ tag: Int) -> String {
     case kMenuTagFocusPaneDown: return BindableAction.focusPaneDown.rawValue
     case kMenuTagFocusPaneLeft: return BindableAction.focusPaneLeft.rawValue
     case kMenuTagFocusPaneRight: return BindableAction.focusPaneRight.rawValue
+    case kMenuTagTogglePaneZoom: return BindableAction.togglePaneZoom.rawValue
+    case kMenuTagFocusPane1: return BindableAction.focusPane1.rawValue
+    case kMenuTagFocusPane2: return BindableAction.focusPane2.rawValue
+    case kMenuTagFocusPane3: return BindableAction.focusPane3.rawValue
+    case kMenuTagFocusPane4: return BindableAction.focusPane4.rawValue
+    case kMenuTagFocusPane5: return BindableAction.focusPane5.rawValue
+    case kMenuTagFocusPane6: return BindableAction.focusPane6.rawValue
+    case kMenuTagSettings: return BindableAction.openSettings.rawValue
+    case kMenuTagRestartAgent: return BindableAction.restartFocusedAgent.rawValue
+    case kMenuTagKillAgent: return BindableAction.killFocusedAgent.rawValue
+    case kMenuTagShowShortcuts: return BindableAction.showShortcuts.rawValue
+    case kMenuTagQuickSwitcher: return BindableAction.quickSwitcher.rawValue
+    case kMenuTagNavigateBack: return BindableAction.navigateBack.rawValue
+    case kMenuTagNavigateForward: return BindableAction.navigateForward.rawValue
     default: return ""
     }
 }
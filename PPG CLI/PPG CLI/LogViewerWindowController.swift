@@ -0,0 +1,701 @@
+import AppKit
+import UniformTypeIdentifiers
+
+/// Lines of an agent's captured pane output (`ppg logs`), each stamped with when the
+/// viewer first received it.
+nonisolated struct LogBuffer {
+    struct Line: Equatable {
+        let text: String
+        let receivedAt: Date
+
+        func rendered(timestamps: Bool) -> String {
+            timestamps ? "\(LogBuffer.timestampFormatter.string(from: receivedAt))  \(text)" : text
+        }
+    }
+
+    /// How new output was folded into the buffer.
+    enum Merge: Equatable {
+        /// These lines were added at the end; everything before is unchanged.
+        case appended([Line])
+        /// The buffer was rebuilt, either because a capture didn't line up with what
+        /// was shown or because the oldest lines were dropped.
+        case replaced
+    }
+
+    /// Cap for a viewer left following for a long time. Past it, the oldest quarter is dropped.
+    static let maxLines = 20_000
+
+    private(set) var lines: [Line] = []
+
+    /// Replace the contents with a fresh capture.
+    mutating func replace(with output: String, at date: Date = Date()) {
+        lines = Self.splitLines(output).map { Line(text: $0, receivedAt: date) }
+    }
+
+    /// Fold in a later capture of the same pane, keeping the arrival time of lines
+    /// already shown. Used when polling and when a follow stream (re)connects.
+    mutating func merge(capture output: String, at date: Date = Date()) -> Merge {
+        let current = Self.splitLines(output)
+        guard let fresh = Self.newLines(since: lines.map(\.text), in: current) else {
+            lines = current.map { Line(text: $0, receivedAt: date) }
+            return .replaced
+        }
+        return append(fresh, at: date)
+    }
+
+    /// Add lines that arrived from a follow stream.
+    @discardableResult
+    mutating func append(_ texts: [String], at date: Date = Date()) -> Merge {
+        let added = texts.map { Line(text: $0, receivedAt: date) }
+        lines += added
+        guard lines.count > Self.maxLines else { return .appended(added) }
+        lines.removeFirst(lines.count - Self.maxLines * 3 / 4)
+        return .replaced
+    }
+
+    /// Text for display or saving, optionally prefixed with each line's arrival time.
+    func rendered(timestamps: Bool) -> String {
+        lines.map { $0.rendered(timestamps: timestamps) }.joined(separator: "\n")
+    }
+
+    /// `ppg logs` output split into lines, without the empty rows capture-pane
+    /// includes below the cursor.
+    static func splitLines(_ output: String) -> [String] {
+        var lines = output.components(separatedBy: "\n")
+        while let last = lines.last, last.trimmingCharacters(in: .whitespaces).isEmpty {
+            lines.removeLast()
+        }
+        return lines
+    }
+
+    /// Lines of `current` past its overlap with the end of `previous`, for two captures
+    /// of the last N lines of a pane. Nil when they don't overlap (the pane was cleared,
+    /// redrawn, or scrolled more than N lines between captures). Same overlap rule as
+    /// `newLinesSince` in the CLI's `ppg logs --follow`, which prints nothing then; the
+    /// viewer can redraw instead.
+    static func newLines(since previous: [String], in current: [String]) -> [String]? {
+        if previous.isEmpty { return current }
+        for start in previous.indices {
+            let overlap = previous.count - start
+            guard overlap <= current.count else { continue }
+            if previous[start...].elementsEqual(current[..<overlap]) {
+                return Array(current[overlap...])
+            }
+        }
+        return nil
+    }
+
+    fileprivate static let timestampFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.dateFormat = "HH:mm:ss"
+        return formatter
+    }()
+}
+
+/// Splits a byte stream into lines, holding back a trailing partial line (and any
+/// UTF-8 sequence cut in half with it) until the rest arrives.
+nonisolated struct LineSplitter {
+    private var pending = Data()
+
+    mutating func feed(_ data: Data) -> [String] {
+        pending.append(data)
+        var lines: [String] = []
+        while let newline = pending.firstIndex(of: 0x0A) {
+            lines.append(String(decoding: pending[pending.startIndex..<newline], as: UTF8.self))
+            pending.removeSubrange(pending.startIndex...newline)
+        }
+        return lines
+    }
+
+    /// The trailing partial line, if any, once the stream has ended without a newline
+    /// after it, e.g. a prompt.
+    mutating func flush() -> String? {
+        defer { pending = Data() }
+        return pending.isEmpty ? nil : String(decoding: pending, as: UTF8.self)
+    }
+}
+
+/// Lines waiting to be handed to the main queue. The first line added after a flush
+/// asks for the next one, so there is at most one flush scheduled at a time and none
+/// while output is idle.
+nonisolated struct LineBatch {
+    private(set) var lines: [String] = []
+
+    /// Queue `newLines`; true if a flush should be scheduled for them.
+    mutating func add(_ newLines: [String]) -> Bool {
+        guard !newLines.isEmpty else { return false }
+        let wasEmpty = lines.isEmpty
+        lines += newLines
+        return wasEmpty
+    }
+
+    /// Everything queued so far, in arrival order.
+    mutating func take() -> [String] {
+        defer { lines = [] }
+        return lines
+    }
+}
+
+/// One `ppg logs --follow` process. Lines and exit are reported on the main queue.
+/// Heavy output is delivered at most once per `flushInterval`, so the viewer does one
+/// text insert and one scroll per frame rather than one per pipe read.
+nonisolated final class LogStream: @unchecked Sendable {
+    static let flushInterval: TimeInterval = 1.0 / 30
+
+    private var process: Process?
+    private var splitter = LineSplitter()
+    private(set) var receivedOutput = false
+    private let batchLock = NSLock()
+    private var batch = LineBatch()
+
+    /// Launch the stream. Returns false if ppg couldn't be started at all.
+    func start(
+        agentId: String,
+        lines: Int,
+        projectRoot: String,
+        onLines: @escaping @MainActor ([String]) -> Void,
+        onExit: @escaping @MainActor (_ exitCode: Int32, _ stderr: String) -> Void
+    ) -> Bool {
+        // PPGService reports output and exit on one serial queue, so the splitter
+        // needs no lock.
+        process = PPGService.shared.startPPGProcess(
+            "logs \(shellEscape(agentId)) --follow --lines \(lines)",
+            projectRoot: projectRoot,
+            onOutput: { [weak self] data in
+                guard let self else { return }
+                let lines = self.splitter.feed(data)
+                guard !lines.isEmpty else { return }
+                self.receivedOutput = true
+                self.batchLock.lock()
+                let needsFlush = self.batch.add(lines)
+                self.batchLock.unlock()
+                guard needsFlush else { return }
+                DispatchQueue.main.asyncAfter(deadline: .now() + Self.flushInterval) { [weak self] in
+                    guard let lines = self?.takeBatch(), !lines.isEmpty else { return }
+                    onLines(lines)
+                }
+            },
+            onExit: { [weak self] exitCode, stderr in
+                if let self, let last = self.splitter.flush() {
+                    self.receivedOutput = true
+                    self.batchLock.lock()
+                    _ = self.batch.add([last])
+                    self.batchLock.unlock()
+                }
+                DispatchQueue.main.async {
+                    // Lines still waiting for their flush come before the exit
+                    if let lines = self?.takeBatch(), !lines.isEmpty { onLines(lines) }
+                    onExit(exitCode, stderr)
+                }
+            }
+        )
+        return process != nil
+    }
+
+    private func takeBatch() -> [String] {
+        batchLock.lock()
+        defer { batchLock.unlock() }
+        return batch.take()
+    }
+
+    func stop() {
+        guard let process, process.isRunning else { return }
+        process.terminate()
+    }
+}
+
+/// Window showing one agent's recent output, with a line-count picker, search (⌘F),
+/// wrap and timestamp toggles, follow, and Save. One window per agent; opening an
+/// agent that already has one brings it forward.
+///
+/// Follow streams `ppg logs --follow`. If the CLI can't stream (an older ppg, or a
+/// stream that keeps ending before it sends anything), the viewer polls `ppg logs`
+/// every two seconds instead and merges each capture with what's already shown.
+final class LogViewerWindowController: NSWindowController, NSWindowDelegate {
+    static let lineCounts = [100, 500, 2000]
+    static let pollInterval: TimeInterval = 2
+    static let reconnectDelay: TimeInterval = 2
+    /// Streams that end this many times in a row without output switch the viewer to polling.
+    static let maxSilentStreamFailures = 3
+
+    private static var openViewers: [String: LogViewerWindowController] = [:]
+
+    /// Open (or focus) the log viewer for `agent`, whose manifest lives in `projectRoot`.
+    static func show(agent: AgentModel, projectRoot: String) {
+        let viewer = openViewers[agent.id] ?? LogViewerWindowController(agent: agent, projectRoot: projectRoot)
+        openViewers[agent.id] = viewer
+        viewer.showWindow(nil)
+        viewer.window?.makeKeyAndOrderFront(nil)
+    }
+
+    /// Pass the sidebar's latest manifest data to open viewers, so following stops when
+    /// an agent exits or disappears. An agent missing from a project in `loadedRoots`
+    /// (the projects that were read successfully) is gone.
+    static func agentsRefreshed(_ index: AgentIndex, loadedRoots: Set<String>) {
+        for viewer in openViewers.values {
+            if let location = index.find(viewer.agent.id) {
+                viewer.agentUpdated(location.agent)
+            } else if loadedRoots.contains(viewer.projectRoot) {
+                viewer.agentEnded("Gone — removed from the manifest")
+            }
+        }
+    }
+
+    private enum FollowMode {
+        case off
+        case streaming(LogStream)
+        case polling(Timer)
+    }
+
+    private var agent: AgentModel
+    private let projectRoot: String
+    private var buffer = LogBuffer()
+    private var lineCount = LogViewerWindowController.lineCounts[0]
+    private var isFetching = false
+    private var followMode = FollowMode.off
+    private var streamingUnsupported = false
+    private var silentStreamFailures = 0
+    private var reconnectWork: DispatchWorkItem?
+    private var hasEnded = false
+
+    private let textView = NSTextView()
+    private let scrollView = NSScrollView()
+    private let lineCountPopup = NSPopUpButton()
+    private let followCheckbox = NSButton(checkboxWithTitle: "Follow", target: nil, action: nil)
+    private let wrapCheckbox = NSButton(checkboxWithTitle: "Wrap", target: nil, action: nil)
+    private let timestampsCheckbox = NSButton(checkboxWithTitle: "Timestamps", target: nil, action: nil)
+    private let statusLabel = NSTextField(labelWithString: "")
+    private let jumpToLatestButton = NSButton(title: "Jump to Latest", target: nil, action: nil)
+
+    private init(agent: AgentModel, projectRoot: String) {
+        self.agent = agent
+        self.projectRoot = projectRoot
+        let window = NSWindow(
+            contentRect: NSRect(x: 0, y: 0, width: 760, height: 520),
+            styleMask: [.titled, .closable, .resizable, .miniaturizable],
+            backing: .buffered,
+            defer: false
+        )
+        let name = agent.name.isEmpty ? agent.id : agent.name
+        window.title = "Logs — \(name)"
+        window.isReleasedWhenClosed = false
+        window.setFrameAutosaveName("LogViewer")
+        window.center()
+        super.init(window: window)
+        window.delegate = self
+        setupUI()
+        if TerminalPane.exitHeadline(status: agent.status, exitCode: agent.exitCode) != nil {
+            hasEnded = true
+            followCheckbox.isEnabled = false
+        }
+        reload()
+    }
+
+    required init?(coder: NSCoder) { fatalError() }
+
+    deinit {
+        NotificationCenter.default.removeObserver(self)
+    }
+
+    // MARK: - UI
+
+    private func setupUI() {
+        guard let contentView = window?.contentView else { return }
+
+        for count in Self.lineCounts {
+            lineCountPopup.addItem(withTitle: "Last \(count) lines")
+            lineCountPopup.lastItem?.tag = count
+        }
+        lineCountPopup.target = self
+        lineCountPopup.action = #selector(lineCountChanged(_:))
+
+        followCheckbox.target = self
+        followCheckbox.action = #selector(followToggled(_:))
+        wrapCheckbox.state = .on
+        wrapCheckbox.target = self
+        wrapCheckbox.action = #selector(wrapToggled(_:))
+        timestampsCheckbox.target = self
+        timestampsCheckbox.action = #selector(timestampsToggled(_:))
+
+        let findButton = NSButton(image: NSImage(systemSymbolName: "magnifyingglass", accessibilityDescription: "Find")!, target: self, action: #selector(showFind(_:)))
+        findButton.bezelStyle = .accessoryBarAction
+        findButton.isBordered = false
+        findButton.toolTip = "Find (⌘F)"
+
+        let refreshButton = NSButton(image: NSImage(systemSymbolName: "arrow.clockwise", accessibilityDescription: "Refresh")!, target: self, action: #selector(refreshClicked(_:)))
+        refreshButton.bezelStyle = .accessoryBarAction
+        refreshButton.isBordered = false
+        refreshButton.toolTip = "Fetch again"
+
+        let saveButton = NSButton(title: "Save…", target: self, action: #selector(saveClicked(_:)))
+        saveButton.bezelStyle = .rounded
+        saveButton.controlSize = .small
+
+        statusLabel.font = .systemFont(ofSize: 11)
+        statusLabel.textColor = .secondaryLabelColor
+        statusLabel.lineBreakMode = .byTruncatingTail
+        statusLabel.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        let spacer = NSView()
+        spacer.setContentHuggingPriority(.defaultLow, for: .horizontal)
+
+        let toolbar = NSStackView(views: [lineCountPopup, followCheckbox, wrapCheckbox, timestampsCheckbox, statusLabel, spacer, findButton, refreshButton, saveButton])
+        toolbar.orientation = .horizontal
+        toolbar.alignment = .centerY
+        toolbar.spacing = 12
+        toolbar.edgeInsets = NSEdgeInsets(top: 8, left: 12, bottom: 8, right: 12)
+        toolbar.translatesAutoresizingMaskIntoConstraints = false
+        contentView.addSubview(toolbar)
+
+        textView.isEditable = false
+        textView.isSelectable = true
+        textView.isRichText = false
+        textView.font = Self.textFont
+        textView.textContainerInset = NSSize(width: 6, height: 6)
+        textView.usesFindBar = true
+        textView.isIncrementalSearchingEnabled = true
+        textView.autoresizingMask = [.width]
+
+        scrollView.documentView = textView
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .noBorder
+        scrollView.findBarPosition = .aboveContent
+        scrollView.translatesAutoresizingMaskIntoConstraints = false
+        scrollView.contentView.postsBoundsChangedNotifications = true
+        contentView.addSubview(scrollView)
+        applyWrap(true)
+
+        NotificationCenter.default.addObserver(
+            self, selector: #selector(scrollPositionChanged(_:)),
+            name: NSView.boundsDidChangeNotification, object: scrollView.contentView
+        )
+
+        jumpToLatestButton.bezelStyle = .inline
+        jumpToLatestButton.image = NSImage(systemSymbolName: "arrow.down", accessibilityDescription: nil)
+        jumpToLatestButton.imagePosition = .imageLeading
+        jumpToLatestButton.target = self
+        jumpToLatestButton.action = #selector(jumpToLatest(_:))
+        jumpToLatestButton.isHidden = true
+        jumpToLatestButton.translatesAutoresizingMaskIntoConstraints = false
+        contentView.addSubview(jumpToLatestButton)
+
+        NSLayoutConstraint.activate([
+            toolbar.topAnchor.constraint(equalTo: contentView.topAnchor),
+            toolbar.leadingAnchor.constraint(equalTo: contentView.leadingAnchor),
+            toolbar.trailingAnchor.constraint(equalTo: contentView.trailingAnchor),
+
+            scrollView.topAnchor.constraint(equalTo: toolbar.bottomAnchor),
+            scrollView.leadingAnchor.constraint(equalTo: contentView.leadingAnchor),
+            scrollView.trailingAnchor.constraint(equalTo: contentView.trailingAnchor),
+            scrollView.bottomAnchor.constraint(equalTo: contentView.bottomAnchor),
+
+            jumpToLatestButton.centerXAnchor.constraint(equalTo: scrollView.centerXAnchor),
+            jumpToLatestButton.bottomAnchor.constraint(equalTo: scrollView.bottomAnchor, constant: -12),
+        ])
+    }
+
+    private static let textFont = NSFont.monospacedSystemFont(ofSize: 11, weight: .regular)
+
+    private func applyWrap(_ wrap: Bool) {
+        guard let container = textView.textContainer else { return }
+        scrollView.hasHorizontalScroller = !wrap
+        textView.isHorizontallyResizable = !wrap
+        textView.maxSize = NSSize(width: CGFloat.greatestFiniteMagnitude, height: CGFloat.greatestFiniteMagnitude)
+        container.widthTracksTextView = wrap
+        if wrap {
+            textView.frame.size.width = scrollView.contentSize.width
+            container.containerSize = NSSize(width: scrollView.contentSize.width, height: CGFloat.greatestFiniteMagnitude)
+        } else {
+            container.containerSize = NSSize(width: CGFloat.greatestFiniteMagnitude, height: CGFloat.greatestFiniteMagnitude)
+        }
+    }
+
+    // MARK: - Rendering
+
+    private var showsTimestamps: Bool { timestampsCheckbox.state == .on }
+
+    /// Whether the view is scrolled to (or within a few points of) the last line.
+    private var isPinnedToBottom: Bool {
+        scrollView.contentView.bounds.maxY >= textView.frame.height - 4
+    }
+
+    private func render() {
+        textView.string = buffer.rendered(timestamps: showsTimestamps)
+        textView.scrollToEndOfDocument(nil)
+        jumpToLatestButton.isHidden = true
+    }
+
+    /// Show new output. Stays at the bottom if the view was there; otherwise keeps the
+    /// reader's place and offers "Jump to Latest".
+    private func show(_ merge: LogBuffer.Merge) {
+        let wasPinned = isPinnedToBottom
+        switch merge {
+        case .appended(let lines):
+            guard !lines.isEmpty, let storage = textView.textStorage else { return }
+            let separator = storage.length > 0 ? "\n" : ""
+            let text = separator + lines.map { $0.rendered(timestamps: showsTimestamps) }.joined(separator: "\n")
+            storage.append(NSAttributedString(string: text, attributes: [
+                .font: Self.textFont,
+                .foregroundColor: NSColor.textColor,
+            ]))
+        case .replaced:
+            let origin = scrollView.contentView.bounds.origin
+            textView.string = buffer.rendered(timestamps: showsTimestamps)
+            if !wasPinned { scrollView.contentView.scroll(to: origin) }
+        }
+        if wasPinned {
+            textView.scrollToEndOfDocument(nil)
+        } else {
+            jumpToLatestButton.isHidden = false
+        }
+    }
+
+    @objc private func scrollPositionChanged(_ notification: Notification) {
+        if isPinnedToBottom { jumpToLatestButton.isHidden = true }
+    }
+
+    // MARK: - Fetching
+
+    private func reload() {
+        guard !isFetching else { return }
+        isFetching = true
+        statusLabel.stringValue = "Loading…"
+        fetch { [weak self] output in
+            guard let self, let output else { return }
+            self.buffer.replace(with: output)
+            self.statusLabel.stringValue = "Updated \(Self.timeFormatter.string(from: Date()))"
+            self.render()
+        }
+    }
+
+    /// One `ppg logs` capture; nil (with the error in the status label) on failure.
+    private func fetch(completion: @escaping (String?) -> Void) {
+        let command = "logs \(shellEscape(agent.id)) --lines \(lineCount)"
+        let projectRoot = projectRoot
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let result = PPGService.shared.runPPGCommand(command, projectRoot: projectRoot)
+            DispatchQueue.main.async {
+                guard let self else { return }
+                self.isFetching = false
+                guard result.exitCode == 0 else {
+                    let message = (result.stderr.isEmpty ? result.stdout : result.stderr)
+                        .trimmingCharacters(in: .whitespacesAndNewlines)
+                    self.statusLabel.stringValue = message.isEmpty ? "Couldn't load logs" : message
+                    completion(nil)
+                    return
+                }
+                completion(result.stdout)
+            }
+        }
+    }
+
+    private static let timeFormatter: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.timeStyle = .medium
+        formatter.dateStyle = .none
+        return formatter
+    }()
+
+    // MARK: - Follow
+
+    private var isFollowing: Bool {
+        if case .off = followMode { return false }
+        return true
+    }
+
+    private func startFollowing() {
+        guard !hasEnded else { return }
+        if streamingUnsupported {
+            startPolling()
+        } else {
+            startStreaming()
+        }
+    }
+
+    private func stopFollowing() {
+        reconnectWork?.cancel()
+        reconnectWork = nil
+        switch followMode {
+        case .off: break
+        case .streaming(let stream): stream.stop()
+        case .polling(let timer): timer.invalidate()
+        }
+        followMode = .off
+    }
+
+    private func startStreaming() {
+        let stream = LogStream()
+        // A stream opens with a full capture, merged with what's shown so a reconnect
+        // doesn't repeat lines; after that it sends only new lines.
+        var awaitingCapture = true
+        let launched = stream.start(
+            agentId: agent.id,
+            lines: lineCount,
+            projectRoot: projectRoot,
+            onLines: { [weak self] lines in
+                guard let self, case .streaming(let current) = self.followMode, current === stream else { return }
+                self.silentStreamFailures = 0
+                self.statusLabel.stringValue = "Following"
+                if awaitingCapture {
+                    awaitingCapture = false
+                    self.show(self.buffer.merge(capture: lines.joined(separator: "\n")))
+                } else {
+                    self.show(self.buffer.append(lines))
+                }
+            },
+            onExit: { [weak self] exitCode, stderr in
+                guard let self, case .streaming(let current) = self.followMode, current === stream else { return }
+                self.streamEnded(stream, exitCode: exitCode, stderr: stderr)
+            }
+        )
+        guard launched else {
+            streamingUnsupported = true
+            startPolling()
+            return
+        }
+        followMode = .streaming(stream)
+        statusLabel.stringValue = "Following"
+    }
+
+    private func streamEnded(_ stream: LogStream, exitCode: Int32, stderr: String) {
+        followMode = .off
+        guard !hasEnded else { return }
+        if !stream.receivedOutput {
+            silentStreamFailures += 1
+            if stderr.contains("unknown option") || silentStreamFailures >= Self.maxSilentStreamFailures {
+                streamingUnsupported = true
+                startPolling()
+                return
+            }
+        }
+        // The pane may have closed because the agent exited, which the next manifest
+        // refresh reports. Until then, retry quietly.
+        scheduleReconnect()
+    }
+
+    private func scheduleReconnect() {
+        statusLabel.stringValue = "Reconnecting…"
+        let work = DispatchWorkItem { [weak self] in
+            guard let self, self.followCheckbox.state == .on, !self.isFollowing, !self.hasEnded else { return }
+            self.startStreaming()
+        }
+        reconnectWork = work
+        DispatchQueue.main.asyncAfter(deadline: .now() + Self.reconnectDelay, execute: work)
+    }
+
+    private func startPolling() {
+        let timer = Timer.scheduledTimer(withTimeInterval: Self.pollInterval, repeats: true) { [weak self] _ in
+            self?.poll()
+        }
+        followMode = .polling(timer)
+        poll()
+    }
+
+    private func poll() {
+        guard !isFetching else { return }
+        isFetching = true
+        fetch { [weak self] output in
+            guard let self, case .polling = self.followMode else { return }
+            guard let output else {
+                self.statusLabel.stringValue = "Reconnecting…"
+                return
+            }
+            self.statusLabel.stringValue = "Following"
+            self.show(self.buffer.merge(capture: output))
+        }
+    }
+
+    // MARK: - Agent Lifecycle
+
+    private func agentUpdated(_ updated: AgentModel) {
+        agent = updated
+        if let reason = Self.endReason(for: updated) {
+            agentEnded(reason)
+        }
+    }
+
+    /// Why following should stop for this manifest state, or nil while the agent lives.
+    static func endReason(for agent: AgentModel) -> String? {
+        TerminalPane.exitHeadline(status: agent.status, exitCode: agent.exitCode)?.text
+    }
+
+    /// Stop following for good. A viewer that was following gets a divider with the reason.
+    private func agentEnded(_ reason: String) {
+        guard !hasEnded else { return }
+        hasEnded = true
+        let wasFollowing = followCheckbox.state == .on
+        stopFollowing()
+        followCheckbox.state = .off
+        followCheckbox.isEnabled = false
+        statusLabel.stringValue = reason
+        guard wasFollowing else { return }
+        show(buffer.append(["──── \(reason) ────"]))
+    }
+
+    // MARK: - Actions
+
+    @objc private func lineCountChanged(_ sender: NSPopUpButton) {
+        lineCount = sender.selectedTag()
+        let wasFollowing = isFollowing
+        stopFollowing()
+        reload()
+        if wasFollowing { startFollowing() }
+    }
+
+    @objc private func followToggled(_ sender: NSButton) {
+        if sender.state == .on {
+            startFollowing()
+        } else {
+            stopFollowing()
+            statusLabel.stringValue = ""
+        }
+    }
+
+    @objc private func wrapToggled(_ sender: NSButton) {
+        applyWrap(sender.state == .on)
+    }
+
+    @objc private func timestampsToggled(_ sender: NSButton) {
+        render()
+    }
+
+    @objc private func refreshClicked(_ sender: Any) {
+        reload()
+    }
+
+    @objc private func jumpToLatest(_ sender: Any) {
+        textView.scrollToEndOfDocument(nil)
+        jumpToLatestButton.isHidden = true
+    }
+
+    @objc private func showFind(_ sender: Any) {
+        let item = NSMenuItem()
+        item.tag = NSTextFinder.Action.showFindInterface.rawValue
+        textView.performTextFinderAction(item)
+    }
+
+    @objc private func saveClicked(_ sender: Any) {
+        guard let window else { return }
+        let panel = NSSavePanel()
+        let name = agent.name.isEmpty ? agent.id : agent.name
+        panel.nameFieldStringValue = "\(name).log"
+        panel.allowedContentTypes = [.log, .plainText]
+        let text = buffer.rendered(timestamps: showsTimestamps) + "\n"
+        panel.beginSheetModal(for: window) { response in
+            guard response == .OK, let url = panel.url else { return }
+            do {
+                try text.write(to: url, atomically: true, encoding: .utf8)
+            } catch {
+                let alert = NSAlert(error: error)
+                alert.beginSheetModal(for: window)
+            }
+        }
+    }
+
+    // MARK: - NSWindowDelegate
+
+    func windowWillClose(_ notification: Notification) {
+        stopFollowing()
+        Self.openViewers[agent.id] = nil
+    }
+}
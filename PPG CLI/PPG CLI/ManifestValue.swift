@@ -0,0 +1,100 @@
+import Foundation
+
+/// A JSON value from a manifest field this build doesn't model. Kept on the entry
+/// so data added by a newer CLI (cost, model, token counts) survives decoding and
+/// re-encoding, and can be shown generically until it gets a real field.
+nonisolated enum ManifestValue: Codable, Sendable, Equatable {
+    case string(String)
+    case number(Double)
+    case bool(Bool)
+    case array([ManifestValue])
+    case object([String: ManifestValue])
+    case null
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([ManifestValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: ManifestValue].self))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .string(let value): try container.encode(value)
+        case .number(let value): try container.encode(value)
+        case .bool(let value): try container.encode(value)
+        case .array(let value): try container.encode(value)
+        case .object(let value): try container.encode(value)
+        case .null: try container.encodeNil()
+        }
+    }
+
+    /// Short text for the Advanced section: scalars as-is, whole numbers without ".0",
+    /// containers as compact JSON.
+    var displayString: String {
+        switch self {
+        case .string(let value):
+            return value
+        case .number(let value):
+            return value.rounded() == value && abs(value) < 1e15 ? String(Int64(value)) : String(value)
+        case .bool(let value):
+            return value ? "true" : "false"
+        case .null:
+            return "null"
+        case .array, .object:
+            let encoder = JSONEncoder()
+            encoder.outputFormatting = .sortedKeys
+            return (try? encoder.encode(self)).flatMap { String(data: $0, encoding: .utf8) } ?? ""
+        }
+    }
+
+    // MARK: - Extra Fields
+
+    private struct FieldKey: CodingKey {
+        let stringValue: String
+        var intValue: Int? { nil }
+        init(stringValue: String) { self.stringValue = stringValue }
+        init?(intValue: Int) { nil }
+    }
+
+    /// Every top-level field of the object being decoded except `known`.
+    static func extraFields(from decoder: Decoder, excluding known: Set<String>) throws -> [String: ManifestValue] {
+        let container = try decoder.container(keyedBy: FieldKey.self)
+        var extra: [String: ManifestValue] = [:]
+        for key in container.allKeys where !known.contains(key.stringValue) {
+            extra[key.stringValue] = try container.decode(ManifestValue.self, forKey: key)
+        }
+        return extra
+    }
+
+    static func encodeExtraFields(_ extra: [String: ManifestValue], to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: FieldKey.self)
+        for (name, value) in extra {
+            try container.encode(value, forKey: FieldKey(stringValue: name))
+        }
+    }
+}
+
+extension Dictionary where Key == String, Value == ManifestValue {
+    /// The field as a string if it holds one, for views that use new data opportunistically.
+    func string(_ name: String) -> String? {
+        if case .string(let value)? = self[name] { return value }
+        return nil
+    }
+
+    func number(_ name: String) -> Double? {
+        if case .number(let value)? = self[name] { return value }
+        return nil
+    }
+}
@@ -0,0 +1,55 @@
+import AppKit
+
+/// Menu bar item shown while "Keep running in the menu bar" is on, so the dashboard
+/// stays reachable after its window is closed.
+final class MenuBarController: NSObject, NSMenuDelegate {
+    var onShowWindow: (() -> Void)?
+    var onNewAgent: (() -> Void)?
+    /// Agents currently running across open projects, read each time the menu opens.
+    var runningAgentCount: (() -> Int)?
+
+    private let statusItem = NSStatusBar.system.statusItem(withLength: NSStatusItem.squareLength)
+
+    override init() {
+        super.init()
+        statusItem.button?.image = NSImage(systemSymbolName: "square.grid.2x2", accessibilityDescription: "PPG CLI")
+        let menu = NSMenu()
+        menu.delegate = self
+        statusItem.menu = menu
+    }
+
+    func remove() {
+        NSStatusBar.system.removeStatusItem(statusItem)
+    }
+
+    // MARK: - NSMenuDelegate
+
+    func menuNeedsUpdate(_ menu: NSMenu) {
+        menu.removeAllItems()
+
+        let count = runningAgentCount?() ?? 0
+        let countItem = NSMenuItem(title: count == 1 ? "1 agent running" : "\(count) agents running", action: nil, keyEquivalent: "")
+        countItem.isEnabled = false
+        menu.addItem(countItem)
+        menu.addItem(.separator())
+
+        let showItem = NSMenuItem(title: "Show Window", action: #selector(showWindow), keyEquivalent: "")
+        showItem.target = self
+        menu.addItem(showItem)
+
+        let newAgentItem = NSMenuItem(title: "New Agent…", action: #selector(newAgent), keyEquivalent: "")
+        newAgentItem.target = self
+        menu.addItem(newAgentItem)
+
+        menu.addItem(.separator())
+        menu.addItem(withTitle: "Quit PPG CLI", action: #selector(NSApplication.terminate(_:)), keyEquivalent: "")
+    }
+
+    @objc private func showWindow() {
+        onShowWindow?()
+    }
+
+    @objc private func newAgent() {
+        onNewAgent?()
+    }
+}
@@ -2,16 +2,25 @@ import Foundation
 
 // MARK: - Codable Models
 
-nonisolated struct ManifestModel: Codable, Sendable {
+nonisolated struct ManifestModel: Sendable {
     let version: Int
     let projectRoot: String
     let sessionName: String
     let worktrees: [String: WorktreeEntryModel]
     let createdAt: String
     let updatedAt: String
+    /// Fields this build doesn't model, kept so they survive a decode/encode round trip.
+    var extra: [String: ManifestValue] = [:]
 }
 
-nonisolated struct WorktreeEntryModel: Codable, Sendable {
+/// What tells one `ppg init` of a project from the next. Re-running init writes a
+/// fresh manifest, with a new creation time and possibly a new tmux session name.
+nonisolated struct ManifestIdentity: Equatable, Sendable {
+    let sessionName: String
+    let createdAt: String
+}
+
+nonisolated struct WorktreeEntryModel: Sendable {
     let id: String
     let name: String
     let path: String
@@ -22,9 +31,10 @@ nonisolated struct WorktreeEntryModel: Codable, Sendable {
     let agents: [String: AgentEntryModel]
     let createdAt: String
     let mergedAt: String?
+    var extra: [String: ManifestValue] = [:]
 }
 
-nonisolated struct AgentEntryModel: Codable, Sendable {
+nonisolated struct AgentEntryModel: Sendable {
     let id: String
     let name: String
     let agentType: String
@@ -36,6 +46,118 @@ nonisolated struct AgentEntryModel: Codable, Sendable {
     let exitCode: Int?
     let error: String?
     let sessionId: String?
+    var extra: [String: ManifestValue] = [:]
+}
+
+/// A manifest entry that couldn't be decoded and was left out of a lenient read.
+nonisolated struct ManifestParseIssue: Equatable, Sendable {
+    /// Where in the manifest, e.g. "worktrees.wt-1.agents.ag-2", or "manifest" for the file itself.
+    let path: String
+    let message: String
+}
+
+// Codable is written out so unknown fields land in `extra` instead of being dropped.
+
+extension ManifestModel: Codable {
+    private enum CodingKeys: String, CodingKey, CaseIterable {
+        case version, projectRoot, sessionName, worktrees, createdAt, updatedAt
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        version = try container.decode(Int.self, forKey: .version)
+        projectRoot = try container.decode(String.self, forKey: .projectRoot)
+        sessionName = try container.decode(String.self, forKey: .sessionName)
+        worktrees = try container.decode([String: WorktreeEntryModel].self, forKey: .worktrees)
+        createdAt = try container.decode(String.self, forKey: .createdAt)
+        updatedAt = try container.decode(String.self, forKey: .updatedAt)
+        extra = try ManifestValue.extraFields(from: decoder, excluding: Set(CodingKeys.allCases.map(\.rawValue)))
+    }
+
+    func encode(to encoder: Encoder) throws {
+        try ManifestValue.encodeExtraFields(extra, to: encoder)
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        try container.encode(version, forKey: .version)
+        try container.encode(projectRoot, forKey: .projectRoot)
+        try container.encode(sessionName, forKey: .sessionName)
+        try container.encode(worktrees, forKey: .worktrees)
+        try container.encode(createdAt, forKey: .createdAt)
+        try container.encode(updatedAt, forKey: .updatedAt)
+    }
+}
+
+extension WorktreeEntryModel: Codable {
+    private enum CodingKeys: String, CodingKey, CaseIterable {
+        case id, name, path, branch, baseBranch, status, tmuxWindow, agents, createdAt, mergedAt
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        id = try container.decode(String.self, forKey: .id)
+        name = try container.decode(String.self, forKey: .name)
+        path = try container.decode(String.self, forKey: .path)
+        branch = try container.decode(String.self, forKey: .branch)
+        baseBranch = try container.decode(String.self, forKey: .baseBranch)
+        status = try container.decode(String.self, forKey: .status)
+        tmuxWindow = try container.decode(String.self, forKey: .tmuxWindow)
+        agents = try container.decode([String: AgentEntryModel].self, forKey: .agents)
+        createdAt = try container.decode(String.self, forKey: .createdAt)
+        mergedAt = try container.decodeIfPresent(String.self, forKey: .mergedAt)
+        extra = try ManifestValue.extraFields(from: decoder, excluding: Set(CodingKeys.allCases.map(\.rawValue)))
+    }
+
+    func encode(to encoder: Encoder) throws {
+        try ManifestValue.encodeExtraFields(extra, to: encoder)
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        try container.encode(id, forKey: .id)
+        try container.encode(name, forKey: .name)
+        try container.encode(path, forKey: .path)
+        try container.encode(branch, forKey: .branch)
+        try container.encode(baseBranch, forKey: .baseBranch)
+        try container.encode(status, forKey: .status)
+        try container.encode(tmuxWindow, forKey: .tmuxWindow)
+        try container.encode(agents, forKey: .agents)
+        try container.encode(createdAt, forKey: .createdAt)
+        try container.encodeIfPresent(mergedAt, forKey: .mergedAt)
+    }
+}
+
+extension AgentEntryModel: Codable {
+    private enum CodingKeys: String, CodingKey, CaseIterable {
+        case id, name, agentType, status, tmuxTarget, prompt, startedAt, completedAt, exitCode, error, sessionId
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        id = try container.decode(String.self, forKey: .id)
+        name = try container.decode(String.self, forKey: .name)
+        agentType = try container.decode(String.self, forKey: .agentType)
+        status = try container.decode(String.self, forKey: .status)
+        tmuxTarget = try container.decode(String.self, forKey: .tmuxTarget)
+        prompt = try container.decode(String.self, forKey: .prompt)
+        startedAt = try container.decode(String.self, forKey: .startedAt)
+        completedAt = try container.decodeIfPresent(String.self, forKey: .completedAt)
+        exitCode = try container.decodeIfPresent(Int.self, forKey: .exitCode)
+        error = try container.decodeIfPresent(String.self, forKey: .error)
+        sessionId = try container.decodeIfPresent(String.self, forKey: .sessionId)
+        extra = try ManifestValue.extraFields(from: decoder, excluding: Set(CodingKeys.allCases.map(\.rawValue)))
+    }
+
+    func encode(to encoder: Encoder) throws {
+        try ManifestValue.encodeExtraFields(extra, to: encoder)
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        try container.encode(id, forKey: .id)
+        try container.encode(name, forKey: .name)
+        try container.encode(agentType, forKey: .agentType)
+        try container.encode(status, forKey: .status)
+        try container.encode(tmuxTarget, forKey: .tmuxTarget)
+        try container.encode(prompt, forKey: .prompt)
+        try container.encode(startedAt, forKey: .startedAt)
+        try container.encodeIfPresent(completedAt, forKey: .completedAt)
+        try container.encodeIfPresent(exitCode, forKey: .exitCode)
+        try container.encodeIfPresent(error, forKey: .error)
+        try container.encodeIfPresent(sessionId, forKey: .sessionId)
+    }
 }
 
 // MARK: - Enums
@@ -48,6 +170,22 @@ nonisolated enum AgentStatus: String, CaseIterable, Sendable {
     case failed
     case killed
     case lost
+    /// A status this build doesn't know, e.g. one added by a newer CLI.
+    /// `AgentModel.rawStatus` keeps the original value.
+    case unknown
+
+    /// The status for a manifest's status string. The CLI writes `running`, `idle`,
+    /// `exited` and `gone`. An idle agent's process has exited back to the shell without
+    /// a known code, so it reads as completed; an exited agent completed or failed by its
+    /// exit code. This enum's own names are read as themselves.
+    init(manifestStatus raw: String, exitCode: Int?) {
+        switch raw {
+        case "idle": self = .completed
+        case "exited": self = (exitCode ?? 0) == 0 ? .completed : .failed
+        case "gone": self = .lost
+        default: self = AgentStatus(rawValue: raw) ?? .unknown
+        }
+    }
 }
 
 // MARK: - View Models (classes for NSOutlineView identity)
@@ -57,17 +195,32 @@ nonisolated class WorktreeModel: @unchecked Sendable {
     let name: String
     let path: String
     let branch: String
+    let baseBranch: String
     let status: String
     let tmuxWindow: String
+    /// Raw manifest timestamp, kept for tooltips and when it doesn't parse.
+    let createdAt: String
+    let createdDate: Date?
+    /// Raw manifest timestamp of the merge, for worktrees that have one.
+    let mergedAt: String?
+    let mergedDate: Date?
+    /// Manifest fields this build doesn't model (see `ManifestValue`).
+    let extra: [String: ManifestValue]
     var agents: [AgentModel]
 
-    init(id: String, name: String, path: String, branch: String, status: String, tmuxWindow: String, agents: [AgentModel]) {
+    init(id: String, name: String, path: String, branch: String, baseBranch: String = "", status: String, tmuxWindow: String, createdAt: String = "", mergedAt: String? = nil, extra: [String: ManifestValue] = [:], agents: [AgentModel]) {
         self.id = id
         self.name = name
         self.path = path
         self.branch = branch
+        self.baseBranch = baseBranch
         self.status = status
         self.tmuxWindow = tmuxWindow
+        self.createdAt = createdAt
+        self.createdDate = parseISO8601(createdAt)
+        self.mergedAt = mergedAt
+        self.mergedDate = mergedAt.flatMap(parseISO8601)
+        self.extra = extra
         self.agents = agents
     }
 }
@@ -77,20 +230,35 @@ nonisolated class AgentModel: @unchecked Sendable {
     let name: String
     let agentType: String
     let status: AgentStatus
+    /// Status string as written in the manifest, e.g. "exited" for a completed agent.
+    let rawStatus: String
     let tmuxTarget: String
     let prompt: String
+    /// Raw manifest timestamps, kept for tooltips and when they don't parse.
     let startedAt: String
     let sessionId: String?
+    let completedAt: String?
+    let startedDate: Date?
+    let completedDate: Date?
+    let exitCode: Int?
+    /// Manifest fields this build doesn't model (see `ManifestValue`).
+    let extra: [String: ManifestValue]
 
-    init(id: String, name: String, agentType: String, status: AgentStatus, tmuxTarget: String, prompt: String, startedAt: String, sessionId: String? = nil) {
+    init(id: String, name: String, agentType: String, status: AgentStatus, rawStatus: String? = nil, tmuxTarget: String, prompt: String, startedAt: String, sessionId: String? = nil, completedAt: String? = nil, exitCode: Int? = nil, extra: [String: ManifestValue] = [:]) {
         self.id = id
         self.name = name
         self.agentType = agentType
         self.status = status
+        self.rawStatus = rawStatus ?? status.rawValue
         self.tmuxTarget = tmuxTarget
         self.prompt = prompt
         self.startedAt = startedAt
         self.sessionId = sessionId
+        self.completedAt = completedAt
+        self.startedDate = parseISO8601(startedAt)
+        self.completedDate = completedAt.flatMap(parseISO8601)
+        self.exitCode = exitCode
+        self.extra = extra
     }
 
     convenience init(from entry: AgentEntryModel) {
@@ -98,13 +266,83 @@ nonisolated class AgentModel: @unchecked Sendable {
             id: entry.id,
             name: entry.name,
             agentType: entry.agentType,
-            status: AgentStatus(rawValue: entry.status) ?? .lost,
+            status: AgentStatus(manifestStatus: entry.status, exitCode: entry.exitCode),
+            rawStatus: entry.status,
             tmuxTarget: entry.tmuxTarget,
             prompt: entry.prompt,
             startedAt: entry.startedAt,
-            sessionId: entry.sessionId
+            sessionId: entry.sessionId,
+            completedAt: entry.completedAt,
+            exitCode: entry.exitCode,
+            extra: entry.extra
         )
     }
+
+    /// Status for display: the enum value, or "unknown (paused)" with the manifest's string.
+    var statusLabel: String {
+        Self.statusLabel(status: status, rawStatus: rawStatus)
+    }
+
+    static func statusLabel(status: AgentStatus, rawStatus: String) -> String {
+        status == .unknown ? "unknown (\(rawStatus))" : status.rawValue
+    }
+
+    /// Tokens the agent has used, when the CLI reports it (`tokensUsed`). Counts too
+    /// large for an Int are clamped rather than trapping.
+    var tokensUsed: Int? {
+        guard let value = extra.number("tokensUsed"), value.isFinite, value >= 0 else { return nil }
+        return Int(exactly: value.rounded(.down)) ?? .max
+    }
+
+    /// What the agent has cost so far in US dollars, when the CLI reports it (`costUsd`).
+    var costUsd: Double? {
+        guard let value = extra.number("costUsd"), value.isFinite, value >= 0 else { return nil }
+        return value
+    }
+
+    /// Both of the above, or nil when neither is reported.
+    var usage: AgentUsage? {
+        let usage = AgentUsage(tokens: tokensUsed, costUsd: costUsd)
+        return usage.tokens == nil && usage.costUsd == nil ? nil : usage
+    }
+}
+
+// MARK: - AgentIndex
+
+/// Agent ID → where it lives, built once per refresh so pane routing and sidebar
+/// lookups don't walk every worktree of every project.
+nonisolated struct AgentIndex {
+    struct Location {
+        let projectRoot: String
+        let worktree: WorktreeModel
+        let agent: AgentModel
+    }
+
+    private var locations: [String: Location] = [:]
+
+    init() {}
+
+    /// Index `projectWorktrees` (keyed by project root). If an ID somehow appears
+    /// twice, the first worktree in each project's order wins.
+    init(projectWorktrees: [String: [WorktreeModel]]) {
+        for (root, worktrees) in projectWorktrees {
+            for worktree in worktrees {
+                for agent in worktree.agents where locations[agent.id] == nil {
+                    locations[agent.id] = Location(projectRoot: root, worktree: worktree, agent: agent)
+                }
+            }
+        }
+    }
+
+    var count: Int { locations.count }
+
+    func find(_ agentId: String) -> Location? {
+        locations[agentId]
+    }
+
+    func worktree(ofAgent agentId: String) -> WorktreeModel? {
+        locations[agentId]?.worktree
+    }
 }
 
 // MARK: - LaunchConfig
@@ -117,27 +355,82 @@ nonisolated struct LaunchConfig: Sendable {
     let projectName: String
     let projectRoot: String
 
-    static func parse(_ args: [String]) -> LaunchConfig {
-        var manifestPath = ""
-        var sessionName = ""
-        var projectRoot = ""
+    /// What a launch asked for: run the app, or print help or the version and exit.
+    enum Command {
+        case run(LaunchConfig)
+        case help
+        case version
+    }
+
+    enum ArgumentError: Error, CustomStringConvertible {
+        case missingValue(String)
+        case unknownOption(String)
+
+        var description: String {
+            switch self {
+            case .missingValue(let option): return "\(option) needs a value"
+            case .unknownOption(let option): return "unknown option \(option)"
+            }
+        }
+    }
+
+    static let usage = """
+    Usage: PPG CLI [--manifest-path <path>] [--session-name <name>] [--project-root <path>]
+
+      --manifest-path <path>  Open the project whose .ppg/manifest.json is at <path>
+      --session-name <name>   tmux session the project's agents run in
+      --project-root <path>   Project directory (default: two levels above the manifest)
+      --help                  Show this help
+      --version               Show the app version
+    """
 
-        var i = 0
+    private static let valueOptions: Set<String> = ["--manifest-path", "--session-name", "--project-root"]
+
+    /// Parse launch arguments, rejecting unknown `--` options and options missing
+    /// their value. Single-dash arguments (`-psn_…`, `-NSDocumentRevisionsDebugMode YES`)
+    /// come from macOS and Xcode and are left for AppKit.
+    static func parseCommand(_ args: [String]) throws -> Command {
+        var values: [String: String] = [:]
+
+        var i = 1
         while i < args.count {
-            if args[i] == "--manifest-path", i + 1 < args.count {
-                manifestPath = args[i + 1]
-                i += 2
-            } else if args[i] == "--session-name", i + 1 < args.count {
-                sessionName = args[i + 1]
+            let arg = args[i]
+            switch arg {
+            case "--help", "-h":
+                return .help
+            case "--version":
+                return .version
+            case _ where valueOptions.contains(arg):
+                guard i + 1 < args.count, !args[i + 1].hasPrefix("--") else {
+                    throw ArgumentError.missingValue(arg)
+                }
+                values[arg] = args[i + 1]
                 i += 2
-            } else if args[i] == "--project-root", i + 1 < args.count {
-                projectRoot = args[i + 1]
-                i += 2
-            } else {
+            case _ where arg.hasPrefix("--"):
+                throw ArgumentError.unknownOption(arg)
+            default:
                 i += 1
             }
         }
 
+        return .run(make(
+            manifestPath: values["--manifest-path"] ?? "",
+            sessionName: values["--session-name"] ?? "",
+            projectRoot: values["--project-root"] ?? ""
+        ))
+    }
+
+    /// Config for a normal launch, or an empty one if the arguments don't describe one.
+    static func parse(_ args: [String]) -> LaunchConfig {
+        if case .run(let config)? = try? parseCommand(args) {
+            return config
+        }
+        return make(manifestPath: "", sessionName: "", projectRoot: "")
+    }
+
+    private static func make(manifestPath: String, sessionName: String, projectRoot: String) -> LaunchConfig {
+        var projectRoot = projectRoot
+
         // Derive projectRoot from manifestPath if not explicitly provided
         if projectRoot.isEmpty, !manifestPath.isEmpty {
             let url = URL(fileURLWithPath: manifestPath)
@@ -234,6 +527,11 @@ nonisolated class RecentProjects: @unchecked Sendable {
         UserDefaults.standard.set(path, forKey: lastOpenedKey)
     }
 
+    func clear() {
+        UserDefaults.standard.removeObject(forKey: key)
+        UserDefaults.standard.removeObject(forKey: lastOpenedKey)
+    }
+
     func isValidProject(_ path: String) -> Bool {
         let manifestPath = (path as NSString)
             .appendingPathComponent(".ppg")
@@ -249,11 +547,51 @@ class ProjectContext {
     let projectName: String
     let manifestPath: String
     var sessionName: String
+    /// Identity of the manifest last read; nil until the project has one.
+    private(set) var manifestIdentity: ManifestIdentity?
     let dashboardSession: DashboardSession
+    let statusHistory: StatusHistory
+    let worktreeNotes: WorktreeNotes
 
-    /// Each variant defines its own default command in AgentVariant.
+    /// The command from Settings ▸ Agents if one is set, otherwise the variant's built-in default.
     func agentCommand(for variant: AgentVariant) -> String {
-        variant.defaultCommand
+        AppSettingsManager.shared.agentCommandOverrides[variant.id] ?? variant.defaultCommand
+    }
+
+    /// Take on the identity of the manifest just read, including its session name.
+    /// Returns true when it replaces a different one, i.e. `ppg init` ran again; a
+    /// project opened before it had a manifest just picks up the real session name.
+    @discardableResult
+    func adoptManifestIdentity(_ identity: ManifestIdentity) -> Bool {
+        let replaced = manifestIdentity.map { $0 != identity } ?? false
+        manifestIdentity = identity
+        sessionName = identity.sessionName
+        return replaced
+    }
+
+    /// `defaultAgent` from `.ppg/config.yaml`, the agent `ppg spawn` uses without `--agent`.
+    /// Read on each call so edits in the Agent Config view apply right away.
+    var configuredDefaultAgent: String? {
+        let path = (projectRoot as NSString).appendingPathComponent(".ppg/config.yaml")
+        guard let content = try? String(contentsOfFile: path, encoding: .utf8) else { return nil }
+        return Self.defaultAgent(inConfig: content)
+    }
+
+    /// The top-level `defaultAgent:` value of a config.yaml, unquoted and without a
+    /// trailing comment.
+    nonisolated static func defaultAgent(inConfig content: String) -> String? {
+        for line in content.components(separatedBy: .newlines) where line.hasPrefix("defaultAgent:") {
+            var value = String(line.dropFirst("defaultAgent:".count))
+            if let comment = value.range(of: " #") {
+                value = String(value[..<comment.lowerBound])
+            }
+            value = value.trimmingCharacters(in: .whitespaces)
+            if value.count >= 2, let first = value.first, first == value.last, first == "\"" || first == "'" {
+                value = String(value.dropFirst().dropLast())
+            }
+            return value.isEmpty ? nil : value
+        }
+        return nil
     }
 
     init(projectRoot: String) {
@@ -267,11 +605,14 @@ class ProjectContext {
         if let data = FileManager.default.contents(atPath: self.manifestPath),
            let manifest = try? JSONDecoder().decode(ManifestModel.self, from: data) {
             self.sessionName = manifest.sessionName
+            self.manifestIdentity = ManifestIdentity(sessionName: manifest.sessionName, createdAt: manifest.createdAt)
         } else {
             self.sessionName = "ppg"
         }
 
         self.dashboardSession = DashboardSession(projectRoot: projectRoot)
+        self.statusHistory = StatusHistory(projectRoot: projectRoot)
+        self.worktreeNotes = WorktreeNotes(projectRoot: projectRoot)
     }
 }
 
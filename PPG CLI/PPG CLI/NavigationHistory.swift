@@ -0,0 +1,59 @@
+import Foundation
+
+/// Something the main window can show: a sidebar row, by its persisted ID, or one of
+/// the sidebar's tabs.
+enum NavigationTarget: Equatable {
+    case item(String)
+    case tab(SidebarTab)
+}
+
+/// Back/forward history of what the window showed, like a browser's. Every selection
+/// is visited, whether it came from a click, the quick switcher or a notification;
+/// walking the history must not visit, or going back would erase what's ahead.
+struct NavigationHistory {
+    static let capacity = 50
+
+    private(set) var current: NavigationTarget?
+    private(set) var backStack: [NavigationTarget] = []
+    private(set) var forwardStack: [NavigationTarget] = []
+
+    var canGoBack: Bool { !backStack.isEmpty }
+    var canGoForward: Bool { !forwardStack.isEmpty }
+
+    /// Record a new destination. Reselecting what's shown (e.g. a refresh re-selecting
+    /// the same row) is not a visit.
+    mutating func visit(_ target: NavigationTarget) {
+        guard target != current else { return }
+        if let current {
+            backStack.append(current)
+            if backStack.count > Self.capacity {
+                backStack.removeFirst(backStack.count - Self.capacity)
+            }
+        }
+        forwardStack.removeAll()
+        current = target
+    }
+
+    /// Step back, returning the destination to show. `isAvailable` skips entries whose
+    /// row has since gone (a removed worktree or killed terminal); those are dropped.
+    mutating func goBack(where isAvailable: (NavigationTarget) -> Bool = { _ in true }) -> NavigationTarget? {
+        while let target = backStack.popLast() {
+            guard isAvailable(target) else { continue }
+            if let current { forwardStack.append(current) }
+            current = target
+            return target
+        }
+        return nil
+    }
+
+    /// Step forward again after `goBack`.
+    mutating func goForward(where isAvailable: (NavigationTarget) -> Bool = { _ in true }) -> NavigationTarget? {
+        while let target = forwardStack.popLast() {
+            guard isAvailable(target) else { continue }
+            if let current { backStack.append(current) }
+            current = target
+            return target
+        }
+        return nil
+    }
+}
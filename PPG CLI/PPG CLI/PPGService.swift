@@ -3,12 +3,128 @@ import Foundation
 nonisolated class PPGService: @unchecked Sendable {
     static let shared = PPGService()
     static let minimumTmuxVersionForCodexInputTheme = "3.5"
+    /// First CLI release that keeps its state in `.ppg/` rather than `.pg/`.
+    static let minimumCLIVersion = "0.3.0"
+    /// Manifest layouts this build understands. A newer CLI may write a version we'd
+    /// misread, so those manifests are refused rather than half-parsed.
+    static let supportedManifestVersions = 1...1
+
+    private let manifestVersionLock = NSLock()
+    private var _ignoresManifestVersion = false
+
+    /// Set for the rest of the session when the user chooses to read unsupported
+    /// manifests anyway, best-effort.
+    var ignoresManifestVersion: Bool {
+        get { manifestVersionLock.withLock { _ignoresManifestVersion } }
+        set { manifestVersionLock.withLock { _ignoresManifestVersion = newValue } }
+    }
 
-    /// Read manifest from the given path. Thread-safe: does not access shared mutable state.
+    /// Read manifest from the given path, salvaging what it can (see `readManifestLeniently`).
+    /// Returns nil for a manifest version this build doesn't support. Thread-safe.
     func readManifest(at path: String) -> ManifestModel? {
-        guard !path.isEmpty else { return nil }
-        guard let data = FileManager.default.contents(atPath: path) else { return nil }
-        return try? JSONDecoder().decode(ManifestModel.self, from: data)
+        readManifestLeniently(at: path).manifest
+    }
+
+    /// Read a manifest, keeping every worktree and agent that decodes on its own when
+    /// the file as a whole doesn't. Entries that fail are left out and described in
+    /// `issues`. With `strict`, any failure drops the whole manifest, as a debugging aid.
+    func readManifestLeniently(at path: String, strict: Bool = false) -> (manifest: ManifestModel?, issues: [ManifestParseIssue]) {
+        guard !path.isEmpty,
+              let data = FileManager.default.contents(atPath: path),
+              blockingManifestVersion(in: data) == nil else { return (nil, []) }
+
+        do {
+            return (try JSONDecoder().decode(ManifestModel.self, from: data), [])
+        } catch {
+            if strict {
+                return (nil, [ManifestParseIssue(path: "manifest", message: Self.describe(error))])
+            }
+        }
+
+        guard var root = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any] else {
+            return (nil, [ManifestParseIssue(path: "manifest", message: "The file isn't a JSON object.")])
+        }
+
+        var issues: [ManifestParseIssue] = []
+        var worktrees: [String: WorktreeEntryModel] = [:]
+        for (worktreeId, value) in root["worktrees"] as? [String: Any] ?? [:] {
+            guard var worktree = value as? [String: Any] else {
+                issues.append(ManifestParseIssue(path: "worktrees.\(worktreeId)", message: "Not a JSON object."))
+                continue
+            }
+            var agents: [String: Any] = [:]
+            for (agentId, agent) in worktree["agents"] as? [String: Any] ?? [:] {
+                do {
+                    _ = try Self.decodeFragment(AgentEntryModel.self, from: agent)
+                    agents[agentId] = agent
+                } catch {
+                    issues.append(ManifestParseIssue(path: "worktrees.\(worktreeId).agents.\(agentId)", message: Self.describe(error)))
+                }
+            }
+            worktree["agents"] = agents
+            do {
+                worktrees[worktreeId] = try Self.decodeFragment(WorktreeEntryModel.self, from: worktree)
+            } catch {
+                issues.append(ManifestParseIssue(path: "worktrees.\(worktreeId)", message: Self.describe(error)))
+            }
+        }
+
+        root["worktrees"] = [String: Any]()
+        let top: ManifestModel
+        do {
+            top = try Self.decodeFragment(ManifestModel.self, from: root)
+        } catch {
+            return (nil, [ManifestParseIssue(path: "manifest", message: Self.describe(error))])
+        }
+        let manifest = ManifestModel(
+            version: top.version,
+            projectRoot: top.projectRoot,
+            sessionName: top.sessionName,
+            worktrees: worktrees,
+            createdAt: top.createdAt,
+            updatedAt: top.updatedAt,
+            extra: top.extra
+        )
+        return (manifest, issues.sorted { $0.path < $1.path })
+    }
+
+    private static func decodeFragment<T: Decodable>(_ type: T.Type, from object: Any) throws -> T {
+        try JSONDecoder().decode(type, from: JSONSerialization.data(withJSONObject: object))
+    }
+
+    /// One-line reason for a decoding failure, naming the field involved.
+    static func describe(_ error: Error) -> String {
+        guard let error = error as? DecodingError else { return error.localizedDescription }
+        func field(_ path: [CodingKey]) -> String {
+            path.map(\.stringValue).joined(separator: ".")
+        }
+        switch error {
+        case .keyNotFound(let key, _):
+            return "Missing \"\(key.stringValue)\"."
+        case .typeMismatch(_, let context):
+            return "\"\(field(context.codingPath))\" has the wrong type."
+        case .valueNotFound(_, let context):
+            return "\"\(field(context.codingPath))\" is null."
+        case .dataCorrupted(let context):
+            return context.debugDescription
+        @unknown default:
+            return error.localizedDescription
+        }
+    }
+
+    /// The manifest's version if it's outside `supportedManifestVersions` and the user
+    /// hasn't chosen to ignore that; nil when the manifest can be used (or doesn't exist).
+    func blockingManifestVersion(at path: String) -> Int? {
+        guard !path.isEmpty, let data = FileManager.default.contents(atPath: path) else { return nil }
+        return blockingManifestVersion(in: data)
+    }
+
+    private func blockingManifestVersion(in data: Data) -> Int? {
+        struct VersionProbe: Decodable { let version: Int }
+        guard !ignoresManifestVersion,
+              let version = (try? JSONDecoder().decode(VersionProbe.self, from: data))?.version,
+              !Self.supportedManifestVersions.contains(version) else { return nil }
+        return version
     }
 
     /// Convenience: read manifest from ProjectState. Only call from main thread.
@@ -25,13 +141,7 @@ nonisolated class PPGService: @unchecked Sendable {
     func runPPGCommand(_ args: String, projectRoot: String) -> CommandResult {
         let task = Process()
         task.executableURL = URL(fileURLWithPath: "/bin/zsh")
-        let cmd = """
-        if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; \
-        [ -f ~/.zprofile ] && source ~/.zprofile; \
-        [ -f ~/.zshrc ] && source ~/.zshrc; \
-        cd \(shellEscape(projectRoot)) && ppg \(args)
-        """
-        task.arguments = ["-c", cmd]
+        task.arguments = ["-c", ppgShellCommand(args, projectRoot: projectRoot)]
 
         let outPipe = Pipe()
         let errPipe = Pipe()
@@ -54,8 +164,82 @@ nonisolated class PPGService: @unchecked Sendable {
         )
     }
 
-    /// Check if ppg CLI is available in the user's PATH.
-    func checkCLIAvailable() -> (available: Bool, version: String?) {
+    /// zsh command line that runs `ppg <args>` in `projectRoot` with the user's login PATH.
+    /// `exec` makes ppg replace the shell, so terminating the process stops ppg itself.
+    private func ppgShellCommand(_ args: String, projectRoot: String) -> String {
+        """
+        if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; \
+        [ -f ~/.zprofile ] && source ~/.zprofile; \
+        [ -f ~/.zshrc ] && source ~/.zshrc; \
+        cd \(shellEscape(projectRoot)) && exec ppg \(args)
+        """
+    }
+
+    /// Start a long-running ppg command (e.g. `logs --follow`) without waiting for it.
+    /// `onOutput` receives stdout as it arrives and `onExit` the exit code and stderr,
+    /// both on one serial background queue, so they never overlap and `onExit` comes
+    /// after the last output. Returns nil if the process couldn't be launched.
+    func startPPGProcess(
+        _ args: String,
+        projectRoot: String,
+        onOutput: @escaping @Sendable (Data) -> Void,
+        onExit: @escaping @Sendable (Int32, String) -> Void
+    ) -> Process? {
+        let task = Process()
+        task.executableURL = URL(fileURLWithPath: "/bin/zsh")
+        task.arguments = ["-c", ppgShellCommand(args, projectRoot: projectRoot)]
+
+        let outPipe = Pipe()
+        let errPipe = Pipe()
+        task.standardOutput = outPipe
+        task.standardError = errPipe
+
+        let queue = DispatchQueue(label: "ppg.process-output", qos: .userInitiated)
+        let state = StartedProcessState()
+        // Exit is reported once stdout and stderr have both hit EOF and the process ended
+        let finished = DispatchGroup()
+        for _ in 0..<3 { finished.enter() }
+        outPipe.fileHandleForReading.readabilityHandler = { handle in
+            let data = handle.availableData
+            guard !data.isEmpty else {
+                handle.readabilityHandler = nil
+                finished.leave()
+                return
+            }
+            queue.async { onOutput(data) }
+        }
+        // Drained as it arrives, so a chatty stderr can't fill the pipe and block ppg
+        errPipe.fileHandleForReading.readabilityHandler = { handle in
+            let data = handle.availableData
+            guard !data.isEmpty else {
+                handle.readabilityHandler = nil
+                finished.leave()
+                return
+            }
+            queue.async { state.stderr.append(data) }
+        }
+        task.terminationHandler = { process in
+            let status = process.terminationStatus
+            queue.async { state.exitCode = status }
+            finished.leave()
+        }
+        finished.notify(queue: queue) {
+            onExit(state.exitCode, String(data: state.stderr, encoding: .utf8) ?? "")
+        }
+
+        do {
+            try task.run()
+        } catch {
+            outPipe.fileHandleForReading.readabilityHandler = nil
+            errPipe.fileHandleForReading.readabilityHandler = nil
+            return nil
+        }
+        return task
+    }
+
+    /// Check if ppg CLI is available in the user's PATH and at least `minimumCLIVersion`.
+    /// Output that doesn't parse as a version is treated as supported.
+    func checkCLIAvailable() -> (available: Bool, version: String?, supported: Bool) {
         let task = Process()
         task.executableURL = URL(fileURLWithPath: "/bin/zsh")
         let cmd = """
@@ -74,19 +258,23 @@ nonisolated class PPGService: @unchecked Sendable {
             try task.run()
             task.waitUntilExit()
         } catch {
-            return (false, nil)
+            return (false, nil, false)
         }
 
         if task.terminationStatus == 0 {
             let data = outPipe.fileHandleForReading.readDataToEndOfFile()
-            let version = String(data: data, encoding: .utf8)?.trimmingCharacters(in: .whitespacesAndNewlines)
-            return (true, version)
+            let output = String(data: data, encoding: .utf8)?.trimmingCharacters(in: .whitespacesAndNewlines) ?? ""
+            let version = extractVersion(from: output) ?? (output.isEmpty ? nil : output)
+            let supported = version.flatMap { isVersion($0, atLeast: Self.minimumCLIVersion) } ?? true
+            return (true, version, supported)
         }
-        return (false, nil)
+        return (false, nil, false)
     }
 
     /// Check if tmux is available in the user's PATH and whether its version
-    /// supports Codex input theming inside tmux.
+    /// supports Codex input theming inside tmux. An unrecognized version string
+    /// is treated as supported rather than blocking setup. Also refreshes the
+    /// version behind `tmuxFeatures`.
     func checkTmuxAvailable() -> (available: Bool, version: String?, supportsCodexInputTheme: Bool) {
         let task = Process()
         task.executableURL = URL(fileURLWithPath: "/bin/zsh")
@@ -104,65 +292,115 @@ nonisolated class PPGService: @unchecked Sendable {
             try task.run()
             task.waitUntilExit()
             guard task.terminationStatus == 0 else {
+                storeTmuxVersion(nil)
                 return (false, nil, false)
             }
             let data = (task.standardOutput as? Pipe)?.fileHandleForReading.readDataToEndOfFile() ?? Data()
             let stdout = String(data: data, encoding: .utf8)?
                 .trimmingCharacters(in: .whitespacesAndNewlines) ?? ""
-            let version = extractTmuxVersion(from: stdout)
-            let supports = version.map {
-                isVersion($0, atLeast: Self.minimumTmuxVersionForCodexInputTheme)
-            } ?? false
-            return (true, version, supports)
+            let version = TmuxVersion.parse(stdout)
+            storeTmuxVersion(version)
+            return (true, version?.description, TmuxFeatures(version: version).supportsCodexInputTheme)
         } catch {
+            storeTmuxVersion(nil)
             return (false, nil, false)
         }
     }
 
-    private func extractTmuxVersion(from output: String) -> String? {
-        // Match the "tmux X.Y" line specifically to avoid false positives
-        // from shell startup scripts that may print version-like numbers.
-        guard let range = output.range(
-            of: #"tmux\s+([0-9]+(?:\.[0-9]+)*[a-z]?)"#,
-            options: .regularExpression
-        ) else {
-            return nil
-        }
-        let match = output[range]
-        // Extract just the version number after "tmux "
-        guard let versionRange = match.range(
-            of: #"[0-9]+(?:\.[0-9]+)*[a-z]?"#,
-            options: .regularExpression
-        ) else {
-            return nil
+    private let tmuxVersionLock = NSLock()
+    private var _tmuxVersion: TmuxVersion?
+    private var tmuxVersionChecked = false
+
+    private func storeTmuxVersion(_ version: TmuxVersion?) {
+        tmuxVersionLock.withLock {
+            _tmuxVersion = version
+            tmuxVersionChecked = true
         }
-        return String(match[versionRange])
     }
 
-    private func isVersion(_ version: String, atLeast minimum: String) -> Bool {
-        guard let lhs = versionComponents(version),
-              let rhs = versionComponents(minimum) else { return false }
-        let count = max(lhs.count, rhs.count)
-        for idx in 0..<count {
-            let l = idx < lhs.count ? lhs[idx] : 0
-            let r = idx < rhs.count ? rhs[idx] : 0
-            if l != r { return l > r }
+    /// The installed tmux's version, from `tmux -V` the first time and cached after.
+    /// Nil if tmux is missing or didn't print a recognizable version. The first call
+    /// blocks on a process; call off the main thread.
+    func tmuxVersion() -> TmuxVersion? {
+        let cached: TmuxVersion?? = tmuxVersionLock.withLock { tmuxVersionChecked ? .some(_tmuxVersion) : .none }
+        if let cached { return cached }
+        _ = checkTmuxAvailable()
+        return tmuxVersionLock.withLock { _tmuxVersion }
+    }
+
+    /// Features of the cached tmux version. Never blocks: until the version has been
+    /// checked, or if it couldn't be read, a current tmux is assumed.
+    var tmuxFeatures: TmuxFeatures {
+        TmuxFeatures(version: tmuxVersionLock.withLock { _tmuxVersion })
+    }
+
+    // MARK: - tmux Inventory
+
+    /// Run a tmux command with the user's PATH. Thread-safe.
+    func runTmuxCommand(_ command: TmuxCommand) -> CommandResult {
+        let task = Process()
+        task.executableURL = URL(fileURLWithPath: "/bin/zsh")
+        let cmd = """
+        if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; \
+        [ -f ~/.zprofile ] && source ~/.zprofile; \
+        [ -f ~/.zshrc ] && source ~/.zshrc; \
+        \(command.shellString)
+        """
+        task.arguments = ["-c", cmd]
+
+        let outPipe = Pipe()
+        let errPipe = Pipe()
+        task.standardOutput = outPipe
+        task.standardError = errPipe
+
+        do {
+            try task.run()
+            task.waitUntilExit()
+        } catch {
+            return CommandResult(exitCode: -1, stdout: "", stderr: error.localizedDescription)
         }
-        return true
+
+        let outData = outPipe.fileHandleForReading.readDataToEndOfFile()
+        let errData = errPipe.fileHandleForReading.readDataToEndOfFile()
+        return CommandResult(
+            exitCode: task.terminationStatus,
+            stdout: String(data: outData, encoding: .utf8) ?? "",
+            stderr: String(data: errData, encoding: .utf8) ?? ""
+        )
+    }
+
+    /// Every session and window on the tmux server, or nil if tmux couldn't be queried.
+    /// Blocks on a tmux process; call off the main thread.
+    func tmuxSnapshot() -> TmuxSnapshot? {
+        let result = runTmuxCommand(TmuxCommand("list-windows").flag("-a").option("-F", TmuxSnapshot.listWindowsFormat))
+        return TmuxSnapshot.parse(exitCode: result.exitCode, stdout: result.stdout, stderr: result.stderr)
     }
 
-    private func versionComponents(_ version: String) -> [Int]? {
-        let parts = version.split(separator: ".")
-        guard !parts.isEmpty else { return nil }
-        var ints: [Int] = []
-        for part in parts {
-            let numericPrefix = part.prefix { $0.isNumber }
-            guard !numericPrefix.isEmpty, let value = Int(numericPrefix) else {
-                return nil
+    /// Last output time of every tmux window, or nil if tmux couldn't be queried.
+    func tmuxWindowActivity() -> TmuxWindowActivity? {
+        let result = runTmuxCommand(TmuxCommand("list-windows").flag("-a").option("-F", TmuxWindowActivity.listWindowsFormat))
+        return TmuxWindowActivity.parse(exitCode: result.exitCode, stdout: result.stdout)
+    }
+
+    /// Names of the running tmux sessions, or nil if tmux couldn't be queried.
+    func listTmuxSessions() -> [String]? {
+        tmuxSnapshot()?.sessions
+    }
+
+    /// Whether `window` (index or name) exists in `session`, or nil if tmux couldn't be queried.
+    func tmuxWindowExists(session: String, window: String) -> Bool? {
+        tmuxSnapshot()?.containsWindow(session: session, window: window)
+    }
+
+    /// Check a manifest tmux target ("session:window[.pane]") off the main thread and
+    /// report on main. Nil means unknown; callers should attach as usual then.
+    func checkTmuxTarget(_ target: String, completion: @escaping @MainActor (Bool?) -> Void) {
+        DispatchQueue.global(qos: .userInitiated).async {
+            let exists = self.tmuxSnapshot()?.contains(TmuxTarget(parsing: target))
+            DispatchQueue.main.async {
+                completion(exists)
             }
-            ints.append(value)
         }
-        return ints
     }
 
     /// Run a git command directly in a specific directory. Faster than runPPGCommand
@@ -236,6 +474,39 @@ nonisolated class PPGService: @unchecked Sendable {
         return (version?.isEmpty == false) ? version : nil
     }
 
+    /// Which of `PackageManager.allCases` are on the user's login-shell PATH.
+    func detectPackageManagers() -> Set<PackageManager> {
+        let task = Process()
+        task.executableURL = URL(fileURLWithPath: "/bin/zsh")
+        let names = PackageManager.allCases.map(\.command).joined(separator: " ")
+        let cmd = """
+        if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; \
+        [ -f ~/.zprofile ] && source ~/.zprofile; \
+        [ -f ~/.zshrc ] && source ~/.zshrc; \
+        for c in \(names); do command -v $c >/dev/null 2>&1 && echo "ppg-found:$c"; done
+        """
+        task.arguments = ["-c", cmd]
+
+        let outPipe = Pipe()
+        task.standardOutput = outPipe
+        task.standardError = Pipe()
+
+        do {
+            try task.run()
+            task.waitUntilExit()
+        } catch {
+            return []
+        }
+
+        let data = outPipe.fileHandleForReading.readDataToEndOfFile()
+        let output = String(data: data, encoding: .utf8) ?? ""
+        // Tagged lines keep anything the profile prints from being mistaken for a result
+        return Set(output.split(whereSeparator: \.isNewline).compactMap { line in
+            guard line.hasPrefix("ppg-found:") else { return nil }
+            return PackageManager(rawValue: String(line.dropFirst("ppg-found:".count)))
+        })
+    }
+
     /// Install the latest version of pure-point-guard globally via npm.
     func updateCLI() -> (success: Bool, output: String) {
         let task = Process()
@@ -277,24 +548,53 @@ nonisolated class PPGService: @unchecked Sendable {
 
     /// Refresh status using the given manifest path. Thread-safe.
     func refreshStatus(manifestPath: String) -> [WorktreeModel] {
-        guard let manifest = readManifest(at: manifestPath) else { return [] }
+        loadStatus(manifestPath: manifestPath).worktrees
+    }
+
+    /// Worktrees from the manifest plus any entries that couldn't be read, and the
+    /// manifest's identity (nil if it couldn't be read). Thread-safe.
+    func loadStatus(manifestPath: String, strict: Bool = false) -> (worktrees: [WorktreeModel], issues: [ManifestParseIssue], identity: ManifestIdentity?) {
+        let (manifest, issues) = readManifestLeniently(at: manifestPath, strict: strict)
+        guard let manifest else { return ([], issues, nil) }
+        let identity = ManifestIdentity(sessionName: manifest.sessionName, createdAt: manifest.createdAt)
 
-        return manifest.worktrees.values
+        let worktrees = manifest.worktrees.values
             .filter { $0.status != "cleaned" && $0.status != "merged" }
-            .sorted { $0.createdAt < $1.createdAt }
             .map { entry in
                 let agents = entry.agents.values
-                    .sorted { $0.startedAt < $1.startedAt }
                     .map { AgentModel(from: $0) }
+                    .sorted { Self.precedes(($0.startedDate, $0.startedAt, $0.id), ($1.startedDate, $1.startedAt, $1.id)) }
                 return WorktreeModel(
                     id: entry.id,
                     name: entry.name,
                     path: entry.path,
                     branch: entry.branch,
+                    baseBranch: entry.baseBranch,
                     status: entry.status,
                     tmuxWindow: entry.tmuxWindow,
+                    createdAt: entry.createdAt,
+                    mergedAt: entry.mergedAt,
+                    extra: entry.extra,
                     agents: agents
                 )
             }
+            .sorted { Self.precedes(($0.createdDate, $0.createdAt, $0.id), ($1.createdDate, $1.createdAt, $1.id)) }
+        return (worktrees, issues, identity)
     }
+
+    /// Creation order for manifest entries. The manifest's maps carry no order of their
+    /// own, so entries created in the same instant fall back to ID to keep their place
+    /// from one refresh to the next.
+    static func precedes(_ lhs: (date: Date?, raw: String, id: String), _ rhs: (date: Date?, raw: String, id: String)) -> Bool {
+        if timestampPrecedes((lhs.date, lhs.raw), (rhs.date, rhs.raw)) { return true }
+        if timestampPrecedes((rhs.date, rhs.raw), (lhs.date, lhs.raw)) { return false }
+        return lhs.id < rhs.id
+    }
+}
+
+/// What a process from `startPPGProcess` has reported so far. Only touched on that
+/// process's output queue.
+private nonisolated final class StartedProcessState: @unchecked Sendable {
+    var stderr = Data()
+    var exitCode: Int32 = 0
 }
@@ -158,6 +158,53 @@ indirect enum PaneSplitNode {
         }
     }
 
+    // MARK: - Reflow
+
+    /// Leaf nodes in tree order (left to right, then top to bottom for grid layouts).
+    var leaves: [PaneSplitNode] {
+        switch self {
+        case .leaf: return [self]
+        case .split(_, let first, let second, _): return first.leaves + second.leaves
+        }
+    }
+
+    /// `leaves` placed `columns` to a row: each row a chain of vertical splits, the rows
+    /// stacked by horizontal splits. The shape a worktree's agents are opened in.
+    static func grid(of leaves: [PaneSplitNode], columns: Int) -> PaneSplitNode? {
+        guard !leaves.isEmpty, columns > 0 else { return nil }
+        let rows = stride(from: 0, to: leaves.count, by: columns).map {
+            chain(Array(leaves[$0..<min($0 + columns, leaves.count)]), direction: .vertical)
+        }
+        return chain(rows, direction: .horizontal)
+    }
+
+    /// Nodes split evenly in one direction, nested to the right.
+    private static func chain(_ nodes: [PaneSplitNode], direction: SplitDirection) -> PaneSplitNode {
+        guard nodes.count > 1 else { return nodes[0] }
+        return .split(direction: direction, first: nodes[0], second: chain(Array(nodes.dropFirst()), direction: direction),
+                      ratio: 1 / CGFloat(nodes.count))
+    }
+
+    /// Columns per row when this tree is laid out like `grid(of:columns:)`, whatever its
+    /// ratios; nil for an arrangement the user has made by hand.
+    var gridColumns: Int? {
+        let leaves = self.leaves
+        return (1...leaves.count).first { columns in
+            Self.grid(of: leaves, columns: columns).map { hasSameStructure(as: $0) } ?? false
+        }
+    }
+
+    private func hasSameStructure(as other: PaneSplitNode) -> Bool {
+        switch (self, other) {
+        case let (.leaf(id, _), .leaf(otherId, _)):
+            return id == otherId
+        case let (.split(direction, first, second, _), .split(otherDirection, otherFirst, otherSecond, _)):
+            return direction == otherDirection && first.hasSameStructure(as: otherFirst) && second.hasSameStructure(as: otherSecond)
+        default:
+            return false
+        }
+    }
+
     // Helpers for accessing split properties without re-destructuring
     private var splitDirection: SplitDirection? {
         if case .split(let dir, _, _, _) = self { return dir }
@@ -239,6 +286,66 @@ class PaneGridController: NSViewController {
     /// Called when a pane's close button is clicked. Parameter: leafId.
     var onClosePane: ((String) -> Void)?
 
+    /// Called after two panes swap places via drag and drop.
+    var onPanesSwapped: (() -> Void)?
+    /// Called after the panes were re-placed for a new width.
+    var onReflowed: (() -> Void)?
+
+    /// Whether the panes are re-placed when the grid's width calls for a different
+    /// number of columns. Only layouts still in `PaneSplitNode.grid` form are touched.
+    var reflowsOnResize = false
+    /// Narrowest a pane gets before the panes move into fewer columns. The owner keeps
+    /// it in step with the Min Pane Width setting; a new value re-places the panes.
+    var minPaneWidth = CGFloat(AppSettingsManager.defaultMinPaneWidth) {
+        didSet {
+            guard reflowsOnResize, minPaneWidth != oldValue else { return }
+            reflow()
+        }
+    }
+    private var lastReflowWidth: CGFloat = 0
+    private var pendingReflow: DispatchWorkItem?
+
+    /// Leaf shown alone while a pane is maximized; nil when the full layout is visible.
+    private(set) var zoomedLeafId: String?
+    /// Panes whose terminal currently lives in its own window, keyed by leafId.
+    private var detachedPanes: [String: DetachedPaneWindowController] = [:]
+
+    /// How long a pane whose agent is gone shows "Agent ended" before it's evicted.
+    var endedOverlayDuration: TimeInterval = 3
+    /// Whether an agent (by ID) has a restart under way, so its ended pane waits for the replacement.
+    var hasPendingRestart: ((String) -> Bool)?
+    /// Entries showing the "agent ended" overlay, waiting to be evicted.
+    private var endingEntryIds: Set<String> = []
+    /// Entries held back by the visible-pane limit. No terminal exists for them until
+    /// they are swapped into a pane from the "+N more" chip.
+    private(set) var overflowEntries: [TabEntry] = []
+    private var overflowButton: NSButton?
+
+    /// Whether the grid moves to the agent with the latest output on its own. Kept per
+    /// grid and never persisted.
+    private(set) var isAutoFollowing = false
+    private var autoFollow = AutoFollow()
+    private var autoFollowTimer: Timer?
+    private var lastAutoFollowPoll: Date?
+    private var isAutoFollowPolling = false
+    /// Pauses auto-follow when the user types into one of the panes.
+    private var keyMonitor: Any?
+    private var autoFollowStack: NSStackView?
+    private var autoFollowButton: NSButton?
+    private var autoFollowPauseChip: NSTextField?
+
+    /// Rows and columns for placing `paneCount` panes within the 2 × 3 grid limit, using as
+    /// many columns as `availableWidth` fits at `minPaneWidth` each (more if two rows can't hold them).
+    /// Columns stop at three however wide the window is, since `PaneSplitNode.canSplit`
+    /// allows no more per row.
+    static func gridShape(paneCount: Int, availableWidth: CGFloat, minPaneWidth: CGFloat) -> (columns: Int, rows: Int) {
+        let count = min(max(paneCount, 1), PaneSplitNode.maxLeaves)
+        let fitting = max(1, Int(availableWidth / minPaneWidth))
+        let needed = (count + 1) / 2
+        let columns = min(3, count, max(fitting, needed))
+        return (columns, (count + columns - 1) / columns)
+    }
+
     init() {
         let initialId = "pane-0"
         self.root = .leaf(id: initialId, entry: nil)
@@ -262,10 +369,41 @@ class PaneGridController: NSViewController {
         installMouseMonitor()
     }
 
+    override func viewDidLayout() {
+        super.viewDidLayout()
+        guard reflowsOnResize, view.bounds.width != lastReflowWidth else { return }
+        lastReflowWidth = view.bounds.width
+        // Let a live resize settle rather than rebuilding the splits at every step
+        pendingReflow?.cancel()
+        let reflow = DispatchWorkItem { [weak self] in self?.reflow() }
+        pendingReflow = reflow
+        DispatchQueue.main.asyncAfter(deadline: .now() + 0.2, execute: reflow)
+    }
+
+    /// Re-place the panes in the column count `availableWidth` calls for (the grid's own
+    /// width by default). Entries, terminals, focus and a maximized pane are kept.
+    /// Returns false when nothing changed or the layout was arranged by hand.
+    @discardableResult
+    func reflow(availableWidth: CGFloat? = nil) -> Bool {
+        guard let current = root.gridColumns else { return false }
+        let width = availableWidth ?? view.bounds.width
+        let columns = Self.gridShape(paneCount: root.leafCount, availableWidth: width, minPaneWidth: minPaneWidth).columns
+        guard columns != current, let reflowed = PaneSplitNode.grid(of: root.leaves, columns: columns) else { return false }
+        root = reflowed
+        rebuild()
+        onReflowed?()
+        return true
+    }
+
     deinit {
+        pendingReflow?.cancel()
         if let monitor = mouseMonitor {
             NSEvent.removeMonitor(monitor)
         }
+        if let monitor = keyMonitor {
+            NSEvent.removeMonitor(monitor)
+        }
+        autoFollowTimer?.invalidate()
     }
 
     private func installMouseMonitor() {
@@ -283,6 +421,7 @@ class PaneGridController: NSViewController {
         for (leafId, cell) in cellViews {
             let locationInCell = cell.convert(event.locationInWindow, from: nil)
             if cell.bounds.contains(locationInCell) {
+                pauseAutoFollow()
                 if leafId != focusedLeafId {
                     setFocus(leafId)
                 }
@@ -333,11 +472,33 @@ class PaneGridController: NSViewController {
         if let cell = cellViews[focusedLeafId] {
             cell.showEntry(entry, provider: terminalViewProvider)
         }
+        updateAutoFollowControls()
+    }
+
+    /// Maximize the focused pane to fill the grid, or restore the full layout if a pane
+    /// is already maximized.
+    func toggleZoom() {
+        if zoomedLeafId != nil {
+            zoomedLeafId = nil
+        } else {
+            guard root.leafCount > 1 else { return }
+            zoomedLeafId = focusedLeafId
+        }
+        rebuild()
+        setFocus(focusedLeafId)
+    }
+
+    /// Restore the full layout before changes that rely on the split view hierarchy.
+    private func unzoomIfNeeded() {
+        guard zoomedLeafId != nil else { return }
+        zoomedLeafId = nil
+        rebuild()
     }
 
     /// Split the focused pane in the given direction.
     @discardableResult
     func splitFocusedPane(direction: SplitDirection) -> Bool {
+        unzoomIfNeeded()
         // Enforce 2-row × 3-column grid constraint
         guard root.canSplit(leafId: focusedLeafId, direction: direction) else {
             return false
@@ -396,6 +557,7 @@ class PaneGridController: NSViewController {
     func closeFocusedPane() -> Bool {
         // Can't close the last pane
         guard root.leafCount > 1 else { return false }
+        unzoomIfNeeded()
 
         let closingId = focusedLeafId
 
@@ -403,6 +565,7 @@ class PaneGridController: NSViewController {
         if let cell = cellViews[closingId] {
             cell.terminateTerminal(using: terminalTerminator)
         }
+        discardDetachedPane(closingId, using: terminalTerminator)
 
         guard let newRoot = root.removingLeaf(id: closingId) else { return false }
 
@@ -461,6 +624,7 @@ class PaneGridController: NSViewController {
             cell.updateFocusIndicator(focused: id == focusedLeafId)
         }
         updateSplitAvailability()
+        updateAutoFollowControls()
 
         return true
     }
@@ -468,11 +632,30 @@ class PaneGridController: NSViewController {
     /// Navigate focus spatially through the split tree.
     /// For a given direction, find the nearest split node matching that axis,
     /// then move to the adjacent sibling's nearest leaf.
+    /// At the edge of the grid, focus wraps around to the farthest pane on the opposite side.
     func moveFocus(_ direction: SplitDirection, forward: Bool) {
-        guard let targetId = findAdjacentLeaf(from: focusedLeafId, in: root, direction: direction, forward: forward) else {
+        unzoomIfNeeded()
+        if let targetId = findAdjacentLeaf(from: focusedLeafId, in: root, direction: direction, forward: forward) {
+            setFocus(targetId)
             return
         }
-        setFocus(targetId)
+        var wrapId = focusedLeafId
+        while let previous = findAdjacentLeaf(from: wrapId, in: root, direction: direction, forward: !forward) {
+            wrapId = previous
+        }
+        if wrapId != focusedLeafId {
+            setFocus(wrapId)
+        }
+    }
+
+    /// Focus the pane at `index` in reading order (0-based). Out-of-range indexes are ignored.
+    func focusPane(at index: Int) {
+        let leafIds = root.allLeafIds()
+        guard leafIds.indices.contains(index) else { return }
+        if let zoomed = zoomedLeafId, zoomed != leafIds[index] {
+            unzoomIfNeeded()
+        }
+        setFocus(leafIds[index])
     }
 
     /// Walk up the tree to find the nearest ancestor split matching the requested axis,
@@ -514,14 +697,16 @@ class PaneGridController: NSViewController {
         }
     }
 
-    func setFocus(_ leafId: String) {
+    func setFocus(_ leafId: String, makeFirstResponder: Bool = true) {
         guard root.findLeaf(id: leafId) != nil else { return }
         let oldFocused = focusedLeafId
         focusedLeafId = leafId
         cellViews[oldFocused]?.updateFocusIndicator(focused: false)
         cellViews[leafId]?.updateFocusIndicator(focused: true)
         // Make terminal first responder
-        cellViews[leafId]?.makeTerminalFirstResponder()
+        if makeFirstResponder {
+            cellViews[leafId]?.makeTerminalFirstResponder()
+        }
     }
 
     /// Check if any leaf has a given entry ID.
@@ -534,6 +719,135 @@ class PaneGridController: NSViewController {
         return false
     }
 
+    /// Swap the entries of two panes, moving their live terminal views along with them.
+    /// The split layout itself is unchanged.
+    func swapPanes(_ leafA: String, _ leafB: String) {
+        guard leafA != leafB,
+              detachedPanes[leafA] == nil, detachedPanes[leafB] == nil,
+              root.findLeaf(id: leafA) != nil, root.findLeaf(id: leafB) != nil,
+              let cellA = cellViews[leafA], let cellB = cellViews[leafB] else { return }
+
+        let entryA = root.entry(forLeafId: leafA)
+        let entryB = root.entry(forLeafId: leafB)
+        let viewA = cellA.detachTerminalView()
+        let viewB = cellB.detachTerminalView()
+
+        root = root.settingEntry(entryB, forLeafId: leafA).settingEntry(entryA, forLeafId: leafB)
+        place(entryB, view: viewB, in: cellA)
+        place(entryA, view: viewA, in: cellB)
+
+        setFocus(leafB)
+        onPanesSwapped?()
+    }
+
+    private func place(_ entry: TabEntry?, view termView: NSView?, in cell: PaneCellView) {
+        if let entry, let termView {
+            cell.showEntryWithView(entry, view: termView)
+        } else if let entry {
+            cell.showEntry(entry, provider: terminalViewProvider)
+        } else {
+            cell.showPlaceholder(
+                onNewAgent: { [weak self] in self?.onNewAgent?() },
+                onNewTerminal: { [weak self] in self?.onNewTerminal?() },
+                onPickFromSidebar: { [weak self] in self?.onPickFromSidebar?() }
+            )
+        }
+    }
+
+    /// Return panes showing entries that no longer exist to the placeholder, terminating
+    /// their terminals. Keeps the layout intact so the user can refill the pane.
+    @discardableResult
+    func evictEntries(notIn validIds: Set<String>) -> Bool {
+        var evicted = false
+        for leafId in root.allLeafIds() {
+            guard let entry = root.entry(forLeafId: leafId), !validIds.contains(entry.id) else { continue }
+            evict(leafId: leafId)
+            evicted = true
+        }
+        if overflowEntries.contains(where: { !validIds.contains($0.id) }) {
+            setOverflowEntries(overflowEntries.filter { validIds.contains($0.id) })
+        }
+        if evicted {
+            didEvict()
+        }
+        return evicted
+    }
+
+    /// Show "Agent ended" over panes whose agents are all in `endedAgentIds` (gone from
+    /// tmux), then evict them once `endedOverlayDuration` has passed. A pane whose agent
+    /// is being restarted stays for the replacement to take over. Returns the IDs of the
+    /// entries that started ending.
+    @discardableResult
+    func endEntries(agentIds endedAgentIds: Set<String>) -> [String] {
+        var started: [String] = []
+        for leafId in root.allLeafIds() {
+            guard let entry = root.entry(forLeafId: leafId), !endingEntryIds.contains(entry.id),
+                  Self.hasEnded(entry, endedAgentIds: endedAgentIds) else { continue }
+            endingEntryIds.insert(entry.id)
+            cellViews[leafId]?.showEndedOverlay()
+            started.append(entry.id)
+            DispatchQueue.main.asyncAfter(deadline: .now() + endedOverlayDuration) { [weak self] in
+                self?.finishEnding(entryId: entry.id)
+            }
+        }
+        let overflowIds = Set(overflowEntries.filter { Self.hasEnded($0, endedAgentIds: endedAgentIds) }.map(\.id))
+        if !overflowIds.isEmpty {
+            setOverflowEntries(overflowEntries.filter { !overflowIds.contains($0.id) })
+        }
+        return started
+    }
+
+    private static func agentIds(of entry: TabEntry) -> [String] {
+        switch entry {
+        case .manifestAgent(let agent, _): return [agent.id]
+        case .agentGroup(let agents, _, _): return agents.map(\.id)
+        case .sessionEntry: return []
+        }
+    }
+
+    /// Whether every agent behind `entry` has ended. Terminals never do.
+    static func hasEnded(_ entry: TabEntry, endedAgentIds: Set<String>) -> Bool {
+        switch entry {
+        case .manifestAgent(let agent, _): return endedAgentIds.contains(agent.id)
+        case .agentGroup(let agents, _, _): return !agents.isEmpty && agents.allSatisfy { endedAgentIds.contains($0.id) }
+        case .sessionEntry: return false
+        }
+    }
+
+    private func finishEnding(entryId: String) {
+        guard endingEntryIds.remove(entryId) != nil,
+              let leafId = root.allLeafIds().first(where: { root.entry(forLeafId: $0)?.id == entryId }),
+              let entry = root.entry(forLeafId: leafId) else { return }
+        // Left in place for `replaceEntry`; if the restart fails, the next refresh ends it again
+        if Self.agentIds(of: entry).contains(where: { hasPendingRestart?($0) == true }) {
+            cellViews[leafId]?.removeEndedOverlay()
+            return
+        }
+        evict(leafId: leafId)
+        didEvict()
+    }
+
+    private func evict(leafId: String) {
+        root = root.settingEntry(nil, forLeafId: leafId)
+        discardDetachedPane(leafId, using: terminalTerminator)
+        if let cell = cellViews[leafId] {
+            cell.terminateTerminal(using: terminalTerminator)
+            cell.showPlaceholder(
+                onNewAgent: { [weak self] in self?.onNewAgent?() },
+                onNewTerminal: { [weak self] in self?.onNewTerminal?() },
+                onPickFromSidebar: { [weak self] in self?.onPickFromSidebar?() }
+            )
+        }
+    }
+
+    private func didEvict() {
+        // A maximized pane that lost its entry shouldn't keep the rest of the grid hidden
+        if let zoomed = zoomedLeafId, root.entry(forLeafId: zoomed) == nil {
+            unzoomIfNeeded()
+        }
+        updateAutoFollowControls()
+    }
+
     /// Update the status on a visible agent terminal.
     func updateEntry(_ entry: TabEntry) {
         for leafId in root.allLeafIds() {
@@ -545,6 +859,17 @@ class PaneGridController: NSViewController {
         }
     }
 
+    /// Point the pane showing `id` at a different entry (e.g. a restarted agent's
+    /// replacement), swapping in a fresh terminal. Returns false if no pane shows `id`.
+    func replaceEntry(id: String, with entry: TabEntry) -> Bool {
+        guard let leafId = root.allLeafIds().first(where: { root.entry(forLeafId: $0)?.id == id }) else { return false }
+        discardDetachedPane(leafId, using: terminalTerminator)
+        cellViews[leafId]?.terminateTerminal(using: terminalTerminator)
+        root = root.settingEntry(entry, forLeafId: leafId)
+        cellViews[leafId]?.showEntry(entry, provider: terminalViewProvider)
+        return true
+    }
+
     /// Get the entry currently in the focused pane.
     var focusedEntry: TabEntry? {
         root.entry(forLeafId: focusedLeafId)
@@ -555,6 +880,301 @@ class PaneGridController: NSViewController {
         for id in root.allLeafIds() where id != leafId {
             cellViews[id]?.terminateTerminal(using: terminator)
         }
+        // Detached windows can't outlive the grid, including the kept pane's
+        for id in Array(detachedPanes.keys) {
+            discardDetachedPane(id, using: terminator)
+        }
+    }
+
+    // MARK: - Detached Panes
+
+    /// Move a pane's terminal into its own window, leaving a "Reattach" tile in the grid.
+    func detachPane(_ leafId: String) {
+        guard detachedPanes[leafId] == nil,
+              let entry = root.entry(forLeafId: leafId),
+              let cell = cellViews[leafId],
+              let termView = cell.detachTerminalView() else { return }
+
+        let controller = DetachedPaneWindowController(leafId: leafId, title: entry.label, terminalView: termView)
+        // Closing the window hands the terminal back to the grid
+        controller.onClose = { [weak self] controller in
+            self?.reattachPane(controller.leafId)
+        }
+        detachedPanes[leafId] = controller
+        cell.showDetached(onReattach: { [weak self] in self?.reattachPane(leafId) })
+        controller.showWindow(nil)
+    }
+
+    /// Return a detached pane's terminal to its grid cell and close its window.
+    func reattachPane(_ leafId: String) {
+        guard let controller = detachedPanes.removeValue(forKey: leafId) else { return }
+        let termView = controller.takeTerminalView()
+        guard let cell = cellViews[leafId], let entry = root.entry(forLeafId: leafId) else {
+            if let termView { terminalTerminator?(termView) }
+            return
+        }
+        if let termView {
+            cell.showEntryWithView(entry, view: termView)
+        } else {
+            cell.showEntry(entry, provider: terminalViewProvider)
+        }
+        setFocus(leafId)
+    }
+
+    func isDetached(_ leafId: String) -> Bool {
+        detachedPanes[leafId] != nil
+    }
+
+    /// Take a pane's terminal view out of the grid without terminating it, closing
+    /// its window first if it is detached. Nil for a pane with no terminal.
+    func takeTerminalView(leafId: String) -> NSView? {
+        if let controller = detachedPanes.removeValue(forKey: leafId) {
+            return controller.takeTerminalView()
+        }
+        return cellViews[leafId]?.detachTerminalView()
+    }
+
+    /// Close a detached pane's window and terminate its terminal; the pane itself is going away.
+    private func discardDetachedPane(_ leafId: String, using terminator: ((NSView) -> Void)?) {
+        guard let controller = detachedPanes.removeValue(forKey: leafId) else { return }
+        if let termView = controller.takeTerminalView() {
+            terminator?(termView)
+        }
+    }
+
+    // MARK: - Overflow
+
+    /// The layout to persist: the split tree plus the entries in the overflow.
+    func layoutNode() -> GridLayoutNode {
+        var node = root.toLayoutNode()
+        if !overflowEntries.isEmpty {
+            node.overflowEntryIds = overflowEntries.map(\.id)
+        }
+        return node
+    }
+
+    func setOverflowEntries(_ entries: [TabEntry]) {
+        overflowEntries = entries
+        updateOverflowButton()
+        updateAutoFollowControls()
+    }
+
+    private func updateOverflowButton() {
+        guard !overflowEntries.isEmpty else {
+            overflowButton?.removeFromSuperview()
+            overflowButton = nil
+            return
+        }
+
+        let button = overflowButton ?? NSButton(title: "", target: self, action: #selector(overflowClicked(_:)))
+        button.title = "+\(overflowEntries.count) more"
+        button.bezelStyle = .rounded
+        button.controlSize = .small
+        button.toolTip = "Swap a hidden agent into the focused pane"
+        if button.superview !== view {
+            button.translatesAutoresizingMaskIntoConstraints = false
+            view.addSubview(button)
+            NSLayoutConstraint.activate([
+                button.trailingAnchor.constraint(equalTo: view.trailingAnchor, constant: -12),
+                button.bottomAnchor.constraint(equalTo: view.bottomAnchor, constant: -12),
+            ])
+        }
+        overflowButton = button
+    }
+
+    @objc private func overflowClicked(_ sender: NSButton) {
+        let menu = NSMenu()
+        for (index, entry) in overflowEntries.enumerated() {
+            let item = NSMenuItem(title: entry.label, action: #selector(overflowEntryChosen(_:)), keyEquivalent: "")
+            item.target = self
+            item.tag = index
+            menu.addItem(item)
+        }
+        menu.popUp(positioning: nil, at: NSPoint(x: 0, y: sender.bounds.height + 4), in: sender)
+    }
+
+    @objc private func overflowEntryChosen(_ item: NSMenuItem) {
+        showOverflowEntry(at: item.tag)
+    }
+
+    /// Show a hidden entry in the focused pane; whatever it displaced joins the overflow.
+    func showOverflowEntry(at index: Int) {
+        guard overflowEntries.indices.contains(index) else { return }
+        let entry = overflowEntries.remove(at: index)
+        let leafId = focusedLeafId
+
+        discardDetachedPane(leafId, using: terminalTerminator)
+        if let displaced = root.entry(forLeafId: leafId) {
+            overflowEntries.append(displaced)
+            cellViews[leafId]?.terminateTerminal(using: terminalTerminator)
+        }
+        root = root.settingEntry(entry, forLeafId: leafId)
+        cellViews[leafId]?.showEntry(entry, provider: terminalViewProvider)
+        updateOverflowButton()
+        onPanesSwapped?()
+    }
+
+    // MARK: - Auto-Follow
+
+    /// Turn auto-follow on or off. While on, the grid polls tmux for output times and
+    /// moves to the agent that printed most recently, pulling it in from the overflow
+    /// if needed; a maximized grid maximizes that agent's pane instead.
+    func setAutoFollow(_ enabled: Bool) {
+        guard enabled != isAutoFollowing else { return }
+        isAutoFollowing = enabled
+        autoFollowTimer?.invalidate()
+        autoFollowTimer = nil
+        if let monitor = keyMonitor {
+            NSEvent.removeMonitor(monitor)
+            keyMonitor = nil
+        }
+        if enabled {
+            autoFollow = AutoFollow()
+            lastAutoFollowPoll = nil
+            // Every second so the pause countdown ticks; polls are spaced out in autoFollowTick
+            autoFollowTimer = Timer.scheduledTimer(withTimeInterval: 1, repeats: true) { [weak self] _ in
+                self?.autoFollowTick()
+            }
+            keyMonitor = NSEvent.addLocalMonitorForEvents(matching: .keyDown) { [weak self] event in
+                self?.handleKeyDown(event)
+                return event
+            }
+            autoFollowTick()
+        }
+        updateAutoFollowControls()
+    }
+
+    @objc private func autoFollowToggled(_ sender: NSButton) {
+        setAutoFollow(sender.state == .on)
+    }
+
+    private func handleKeyDown(_ event: NSEvent) {
+        guard let window = view.window, event.window === window,
+              let responder = window.firstResponder as? NSView, responder.isDescendant(of: view) else { return }
+        pauseAutoFollow()
+    }
+
+    /// Hold auto-follow back after the user clicked or typed in a pane.
+    private func pauseAutoFollow() {
+        guard isAutoFollowing else { return }
+        autoFollow.pause(at: Date())
+        updateAutoFollowControls()
+    }
+
+    /// Agents an entry shows; terminals have none and are never followed.
+    private static func agents(in entry: TabEntry) -> [AgentModel] {
+        switch entry {
+        case .manifestAgent(let agent, _): return [agent]
+        case .agentGroup(let agents, _, _): return agents
+        case .sessionEntry: return []
+        }
+    }
+
+    /// Entries auto-follow can move to: agents in attached panes and in the overflow.
+    private var followableEntries: [TabEntry] {
+        let inPanes = root.allLeafIds()
+            .filter { detachedPanes[$0] == nil }
+            .compactMap { root.entry(forLeafId: $0) }
+        return (inPanes + overflowEntries).filter { !Self.agents(in: $0).isEmpty }
+    }
+
+    private func autoFollowTick() {
+        let now = Date()
+        updateAutoFollowControls(now: now)
+        // Suspended grids (another sidebar item is showing) keep their setting but don't poll
+        guard view.window != nil, !view.isHiddenOrHasHiddenAncestor,
+              autoFollow.pauseRemaining(at: now) == nil, !isAutoFollowPolling else { return }
+        if let lastAutoFollowPoll, now.timeIntervalSince(lastAutoFollowPoll) < AutoFollow.switchInterval { return }
+        let agents = followableEntries.flatMap(Self.agents(in:))
+        guard !agents.isEmpty else { return }
+        lastAutoFollowPoll = now
+        isAutoFollowPolling = true
+        DispatchQueue.global(qos: .utility).async { [weak self] in
+            let activity = PPGService.shared.tmuxWindowActivity()
+            DispatchQueue.main.async {
+                guard let self else { return }
+                self.isAutoFollowPolling = false
+                guard self.isAutoFollowing, let activity else { return }
+                AgentActivity.shared.recordOutput(activity, agents: agents)
+                self.followLatestOutput()
+            }
+        }
+    }
+
+    private func followLatestOutput() {
+        var lastOutput: [String: Date] = [:]
+        for entry in followableEntries {
+            if let latest = Self.agents(in: entry).compactMap({ AgentActivity.shared.lastOutput(agentId: $0.id) }).max() {
+                lastOutput[entry.id] = latest
+            }
+        }
+        guard let targetId = autoFollow.nextEntry(lastOutput: lastOutput, current: focusedEntry?.id, now: Date()) else { return }
+
+        // Only take keyboard focus if the grid already had it; following shouldn't pull
+        // the cursor out of the sidebar or a text field
+        let hadFocus = (view.window?.firstResponder as? NSView)?.isDescendant(of: view) ?? false
+        if let leafId = root.allLeafIds().first(where: { root.entry(forLeafId: $0)?.id == targetId }) {
+            if zoomedLeafId != nil, zoomedLeafId != leafId {
+                zoomedLeafId = leafId
+                rebuild()
+            }
+            setFocus(leafId, makeFirstResponder: hadFocus)
+        } else if let index = overflowEntries.firstIndex(where: { $0.id == targetId }) {
+            showOverflowEntry(at: index)
+            setFocus(focusedLeafId, makeFirstResponder: hadFocus)
+        }
+    }
+
+    /// Show the toggle once there are two agents to follow between (or while it's on),
+    /// with a countdown chip while a pause holds it back.
+    private func updateAutoFollowControls(now: Date = Date()) {
+        guard isAutoFollowing || followableEntries.count > 1 else {
+            autoFollowStack?.removeFromSuperview()
+            autoFollowStack = nil
+            autoFollowButton = nil
+            autoFollowPauseChip = nil
+            return
+        }
+
+        if autoFollowStack == nil {
+            let button = NSButton(title: "Auto-follow", target: self, action: #selector(autoFollowToggled(_:)))
+            button.setButtonType(.pushOnPushOff)
+            button.bezelStyle = .rounded
+            button.controlSize = .small
+            button.toolTip = "Follow the agent with the newest output. Clicking or typing in a pane pauses it for a minute."
+
+            let chip = NSTextField(labelWithString: "")
+            chip.font = .monospacedDigitSystemFont(ofSize: 11, weight: .medium)
+            chip.textColor = .secondaryLabelColor
+            chip.wantsLayer = true
+            chip.layer?.cornerRadius = 4
+            chip.layer?.backgroundColor = NSColor.quaternaryLabelColor.cgColor
+
+            let stack = NSStackView(views: [button, chip])
+            stack.orientation = .horizontal
+            stack.spacing = 6
+            autoFollowStack = stack
+            autoFollowButton = button
+            autoFollowPauseChip = chip
+        }
+        guard let stack = autoFollowStack else { return }
+
+        autoFollowButton?.state = isAutoFollowing ? .on : .off
+        if isAutoFollowing, let remaining = autoFollow.pauseRemaining(at: now) {
+            autoFollowPauseChip?.stringValue = " Paused \(remaining)s "
+            autoFollowPauseChip?.isHidden = false
+        } else {
+            autoFollowPauseChip?.isHidden = true
+        }
+
+        if stack.superview !== view {
+            stack.translatesAutoresizingMaskIntoConstraints = false
+            view.addSubview(stack)
+            NSLayoutConstraint.activate([
+                stack.leadingAnchor.constraint(equalTo: view.leadingAnchor, constant: 12),
+                stack.bottomAnchor.constraint(equalTo: view.bottomAnchor, constant: -12),
+            ])
+        }
     }
 
     // MARK: - Rebuild UI
@@ -578,7 +1198,16 @@ class PaneGridController: NSViewController {
             cellViews.removeValue(forKey: id)
         }
 
-        let builtView = buildView(for: root, path: "root")
+        // While maximized, only the zoomed leaf's cell is placed in the view
+        if let zoomed = zoomedLeafId, root.findLeaf(id: zoomed) == nil {
+            zoomedLeafId = nil
+        }
+        let builtView: NSView
+        if let zoomed = zoomedLeafId, let leaf = root.findLeaf(id: zoomed) {
+            builtView = buildView(for: leaf, path: "root")
+        } else {
+            builtView = buildView(for: root, path: "root")
+        }
         builtView.translatesAutoresizingMaskIntoConstraints = false
         view.addSubview(builtView)
         NSLayoutConstraint.activate([
@@ -593,6 +1222,8 @@ class PaneGridController: NSViewController {
             cell.updateFocusIndicator(focused: id == focusedLeafId)
         }
         updateSplitAvailability()
+        updateOverflowButton()
+        updateAutoFollowControls()
     }
 
     /// Update canSplitH/canSplitV on all leaf cell views based on current tree shape.
@@ -600,6 +1231,7 @@ class PaneGridController: NSViewController {
         for (leafId, cell) in cellViews {
             cell.canSplitH = root.canSplit(leafId: leafId, direction: .horizontal)
             cell.canSplitV = root.canSplit(leafId: leafId, direction: .vertical)
+            cell.isZoomed = leafId == zoomedLeafId
         }
     }
 
@@ -607,7 +1239,9 @@ class PaneGridController: NSViewController {
         switch node {
         case .leaf(let id, let entry):
             let cell = cellView(for: id)
-            if let entry = entry {
+            if detachedPanes[id] != nil {
+                // Terminal lives in its own window; keep the "Reattach" tile
+            } else if let entry = entry {
                 cell.showEntry(entry, provider: terminalViewProvider)
             } else {
                 cell.showPlaceholder(
@@ -628,6 +1262,16 @@ class PaneGridController: NSViewController {
             cell.onClose = { [weak self] in
                 self?.onClosePane?(id)
             }
+            cell.onToggleZoom = { [weak self] in
+                self?.setFocus(id)
+                self?.toggleZoom()
+            }
+            cell.onDropPane = { [weak self] sourceLeafId in
+                self?.swapPanes(sourceLeafId, id)
+            }
+            cell.onDetach = { [weak self] in
+                self?.detachPane(id)
+            }
             return cell
 
         case .split(let direction, let first, let second, let ratio):
@@ -709,10 +1353,18 @@ class PaneCellView: NSView {
     var onSplitHorizontal: (() -> Void)?
     var onSplitVertical: (() -> Void)?
     var onClose: (() -> Void)?
+    var onToggleZoom: (() -> Void)?
+    /// Called when another pane's drag handle is dropped here. Parameter: source leafId.
+    var onDropPane: ((String) -> Void)?
+    var onDetach: (() -> Void)?
+    private var detachedView: NSView?
+    private var onReattach: (() -> Void)?
 
     /// Whether this pane can be split in each direction (updated by PaneGridController).
     var canSplitH: Bool = true
     var canSplitV: Bool = true
+    /// Whether this pane is currently maximized over the rest of the grid.
+    var isZoomed: Bool = false
 
     private let focusBarLayer = CALayer()
     private static let focusBarHeight: CGFloat = 2
@@ -721,6 +1373,7 @@ class PaneCellView: NSView {
     private var hoverOverlay: PaneHoverOverlay?
     private var trackingArea: NSTrackingArea?
     private var fadeOutWork: DispatchWorkItem?
+    private var endedOverlay: NSView?
 
     init(leafId: String) {
         self.leafId = leafId
@@ -734,6 +1387,8 @@ class PaneCellView: NSView {
         focusBarLayer.isHidden = true
         focusBarLayer.zPosition = 1000
         layer?.addSublayer(focusBarLayer)
+
+        registerForDraggedTypes([.ppgPaneLeafId])
     }
 
     required init?(coder: NSCoder) {
@@ -779,13 +1434,22 @@ class PaneCellView: NSView {
             overlay.onSplitHorizontal = { [weak self] in self?.onSplitHorizontal?() }
             overlay.onSplitVertical = { [weak self] in self?.onSplitVertical?() }
             overlay.onClose = { [weak self] in self?.onClose?() }
+            overlay.onToggleZoom = { [weak self] in self?.onToggleZoom?() }
+            overlay.onDetach = { [weak self] in self?.onDetach?() }
+            overlay.onShowInfo = { [weak self] anchor in
+                (self?.currentTerminalView as? TerminalPane)?.showInfoPopover(relativeTo: anchor)
+            }
+            overlay.dragLeafId = leafId
             addSubview(overlay)
             overlay.updatePosition(in: bounds)
             hoverOverlay = overlay
         }
         // Ensure overlay is above terminal views that may have been added after it
         hoverOverlay!.superview?.addSubview(hoverOverlay!, positioned: .above, relativeTo: nil)
-        hoverOverlay?.updateSplitAvailability(canSplitH: canSplitH, canSplitV: canSplitV)
+        hoverOverlay?.updateSplitAvailability(canSplitH: canSplitH && !isZoomed, canSplitV: canSplitV && !isZoomed)
+        hoverOverlay?.updateZoomState(isZoomed)
+        hoverOverlay?.updateInfo(for: currentTerminalView as? TerminalPane)
+        hoverOverlay?.updatePosition(in: bounds)
         hoverOverlay?.animator().alphaValue = 1
     }
 
@@ -812,6 +1476,39 @@ class PaneCellView: NSView {
         focusBarLayer.backgroundColor = Self.focusBarColor.resolvedCGColor(for: effectiveAppearance)
     }
 
+    // MARK: Drop Target
+
+    private func draggedLeafId(_ sender: NSDraggingInfo) -> String? {
+        guard let id = sender.draggingPasteboard.string(forType: .ppgPaneLeafId), id != leafId else { return nil }
+        return id
+    }
+
+    private func setDropHighlight(_ highlighted: Bool) {
+        layer?.borderWidth = highlighted ? 2 : 0
+        layer?.borderColor = highlighted ? NSColor.controlAccentColor.resolvedCGColor(for: effectiveAppearance) : nil
+    }
+
+    override func draggingEntered(_ sender: NSDraggingInfo) -> NSDragOperation {
+        guard draggedLeafId(sender) != nil else { return [] }
+        setDropHighlight(true)
+        return .move
+    }
+
+    override func draggingExited(_ sender: NSDraggingInfo?) {
+        setDropHighlight(false)
+    }
+
+    override func performDragOperation(_ sender: NSDraggingInfo) -> Bool {
+        setDropHighlight(false)
+        guard let sourceId = draggedLeafId(sender) else { return false }
+        onDropPane?(sourceId)
+        return true
+    }
+
+    override func concludeDragOperation(_ sender: NSDraggingInfo?) {
+        setDropHighlight(false)
+    }
+
     func updateFocusIndicator(focused: Bool) {
         if focused {
             focusBarLayer.isHidden = false
@@ -847,6 +1544,8 @@ class PaneCellView: NSView {
         // Remove placeholder if present
         placeholderView?.removeFromSuperview()
         placeholderView = nil
+        removeDetachedView()
+        removeEndedOverlay()
 
         // If already showing this entry, no-op
         if entryId == entry.id { return }
@@ -865,6 +1564,8 @@ class PaneCellView: NSView {
     func showEntryWithView(_ entry: TabEntry, view termView: NSView) {
         placeholderView?.removeFromSuperview()
         placeholderView = nil
+        removeDetachedView()
+        removeEndedOverlay()
 
         if entryId == entry.id { return }
 
@@ -903,6 +1604,8 @@ class PaneCellView: NSView {
         currentTerminalView?.removeFromSuperview()
         currentTerminalView = nil
         entryId = nil
+        removeDetachedView()
+        removeEndedOverlay()
 
         if placeholderView == nil {
             placeholderView = PanePlaceholderView()
@@ -926,13 +1629,86 @@ class PaneCellView: NSView {
         }
     }
 
+    /// Tile shown while this pane's terminal lives in its own window.
+    func showDetached(onReattach: @escaping () -> Void) {
+        removeDetachedView()
+        self.onReattach = onReattach
+
+        let label = NSTextField(labelWithString: "Open in a separate window")
+        label.font = .systemFont(ofSize: 13)
+        label.textColor = .secondaryLabelColor
+
+        let button = NSButton(title: "Reattach", target: self, action: #selector(reattachClicked))
+        button.bezelStyle = .rounded
+
+        let stack = NSStackView(views: [label, button])
+        stack.orientation = .vertical
+        stack.spacing = 8
+        stack.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(stack)
+        NSLayoutConstraint.activate([
+            stack.centerXAnchor.constraint(equalTo: centerXAnchor),
+            stack.centerYAnchor.constraint(equalTo: centerYAnchor),
+        ])
+        detachedView = stack
+    }
+
+    private func removeDetachedView() {
+        detachedView?.removeFromSuperview()
+        detachedView = nil
+        onReattach = nil
+    }
+
+    @objc private func reattachClicked() {
+        onReattach?()
+    }
+
+    var isShowingEndedOverlay: Bool { endedOverlay != nil }
+
+    /// Whether the hosted agent pane has its exit summary up.
+    var isShowingExitBanner: Bool { (currentTerminalView as? TerminalPane)?.isShowingExitBanner == true }
+
+    /// Dim the pane under "Agent ended" until it's evicted or refilled.
+    func showEndedOverlay() {
+        guard endedOverlay == nil else { return }
+        let overlay = NSView()
+        overlay.wantsLayer = true
+        overlay.layer?.backgroundColor = NSColor.windowBackgroundColor.withAlphaComponent(0.85).cgColor
+        overlay.translatesAutoresizingMaskIntoConstraints = false
+
+        let label = NSTextField(labelWithString: "Agent ended")
+        label.font = .systemFont(ofSize: 13, weight: .medium)
+        label.textColor = .secondaryLabelColor
+        label.translatesAutoresizingMaskIntoConstraints = false
+        overlay.addSubview(label)
+
+        addSubview(overlay)
+        NSLayoutConstraint.activate([
+            overlay.topAnchor.constraint(equalTo: topAnchor),
+            overlay.leadingAnchor.constraint(equalTo: leadingAnchor),
+            overlay.trailingAnchor.constraint(equalTo: trailingAnchor),
+            overlay.bottomAnchor.constraint(equalTo: bottomAnchor),
+            label.centerXAnchor.constraint(equalTo: overlay.centerXAnchor),
+            label.centerYAnchor.constraint(equalTo: overlay.centerYAnchor),
+        ])
+        endedOverlay = overlay
+    }
+
+    func removeEndedOverlay() {
+        endedOverlay?.removeFromSuperview()
+        endedOverlay = nil
+    }
+
     func updateStatus(_ entry: TabEntry) {
         guard let pane = currentTerminalView as? TerminalPane else { return }
         switch entry {
         case .manifestAgent(let agent, _):
-            pane.updateStatus(agent.status)
+            pane.updateStatus(agent.status, rawStatus: agent.rawStatus, exitCode: agent.exitCode, completedAt: agent.completedDate,
+                              stalledSince: AgentActivity.shared.stalledSince(agent))
         case .agentGroup(let agents, _, _):
-            pane.updateStatus(agents.first?.status ?? .lost)
+            let lead = agents.first
+            pane.updateStatus(lead?.status ?? .lost, rawStatus: lead?.rawStatus, exitCode: lead?.exitCode, completedAt: lead?.completedDate,
+                              stalledSince: lead.flatMap { AgentActivity.shared.stalledSince($0) })
         case .sessionEntry:
             break
         }
@@ -962,6 +1738,18 @@ class PaneHoverOverlay: NSView {
     var onSplitHorizontal: (() -> Void)?
     var onSplitVertical: (() -> Void)?
     var onClose: (() -> Void)?
+    var onToggleZoom: (() -> Void)?
+    var onDetach: (() -> Void)?
+    /// Called with the info button as the popover anchor.
+    var onShowInfo: ((NSView) -> Void)?
+    /// Leaf carried by the drag handle; nil hides the grid-only controls (single-pane overlay).
+    var dragLeafId: String? {
+        didSet {
+            dragHandle.leafId = dragLeafId
+            dragHandle.isHidden = dragLeafId == nil
+            detachButton.isHidden = dragLeafId == nil
+        }
+    }
 
     private static let buttonSize: CGFloat = 24
     private static let padding: CGFloat = 6
@@ -970,6 +1758,10 @@ class PaneHoverOverlay: NSView {
     private var splitHButton: NSButton!
     private var splitVButton: NSButton!
     private var closeButton: NSButton!
+    private var zoomButton: NSButton!
+    private var detachButton: NSButton!
+    private var infoButton: NSButton!
+    private let dragHandle = PaneDragHandle()
 
     override init(frame frameRect: NSRect) {
         super.init(frame: frameRect)
@@ -1002,8 +1794,35 @@ class PaneHoverOverlay: NSView {
             tooltip: "Close Pane",
             action: #selector(closeClicked)
         )
+        zoomButton = makeButton(
+            icon: "arrow.up.left.and.arrow.down.right",
+            tooltip: "Maximize Pane",
+            action: #selector(zoomClicked)
+        )
+        // Only grid panes can be maximized; they reveal the button via updateZoomState.
+        zoomButton.isHidden = true
+        detachButton = makeButton(
+            icon: "macwindow.on.rectangle",
+            tooltip: "Detach to Window",
+            action: #selector(detachClicked)
+        )
+        detachButton.isHidden = true
+        infoButton = makeButton(
+            icon: "info.circle",
+            tooltip: "Agent Info",
+            action: #selector(infoClicked)
+        )
+        // Only agent terminals have info to show; revealed via updateInfo.
+        infoButton.isHidden = true
 
-        let stack = NSStackView(views: [splitVButton, splitHButton, closeButton])
+        dragHandle.isHidden = true
+        dragHandle.translatesAutoresizingMaskIntoConstraints = false
+        NSLayoutConstraint.activate([
+            dragHandle.widthAnchor.constraint(equalToConstant: Self.buttonSize),
+            dragHandle.heightAnchor.constraint(equalToConstant: Self.buttonSize),
+        ])
+
+        let stack = NSStackView(views: [dragHandle, infoButton, detachButton, zoomButton, splitVButton, splitHButton, closeButton])
         stack.orientation = .horizontal
         stack.spacing = Self.spacing
         stack.translatesAutoresizingMaskIntoConstraints = false
@@ -1024,6 +1843,21 @@ class PaneHoverOverlay: NSView {
         splitVButton.isHidden = !canSplitV
     }
 
+    /// Show the info button for an agent pane, with the prompt's first line as its tooltip
+    /// so same-named agents can be told apart at a glance.
+    func updateInfo(for pane: TerminalPane?) {
+        infoButton.isHidden = pane == nil
+        infoButton.toolTip = pane?.promptSummary ?? "Agent Info"
+    }
+
+    func updateZoomState(_ zoomed: Bool) {
+        let tooltip = zoomed ? "Restore Pane" : "Maximize Pane"
+        let icon = zoomed ? "arrow.down.right.and.arrow.up.left" : "arrow.up.left.and.arrow.down.right"
+        zoomButton.image = NSImage(systemSymbolName: icon, accessibilityDescription: tooltip)
+        zoomButton.toolTip = tooltip
+        zoomButton.isHidden = false
+    }
+
     private func makeButton(icon: String, tooltip: String, action: Selector) -> NSButton {
         let btn = NSButton()
         btn.bezelStyle = .accessoryBarAction
@@ -1046,7 +1880,7 @@ class PaneHoverOverlay: NSView {
     func updatePosition(in parentBounds: CGRect) {
         guard superview != nil else { return }
         let visibleCount = CGFloat(
-            [splitHButton, splitVButton, closeButton].filter({ !$0.isHidden }).count
+            ([dragHandle, infoButton, detachButton, zoomButton, splitHButton, splitVButton, closeButton] as [NSView]).filter({ !$0.isHidden }).count
         )
         let gaps = max(visibleCount - 1, 0)
         let overlayWidth = Self.padding * 2 + Self.buttonSize * visibleCount + Self.spacing * gaps
@@ -1059,9 +1893,12 @@ class PaneHoverOverlay: NSView {
         )
     }
 
-    // Prevent mouse events on the overlay from falling through to the terminal
+    // Prevent mouse events on the overlay from falling through to the terminal. A double
+    // click on the header (not the terminal, where it selects a word) toggles the zoom.
     override func mouseDown(with event: NSEvent) {
-        // no-op — absorb click
+        if event.clickCount == 2 {
+            onToggleZoom?()
+        }
     }
 
     override func viewDidChangeEffectiveAppearance() {
@@ -1073,6 +1910,132 @@ class PaneHoverOverlay: NSView {
     @objc private func splitHClicked() { onSplitHorizontal?() }
     @objc private func splitVClicked() { onSplitVertical?() }
     @objc private func closeClicked() { onClose?() }
+    @objc private func zoomClicked() { onToggleZoom?() }
+    @objc private func detachClicked() { onDetach?() }
+    @objc private func infoClicked() { onShowInfo?(infoButton) }
+}
+
+// MARK: - Detached Pane Window
+
+/// Hosts a grid pane's terminal in its own window (e.g. on a second display).
+final class DetachedPaneWindowController: NSWindowController, NSWindowDelegate {
+    let leafId: String
+    /// Called when the user closes the window; the grid reattaches the terminal.
+    var onClose: ((DetachedPaneWindowController) -> Void)?
+    private var terminalView: NSView?
+    private var isClosing = false
+
+    init(leafId: String, title: String, terminalView: NSView) {
+        self.leafId = leafId
+        self.terminalView = terminalView
+
+        let window = NSWindow(
+            contentRect: NSRect(x: 0, y: 0, width: 900, height: 560),
+            styleMask: [.titled, .closable, .miniaturizable, .resizable],
+            backing: .buffered,
+            defer: false
+        )
+        window.title = title
+        window.isReleasedWhenClosed = false
+
+        let container = NSView()
+        container.wantsLayer = true
+        container.layer?.backgroundColor = Theme.contentBackground.resolvedCGColor(for: container.effectiveAppearance)
+        window.contentView = container
+
+        terminalView.removeFromSuperview()
+        terminalView.isHidden = false
+        terminalView.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(terminalView)
+        NSLayoutConstraint.activate([
+            terminalView.topAnchor.constraint(equalTo: container.topAnchor),
+            terminalView.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+            terminalView.trailingAnchor.constraint(equalTo: container.trailingAnchor),
+            terminalView.bottomAnchor.constraint(equalTo: container.bottomAnchor),
+        ])
+
+        super.init(window: window)
+        window.delegate = self
+        window.center()
+    }
+
+    required init?(coder: NSCoder) {
+        fatalError("init(coder:) not supported")
+    }
+
+    /// Remove the terminal view and close the window without firing `onClose`.
+    func takeTerminalView() -> NSView? {
+        let view = terminalView
+        terminalView = nil
+        view?.removeFromSuperview()
+        onClose = nil
+        if !isClosing {
+            window?.close()
+        }
+        return view
+    }
+
+    func windowWillClose(_ notification: Notification) {
+        isClosing = true
+        onClose?(self)
+    }
+}
+
+// MARK: - Pane Drag Handle
+
+extension NSPasteboard.PasteboardType {
+    /// Leaf ID of a grid pane being dragged to swap positions.
+    static let ppgPaneLeafId = NSPasteboard.PasteboardType("com.2witstudios.ppg.pane-leaf-id")
+}
+
+/// Grip in the hover overlay that starts a pane drag; dropping on another pane swaps them.
+class PaneDragHandle: NSView, NSDraggingSource {
+    var leafId: String?
+
+    private let imageView = NSImageView()
+
+    override init(frame frameRect: NSRect) {
+        super.init(frame: frameRect)
+        imageView.image = NSImage(systemSymbolName: "line.3.horizontal", accessibilityDescription: "Drag to Rearrange")
+        imageView.contentTintColor = Theme.paneOverlayButtonTint
+        imageView.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(imageView)
+        NSLayoutConstraint.activate([
+            imageView.centerXAnchor.constraint(equalTo: centerXAnchor),
+            imageView.centerYAnchor.constraint(equalTo: centerYAnchor),
+        ])
+        toolTip = "Drag onto another pane to swap"
+    }
+
+    required init?(coder: NSCoder) {
+        fatalError("init(coder:) not supported")
+    }
+
+    override func resetCursorRects() {
+        addCursorRect(bounds, cursor: .openHand)
+    }
+
+    override func mouseDragged(with event: NSEvent) {
+        guard let leafId else { return }
+        let item = NSPasteboardItem()
+        item.setString(leafId, forType: .ppgPaneLeafId)
+        let draggingItem = NSDraggingItem(pasteboardWriter: item)
+        let snapshot = bitmapImageRepForCachingDisplay(in: bounds).map { rep -> NSImage in
+            cacheDisplay(in: bounds, to: rep)
+            let image = NSImage(size: bounds.size)
+            image.addRepresentation(rep)
+            return image
+        }
+        draggingItem.setDraggingFrame(bounds, contents: snapshot)
+        beginDraggingSession(with: [draggingItem], event: event, source: self)
+    }
+
+    // Absorb clicks so they don't fall through to the terminal underneath
+    override func mouseDown(with event: NSEvent) {}
+
+    func draggingSession(_ session: NSDraggingSession, sourceOperationMaskFor context: NSDraggingContext) -> NSDragOperation {
+        context == .withinApplication ? .move : []
+    }
 }
 
 // MARK: - Pane Placeholder View
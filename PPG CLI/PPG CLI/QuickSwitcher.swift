@@ -0,0 +1,163 @@
+import Foundation
+
+// MARK: - FuzzyMatcher
+
+/// Subsequence matching for the quick switcher: every query character must appear in
+/// order. Matches at the start of the text or of a word, and runs of consecutive
+/// characters, score higher, so "fl" ranks "fix-login" above "buffalo".
+nonisolated enum FuzzyMatcher {
+    /// Score for `query` against `text` (case-insensitive), or nil if it doesn't match.
+    /// An empty query matches everything with 0.
+    static func score(_ query: String, in text: String) -> Int? {
+        let needle = Array(query.lowercased().filter { !$0.isWhitespace })
+        guard !needle.isEmpty else { return 0 }
+        let haystack = Array(text.lowercased())
+
+        var score = 0
+        var position = 0
+        var previousMatch: Int?
+        for character in needle {
+            guard let index = haystack[position...].firstIndex(of: character) else { return nil }
+            score += 1
+            if index == 0 {
+                score += 8
+            } else if isWordBoundary(haystack[index - 1]) {
+                score += 6
+            }
+            if let previousMatch, index == previousMatch + 1 {
+                score += 4
+            }
+            previousMatch = index
+            position = index + 1
+        }
+        // Prefer tighter, shorter matches among otherwise equal candidates.
+        return score * 100 - haystack.count
+    }
+
+    private static func isWordBoundary(_ character: Character) -> Bool {
+        character == " " || character == "-" || character == "_" || character == "/" || character == "."
+    }
+}
+
+// MARK: - QuickSwitcherItem
+
+/// A worktree or agent the quick switcher can jump to.
+nonisolated struct QuickSwitcherItem: Equatable {
+    enum Kind: Equatable {
+        case worktree
+        case agent(AgentStatus)
+    }
+
+    /// Sidebar item ID to select (`SidebarItem.id`).
+    let id: String
+    let kind: Kind
+    let title: String
+    /// Where the item lives: branch for worktrees, worktree (and type) for agents.
+    let subtitle: String
+    /// Everything a query can match: name, branch, agent type, status, project.
+    let searchText: String
+
+    /// Every worktree and agent in `projectWorktrees` (keyed by project root), in
+    /// sidebar order. Project names are included in the context only when more than
+    /// one project is open.
+    static func items(projectWorktrees: [(root: String, worktrees: [WorktreeModel])]) -> [QuickSwitcherItem] {
+        let showProject = projectWorktrees.count > 1
+        var items: [QuickSwitcherItem] = []
+        for (root, worktrees) in projectWorktrees {
+            let project = URL(fileURLWithPath: root).lastPathComponent
+            let projectPrefix = showProject ? "\(project) · " : ""
+            for worktree in worktrees {
+                items.append(QuickSwitcherItem(
+                    id: worktree.id,
+                    kind: .worktree,
+                    title: worktree.name,
+                    subtitle: projectPrefix + worktree.branch,
+                    searchText: [worktree.name, worktree.branch, worktree.status, project].joined(separator: " ")
+                ))
+                for agent in worktree.agents {
+                    let name = agent.name.isEmpty ? agent.id : agent.name
+                    items.append(QuickSwitcherItem(
+                        id: agent.id,
+                        kind: .agent(agent.status),
+                        title: name,
+                        subtitle: "\(projectPrefix)\(worktree.name) · \(agent.agentType) · \(agent.statusLabel)",
+                        searchText: [name, agent.agentType, agent.rawStatus, worktree.name, worktree.branch, project].joined(separator: " ")
+                    ))
+                }
+            }
+        }
+        return items
+    }
+
+    /// `items` matching `query`, best first: fuzzy score plus a frecency bonus (capped,
+    /// so a habit can outrank a slightly better match but not a much better one).
+    /// With an empty query, frecency alone orders them, then sidebar order.
+    static func rank(_ items: [QuickSwitcherItem], query: String, frecency: (String) -> Double) -> [QuickSwitcherItem] {
+        let scored: [(item: QuickSwitcherItem, score: Double, order: Int)] = items.enumerated().compactMap { order, item in
+            // Titles count double so "claude" finds agents named claude before items that only mention it.
+            let titleScore = FuzzyMatcher.score(query, in: item.title).map { $0 * 2 }
+            let textScore = FuzzyMatcher.score(query, in: item.searchText)
+            guard let match = [titleScore, textScore].compactMap({ $0 }).max() else { return nil }
+            return (item, Double(match) + min(frecency(item.id), maxFrecencyBonus), order)
+        }
+        return scored.sorted { lhs, rhs in
+            lhs.score != rhs.score ? lhs.score > rhs.score : lhs.order < rhs.order
+        }.map(\.item)
+    }
+
+    static let maxFrecencyBonus: Double = 600
+}
+
+// MARK: - QuickSwitcherFrecency
+
+/// Remembers recent quick-switcher jumps so frequently and recently used items rank
+/// first. Keeps the last `maxVisits` jump times per item in user defaults.
+nonisolated final class QuickSwitcherFrecency: @unchecked Sendable {
+    static let shared = QuickSwitcherFrecency()
+    static let maxVisits = 10
+    static let maxItems = 200
+
+    private let defaults: UserDefaults
+    private let key = "PPGQuickSwitcherVisits"
+
+    init(defaults: UserDefaults = .standard) {
+        self.defaults = defaults
+    }
+
+    private var visits: [String: [Double]] {
+        (defaults.dictionary(forKey: key) as? [String: [Double]]) ?? [:]
+    }
+
+    func recordVisit(_ id: String, at date: Date = Date()) {
+        var all = visits
+        all[id] = Array(((all[id] ?? []) + [date.timeIntervalSince1970]).suffix(Self.maxVisits))
+        if all.count > Self.maxItems {
+            // Forget the items whose latest jump is oldest
+            let keep = all.sorted { ($0.value.last ?? 0) > ($1.value.last ?? 0) }.prefix(Self.maxItems)
+            all = Dictionary(uniqueKeysWithValues: keep.map { ($0.key, $0.value) })
+        }
+        defaults.set(all, forKey: key)
+    }
+
+    /// Sum of the item's jumps, each weighted by how recent it is.
+    func score(for id: String, now: Date = Date()) -> Double {
+        scores(now: now)[id] ?? 0
+    }
+
+    /// Scores for every item at once, to avoid re-reading defaults while ranking.
+    func scores(now: Date = Date()) -> [String: Double] {
+        visits.mapValues { times in
+            times.reduce(0) { $0 + Self.weight(age: now.timeIntervalSince1970 - $1) }
+        }
+    }
+
+    static func weight(age: TimeInterval) -> Double {
+        switch age {
+        case ..<(4 * 3600): return 100
+        case ..<(24 * 3600): return 80
+        case ..<(7 * 24 * 3600): return 60
+        case ..<(30 * 24 * 3600): return 30
+        default: return 10
+        }
+    }
+}
@@ -0,0 +1,367 @@
+import AppKit
+
+// MARK: - QuickSwitcherPanel
+
+/// Floating search over every worktree and agent, styled like the command palette.
+/// Enter jumps to the highlighted item; Escape or a click outside dismisses.
+class QuickSwitcherPanel: NSPanel {
+    private var localMouseMonitor: Any?
+
+    override var canBecomeKey: Bool { true }
+
+    init() {
+        super.init(
+            contentRect: NSRect(x: 0, y: 0, width: 520, height: 380),
+            styleMask: [.borderless, .nonactivatingPanel],
+            backing: .buffered,
+            defer: false
+        )
+        isFloatingPanel = true
+        level = .floating
+        isOpaque = false
+        backgroundColor = .clear
+        hasShadow = true
+        appearance = NSApp.appearance
+        contentViewController = QuickSwitcherViewController()
+    }
+
+    func showRelativeTo(window: NSWindow?) {
+        guard let parentWindow = window else { return }
+        appearance = parentWindow.effectiveAppearance
+
+        let parentFrame = parentWindow.frame
+        let x = parentFrame.midX - frame.width / 2
+        let y = parentFrame.midY - frame.height / 2 + 60
+        setFrameOrigin(NSPoint(x: x, y: y))
+
+        parentWindow.addChildWindow(self, ordered: .above)
+        makeKeyAndOrderFront(nil)
+
+        localMouseMonitor = NSEvent.addLocalMonitorForEvents(matching: [.leftMouseDown, .rightMouseDown]) { [weak self] event in
+            guard let self = self else { return event }
+            if event.window !== self {
+                self.dismiss()
+                return nil
+            }
+            return event
+        }
+    }
+
+    func dismiss() {
+        if let monitor = localMouseMonitor {
+            NSEvent.removeMonitor(monitor)
+            localMouseMonitor = nil
+        }
+        parent?.removeChildWindow(self)
+        orderOut(nil)
+    }
+
+    deinit {
+        if let monitor = localMouseMonitor {
+            NSEvent.removeMonitor(monitor)
+        }
+    }
+
+    override func cancelOperation(_ sender: Any?) {
+        dismiss()
+    }
+
+    /// Show the switcher over `items`. `onSelect` gets the chosen item after the panel closes.
+    @discardableResult
+    static func show(relativeTo window: NSWindow?, items: [QuickSwitcherItem], onSelect: @escaping (QuickSwitcherItem) -> Void) -> QuickSwitcherPanel {
+        let panel = QuickSwitcherPanel()
+        guard let vc = panel.contentViewController as? QuickSwitcherViewController else { return panel }
+        vc.allItems = items
+        vc.onSelect = { item in
+            panel.dismiss()
+            QuickSwitcherFrecency.shared.recordVisit(item.id)
+            onSelect(item)
+        }
+        vc.onDismiss = { panel.dismiss() }
+        panel.showRelativeTo(window: window)
+        return panel
+    }
+}
+
+// MARK: - QuickSwitcherViewController
+
+class QuickSwitcherViewController: NSViewController, NSTextFieldDelegate {
+    var onSelect: ((QuickSwitcherItem) -> Void)?
+    var onDismiss: (() -> Void)?
+
+    var allItems: [QuickSwitcherItem] = [] {
+        didSet {
+            frecencyScores = QuickSwitcherFrecency.shared.scores()
+            filter(query: searchField.stringValue)
+        }
+    }
+    private var frecencyScores: [String: Double] = [:]
+    private var filteredItems: [QuickSwitcherItem] = []
+    private var selectedIndex = 0
+
+    private let containerView = ThemeAwareView()
+    private let searchField = NSTextField()
+    private let separatorView = NSBox()
+    private let scrollView = NSScrollView()
+    private let tableView = NSTableView()
+    private let emptyLabel = NSTextField(labelWithString: "No matching worktrees or agents")
+
+    private static let rowHeight: CGFloat = 40
+    private static let maxHeight: CGFloat = 380
+
+    private var resolvedAppearance: NSAppearance { containerView.effectiveAppearance }
+    private var textColor: NSColor { Theme.primaryText.resolvedColor(for: resolvedAppearance) }
+    private var dimColor: NSColor { NSColor.secondaryLabelColor.resolvedColor(for: resolvedAppearance) }
+    private var highlightCGColor: CGColor { Theme.paletteHighlight.resolvedCGColor(for: resolvedAppearance) }
+    private var clearCGColor: CGColor { NSColor.clear.resolvedCGColor(for: resolvedAppearance) }
+
+    override func loadView() {
+        let wrapper = NSView(frame: NSRect(x: 0, y: 0, width: 520, height: Self.maxHeight))
+        view = wrapper
+
+        containerView.wantsLayer = true
+        containerView.layer?.cornerRadius = 12
+        containerView.layer?.borderWidth = 1
+        let shadow = NSShadow()
+        shadow.shadowBlurRadius = 20
+        shadow.shadowOffset = NSSize(width: 0, height: -4)
+        containerView.shadow = shadow
+        containerView.onAppearanceChanged = { [weak self] in self?.refreshTheme() }
+        containerView.frame = wrapper.bounds
+        containerView.autoresizingMask = [.width, .height]
+        wrapper.addSubview(containerView)
+
+        searchField.placeholderString = "Jump to a worktree or agent…"
+        searchField.isBordered = false
+        searchField.isBezeled = false
+        searchField.focusRingType = .none
+        searchField.drawsBackground = false
+        searchField.font = .systemFont(ofSize: 16)
+        searchField.delegate = self
+        searchField.translatesAutoresizingMaskIntoConstraints = false
+        containerView.addSubview(searchField)
+
+        separatorView.boxType = .separator
+        separatorView.translatesAutoresizingMaskIntoConstraints = false
+        containerView.addSubview(separatorView)
+
+        let column = NSTableColumn(identifier: NSUserInterfaceItemIdentifier("item"))
+        column.width = 488
+        tableView.addTableColumn(column)
+        tableView.headerView = nil
+        tableView.backgroundColor = .clear
+        tableView.rowHeight = Self.rowHeight
+        tableView.intercellSpacing = NSSize(width: 0, height: 0)
+        tableView.selectionHighlightStyle = .none
+        tableView.dataSource = self
+        tableView.delegate = self
+        tableView.target = self
+        tableView.doubleAction = #selector(tableViewDoubleClick)
+
+        scrollView.documentView = tableView
+        scrollView.hasVerticalScroller = true
+        scrollView.autohidesScrollers = true
+        scrollView.drawsBackground = false
+        scrollView.translatesAutoresizingMaskIntoConstraints = false
+        containerView.addSubview(scrollView)
+
+        emptyLabel.font = .systemFont(ofSize: 13)
+        emptyLabel.alignment = .center
+        emptyLabel.isHidden = true
+        emptyLabel.translatesAutoresizingMaskIntoConstraints = false
+        containerView.addSubview(emptyLabel)
+
+        NSLayoutConstraint.activate([
+            searchField.topAnchor.constraint(equalTo: containerView.topAnchor, constant: 16),
+            searchField.leadingAnchor.constraint(equalTo: containerView.leadingAnchor, constant: 16),
+            searchField.trailingAnchor.constraint(equalTo: containerView.trailingAnchor, constant: -16),
+            searchField.heightAnchor.constraint(equalToConstant: 24),
+
+            separatorView.topAnchor.constraint(equalTo: searchField.bottomAnchor, constant: 12),
+            separatorView.leadingAnchor.constraint(equalTo: containerView.leadingAnchor),
+            separatorView.trailingAnchor.constraint(equalTo: containerView.trailingAnchor),
+            separatorView.heightAnchor.constraint(equalToConstant: 1),
+
+            scrollView.topAnchor.constraint(equalTo: separatorView.bottomAnchor, constant: 4),
+            scrollView.leadingAnchor.constraint(equalTo: containerView.leadingAnchor),
+            scrollView.trailingAnchor.constraint(equalTo: containerView.trailingAnchor),
+            scrollView.bottomAnchor.constraint(equalTo: containerView.bottomAnchor, constant: -8),
+
+            emptyLabel.topAnchor.constraint(equalTo: separatorView.bottomAnchor, constant: 12),
+            emptyLabel.centerXAnchor.constraint(equalTo: containerView.centerXAnchor),
+        ])
+
+        applyTheme()
+    }
+
+    override func viewDidAppear() {
+        super.viewDidAppear()
+        view.window?.makeFirstResponder(searchField)
+        resizePanel()
+    }
+
+    // MARK: - Filtering
+
+    private func filter(query: String) {
+        filteredItems = QuickSwitcherItem.rank(allItems, query: query) { [frecencyScores] in frecencyScores[$0] ?? 0 }
+        selectedIndex = filteredItems.isEmpty ? -1 : 0
+        guard isViewLoaded else { return }
+        emptyLabel.isHidden = !filteredItems.isEmpty
+        tableView.reloadData()
+        if selectedIndex >= 0 { tableView.scrollRowToVisible(0) }
+        resizePanel()
+    }
+
+    private func resizePanel() {
+        let headerHeight: CGFloat = 56
+        let rowsHeight = CGFloat(max(min(filteredItems.count, 8), 1)) * Self.rowHeight
+        let panelHeight = min(headerHeight + rowsHeight + 8, Self.maxHeight)
+        guard let panel = view.window as? QuickSwitcherPanel, panel.frame.height != panelHeight else { return }
+        var frame = panel.frame
+        frame.origin.y += frame.height - panelHeight
+        frame.size.height = panelHeight
+        panel.setFrame(frame, display: true)
+    }
+
+    // MARK: - Theme
+
+    func refreshTheme() {
+        guard isViewLoaded else { return }
+        applyTheme()
+        tableView.reloadData()
+    }
+
+    private func applyTheme() {
+        containerView.layer?.backgroundColor = Theme.paletteBackground.resolvedCGColor(for: resolvedAppearance)
+        containerView.layer?.borderColor = Theme.paletteBorder.resolvedCGColor(for: resolvedAppearance)
+        if let shadow = containerView.shadow {
+            shadow.shadowColor = Theme.paletteShadow.resolvedColor(for: resolvedAppearance)
+            containerView.shadow = shadow
+        }
+        searchField.textColor = textColor
+        emptyLabel.textColor = dimColor
+    }
+
+    private func updateHighlight() {
+        for row in 0..<tableView.numberOfRows {
+            tableView.view(atColumn: 0, row: row, makeIfNecessary: false)?.layer?.backgroundColor =
+                row == selectedIndex ? highlightCGColor : clearCGColor
+        }
+    }
+
+    // MARK: - Actions
+
+    private func moveSelection(by delta: Int) {
+        guard !filteredItems.isEmpty else { return }
+        selectedIndex = max(0, min(filteredItems.count - 1, selectedIndex + delta))
+        updateHighlight()
+        tableView.scrollRowToVisible(selectedIndex)
+    }
+
+    private func chooseSelected() {
+        guard filteredItems.indices.contains(selectedIndex) else { return }
+        onSelect?(filteredItems[selectedIndex])
+    }
+
+    @objc private func tableViewDoubleClick() {
+        let row = tableView.clickedRow
+        guard filteredItems.indices.contains(row) else { return }
+        selectedIndex = row
+        chooseSelected()
+    }
+
+    // MARK: - NSTextFieldDelegate
+
+    func controlTextDidChange(_ obj: Notification) {
+        filter(query: searchField.stringValue)
+    }
+
+    func control(_ control: NSControl, textView: NSTextView, doCommandBy commandSelector: Selector) -> Bool {
+        switch commandSelector {
+        case #selector(NSResponder.moveUp(_:)):
+            moveSelection(by: -1)
+            return true
+        case #selector(NSResponder.moveDown(_:)):
+            moveSelection(by: 1)
+            return true
+        case #selector(NSResponder.insertNewline(_:)):
+            chooseSelected()
+            return true
+        case #selector(NSResponder.cancelOperation(_:)):
+            onDismiss?()
+            return true
+        default:
+            return false
+        }
+    }
+}
+
+// MARK: - NSTableViewDataSource & Delegate
+
+extension QuickSwitcherViewController: NSTableViewDataSource, NSTableViewDelegate {
+
+    func numberOfRows(in tableView: NSTableView) -> Int {
+        filteredItems.count
+    }
+
+    func tableView(_ tableView: NSTableView, viewFor tableColumn: NSTableColumn?, row: Int) -> NSView? {
+        let item = filteredItems[row]
+
+        let cellView = NSTableCellView()
+        cellView.wantsLayer = true
+        cellView.layer?.backgroundColor = row == selectedIndex ? highlightCGColor : clearCGColor
+        cellView.layer?.cornerRadius = 6
+
+        let iconView = NSImageView()
+        switch item.kind {
+        case .worktree:
+            iconView.image = NSImage(systemSymbolName: "arrow.triangle.branch", accessibilityDescription: "Worktree")
+            iconView.contentTintColor = dimColor
+            iconView.symbolConfiguration = NSImage.SymbolConfiguration(pointSize: 12, weight: .regular)
+        case .agent(let status):
+            iconView.image = NSImage(systemSymbolName: "circle.fill", accessibilityDescription: "Agent \(status.rawValue)")
+            iconView.contentTintColor = Theme.statusColor(for: status)
+            iconView.symbolConfiguration = NSImage.SymbolConfiguration(pointSize: 7, weight: .regular)
+        }
+        iconView.translatesAutoresizingMaskIntoConstraints = false
+
+        let titleLabel = NSTextField(labelWithString: item.title)
+        titleLabel.font = .boldSystemFont(ofSize: 13)
+        titleLabel.textColor = textColor
+        titleLabel.lineBreakMode = .byTruncatingTail
+
+        let subtitleLabel = NSTextField(labelWithString: item.subtitle)
+        subtitleLabel.font = .systemFont(ofSize: 11)
+        subtitleLabel.textColor = dimColor
+        subtitleLabel.lineBreakMode = .byTruncatingMiddle
+
+        let textStack = NSStackView(views: [titleLabel, subtitleLabel])
+        textStack.orientation = .vertical
+        textStack.alignment = .leading
+        textStack.spacing = 1
+        textStack.translatesAutoresizingMaskIntoConstraints = false
+
+        cellView.addSubview(iconView)
+        cellView.addSubview(textStack)
+        cellView.setAccessibilityLabel("\(item.title), \(item.subtitle)")
+
+        NSLayoutConstraint.activate([
+            iconView.leadingAnchor.constraint(equalTo: cellView.leadingAnchor, constant: 16),
+            iconView.centerYAnchor.constraint(equalTo: cellView.centerYAnchor),
+            iconView.widthAnchor.constraint(equalToConstant: 16),
+            iconView.heightAnchor.constraint(equalToConstant: 16),
+
+            textStack.leadingAnchor.constraint(equalTo: iconView.trailingAnchor, constant: 10),
+            textStack.centerYAnchor.constraint(equalTo: cellView.centerYAnchor),
+            textStack.trailingAnchor.constraint(lessThanOrEqualTo: cellView.trailingAnchor, constant: -16),
+        ])
+
+        return cellView
+    }
+
+    func tableView(_ tableView: NSTableView, shouldSelectRow row: Int) -> Bool {
+        selectedIndex = row
+        updateHighlight()
+        return false // Selection is tracked in selectedIndex
+    }
+}
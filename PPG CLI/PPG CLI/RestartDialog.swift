@@ -0,0 +1,172 @@
+import AppKit
+
+/// What to change when restarting an agent (`ppg restart`). Nil fields keep the old
+/// agent's prompt or type.
+nonisolated struct RestartRequest: Equatable {
+    var prompt: String?
+    var agent: String?
+
+    /// A prompt edited in the restart dialog. Like the palette, surrounding whitespace is
+    /// dropped; unlike it, a blank prompt is rejected, since ppg would treat it as "no
+    /// override" and silently reuse the old one.
+    static func edited(prompt: String, agent: String?) -> RestartRequest? {
+        let trimmed = prompt.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmed.isEmpty else { return nil }
+        return RestartRequest(prompt: trimmed, agent: agent)
+    }
+
+    func arguments(agentId: String) -> String {
+        var args = "restart \(shellEscape(agentId))"
+        if let prompt { args += " --prompt \(shellEscape(prompt))" }
+        if let agent { args += " --agent \(shellEscape(agent))" }
+        return args + " --json"
+    }
+
+    /// The replacement agent described by `ppg restart --json`.
+    struct Response: Equatable {
+        let newAgentId: String
+        let tmuxTarget: String?
+        let sessionId: String?
+        let worktreeId: String?
+    }
+
+    static func response(fromJSON output: String) -> Response? {
+        guard let data = output.data(using: .utf8),
+              let json = try? JSONSerialization.jsonObject(with: data) as? [String: Any],
+              let newAgent = json["newAgent"] as? [String: Any],
+              let id = newAgent["id"] as? String else { return nil }
+        return Response(
+            newAgentId: id,
+            tmuxTarget: newAgent["tmuxTarget"] as? String,
+            sessionId: newAgent["sessionId"] as? String,
+            worktreeId: newAgent["worktreeId"] as? String
+        )
+    }
+
+    /// The replacement agent's ID from `ppg restart --json` output.
+    static func newAgentId(fromJSON output: String) -> String? {
+        response(fromJSON: output)?.newAgentId
+    }
+}
+
+/// Progress of restarting every exited agent in a worktree, e.g. after a reboot left
+/// them all completed or lost. Running agents are never part of a batch.
+nonisolated struct BatchRestart {
+    /// Restarts in flight at once; each one spawns a tmux window and locks the manifest.
+    static let maxConcurrent = 3
+
+    let total: Int
+    private(set) var restarted = 0
+    private(set) var failures: [(name: String, failure: CommandFailure)] = []
+
+    init(total: Int) {
+        self.total = total
+    }
+
+    /// Agents that have exited and can be respawned, in worktree order. The CLI's
+    /// `idle`, `exited` and `gone` read as completed, failed or lost.
+    static func restartable(_ agents: [AgentModel]) -> [AgentModel] {
+        agents.filter { [.completed, .failed, .killed, .lost].contains($0.status) }
+    }
+
+    var isFinished: Bool { restarted + failures.count >= total }
+
+    mutating func recordSuccess() {
+        restarted += 1
+    }
+
+    mutating func recordFailure(name: String, _ failure: CommandFailure) {
+        failures.append((name, failure))
+    }
+
+    /// "Restarted 3 of 4 agents — 1 failed", or "Restarted 4 agents" when all went through.
+    var summary: String {
+        let noun = total == 1 ? "agent" : "agents"
+        guard !failures.isEmpty else { return "Restarted \(restarted) \(noun)" }
+        return "Restarted \(restarted) of \(total) \(noun) — \(failures.count) failed"
+    }
+}
+
+/// Asks for a new prompt (and optionally a different agent type) before a restart.
+/// Pre-filled with the agent's full prompt from `.ppg/agent-prompts/`; the manifest's
+/// copy is cut at 500 characters and is only used when that file is gone.
+final class RestartDialog: NSObject, NSTextViewDelegate {
+    private let agent: AgentModel
+    private let alert = NSAlert()
+    private let textView = NSTextView()
+    private let typePopUp = NSPopUpButton()
+    /// Agent type per popup item; nil keeps the current one.
+    private var types: [String?] = []
+
+    init(agent: AgentModel, projectRoot: String) {
+        self.agent = agent
+        super.init()
+
+        let name = agent.name.isEmpty ? agent.id : agent.name
+        alert.messageText = "Restart \(name) with New Prompt"
+        alert.informativeText = agent.status == .running
+            ? "The running agent is killed and a new one starts in the same worktree."
+            : "A new agent starts in the same worktree."
+        alert.addButton(withTitle: "Restart")
+        alert.addButton(withTitle: "Cancel")
+
+        textView.string = Self.originalPrompt(of: agent, projectRoot: projectRoot)
+        textView.isRichText = false
+        textView.allowsUndo = true
+        textView.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
+        textView.isVerticallyResizable = true
+        textView.autoresizingMask = [.width]
+        textView.textContainer?.widthTracksTextView = true
+        textView.delegate = self
+        textView.setAccessibilityLabel("Prompt")
+
+        let scrollView = NSScrollView(frame: NSRect(x: 0, y: 32, width: 420, height: 160))
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .bezelBorder
+        scrollView.documentView = textView
+        textView.frame = NSRect(origin: .zero, size: scrollView.contentSize)
+
+        let variants = AgentVariant.allVariants.filter { $0.kind == .agent }
+        let current = variants.first { $0.id == agent.agentType }?.displayName ?? agent.agentType
+        typePopUp.addItem(withTitle: "Same agent (\(current))")
+        types.append(nil)
+        for variant in variants where variant.id != agent.agentType {
+            typePopUp.addItem(withTitle: variant.displayName)
+            types.append(variant.id)
+        }
+        typePopUp.frame = NSRect(x: 80, y: 0, width: 200, height: 26)
+        typePopUp.setAccessibilityLabel("Agent type")
+
+        let typeLabel = NSTextField(labelWithString: "Agent:")
+        typeLabel.frame = NSRect(x: 0, y: 4, width: 76, height: 17)
+
+        let accessory = NSView(frame: NSRect(x: 0, y: 0, width: 420, height: 192))
+        accessory.addSubview(scrollView)
+        accessory.addSubview(typeLabel)
+        accessory.addSubview(typePopUp)
+        alert.accessoryView = accessory
+        alert.window.initialFirstResponder = textView
+        updateRestartButton()
+    }
+
+    /// Show the dialog; nil if cancelled.
+    func run() -> RestartRequest? {
+        guard alert.runModal() == .alertFirstButtonReturn else { return nil }
+        return RestartRequest.edited(prompt: textView.string, agent: types[typePopUp.indexOfSelectedItem])
+    }
+
+    static func originalPrompt(of agent: AgentModel, projectRoot: String) -> String {
+        let path = (projectRoot as NSString).appendingPathComponent(".ppg/agent-prompts/\(agent.id).md")
+        return (try? String(contentsOfFile: path, encoding: .utf8)) ?? agent.prompt
+    }
+
+    private func updateRestartButton() {
+        alert.buttons.first?.isEnabled = RestartRequest.edited(prompt: textView.string, agent: nil) != nil
+    }
+
+    // MARK: - NSTextViewDelegate
+
+    func textDidChange(_ notification: Notification) {
+        updateRestartButton()
+    }
+}
@@ -442,6 +442,7 @@ class SchedulesView: NSView {
         prevButton.bezelStyle = .accessoryBarAction
         prevButton.image = NSImage(systemSymbolName: "chevron.left", accessibilityDescription: "Previous")
         prevButton.isBordered = false
+        prevButton.toolTip = "Previous"
         prevButton.contentTintColor = Theme.primaryText
         prevButton.target = self
         prevButton.action = #selector(prevClicked)
@@ -461,6 +462,7 @@ class SchedulesView: NSView {
         nextButton.bezelStyle = .accessoryBarAction
         nextButton.image = NSImage(systemSymbolName: "chevron.right", accessibilityDescription: "Next")
         nextButton.isBordered = false
+        nextButton.toolTip = "Next"
         nextButton.contentTintColor = Theme.primaryText
         nextButton.target = self
         nextButton.action = #selector(nextClicked)
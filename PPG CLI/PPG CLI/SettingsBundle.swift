@@ -0,0 +1,239 @@
+import AppKit
+
+/// JSON snapshot of app settings and custom shortcuts, written by Settings ▸ Export and
+/// read back by Import to set up another machine the same way.
+///
+/// Fields this version doesn't recognize (added by a newer app within the same format
+/// version) are stored on import and written back out on the next export, so a round
+/// trip doesn't lose them. A newer format version is refused rather than half-read.
+struct SettingsBundle {
+    static let formatVersion = 1
+
+    /// Values keyed by `AppSettingsKey` raw value.
+    var settings: [String: Any]
+    /// Custom bindings keyed by `BindableAction` raw value. Actions left out use their defaults.
+    var shortcuts: [String: StoredBinding]
+    /// Top-level fields other than version/settings/shortcuts.
+    var extraFields: [String: Any] = [:]
+
+    // MARK: - Encoding
+
+    func encoded() throws -> Data {
+        var root = extraFields
+        root["version"] = Self.formatVersion
+        root["settings"] = settings
+        root["shortcuts"] = shortcuts.mapValues { ["key": $0.keyEquivalent, "modifiers": $0.modifiers] as [String: Any] }
+        return try JSONSerialization.data(withJSONObject: root, options: [.prettyPrinted, .sortedKeys])
+    }
+
+    static func decode(_ data: Data) throws -> SettingsBundle {
+        guard let root = (try? JSONSerialization.jsonObject(with: data)) as? [String: Any] else {
+            throw SettingsBundleError.notJSON
+        }
+        guard let version = root["version"] as? Int else {
+            throw SettingsBundleError.missingField("version")
+        }
+        guard version >= 1 else {
+            throw SettingsBundleError.unsupportedVersion(version)
+        }
+        guard version <= formatVersion else {
+            throw SettingsBundleError.newerVersion(version)
+        }
+        guard let settings = root["settings"] as? [String: Any] else {
+            throw SettingsBundleError.missingField("settings")
+        }
+
+        let shortcuts = try root["shortcuts"].map(decodeShortcuts) ?? [:]
+
+        var extra = root
+        for field in ["version", "settings", "shortcuts"] {
+            extra.removeValue(forKey: field)
+        }
+        return SettingsBundle(settings: settings, shortcuts: shortcuts, extraFields: extra)
+    }
+
+    private static func decodeShortcuts(_ raw: Any) throws -> [String: StoredBinding] {
+        guard let entries = raw as? [String: Any] else {
+            throw SettingsBundleError.invalidValue(field: "shortcuts")
+        }
+        var shortcuts: [String: StoredBinding] = [:]
+        for (action, value) in entries {
+            guard let binding = value as? [String: Any],
+                  let key = binding["key"] as? String, !key.isEmpty,
+                  let modifiers = binding["modifiers"] as? UInt else {
+                throw SettingsBundleError.invalidValue(field: "shortcuts.\(action)")
+            }
+            shortcuts[action] = StoredBinding(keyEquivalent: key, modifiers: modifiers)
+        }
+        return shortcuts
+    }
+
+    // MARK: - Validation
+
+    /// Normalize an imported value for `key`, or nil if it has the wrong type or is out of range.
+    static func validatedValue(_ value: Any, for key: AppSettingsKey) -> Any? {
+        switch key {
+        case .refreshInterval:
+            guard let seconds = value as? Double, seconds > 0 else { return nil }
+            return seconds
+        case .terminalFontSize:
+            guard let size = value as? Double, size > 0 else { return nil }
+            return size
+        case .terminalFont, .shell:
+            guard let text = value as? String, !text.isEmpty else { return nil }
+            return text
+        case .historyLimit:
+            guard let limit = value as? Int, limit > 0 else { return nil }
+            return limit
+        case .maxVisiblePanes:
+            guard let count = value as? Int, AppSettingsManager.maxVisiblePanesRange.contains(count) else { return nil }
+            return count
+        case .minPaneWidth:
+            guard let width = value as? Int, AppSettingsManager.minPaneWidthRange.contains(width) else { return nil }
+            return width
+        case .stalledAgentMinutes:
+            guard let minutes = value as? Int, AppSettingsManager.stalledAgentMinutesRange.contains(minutes) else { return nil }
+            return minutes
+        case .autoOpenSpawnedAgents, .compactSidebarRows, .restoreLastSelection, .autoContinueSetup, .keepRunningInMenuBar,
+             .confirmQuitWithRunningAgents:
+            return value as? Bool
+        case .appearanceMode:
+            guard let raw = value as? String, let mode = AppearanceMode(rawValue: raw) else { return nil }
+            return mode
+        case .preferredTerminal:
+            // Empty is automatic; a terminal this version doesn't know falls back at launch time
+            return value as? String
+        case .agentCommands:
+            // Keyed by variant ID; commands for variants this version lacks are kept as-is
+            guard let commands = value as? [String: String],
+                  commands.values.allSatisfy({ !$0.trimmingCharacters(in: .whitespaces).isEmpty }) else { return nil }
+            return commands
+        }
+    }
+
+    /// Check every field and split out the ones this version doesn't know.
+    /// Throws on the first invalid value so nothing is half-applied.
+    func importPlan() throws -> SettingsImportPlan {
+        var known: [AppSettingsKey: Any] = [:]
+        var unknownSettings: [String: Any] = [:]
+        for (name, value) in settings {
+            guard let key = AppSettingsKey(rawValue: name) else {
+                unknownSettings[name] = value
+                continue
+            }
+            guard let normalized = Self.validatedValue(value, for: key) else {
+                throw SettingsBundleError.invalidValue(field: "settings.\(name)")
+            }
+            known[key] = normalized
+        }
+
+        var bindings: [BindableAction: StoredBinding] = [:]
+        var unknownShortcuts: [String: StoredBinding] = [:]
+        for (name, binding) in shortcuts {
+            guard let action = BindableAction(rawValue: name) else {
+                unknownShortcuts[name] = binding
+                continue
+            }
+            bindings[action] = binding
+        }
+
+        // Two actions on the same shortcut would leave one unreachable
+        var seen: [String: BindableAction] = [:]
+        for action in BindableAction.allCases {
+            let binding = bindings[action] ?? StoredBinding(keyEquivalent: action.defaultKeyEquivalent, modifiers: action.defaultModifierMask.rawValue)
+            let fingerprint = "\(binding.modifiers)-\(binding.keyEquivalent)"
+            if let other = seen[fingerprint] {
+                throw SettingsBundleError.shortcutConflict(other.displayName, action.displayName)
+            }
+            seen[fingerprint] = action
+        }
+
+        var preserved = extraFields
+        if !unknownSettings.isEmpty { preserved["settings"] = unknownSettings }
+        if !unknownShortcuts.isEmpty {
+            preserved["shortcuts"] = unknownShortcuts.mapValues { ["key": $0.keyEquivalent, "modifiers": $0.modifiers] as [String: Any] }
+        }
+        return SettingsImportPlan(settings: known, shortcuts: bindings, preservedFields: preserved)
+    }
+}
+
+/// A validated import, ready to preview and apply.
+struct SettingsImportPlan {
+    let settings: [AppSettingsKey: Any]
+    let shortcuts: [BindableAction: StoredBinding]
+    /// Fields from a newer app version, stored so the next export carries them along.
+    let preservedFields: [String: Any]
+}
+
+extension SettingsBundle {
+    /// Snapshot of the current settings, shortcuts, and any preserved fields from earlier imports.
+    static func current() -> SettingsBundle {
+        var preserved = AppSettingsManager.shared.preservedImportFields
+        var settings = preserved.removeValue(forKey: "settings") as? [String: Any] ?? [:]
+        var shortcuts = (try? preserved.removeValue(forKey: "shortcuts").map(decodeShortcuts)) ?? [:]
+        settings.merge(AppSettingsManager.shared.exportedSettings()) { _, current in current }
+        shortcuts.merge(KeybindingManager.shared.exportedBindings) { _, current in current }
+        return SettingsBundle(settings: settings, shortcuts: shortcuts, extraFields: preserved)
+    }
+}
+
+extension SettingsImportPlan {
+    /// Human-readable "Name: old → new" lines for the confirmation dialog.
+    func changeSummary() -> [String] {
+        var lines: [String] = []
+        let current = AppSettingsManager.shared.exportedSettings()
+        for (key, value) in settings.sorted(by: { $0.key.rawValue < $1.key.rawValue }) {
+            let incoming = (value as? AppearanceMode)?.rawValue ?? "\(value)"
+            let existing = current[key.rawValue].map { "\($0)" } ?? ""
+            if incoming != existing {
+                lines.append("\(key.rawValue): \(existing) → \(incoming)")
+            }
+        }
+
+        let keybindings = KeybindingManager.shared
+        for action in BindableAction.allCases {
+            let existing = keybindings.displayString(for: action)
+            let binding = shortcuts[action]
+            let incoming = KeybindingManager.displayString(
+                keyEquivalent: binding?.keyEquivalent ?? action.defaultKeyEquivalent,
+                modifiers: binding.map { NSEvent.ModifierFlags(rawValue: $0.modifiers) } ?? action.defaultModifierMask
+            )
+            if incoming != existing {
+                lines.append("\(action.displayName) shortcut: \(existing) → \(incoming)")
+            }
+        }
+        return lines
+    }
+
+    func apply() {
+        AppSettingsManager.shared.applyImported(settings)
+        KeybindingManager.shared.replaceAll(with: shortcuts)
+        AppSettingsManager.shared.preservedImportFields = preservedFields
+    }
+}
+
+enum SettingsBundleError: LocalizedError {
+    case notJSON
+    case missingField(String)
+    case unsupportedVersion(Int)
+    case newerVersion(Int)
+    case invalidValue(field: String)
+    case shortcutConflict(String, String)
+
+    var errorDescription: String? {
+        switch self {
+        case .notJSON:
+            return "The file isn't a settings export (it doesn't contain a JSON object)."
+        case .missingField(let field):
+            return "The file is missing the \"\(field)\" field."
+        case .unsupportedVersion(let version):
+            return "Settings format version \(version) isn't supported."
+        case .newerVersion(let version):
+            return "The file was made by a newer version of the app (settings format \(version)). Update the app to import it."
+        case .invalidValue(let field):
+            return "\"\(field)\" has a value of the wrong type or out of range."
+        case .shortcutConflict(let first, let second):
+            return "\"\(first)\" and \"\(second)\" are assigned the same shortcut."
+        }
+    }
+}
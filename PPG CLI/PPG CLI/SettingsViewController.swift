@@ -1,6 +1,7 @@
 import AppKit
+import UniformTypeIdentifiers
 
-class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableViewDelegate {
+class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableViewDelegate, NSFontChanging {
 
     private let segmentedControl = NSSegmentedControl()
     private let contentContainer = NSView()
@@ -9,6 +10,7 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
     // Cached tab views (built once, reused)
     private var displayView: NSView?
     private var terminalView: NSView?
+    private var agentsView: NSView?
     private var shortcutsView: NSView?
 
     // Shortcuts tab state
@@ -19,9 +21,18 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
     private var eventMonitor: Any? = nil
 
     // Terminal tab controls (retained for commitTextFields / live-update)
+    private var fontPopup: NSPopUpButton?
+    private var fontPreviewLabel: NSTextField?
     private var fontSizeField: NSTextField?
+    private var fontSizeStepper: NSStepper?
     private var shellField: NSTextField?
     private var historyField: NSTextField?
+    private var maxPanesField: NSTextField?
+    private var minPaneWidthField: NSTextField?
+    private var stalledMinutesField: NSTextField?
+
+    // Agents tab controls, one per configurable variant
+    private var agentCommandFields: [(variant: AgentVariant, field: NSTextField, revert: NSButton)] = []
 
     override func loadView() {
         let container = ThemeAwareView(frame: NSRect(x: 0, y: 0, width: 620, height: 520))
@@ -40,10 +51,11 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         view.layer?.backgroundColor = Theme.contentBackground.resolvedCGColor(for: view.effectiveAppearance)
 
         // Segmented control
-        segmentedControl.segmentCount = 3
+        segmentedControl.segmentCount = 4
         segmentedControl.setLabel("Display", forSegment: 0)
         segmentedControl.setLabel("Terminal", forSegment: 1)
-        segmentedControl.setLabel("Shortcuts", forSegment: 2)
+        segmentedControl.setLabel("Agents", forSegment: 2)
+        segmentedControl.setLabel("Shortcuts", forSegment: 3)
         segmentedControl.segmentStyle = .texturedRounded
         segmentedControl.selectedSegment = 0
         segmentedControl.target = self
@@ -62,6 +74,32 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         doneButton.translatesAutoresizingMaskIntoConstraints = false
         view.addSubview(doneButton)
 
+        // Move settings between machines
+        let exportButton = NSButton(title: "Export…", target: self, action: #selector(exportSettings))
+        exportButton.bezelStyle = .rounded
+        exportButton.translatesAutoresizingMaskIntoConstraints = false
+        view.addSubview(exportButton)
+
+        let importButton = NSButton(title: "Import…", target: self, action: #selector(importSettings))
+        importButton.bezelStyle = .rounded
+        importButton.translatesAutoresizingMaskIntoConstraints = false
+        view.addSubview(importButton)
+
+        // Recover from a wedged configuration without deleting preferences by hand
+        let resetPopup = NSPopUpButton(frame: .zero, pullsDown: true)
+        resetPopup.addItem(withTitle: "Reset")
+        for (title, action) in [
+            ("Reset Appearance & Terminal…", #selector(resetAppearanceAndTerminal)),
+            ("Reset Shortcuts…", #selector(confirmResetShortcuts)),
+            ("Reset Everything…", #selector(resetEverything)),
+        ] {
+            let item = NSMenuItem(title: title, action: action, keyEquivalent: "")
+            item.target = self
+            resetPopup.menu?.addItem(item)
+        }
+        resetPopup.translatesAutoresizingMaskIntoConstraints = false
+        view.addSubview(resetPopup)
+
         NSLayoutConstraint.activate([
             segmentedControl.topAnchor.constraint(equalTo: view.topAnchor, constant: 20),
             segmentedControl.centerXAnchor.constraint(equalTo: view.centerXAnchor),
@@ -73,6 +111,15 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
 
             doneButton.trailingAnchor.constraint(equalTo: view.trailingAnchor, constant: -20),
             doneButton.bottomAnchor.constraint(equalTo: view.bottomAnchor, constant: -16),
+
+            exportButton.leadingAnchor.constraint(equalTo: view.leadingAnchor, constant: 20),
+            exportButton.centerYAnchor.constraint(equalTo: doneButton.centerYAnchor),
+
+            importButton.leadingAnchor.constraint(equalTo: exportButton.trailingAnchor, constant: 8),
+            importButton.centerYAnchor.constraint(equalTo: doneButton.centerYAnchor),
+
+            resetPopup.leadingAnchor.constraint(equalTo: importButton.trailingAnchor, constant: 8),
+            resetPopup.centerYAnchor.constraint(equalTo: doneButton.centerYAnchor),
         ])
 
         showTab(0)
@@ -105,6 +152,9 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
             if terminalView == nil { terminalView = makeTerminalView() }
             tabView = terminalView!
         case 2:
+            if agentsView == nil { agentsView = makeAgentsView() }
+            tabView = agentsView!
+        case 3:
             if shortcutsView == nil { shortcutsView = makeShortcutsView() }
             tabView = shortcutsView!
         default: return
@@ -154,6 +204,43 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         hint.translatesAutoresizingMaskIntoConstraints = false
         container.addSubview(hint)
 
+        let sidebarLabel = makeLabel("Sidebar:")
+        sidebarLabel.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(sidebarLabel)
+
+        let compactCheckbox = NSButton(checkboxWithTitle: "Compact rows (hide branch names under worktrees)", target: self, action: #selector(compactSidebarRowsChanged(_:)))
+        compactCheckbox.state = AppSettingsManager.shared.compactSidebarRows ? .on : .off
+        compactCheckbox.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(compactCheckbox)
+
+        let startupLabel = makeLabel("On Launch:")
+        startupLabel.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(startupLabel)
+
+        let restoreCheckbox = NSButton(checkboxWithTitle: "Reopen the last selected item instead of the dashboard", target: self, action: #selector(restoreLastSelectionChanged(_:)))
+        restoreCheckbox.state = AppSettingsManager.shared.restoreLastSelection ? .on : .off
+        restoreCheckbox.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(restoreCheckbox)
+
+        let autoContinueCheckbox = NSButton(checkboxWithTitle: "Leave the setup screen automatically once ppg and tmux are found", target: self, action: #selector(autoContinueSetupChanged(_:)))
+        autoContinueCheckbox.state = AppSettingsManager.shared.autoContinueSetup ? .on : .off
+        autoContinueCheckbox.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(autoContinueCheckbox)
+
+        let windowLabel = makeLabel("Closing and Quitting:")
+        windowLabel.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(windowLabel)
+
+        let menuBarCheckbox = NSButton(checkboxWithTitle: "Keep running in the menu bar", target: self, action: #selector(keepRunningInMenuBarChanged(_:)))
+        menuBarCheckbox.state = AppSettingsManager.shared.keepRunningInMenuBar ? .on : .off
+        menuBarCheckbox.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(menuBarCheckbox)
+
+        let confirmQuitCheckbox = NSButton(checkboxWithTitle: "Ask before quitting while agents are running", target: self, action: #selector(confirmQuitChanged(_:)))
+        confirmQuitCheckbox.state = AppSettingsManager.shared.confirmQuitWithRunningAgents ? .on : .off
+        confirmQuitCheckbox.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(confirmQuitCheckbox)
+
         NSLayoutConstraint.activate([
             label.topAnchor.constraint(equalTo: container.topAnchor, constant: 8),
             label.leadingAnchor.constraint(equalTo: container.leadingAnchor),
@@ -163,11 +250,55 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
 
             hint.topAnchor.constraint(equalTo: seg.bottomAnchor, constant: 8),
             hint.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            sidebarLabel.topAnchor.constraint(equalTo: hint.bottomAnchor, constant: 24),
+            sidebarLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            compactCheckbox.topAnchor.constraint(equalTo: sidebarLabel.bottomAnchor, constant: 8),
+            compactCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            startupLabel.topAnchor.constraint(equalTo: compactCheckbox.bottomAnchor, constant: 24),
+            startupLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            restoreCheckbox.topAnchor.constraint(equalTo: startupLabel.bottomAnchor, constant: 8),
+            restoreCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            autoContinueCheckbox.topAnchor.constraint(equalTo: restoreCheckbox.bottomAnchor, constant: 8),
+            autoContinueCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            windowLabel.topAnchor.constraint(equalTo: autoContinueCheckbox.bottomAnchor, constant: 24),
+            windowLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            menuBarCheckbox.topAnchor.constraint(equalTo: windowLabel.bottomAnchor, constant: 8),
+            menuBarCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            confirmQuitCheckbox.topAnchor.constraint(equalTo: menuBarCheckbox.bottomAnchor, constant: 8),
+            confirmQuitCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
         ])
 
         return container
     }
 
+    @objc private func compactSidebarRowsChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.compactSidebarRows = sender.state == .on
+    }
+
+    @objc private func restoreLastSelectionChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.restoreLastSelection = sender.state == .on
+    }
+
+    @objc private func autoContinueSetupChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.autoContinueSetup = sender.state == .on
+    }
+
+    @objc private func keepRunningInMenuBarChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.keepRunningInMenuBar = sender.state == .on
+    }
+
+    @objc private func confirmQuitChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.confirmQuitWithRunningAgents = sender.state == .on
+    }
+
     @objc private func appearanceChanged(_ sender: NSSegmentedControl) {
         let mode: AppearanceMode
         switch sender.selectedSegment {
@@ -188,13 +319,19 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         let fontLabel = makeLabel("Font:")
         let popup = NSPopUpButton()
         popup.translatesAutoresizingMaskIntoConstraints = false
-        let monoFonts = monospaceFontFamilies()
-        popup.addItems(withTitles: monoFonts)
-        if let idx = monoFonts.firstIndex(of: settings.terminalFontName) {
-            popup.selectItem(at: idx)
-        }
         popup.target = self
         popup.action = #selector(fontChanged(_:))
+        fontPopup = popup
+        reloadFontPopup()
+
+        // Any installed font, via the system font panel
+        let chooseFontButton = NSButton(title: "Other…", target: self, action: #selector(showFontPanel))
+        chooseFontButton.bezelStyle = .rounded
+
+        let preview = NSTextField(labelWithString: "")
+        preview.lineBreakMode = .byTruncatingTail
+        fontPreviewLabel = preview
+        updateFontPreview()
 
         // Font Size
         let sizeLabel = makeLabel("Font Size:")
@@ -212,6 +349,7 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         stepper.target = self
         stepper.action = #selector(fontSizeStepperChanged(_:))
         stepper.translatesAutoresizingMaskIntoConstraints = false
+        fontSizeStepper = stepper
 
         // Shell
         let shellLabel = makeLabel("Shell:")
@@ -235,7 +373,59 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         histF.action = #selector(historyLimitChanged(_:))
         historyField = histF
 
-        for v: NSView in [fontLabel, popup, sizeLabel, sizeField, stepper, shellLabel, shellF, histLabel, histF] {
+        // Max Visible Panes
+        let panesLabel = makeLabel("Max Panes per Worktree:")
+        let panesField = NSTextField(labelWithString: "\(settings.maxVisiblePanes)")
+        panesField.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
+        panesField.textColor = Theme.primaryText
+        maxPanesField = panesField
+
+        let panesStepper = NSStepper()
+        panesStepper.minValue = Double(AppSettingsManager.maxVisiblePanesRange.lowerBound)
+        panesStepper.maxValue = Double(AppSettingsManager.maxVisiblePanesRange.upperBound)
+        panesStepper.integerValue = settings.maxVisiblePanes
+        panesStepper.increment = 1
+        panesStepper.target = self
+        panesStepper.action = #selector(maxPanesStepperChanged(_:))
+
+        // Min Pane Width
+        let widthLabel = makeLabel("Min Pane Width:")
+        let widthField = NSTextField(labelWithString: "\(settings.minPaneWidth) pt")
+        widthField.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
+        widthField.textColor = Theme.primaryText
+        minPaneWidthField = widthField
+
+        let widthStepper = NSStepper()
+        widthStepper.minValue = Double(AppSettingsManager.minPaneWidthRange.lowerBound)
+        widthStepper.maxValue = Double(AppSettingsManager.minPaneWidthRange.upperBound)
+        widthStepper.integerValue = settings.minPaneWidth
+        widthStepper.increment = 40
+        widthStepper.target = self
+        widthStepper.action = #selector(minPaneWidthStepperChanged(_:))
+
+        // Auto-open
+        let autoOpenCheckbox = NSButton(checkboxWithTitle: "Open new agents when they start", target: self, action: #selector(autoOpenChanged(_:)))
+        autoOpenCheckbox.state = settings.autoOpenSpawnedAgents ? .on : .off
+
+        // External terminal, for Attach and the setup screen's Run buttons
+        let externalLabel = makeLabel("Open Commands In:")
+        let externalPopup = NSPopUpButton()
+        externalPopup.target = self
+        externalPopup.action = #selector(externalTerminalChanged(_:))
+        externalPopup.addItem(withTitle: "Automatic (Terminal)")
+        externalPopup.lastItem?.representedObject = ""
+        var choices = ExternalTerminal.installed()
+        if let preferred = ExternalTerminal(rawValue: settings.preferredTerminal), !choices.contains(preferred) {
+            choices.append(preferred)
+        }
+        for terminal in choices {
+            let installed = terminal.applicationURL != nil
+            externalPopup.addItem(withTitle: installed ? terminal.displayName : "\(terminal.displayName) (not installed)")
+            externalPopup.lastItem?.representedObject = terminal.rawValue
+        }
+        externalPopup.selectItem(at: max(0, externalPopup.indexOfItem(withRepresentedObject: settings.preferredTerminal)))
+
+        for v: NSView in [fontLabel, popup, chooseFontButton, preview, sizeLabel, sizeField, stepper, shellLabel, shellF, histLabel, histF, panesLabel, panesField, panesStepper, widthLabel, widthField, widthStepper, autoOpenCheckbox, externalLabel, externalPopup] {
             v.translatesAutoresizingMaskIntoConstraints = false
             container.addSubview(v)
         }
@@ -248,7 +438,14 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
             popup.leadingAnchor.constraint(equalTo: container.leadingAnchor),
             popup.widthAnchor.constraint(equalToConstant: 240),
 
-            sizeLabel.topAnchor.constraint(equalTo: popup.bottomAnchor, constant: 20),
+            chooseFontButton.centerYAnchor.constraint(equalTo: popup.centerYAnchor),
+            chooseFontButton.leadingAnchor.constraint(equalTo: popup.trailingAnchor, constant: 8),
+
+            preview.topAnchor.constraint(equalTo: popup.bottomAnchor, constant: 6),
+            preview.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+            preview.trailingAnchor.constraint(lessThanOrEqualTo: container.trailingAnchor),
+
+            sizeLabel.topAnchor.constraint(equalTo: preview.bottomAnchor, constant: 20),
             sizeLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
 
             sizeField.topAnchor.constraint(equalTo: sizeLabel.bottomAnchor, constant: 6),
@@ -270,19 +467,146 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
             histF.topAnchor.constraint(equalTo: histLabel.bottomAnchor, constant: 6),
             histF.leadingAnchor.constraint(equalTo: container.leadingAnchor),
             histF.widthAnchor.constraint(equalToConstant: 120),
+
+            panesLabel.topAnchor.constraint(equalTo: histF.bottomAnchor, constant: 20),
+            panesLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            panesField.topAnchor.constraint(equalTo: panesLabel.bottomAnchor, constant: 6),
+            panesField.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            panesStepper.centerYAnchor.constraint(equalTo: panesField.centerYAnchor),
+            panesStepper.leadingAnchor.constraint(equalTo: panesField.trailingAnchor, constant: 8),
+
+            // Beside the pane limit, to keep the tab's height
+            widthLabel.topAnchor.constraint(equalTo: panesLabel.topAnchor),
+            widthLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor, constant: 240),
+
+            widthField.topAnchor.constraint(equalTo: widthLabel.bottomAnchor, constant: 6),
+            widthField.leadingAnchor.constraint(equalTo: widthLabel.leadingAnchor),
+
+            widthStepper.centerYAnchor.constraint(equalTo: widthField.centerYAnchor),
+            widthStepper.leadingAnchor.constraint(equalTo: widthField.trailingAnchor, constant: 8),
+
+            autoOpenCheckbox.topAnchor.constraint(equalTo: panesField.bottomAnchor, constant: 20),
+            autoOpenCheckbox.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            externalLabel.topAnchor.constraint(equalTo: autoOpenCheckbox.bottomAnchor, constant: 20),
+            externalLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            externalPopup.topAnchor.constraint(equalTo: externalLabel.bottomAnchor, constant: 6),
+            externalPopup.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+            externalPopup.widthAnchor.constraint(equalToConstant: 240),
         ])
 
         return container
     }
 
     @objc private func fontChanged(_ sender: NSPopUpButton) {
-        guard let name = sender.titleOfSelectedItem else { return }
+        guard let name = sender.selectedItem?.representedObject as? String else { return }
         AppSettingsManager.shared.terminalFontName = name
+        updateFontPreview()
     }
 
     @objc private func fontSizeStepperChanged(_ sender: NSStepper) {
         fontSizeField?.stringValue = "\(sender.integerValue)"
         AppSettingsManager.shared.terminalFontSize = CGFloat(sender.integerValue)
+        updateFontPreview()
+    }
+
+    @objc private func showFontPanel() {
+        let fontManager = NSFontManager.shared
+        fontManager.target = self
+        fontManager.setSelectedFont(currentTerminalFont(), isMultiple: false)
+        fontManager.orderFrontFontPanel(self)
+    }
+
+    func changeFont(_ sender: NSFontManager?) {
+        guard let sender else { return }
+        let font = sender.convert(currentTerminalFont())
+        let settings = AppSettingsManager.shared
+        settings.terminalFontName = font.familyName ?? font.fontName
+
+        let size = Int(font.pointSize.rounded())
+        if let stepper = fontSizeStepper {
+            stepper.integerValue = size
+            if stepper.integerValue != Int(settings.terminalFontSize) {
+                settings.terminalFontSize = CGFloat(stepper.integerValue)
+            }
+            fontSizeField?.stringValue = "\(stepper.integerValue)"
+        }
+        reloadFontPopup()
+        updateFontPreview()
+    }
+
+    func validModesForFontPanel(_ fontPanel: NSFontPanel) -> NSFontPanel.ModeMask {
+        [.collection, .face, .size]
+    }
+
+    private func currentTerminalFont() -> NSFont {
+        let settings = AppSettingsManager.shared
+        return NSFont(name: settings.terminalFontName, size: settings.terminalFontSize)
+            ?? .monospacedSystemFont(ofSize: settings.terminalFontSize, weight: .regular)
+    }
+
+    /// Monospace families, each drawn in its own face. A saved font outside that list
+    /// (picked via Other… or imported) is listed first so the popup doesn't misreport it.
+    private func reloadFontPopup() {
+        guard let popup = fontPopup else { return }
+        let current = AppSettingsManager.shared.terminalFontName
+        var families = monospaceFontFamilies()
+        if !families.contains(current) {
+            families.insert(current, at: 0)
+        }
+
+        popup.removeAllItems()
+        for family in families {
+            let item = NSMenuItem(title: family, action: nil, keyEquivalent: "")
+            item.representedObject = family
+            if let font = NSFont(name: family, size: 13) {
+                item.attributedTitle = NSAttributedString(string: family, attributes: [.font: font])
+            }
+            popup.menu?.addItem(item)
+        }
+        popup.selectItem(withTitle: current)
+    }
+
+    /// Sample text in the chosen font, or a warning when it can't be used as-is.
+    private func updateFontPreview() {
+        guard let label = fontPreviewLabel else { return }
+        let settings = AppSettingsManager.shared
+        guard let font = NSFont(name: settings.terminalFontName, size: settings.terminalFontSize) else {
+            label.font = .systemFont(ofSize: 11)
+            label.textColor = .systemRed
+            label.stringValue = "\"\(settings.terminalFontName)\" isn't installed — terminals use the system font."
+            return
+        }
+        if font.isFixedPitch {
+            label.font = font
+            label.textColor = .secondaryLabelColor
+            label.stringValue = "ppg spawn --name api -- 0O 1lI {}"
+        } else {
+            label.font = .systemFont(ofSize: 11)
+            label.textColor = .systemOrange
+            label.stringValue = "\"\(settings.terminalFontName)\" isn't monospaced — columns may not line up."
+        }
+    }
+
+    @objc private func maxPanesStepperChanged(_ sender: NSStepper) {
+        maxPanesField?.stringValue = "\(sender.integerValue)"
+        AppSettingsManager.shared.maxVisiblePanes = sender.integerValue
+    }
+
+    @objc private func minPaneWidthStepperChanged(_ sender: NSStepper) {
+        minPaneWidthField?.stringValue = "\(sender.integerValue) pt"
+        AppSettingsManager.shared.minPaneWidth = sender.integerValue
+    }
+
+    @objc private func externalTerminalChanged(_ sender: NSPopUpButton) {
+        AppSettingsManager.shared.preferredTerminal = sender.selectedItem?.representedObject as? String ?? ""
+    }
+
+    @objc private func autoOpenChanged(_ sender: NSButton) {
+        AppSettingsManager.shared.autoOpenSpawnedAgents = sender.state == .on
     }
 
     @objc private func shellChanged(_ sender: NSTextField) {
@@ -317,6 +641,119 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         }.sorted()
     }
 
+    // MARK: - Agents Tab
+
+    private func makeAgentsView() -> NSView {
+        let container = NSView()
+        let overrides = AppSettingsManager.shared.agentCommandOverrides
+        agentCommandFields = []
+
+        let hint = NSTextField(wrappingLabelWithString: "Command used to launch each agent. The prompt is added the same way as with the default command.")
+        hint.font = .systemFont(ofSize: 11)
+        hint.textColor = .secondaryLabelColor
+        hint.translatesAutoresizingMaskIntoConstraints = false
+        container.addSubview(hint)
+
+        var constraints: [NSLayoutConstraint] = [
+            hint.topAnchor.constraint(equalTo: container.topAnchor, constant: 8),
+            hint.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+            hint.trailingAnchor.constraint(equalTo: container.trailingAnchor),
+        ]
+        var previous: NSView = hint
+
+        for variant in AgentVariant.allVariants where variant.kind == .agent {
+            let label = makeLabel("\(variant.displayName):")
+
+            let field = NSTextField()
+            field.stringValue = overrides[variant.id] ?? variant.defaultCommand
+            field.placeholderString = variant.defaultCommand
+            field.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
+            field.target = self
+            field.action = #selector(agentCommandChanged(_:))
+
+            let revert = NSButton(title: "Revert to Default", target: self, action: #selector(revertAgentCommand(_:)))
+            revert.bezelStyle = .rounded
+            revert.isEnabled = overrides[variant.id] != nil
+
+            for v: NSView in [label, field, revert] {
+                v.translatesAutoresizingMaskIntoConstraints = false
+                container.addSubview(v)
+            }
+            constraints += [
+                label.topAnchor.constraint(equalTo: previous.bottomAnchor, constant: 20),
+                label.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+                field.topAnchor.constraint(equalTo: label.bottomAnchor, constant: 6),
+                field.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+                field.trailingAnchor.constraint(equalTo: revert.leadingAnchor, constant: -8),
+
+                revert.centerYAnchor.constraint(equalTo: field.centerYAnchor),
+                revert.trailingAnchor.constraint(equalTo: container.trailingAnchor),
+            ]
+            agentCommandFields.append((variant, field, revert))
+            previous = field
+        }
+
+        // Running agents that go quiet this long get a "stalled" marker
+        let stalledLabel = makeLabel("Mark Running Agents Stalled After:")
+        let stalledField = NSTextField(labelWithString: stalledMinutesText(AppSettingsManager.shared.stalledAgentMinutes))
+        stalledField.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
+        stalledField.textColor = Theme.primaryText
+        stalledMinutesField = stalledField
+
+        let stalledStepper = NSStepper()
+        stalledStepper.minValue = Double(AppSettingsManager.stalledAgentMinutesRange.lowerBound)
+        stalledStepper.maxValue = Double(AppSettingsManager.stalledAgentMinutesRange.upperBound)
+        stalledStepper.integerValue = AppSettingsManager.shared.stalledAgentMinutes
+        stalledStepper.increment = 5
+        stalledStepper.target = self
+        stalledStepper.action = #selector(stalledMinutesStepperChanged(_:))
+
+        for v: NSView in [stalledLabel, stalledField, stalledStepper] {
+            v.translatesAutoresizingMaskIntoConstraints = false
+            container.addSubview(v)
+        }
+        constraints += [
+            stalledLabel.topAnchor.constraint(equalTo: previous.bottomAnchor, constant: 28),
+            stalledLabel.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            stalledField.topAnchor.constraint(equalTo: stalledLabel.bottomAnchor, constant: 6),
+            stalledField.leadingAnchor.constraint(equalTo: container.leadingAnchor),
+
+            stalledStepper.centerYAnchor.constraint(equalTo: stalledField.centerYAnchor),
+            stalledStepper.leadingAnchor.constraint(equalTo: stalledField.trailingAnchor, constant: 8),
+        ]
+
+        NSLayoutConstraint.activate(constraints)
+        return container
+    }
+
+    @objc private func agentCommandChanged(_ sender: NSTextField) {
+        guard let row = agentCommandFields.first(where: { $0.field === sender }) else { return }
+        let value = sender.stringValue.trimmingCharacters(in: .whitespaces)
+        AppSettingsManager.shared.setAgentCommand(value, for: row.variant)
+        if value.isEmpty {
+            sender.stringValue = row.variant.defaultCommand
+        }
+        row.revert.isEnabled = AppSettingsManager.shared.agentCommandOverrides[row.variant.id] != nil
+    }
+
+    private func stalledMinutesText(_ minutes: Int) -> String {
+        "\(minutes) min without output"
+    }
+
+    @objc private func stalledMinutesStepperChanged(_ sender: NSStepper) {
+        stalledMinutesField?.stringValue = stalledMinutesText(sender.integerValue)
+        AppSettingsManager.shared.stalledAgentMinutes = sender.integerValue
+    }
+
+    @objc private func revertAgentCommand(_ sender: NSButton) {
+        guard let row = agentCommandFields.first(where: { $0.revert === sender }) else { return }
+        AppSettingsManager.shared.setAgentCommand(nil, for: row.variant)
+        row.field.stringValue = row.variant.defaultCommand
+        sender.isEnabled = false
+    }
+
     // MARK: - Shortcuts Tab
 
     private func makeShortcutsView() -> NSView {
@@ -393,6 +830,9 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         if let field = historyField {
             historyLimitChanged(field)
         }
+        for row in agentCommandFields {
+            agentCommandChanged(row.field)
+        }
     }
 
     // MARK: - Actions
@@ -404,6 +844,121 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         applyAndRefreshMenu()
     }
 
+    @objc private func exportSettings() {
+        cancelRecording()
+        commitTextFields()
+
+        let panel = NSSavePanel()
+        panel.allowedContentTypes = [.json]
+        panel.nameFieldStringValue = "ppg-settings.json"
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+
+        do {
+            try SettingsBundle.current().encoded().write(to: url, options: .atomic)
+        } catch {
+            showAlert("Couldn't Export Settings", error.localizedDescription)
+        }
+    }
+
+    @objc private func importSettings() {
+        cancelRecording()
+        commitTextFields()
+
+        let panel = NSOpenPanel()
+        panel.allowedContentTypes = [.json]
+        panel.allowsMultipleSelection = false
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+
+        let plan: SettingsImportPlan
+        do {
+            plan = try SettingsBundle.decode(Data(contentsOf: url)).importPlan()
+        } catch {
+            showAlert("Couldn't Import Settings", error.localizedDescription)
+            return
+        }
+
+        let changes = plan.changeSummary()
+        guard !changes.isEmpty else {
+            showAlert("Nothing to Import", "The file matches your current settings.")
+            return
+        }
+
+        let alert = NSAlert()
+        alert.messageText = "Import \(changes.count) Change\(changes.count == 1 ? "" : "s")?"
+        alert.informativeText = changes.joined(separator: "\n")
+        alert.addButton(withTitle: "Import")
+        alert.addButton(withTitle: "Cancel")
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        plan.apply()
+        reloadAllTabs()
+    }
+
+    // MARK: - Reset
+
+    @objc private func resetAppearanceAndTerminal() {
+        guard confirmReset(
+            "Reset Appearance & Terminal?",
+            "The appearance mode, compact sidebar rows, terminal font and size, shell, tmux history limit, and pane limit and minimum width go back to their defaults. Open terminals switch fonts right away; a new shell applies to terminals opened afterward."
+        ) else { return }
+        AppSettingsManager.shared.reset(AppSettingsManager.appearanceAndTerminalKeys)
+        reloadAllTabs()
+    }
+
+    @objc private func confirmResetShortcuts() {
+        guard confirmReset(
+            "Reset Shortcuts?",
+            "Every custom keyboard shortcut goes back to its default."
+        ) else { return }
+        resetAllDefaults()
+    }
+
+    @objc private func resetEverything() {
+        let alert = NSAlert()
+        alert.messageText = "Reset Everything?"
+        alert.informativeText = "All settings and keyboard shortcuts go back to their defaults, including fields kept from an imported settings file."
+        alert.alertStyle = .warning
+        alert.showsSuppressionButton = true
+        alert.suppressionButton?.title = "Also forget recent projects and the last selected item"
+        alert.addButton(withTitle: "Reset")
+        alert.addButton(withTitle: "Cancel")
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        cancelRecording()
+        AppSettingsManager.shared.resetAll()
+        KeybindingManager.shared.resetAll()
+        if alert.suppressionButton?.state == .on {
+            AppSettingsManager.shared.lastSelectedItemId = nil
+            RecentProjects.shared.clear()
+        }
+        reloadAllTabs()
+    }
+
+    private func confirmReset(_ title: String, _ message: String) -> Bool {
+        cancelRecording()
+        let alert = NSAlert()
+        alert.messageText = title
+        alert.informativeText = message
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "Reset")
+        alert.addButton(withTitle: "Cancel")
+        return alert.runModal() == .alertFirstButtonReturn
+    }
+
+    /// Rebuild the tabs so their controls show values changed behind their back.
+    private func reloadAllTabs() {
+        displayView = nil
+        terminalView = nil
+        agentsView = nil
+        // Drop the old fields so commitTextFields can't write their stale values back
+        shellField = nil
+        historyField = nil
+        agentCommandFields = []
+        tableView.reloadData()
+        showTab(segmentedControl.selectedSegment)
+        applyAndRefreshMenu()
+    }
+
     @objc private func dismissSettings() {
         cancelRecording()
         // commitTextFields + applyAndRefreshMenu handled by viewWillDisappear
@@ -429,6 +984,13 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
         sender.title = "Press key\u{2026}"
 
         eventMonitor = NSEvent.addLocalMonitorForEvents(matching: .keyDown) { [weak self] event in
+            // Plain Tab / Shift-Tab stop recording and move focus as usual, so keyboard
+            // users aren't stuck on the button.
+            let mods = event.modifierFlags.intersection([.command, .control])
+            if event.keyCode == 48, mods.isEmpty {
+                self?.cancelRecording()
+                return event
+            }
             self?.handleRecordedKey(event)
             return nil  // consume the event
         }
@@ -557,6 +1119,7 @@ class SettingsViewController: NSViewController, NSTableViewDataSource, NSTableVi
             button.tag = row
             button.target = self
             button.action = #selector(recordButtonClicked(_:))
+            button.setAccessibilityLabel("Record shortcut for \(action.displayName)")
             button.translatesAutoresizingMaskIntoConstraints = false
 
             if action == .quit {
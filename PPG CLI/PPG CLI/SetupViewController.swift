@@ -1,14 +1,30 @@
 import AppKit
 
-/// Onboarding screen shown when ppg CLI or tmux is not detected.
+/// Onboarding screen shown when ppg CLI or tmux is not detected or too old.
 /// Guides the user through installing the required dependencies.
 class SetupViewController: NSViewController {
     var onReady: (() -> Void)?
+    /// Called by "Skip Checks"; the caller proceeds as if every check passed.
+    var onSkip: (() -> Void)?
+    /// Set when the checks are shown over the dashboard; adds a Close button that calls it.
+    var onClose: (() -> Void)?
 
     private var ppgStatus: StatusRow!
     private var tmuxStatus: StatusRow!
     private var continueButton: NSButton!
 
+    /// Re-runs the checks while setup is on screen, so installing tmux in a
+    /// terminal is picked up without clicking Re-check.
+    private var recheckTimer: Timer?
+    private var isChecking = false
+    /// Detected once per setup session; a newly installed manager rarely matters mid-setup.
+    private var packageManagers: Set<PackageManager>?
+    private var allChecksPassed = false
+    private var autoContinueTimer: Timer?
+    private var autoContinueRemaining = 0
+    private static let recheckInterval: TimeInterval = 3
+    private static let autoContinueSeconds = 3
+
     override func loadView() {
         let root = ThemeAwareView()
         root.onAppearanceChanged = { [weak self] in
@@ -55,14 +71,14 @@ class SetupViewController: NSViewController {
         // Status rows
         ppgStatus = StatusRow(
             title: "ppg CLI",
-            installHint: "npm install -g pure-point-guard"
+            installHints: ["npm install -g pure-point-guard"]
         )
         ppgStatus.translatesAutoresizingMaskIntoConstraints = false
         card.addSubview(ppgStatus)
 
         tmuxStatus = StatusRow(
             title: "tmux",
-            installHint: "brew install tmux"
+            installHints: ["brew install tmux"]
         )
         tmuxStatus.translatesAutoresizingMaskIntoConstraints = false
         card.addSubview(tmuxStatus)
@@ -80,7 +96,23 @@ class SetupViewController: NSViewController {
         continueButton.isEnabled = false
         continueButton.translatesAutoresizingMaskIntoConstraints = false
 
-        let buttonStack = NSStackView(views: [recheckButton, NSView(), continueButton])
+        // For setups the checks can't see, e.g. ppg installed somewhere the login shell doesn't put on PATH
+        let skipButton = NSButton(title: "Skip Checks", target: self, action: #selector(skipClicked(_:)))
+        skipButton.isBordered = false
+        skipButton.contentTintColor = .linkColor
+        skipButton.toolTip = "Open the app without ppg and tmux checks on this and future launches. Run them again from the PPG CLI menu."
+        skipButton.translatesAutoresizingMaskIntoConstraints = false
+
+        var buttons: [NSView] = [recheckButton, skipButton, NSView()]
+        if onClose != nil {
+            let closeButton = NSButton(title: "Close", target: self, action: #selector(closeClicked(_:)))
+            closeButton.bezelStyle = .rounded
+            closeButton.controlSize = .large
+            closeButton.keyEquivalent = "\u{1b}"
+            buttons.append(closeButton)
+        }
+        buttons.append(continueButton)
+        let buttonStack = NSStackView(views: buttons)
         buttonStack.orientation = .horizontal
         buttonStack.translatesAutoresizingMaskIntoConstraints = false
         card.addSubview(buttonStack)
@@ -128,27 +160,58 @@ class SetupViewController: NSViewController {
         // Make window fully opaque so setup text is readable
         view.window?.isOpaque = true
         view.window?.backgroundColor = Theme.contentBackground
+
+        recheckTimer?.invalidate()
+        recheckTimer = Timer.scheduledTimer(withTimeInterval: Self.recheckInterval, repeats: true) { [weak self] _ in
+            guard let self, !self.allChecksPassed else { return }
+            self.runChecks()
+        }
     }
 
     override func viewWillDisappear() {
         super.viewWillDisappear()
+        recheckTimer?.invalidate()
+        recheckTimer = nil
+        cancelAutoContinue()
         // Restore transparency for other screens
         view.window?.isOpaque = false
         view.window?.backgroundColor = Theme.chromeBackground
     }
 
     private func runChecks() {
+        // A slow shell profile can make one pass outlast the timer interval
+        guard !isChecking else { return }
+        isChecking = true
+        let knownManagers = packageManagers
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
             let cli = PPGService.shared.checkCLIAvailable()
             let tmux = PPGService.shared.checkTmuxAvailable()
+            let managers = knownManagers ?? PPGService.shared.detectPackageManagers()
 
             DispatchQueue.main.async {
+                if knownManagers == nil {
+                    self?.packageManagers = managers
+                    self?.ppgStatus.installHints = ppgInstallHints(available: managers)
+                    self?.tmuxStatus.installHints = tmuxInstallHints(available: managers)
+                }
+                let cliInstalledAndSupported = cli.available && cli.supported
+                let cliDetail = cli.version.map { version in
+                    cli.supported ? version : "Found \(version) — needs ≥ \(PPGService.minimumCLIVersion)"
+                }
+                let cliIssueHint = (cli.available && !cli.supported)
+                    ? "npm install -g pure-point-guard@latest"
+                    : nil
                 self?.ppgStatus.setStatus(
-                    installed: cli.available,
-                    detail: cli.version
+                    installed: cliInstalledAndSupported,
+                    detail: cliDetail,
+                    issueHint: cliIssueHint
                 )
                 let tmuxInstalledAndSupported = tmux.available && tmux.supportsCodexInputTheme
-                let tmuxDetail = tmux.version.map { "tmux \($0)" }
+                let tmuxDetail = tmux.version.map { version in
+                    tmux.supportsCodexInputTheme
+                        ? "tmux \(version)"
+                        : "Found tmux \(version) — needs ≥ \(PPGService.minimumTmuxVersionForCodexInputTheme)"
+                }
                 let tmuxIssueHint = (tmux.available && !tmux.supportsCodexInputTheme)
                     ? "Update to tmux \(PPGService.minimumTmuxVersionForCodexInputTheme)+ for Codex input theming."
                     : nil
@@ -157,12 +220,63 @@ class SetupViewController: NSViewController {
                     detail: tmuxDetail,
                     issueHint: tmuxIssueHint
                 )
-                self?.continueButton.isEnabled = cli.available && tmuxInstalledAndSupported
+                self?.finishChecks(allPassed: cliInstalledAndSupported && tmuxInstalledAndSupported)
+            }
+        }
+    }
+
+    private func finishChecks(allPassed: Bool) {
+        isChecking = false
+        let newlyPassed = allPassed && !allChecksPassed
+        allChecksPassed = allPassed
+        continueButton.isEnabled = allPassed
+        guard newlyPassed else { return }
+
+        flashContinueButton()
+        if AppSettingsManager.shared.autoContinueSetup {
+            startAutoContinue()
+        }
+    }
+
+    private func flashContinueButton() {
+        for (index, highlighted) in [true, false, true, false].enumerated() {
+            DispatchQueue.main.asyncAfter(deadline: .now() + 0.15 * Double(index)) { [weak self] in
+                self?.continueButton.highlight(highlighted)
             }
         }
     }
 
+    // MARK: - Auto-continue
+
+    private func startAutoContinue() {
+        autoContinueRemaining = Self.autoContinueSeconds
+        updateAutoContinueTitle()
+        autoContinueTimer = Timer.scheduledTimer(withTimeInterval: 1, repeats: true) { [weak self] _ in
+            guard let self else { return }
+            self.autoContinueRemaining -= 1
+            if self.autoContinueRemaining <= 0 {
+                self.cancelAutoContinue()
+                self.onReady?()
+            } else {
+                self.updateAutoContinueTitle()
+            }
+        }
+    }
+
+    private func updateAutoContinueTitle() {
+        continueButton.title = "Continue (\(autoContinueRemaining))"
+    }
+
+    private func cancelAutoContinue() {
+        autoContinueTimer?.invalidate()
+        autoContinueTimer = nil
+        continueButton.title = "Continue"
+    }
+
     @objc private func recheckClicked(_ sender: Any) {
+        guard !isChecking else { return }
+        cancelAutoContinue()
+        allChecksPassed = false
         ppgStatus.setChecking()
         tmuxStatus.setChecking()
         continueButton.isEnabled = false
@@ -170,8 +284,20 @@ class SetupViewController: NSViewController {
     }
 
     @objc private func continueClicked(_ sender: Any) {
+        cancelAutoContinue()
         onReady?()
     }
+
+    @objc private func closeClicked(_ sender: Any) {
+        cancelAutoContinue()
+        onClose?()
+    }
+
+    @objc private func skipClicked(_ sender: Any) {
+        cancelAutoContinue()
+        AppSettingsManager.shared.skipSetupChecks = true
+        onSkip?()
+    }
 }
 
 // MARK: - StatusRow
@@ -180,13 +306,23 @@ private class StatusRow: NSView {
     private let statusIcon = NSImageView()
     private let titleField: NSTextField
     private let detailField = NSTextField(labelWithString: "")
-    private let hintField: NSTextField
-    private let installHint: String
+    private let hintField = NSTextField(labelWithString: "")
+    private let copyButton = NSButton()
+    private let runButton = NSButton()
+    /// Install commands for this tool, preferred first. All are shown; Copy takes the first.
+    var installHints: [String] {
+        didSet { if lastResult == .missing { showInstallHints() } }
+    }
+    /// Last non-checking result, so a change found by a background re-check can be animated.
+    private var lastResult: Result?
+
+    private enum Result {
+        case installed, unsupported, missing
+    }
 
-    init(title: String, installHint: String) {
+    init(title: String, installHints: [String]) {
         titleField = NSTextField(labelWithString: title)
-        self.installHint = installHint
-        hintField = NSTextField(labelWithString: installHint)
+        self.installHints = installHints
         super.init(frame: .zero)
 
         wantsLayer = true
@@ -194,6 +330,7 @@ private class StatusRow: NSView {
         layer?.borderWidth = 1
         layer?.borderColor = NSColor.separatorColor.resolvedCGColor(for: effectiveAppearance)
 
+        statusIcon.wantsLayer = true
         statusIcon.translatesAutoresizingMaskIntoConstraints = false
         addSubview(statusIcon)
 
@@ -210,9 +347,28 @@ private class StatusRow: NSView {
         hintField.font = .monospacedSystemFont(ofSize: 12, weight: .regular)
         hintField.textColor = .secondaryLabelColor
         hintField.isSelectable = true
+        hintField.lineBreakMode = .byTruncatingTail
         hintField.translatesAutoresizingMaskIntoConstraints = false
         addSubview(hintField)
 
+        copyButton.image = NSImage(systemSymbolName: "doc.on.doc", accessibilityDescription: "Copy install command")
+        copyButton.bezelStyle = .accessoryBarAction
+        copyButton.isBordered = false
+        copyButton.toolTip = "Copy install command"
+        copyButton.target = self
+        copyButton.action = #selector(copyInstallCommand)
+        copyButton.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(copyButton)
+
+        runButton.image = NSImage(systemSymbolName: "terminal", accessibilityDescription: "Run install command in a terminal")
+        runButton.bezelStyle = .accessoryBarAction
+        runButton.isBordered = false
+        runButton.toolTip = "Run install command in a terminal"
+        runButton.target = self
+        runButton.action = #selector(runInstallCommand)
+        runButton.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(runButton)
+
         NSLayoutConstraint.activate([
             heightAnchor.constraint(equalToConstant: 56),
 
@@ -229,6 +385,13 @@ private class StatusRow: NSView {
 
             hintField.leadingAnchor.constraint(equalTo: titleField.leadingAnchor),
             hintField.topAnchor.constraint(equalTo: titleField.bottomAnchor, constant: 2),
+            hintField.trailingAnchor.constraint(lessThanOrEqualTo: runButton.leadingAnchor, constant: -8),
+
+            runButton.trailingAnchor.constraint(equalTo: copyButton.leadingAnchor, constant: -4),
+            runButton.centerYAnchor.constraint(equalTo: hintField.centerYAnchor),
+
+            copyButton.trailingAnchor.constraint(equalTo: trailingAnchor, constant: -12),
+            copyButton.centerYAnchor.constraint(equalTo: hintField.centerYAnchor),
         ])
 
         setChecking()
@@ -245,27 +408,106 @@ private class StatusRow: NSView {
         statusIcon.image = NSImage(systemSymbolName: "circle.dashed", accessibilityDescription: "Checking")
         statusIcon.contentTintColor = .tertiaryLabelColor
         detailField.stringValue = "Checking…"
+        detailField.textColor = .tertiaryLabelColor
         hintField.isHidden = true
+        copyButton.isHidden = true
+        runButton.isHidden = true
     }
 
     func setStatus(installed: Bool, detail: String?, issueHint: String? = nil) {
+        let result: Result = installed ? .installed : (issueHint != nil ? .unsupported : .missing)
+        if let lastResult, lastResult != result {
+            let fade = CATransition()
+            fade.type = .fade
+            fade.duration = 0.4
+            statusIcon.layer?.add(fade, forKey: "statusChange")
+        }
+        lastResult = result
+
         if installed {
             statusIcon.image = NSImage(systemSymbolName: "checkmark.circle.fill", accessibilityDescription: "Installed")
             statusIcon.contentTintColor = .systemGreen
             detailField.stringValue = detail ?? "Installed"
+            detailField.textColor = .tertiaryLabelColor
             hintField.isHidden = true
+            copyButton.isHidden = true
+            runButton.isHidden = true
         } else if let issueHint {
             statusIcon.image = NSImage(systemSymbolName: "exclamationmark.triangle.fill", accessibilityDescription: "Unsupported version")
             statusIcon.contentTintColor = .systemOrange
             detailField.stringValue = detail ?? "Installed"
+            detailField.textColor = .systemOrange
             hintField.stringValue = issueHint
             hintField.isHidden = false
+            copyButton.isHidden = true
+            runButton.isHidden = true
         } else {
             statusIcon.image = NSImage(systemSymbolName: "xmark.circle.fill", accessibilityDescription: "Not found")
             statusIcon.contentTintColor = .systemRed
             detailField.stringValue = "Not found"
-            hintField.stringValue = installHint
-            hintField.isHidden = false
+            detailField.textColor = .tertiaryLabelColor
+            showInstallHints()
+        }
+    }
+
+    private func showInstallHints() {
+        hintField.stringValue = installHints.joined(separator: "  or  ")
+        hintField.isHidden = false
+        copyButton.isHidden = installHints.isEmpty
+        runButton.isHidden = installHints.isEmpty
+    }
+
+    @objc private func copyInstallCommand() {
+        guard let command = installHints.first else { return }
+        NSPasteboard.general.clearContents()
+        NSPasteboard.general.setString(command, forType: .string)
+    }
+
+    /// Open the preferred install command in the user's terminal once they've confirmed it;
+    /// the background re-check picks up the result once it finishes. Falls back to copying it.
+    @objc private func runInstallCommand() {
+        guard let command = installHints.first else { return }
+        let terminal = ExternalTerminal.detect(preferred: AppSettingsManager.shared.preferredTerminal)
+
+        let confirm = NSAlert()
+        confirm.messageText = "Run Install Command?"
+        confirm.informativeText = "This opens \(terminal.displayName) and runs:\n\n\(command)"
+            + (command.hasPrefix("sudo ") ? "\n\nIt will ask for your password." : "")
+        confirm.alertStyle = .informational
+        confirm.addButton(withTitle: "Run in \(terminal.displayName)")
+        confirm.addButton(withTitle: "Cancel")
+        if let window {
+            confirm.beginSheetModal(for: window) { [weak self] response in
+                guard response == .alertFirstButtonReturn else { return }
+                self?.open(command, in: terminal)
+            }
+        } else if confirm.runModal() == .alertFirstButtonReturn {
+            open(command, in: terminal)
+        }
+    }
+
+    private func open(_ command: String, in terminal: ExternalTerminal) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let failure: String
+            do {
+                try terminal.open(command: command)
+                return
+            } catch {
+                failure = error.localizedDescription
+            }
+            DispatchQueue.main.async {
+                self?.copyInstallCommand()
+                let alert = NSAlert()
+                alert.messageText = "Install Command Copied"
+                alert.informativeText = "\(failure) Paste the copied command into any terminal:\n\n\(command)"
+                alert.alertStyle = .informational
+                alert.addButton(withTitle: "OK")
+                if let window = self?.window {
+                    alert.beginSheetModal(for: window)
+                } else {
+                    alert.runModal()
+                }
+            }
         }
     }
 }
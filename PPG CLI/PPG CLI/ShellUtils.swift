@@ -1,8 +1,29 @@
 import Foundation
 
-/// Shell-escape a string by wrapping in single quotes and escaping embedded single quotes.
+/// Quote a string as a single shell word. Single quotes keep `;`, `$`, backslashes and
+/// spaces literal. Strings with control characters (newline, tab, escape…) use ANSI-C
+/// `$'…'` quoting instead so the word stays on one line, which matters for commands
+/// typed into a terminal. NUL can't appear in an argument and is dropped.
 nonisolated func shellEscape(_ s: String) -> String {
-    "'" + s.replacingOccurrences(of: "'", with: "'\\''") + "'"
+    let s = s.replacingOccurrences(of: "\0", with: "")
+    guard s.unicodeScalars.contains(where: { $0.properties.generalCategory == .control }) else {
+        return "'" + s.replacingOccurrences(of: "'", with: "'\\''") + "'"
+    }
+    var quoted = "$'"
+    for scalar in s.unicodeScalars {
+        switch scalar {
+        case "\n": quoted += "\\n"
+        case "\t": quoted += "\\t"
+        case "\r": quoted += "\\r"
+        case "\\": quoted += "\\\\"
+        case "'": quoted += "\\'"
+        case _ where scalar.properties.generalCategory == .control:
+            quoted += scalar.value < 0x80 ? String(format: "\\x%02x", scalar.value) : String(format: "\\u%04x", scalar.value)
+        default:
+            quoted.unicodeScalars.append(scalar)
+        }
+    }
+    return quoted + "'"
 }
 
 /// Returns a shell init script that sources the appropriate profile files for the given shell.
@@ -31,3 +52,263 @@ nonisolated func shellProfileScript(for shellPath: String) -> String {
         return ""
     }
 }
+
+/// A tmux target as the CLI writes it: "session", "session:window", or
+/// "session:window.pane". Window names can contain dots ("ppg-proj:auth-fix.0"),
+/// so only a trailing all-digit component is read as the pane index.
+nonisolated struct TmuxTarget: Equatable {
+    let session: String
+    let window: String?
+    let pane: String?
+
+    init(session: String, window: String? = nil, pane: String? = nil) {
+        self.session = session
+        self.window = window
+        self.pane = pane
+    }
+
+    init(parsing target: String) {
+        guard let colonIdx = target.firstIndex(of: ":") else {
+            self.init(session: target)
+            return
+        }
+        let session = String(target[..<colonIdx])
+        let spec = String(target[target.index(after: colonIdx)...])
+        guard !spec.isEmpty else {
+            self.init(session: session)
+            return
+        }
+        if let dotIdx = spec.lastIndex(of: "."), dotIdx != spec.startIndex {
+            let pane = String(spec[spec.index(after: dotIdx)...])
+            if !pane.isEmpty, pane.allSatisfy(\.isASCII), pane.allSatisfy(\.isNumber) {
+                self.init(session: session, window: String(spec[..<dotIdx]), pane: pane)
+                return
+            }
+        }
+        self.init(session: session, window: spec)
+    }
+
+    /// "session:window", or just the session when there's no window.
+    var windowTarget: String {
+        window.map { "\(session):\($0)" } ?? session
+    }
+
+    /// "session:window.pane", or `windowTarget` when there's no pane.
+    var paneTarget: String {
+        guard let window, let pane else { return windowTarget }
+        return "\(session):\(window).\(pane)"
+    }
+}
+
+/// Sessions and windows on a tmux server, from `list-windows -a`. Used to check that a
+/// manifest's tmux targets still exist before attaching to them.
+nonisolated struct TmuxSnapshot: Equatable {
+    struct Window: Equatable {
+        let index: String
+        let name: String
+    }
+
+    /// Windows keyed by session name.
+    let windows: [String: [Window]]
+
+    static let listWindowsFormat = "#{session_name}\t#{window_index}\t#{window_name}"
+
+    var sessions: [String] { windows.keys.sorted() }
+
+    /// Whether `target`'s session, and its window if it names one (by index or name), exists.
+    /// The pane isn't checked; a window only exists while it has at least one pane.
+    func contains(_ target: TmuxTarget) -> Bool {
+        guard let sessionWindows = windows[target.session] else { return false }
+        guard let window = target.window else { return true }
+        return sessionWindows.contains { $0.index == window || $0.name == window }
+    }
+
+    func containsWindow(session: String, window: String) -> Bool {
+        contains(TmuxTarget(session: session, window: window))
+    }
+
+    /// Parse the result of `tmux list-windows -a -F listWindowsFormat`. When no server is
+    /// running tmux exits non-zero; that's an empty snapshot, since every session is gone.
+    /// Nil for any other failure (tmux missing, unexpected error), when nothing is known.
+    static func parse(exitCode: Int32, stdout: String, stderr: String) -> TmuxSnapshot? {
+        guard exitCode == 0 else {
+            let noServer = stderr.contains("no server running") || stderr.contains("error connecting to")
+            return noServer ? TmuxSnapshot(windows: [:]) : nil
+        }
+        var windows: [String: [Window]] = [:]
+        for line in stdout.split(whereSeparator: \.isNewline) {
+            let fields = line.split(separator: "\t", maxSplits: 2, omittingEmptySubsequences: false)
+            guard fields.count == 3 else { continue }
+            windows[String(fields[0]), default: []].append(Window(index: String(fields[1]), name: String(fields[2])))
+        }
+        return TmuxSnapshot(windows: windows)
+    }
+}
+
+/// When each tmux window last printed anything, from `#{window_activity}`. tmux tracks
+/// output per window, so agents sharing a window in a split share one time.
+nonisolated struct TmuxWindowActivity: Equatable {
+    /// Keyed by "session:index" and "session:name", so either form of target matches.
+    let lastOutput: [String: Date]
+
+    static let listWindowsFormat = "#{session_name}\t#{window_index}\t#{window_activity}\t#{window_name}"
+
+    func lastOutput(of target: TmuxTarget) -> Date? {
+        guard target.window != nil else { return nil }
+        return lastOutput[target.windowTarget]
+    }
+
+    /// Parse `tmux list-windows -a -F listWindowsFormat`; nil when tmux failed.
+    static func parse(exitCode: Int32, stdout: String) -> TmuxWindowActivity? {
+        guard exitCode == 0 else { return nil }
+        var lastOutput: [String: Date] = [:]
+        for line in stdout.split(whereSeparator: \.isNewline) {
+            let fields = line.split(separator: "\t", maxSplits: 3, omittingEmptySubsequences: false)
+            guard fields.count == 4, let seconds = TimeInterval(fields[2]) else { continue }
+            let date = Date(timeIntervalSince1970: seconds)
+            lastOutput["\(fields[0]):\(fields[1])"] = date
+            lastOutput["\(fields[0]):\(fields[3])"] = date
+        }
+        return TmuxWindowActivity(lastOutput: lastOutput)
+    }
+}
+
+/// Shell command that attaches a user's own terminal to a tmux target ("session:window"
+/// or a bare session). Used when handing a window off to an external terminal.
+nonisolated func tmuxAttachShellCommand(target: String) -> String {
+    let parsed = TmuxTarget(parsing: target)
+    var command = TmuxCommand("attach-session").option("-t", parsed.session)
+    if parsed.window != nil {
+        command = command.then("select-window").option("-t", target)
+    }
+    return command.shellString
+}
+
+/// Like `tmuxAttachShellCommand`, but for an agent's full "session:window.pane" target:
+/// selects the window, then the agent's pane, so input goes to that agent rather than
+/// whichever pane of a shared window was last active. `unsynchronizePanes` also turns
+/// off synchronize-panes on the window, which would otherwise copy input to every pane.
+nonisolated func tmuxAttachPaneShellCommand(target: String, unsynchronizePanes: Bool = false) -> String {
+    let parsed = TmuxTarget(parsing: target)
+    guard parsed.window != nil else { return tmuxAttachShellCommand(target: target) }
+    var command = TmuxCommand("attach-session").option("-t", parsed.session)
+        .then("select-window").option("-t", parsed.windowTarget)
+    if unsynchronizePanes {
+        command = command.then("set-window-option").option("-t", parsed.windowTarget).arguments("synchronize-panes", "off")
+    }
+    if parsed.pane != nil {
+        command = command.then("select-pane").option("-t", parsed.paneTarget)
+    }
+    return command.shellString
+}
+
+/// A tmux invocation of one or more commands, kept as separate arguments until it's
+/// rendered, so names containing `;`, quotes or leading dashes can't change its meaning.
+///
+///     TmuxCommand("attach-session").option("-t", session)
+///         .then("select-window").option("-t", target)
+///
+/// Command names and option flags are written by the caller and used as-is; option
+/// values and positional arguments are treated as untrusted data.
+nonisolated struct TmuxCommand {
+    private struct Step {
+        let name: String
+        var options: [(flag: String, value: String?)] = []
+        var arguments: [String] = []
+
+        /// A positional starting with "-" would be read as a flag, so "--" ends the options first.
+        var needsOptionTerminator: Bool {
+            arguments.contains { $0.hasPrefix("-") }
+        }
+    }
+
+    private var steps: [Step]
+
+    init(_ name: String) {
+        steps = [Step(name: name)]
+    }
+
+    /// Start another command in the same invocation (tmux's `;` separator).
+    func then(_ name: String) -> TmuxCommand {
+        var copy = self
+        copy.steps.append(Step(name: name))
+        return copy
+    }
+
+    /// A flag with a value, such as `-t target`. The value may start with a dash.
+    func option(_ flag: String, _ value: String) -> TmuxCommand {
+        updatingLast { $0.options.append((flag, value)) }
+    }
+
+    /// A flag without a value, such as `-d` or `-l`.
+    func flag(_ flag: String) -> TmuxCommand {
+        updatingLast { $0.options.append((flag, nil)) }
+    }
+
+    /// Positional arguments, such as the keys for `send-keys`.
+    func arguments(_ values: String...) -> TmuxCommand {
+        updatingLast { $0.arguments.append(contentsOf: values) }
+    }
+
+    /// Start a `set-option` command for each option `features` needs to turn the mouse on.
+    /// `target` nil applies them to the session the command runs in.
+    func thenEnablingMouse(target: String? = nil, features: TmuxFeatures) -> TmuxCommand {
+        var command = self
+        for name in features.mouseOptionNames {
+            command = command.then("set-option")
+            if let target { command = command.option("-t", target) }
+            command = command.arguments(name, "on")
+        }
+        return command
+    }
+
+    private func updatingLast(_ change: (inout Step) -> Void) -> TmuxCommand {
+        var copy = self
+        change(&copy.steps[copy.steps.count - 1])
+        return copy
+    }
+
+    // MARK: - Rendering
+
+    /// Arguments for spawning tmux directly, starting with "tmux". Commands are split by
+    /// a lone ";" argument. tmux also reads a trailing ";" on any argument as a split,
+    /// so data ending in ";" has it escaped.
+    var argv: [String] {
+        var argv = ["tmux"]
+        for (index, step) in steps.enumerated() {
+            if index > 0 { argv.append(";") }
+            argv.append(step.name)
+            for option in step.options {
+                argv.append(option.flag)
+                if let value = option.value { argv.append(Self.escapedSeparator(value)) }
+            }
+            if step.needsOptionTerminator { argv.append("--") }
+            argv.append(contentsOf: step.arguments.map(Self.escapedSeparator))
+        }
+        return argv
+    }
+
+    /// The same invocation as one shell command line, with every value quoted by
+    /// `shellEscape` and commands split by `\;`.
+    var shellString: String {
+        var words = ["tmux"]
+        for (index, step) in steps.enumerated() {
+            if index > 0 { words.append("\\;") }
+            words.append(step.name)
+            for option in step.options {
+                words.append(option.flag)
+                if let value = option.value { words.append(shellEscape(Self.escapedSeparator(value))) }
+            }
+            if step.needsOptionTerminator { words.append("--") }
+            words.append(contentsOf: step.arguments.map { shellEscape(Self.escapedSeparator($0)) })
+        }
+        return words.joined(separator: " ")
+    }
+
+    /// tmux turns a trailing "\\;" back into ";", so this also round-trips data that
+    /// already ends in a backslash and semicolon.
+    private static func escapedSeparator(_ value: String) -> String {
+        guard value.hasSuffix(";") else { return value }
+        return String(value.dropLast()) + "\\;"
+    }
+}
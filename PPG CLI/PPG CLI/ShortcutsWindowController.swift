@@ -0,0 +1,85 @@
+import AppKit
+
+/// Cheat sheet of every rebindable action, grouped by `BindableAction.Category`.
+/// Built from KeybindingManager each time it's shown, so custom bindings appear
+/// as the user set them rather than as the defaults.
+final class ShortcutsWindowController: NSWindowController {
+    private let stack = NSStackView()
+
+    init() {
+        let window = NSWindow(
+            contentRect: NSRect(x: 0, y: 0, width: 420, height: 560),
+            styleMask: [.titled, .closable, .resizable],
+            backing: .buffered,
+            defer: false
+        )
+        window.title = "Keyboard Shortcuts"
+        window.isReleasedWhenClosed = false
+        window.center()
+        super.init(window: window)
+
+        stack.orientation = .vertical
+        stack.alignment = .leading
+        stack.spacing = 6
+        stack.edgeInsets = NSEdgeInsets(top: 16, left: 20, bottom: 20, right: 20)
+        stack.translatesAutoresizingMaskIntoConstraints = false
+
+        let scrollView = NSScrollView()
+        scrollView.hasVerticalScroller = true
+        scrollView.drawsBackground = false
+        scrollView.documentView = stack
+        window.contentView = scrollView
+
+        NSLayoutConstraint.activate([
+            stack.leadingAnchor.constraint(equalTo: scrollView.contentView.leadingAnchor),
+            stack.trailingAnchor.constraint(equalTo: scrollView.contentView.trailingAnchor),
+            stack.topAnchor.constraint(equalTo: scrollView.contentView.topAnchor),
+        ])
+    }
+
+    required init?(coder: NSCoder) { fatalError() }
+
+    override func showWindow(_ sender: Any?) {
+        rebuild()
+        super.showWindow(sender)
+    }
+
+    private func rebuild() {
+        stack.arrangedSubviews.forEach { $0.removeFromSuperview() }
+        let keybindings = KeybindingManager.shared
+
+        for category in BindableAction.Category.allCases {
+            let actions = BindableAction.allCases.filter { $0.category == category }
+            guard !actions.isEmpty else { continue }
+
+            let header = NSTextField(labelWithString: category.rawValue)
+            header.font = .systemFont(ofSize: 13, weight: .semibold)
+            header.textColor = Theme.primaryText
+            if let previous = stack.arrangedSubviews.last {
+                stack.setCustomSpacing(18, after: previous)
+            }
+            stack.addArrangedSubview(header)
+
+            for action in actions {
+                let row = makeRow(action.displayName, keybindings.displayString(for: action))
+                stack.addArrangedSubview(row)
+                row.widthAnchor.constraint(equalTo: stack.widthAnchor, constant: -40).isActive = true
+            }
+        }
+    }
+
+    private func makeRow(_ name: String, _ shortcut: String) -> NSView {
+        let nameLabel = NSTextField(labelWithString: name)
+        nameLabel.font = .systemFont(ofSize: 13)
+        nameLabel.textColor = Theme.primaryText
+
+        let shortcutLabel = NSTextField(labelWithString: shortcut)
+        shortcutLabel.font = .monospacedSystemFont(ofSize: 13, weight: .regular)
+        shortcutLabel.textColor = .secondaryLabelColor
+        shortcutLabel.alignment = .right
+
+        let row = NSStackView(views: [nameLabel, NSView(), shortcutLabel])
+        row.orientation = .horizontal
+        return row
+    }
+}
@@ -0,0 +1,54 @@
+import Foundation
+
+/// Which agents the sidebar lists, picked with the chips above it. Chips of one kind
+/// widen the filter (claude or codex); the two kinds narrow each other (claude and
+/// failed). Empty means everything is shown.
+nonisolated struct SidebarFilter: Equatable {
+    /// Statuses offered as chips: the CLI's running and gone, and its finished agents
+    /// split by exit code so "only failed" is one click. Idle agents read as completed.
+    static let statusChips: [AgentStatus] = [.running, .completed, .failed, .lost]
+
+    /// Chip and summary name for a status, in the CLI's words where it has one.
+    static func title(for status: AgentStatus) -> String {
+        switch status {
+        case .lost: return "gone"
+        default: return status.rawValue
+        }
+    }
+
+    var agentTypes: Set<String> = []
+    var statuses: Set<AgentStatus> = []
+
+    var isActive: Bool { !agentTypes.isEmpty || !statuses.isEmpty }
+
+    func matches(_ agent: AgentModel) -> Bool {
+        (agentTypes.isEmpty || agentTypes.contains(agent.agentType))
+            && (statuses.isEmpty || statuses.contains(agent.status))
+    }
+
+    mutating func toggle(agentType: String) {
+        if agentTypes.remove(agentType) == nil { agentTypes.insert(agentType) }
+    }
+
+    mutating func toggle(status: AgentStatus) {
+        if statuses.remove(status) == nil { statuses.insert(status) }
+    }
+
+    /// Drop agent types no longer in any manifest, so a chip that went away can't keep
+    /// hiding everything.
+    mutating func keepAgentTypes(in available: [String]) {
+        agentTypes.formIntersection(available)
+    }
+
+    /// "claude · codex · failed", types first, each kind in chip order.
+    var summary: String {
+        let types = agentTypes.sorted()
+        let statusNames = Self.statusChips.filter { statuses.contains($0) }.map(Self.title(for:))
+        return (types + statusNames).joined(separator: " · ")
+    }
+
+    /// Agent types in the worktrees, sorted, for the type chips.
+    static func agentTypes(in worktrees: [WorktreeModel]) -> [String] {
+        Set(worktrees.flatMap { $0.agents.map(\.agentType) }).filter { !$0.isEmpty }.sorted()
+    }
+}
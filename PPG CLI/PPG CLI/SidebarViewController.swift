@@ -17,7 +17,8 @@ enum SidebarItem {
 
     var id: String {
         switch self {
-        case .project(let ctx): return "project-\(ctx.projectRoot.hashValue)"
+        // Persisted as the last selection, so it must not use hashValue (seeded per launch)
+        case .project(let ctx): return "project:\(ctx.projectRoot)"
         case .worktree(let wt): return wt.id
         case .agent(let ag): return ag.id
         case .agentGroup(_, let windowKey): return "group-\(windowKey)"
@@ -35,9 +36,9 @@ enum SidebarItem {
         case .worktree(let wt):
             return "\(wt.name)|\(wt.branch)|\(wt.status)|\(wt.agents.count)"
         case .agent(let ag):
-            return "\(ag.name)|\(ag.agentType)|\(ag.status.rawValue)"
+            return "\(ag.name)|\(ag.agentType)|\(ag.statusLabel)"
         case .agentGroup(let agents, _):
-            return agents.map { "\($0.id):\($0.status.rawValue)" }.joined(separator: ",")
+            return agents.map { "\($0.id):\($0.statusLabel)" }.joined(separator: ",")
         case .terminal(let te):
             return "\(te.label)|\(te.kind.rawValue)"
         }
@@ -71,8 +72,18 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
     let outlineView = NSOutlineView()
     private let gearButton = NSButton()
     private let addProjectButton = NSButton()
+    private let manifestIssuesButton = NSButton()
 
-    var projectWorktrees: [String: [WorktreeModel]] = [:]
+    var projectWorktrees: [String: [WorktreeModel]] = [:] {
+        didSet { agentIndex = AgentIndex(projectWorktrees: projectWorktrees) }
+    }
+    /// Lookup by agent ID into `projectWorktrees`; rebuilt whenever it changes.
+    private(set) var agentIndex = AgentIndex()
+    /// Projects whose manifest version this build can't read, keyed by project root.
+    /// Their worktrees stay at the last good refresh until the user decides what to do.
+    private(set) var blockedManifestVersions: [String: Int] = [:]
+    /// Entries left out of each project's last refresh because they couldn't be read.
+    private(set) var manifestIssues: [String: [ManifestParseIssue]] = [:]
 
     var onItemSelected: ((SidebarItem) -> Void)?
     var onAddAgent: ((ProjectContext, String?) -> Void)?
@@ -82,11 +93,17 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
     var onDeleteTerminal: ((ProjectContext, String) -> Void)?            // (project, id)
     var onRenameAgent: ((ProjectContext, String, String) -> Void)?       // (project, agentId, newName)
     var onDeleteAgent: ((ProjectContext, String) -> Void)?               // (project, agentId)
+    var onShowAgentLogs: ((ProjectContext, AgentModel) -> Void)?
+    var onRestartAgentWithNewPrompt: ((ProjectContext, AgentModel) -> Void)?
+    var onRestartExitedAgents: ((ProjectContext, WorktreeModel) -> Void)?
+    var onKillWorktreeAgents: ((ProjectContext, String) -> Void)?        // (project, worktreeId)
     var onDeleteWorktree: ((ProjectContext, String) -> Void)?            // (project, worktreeId)
     var onDataRefreshed: ((SidebarItem?) -> Void)?
     var onSettingsClicked: (() -> Void)?
     var onAddProject: (() -> Void)?
     var onProjectAddClicked: ((ProjectContext) -> Void)?
+    /// `ppg init` ran again in an open project, replacing its manifest.
+    var onProjectReinitialized: ((ProjectContext) -> Void)?
     var onDashboardClicked: (() -> Void)?
     var onSwarmsClicked: (() -> Void)?
     var onPromptsClicked: (() -> Void)?
@@ -95,10 +112,19 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
     private var safetyTimer: Timer?
     private var settingsObserver: NSObjectProtocol?
+    private var themeObserver: NSObjectProtocol?
+    private var notesObserver: NSObjectProtocol?
+    /// Agents shown with the stalled marker as of the last refresh.
+    private var stalledAgentIds: Set<String> = []
     private var manifestWatchers: [String: ManifestWatcher] = [:]  // projectRoot -> watcher
-    private var debounceWorkItem: DispatchWorkItem?
+    /// Coalesces bursts of manifest writes (e.g. several agents spawning) into one refresh.
+    private lazy var refreshDebouncer = Debouncer(delay: 0.15, maxDelay: 1.0) { [weak self] in
+        self?.refresh()
+    }
     /// Prevents overlapping background refreshes from piling up.
     private var isRefreshing = false
+    /// A refresh was requested while one was in flight; run another when it lands.
+    private var refreshPending = false
     private(set) var activeTab: SidebarTab?
     var isDashboardSelected: Bool { activeTab == .dashboard }
     private var dashboardRow: SidebarNavRow!
@@ -107,6 +133,16 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
     private var schedulesRow: SidebarNavRow!
     private var agentConfigRow: SidebarNavRow!
     var projectNodes: [SidebarNode] = []
+    /// Agent type and status chips under the navigation rows. Applied in `buildTree`,
+    /// so every refresh and diff keeps it.
+    private(set) var filter = SidebarFilter()
+    private let filterStack = NSStackView()
+    private let chipScrollView = NSScrollView()
+    private let chipStack = NSStackView()
+    private let filterSummaryRow = NSStackView()
+    private let filterSummaryLabel = NSTextField(labelWithString: "")
+    /// Agent types the type chips were last built for.
+    private var chipAgentTypes: [String] = []
     private var suppressSelectionCallback = false
     private var contextClickedNode: SidebarNode?
 
@@ -179,6 +215,63 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
         view.addSubview(navBar)
 
+        // Filter chips; scroll sideways when there are more types than fit
+        chipStack.orientation = .horizontal
+        chipStack.spacing = 4
+        chipStack.edgeInsets = NSEdgeInsets(top: 0, left: 8, bottom: 0, right: 8)
+        chipStack.translatesAutoresizingMaskIntoConstraints = false
+
+        let chipClip = NSClipView()
+        chipClip.drawsBackground = false
+        chipScrollView.contentView = chipClip
+        chipScrollView.documentView = chipStack
+        chipScrollView.hasHorizontalScroller = false
+        chipScrollView.horizontalScrollElasticity = .allowed
+        chipScrollView.verticalScrollElasticity = .none
+        chipScrollView.drawsBackground = false
+        chipScrollView.isHidden = true
+        chipScrollView.translatesAutoresizingMaskIntoConstraints = false
+
+        filterSummaryLabel.font = .systemFont(ofSize: 11)
+        filterSummaryLabel.textColor = .secondaryLabelColor
+        filterSummaryLabel.lineBreakMode = .byTruncatingTail
+        filterSummaryLabel.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        let clearFilterButton = NSButton(title: "Clear", target: self, action: #selector(clearFilterClicked))
+        clearFilterButton.isBordered = false
+        clearFilterButton.font = .systemFont(ofSize: 11)
+        clearFilterButton.contentTintColor = .linkColor
+        clearFilterButton.setContentHuggingPriority(.required, for: .horizontal)
+
+        filterSummaryRow.orientation = .horizontal
+        filterSummaryRow.spacing = 4
+        filterSummaryRow.edgeInsets = NSEdgeInsets(top: 0, left: 12, bottom: 0, right: 8)
+        filterSummaryRow.addArrangedSubview(filterSummaryLabel)
+        filterSummaryRow.addArrangedSubview(clearFilterButton)
+        filterSummaryRow.isHidden = true
+
+        filterStack.orientation = .vertical
+        filterStack.alignment = .leading
+        filterStack.spacing = 4
+        filterStack.addArrangedSubview(chipScrollView)
+        filterStack.addArrangedSubview(filterSummaryRow)
+        filterStack.translatesAutoresizingMaskIntoConstraints = false
+        view.addSubview(filterStack)
+
+        NSLayoutConstraint.activate([
+            filterStack.topAnchor.constraint(equalTo: navBar.bottomAnchor, constant: 4),
+            filterStack.leadingAnchor.constraint(equalTo: view.safeAreaLayoutGuide.leadingAnchor),
+            filterStack.trailingAnchor.constraint(equalTo: view.safeAreaLayoutGuide.trailingAnchor),
+            chipScrollView.leadingAnchor.constraint(equalTo: filterStack.leadingAnchor),
+            chipScrollView.trailingAnchor.constraint(equalTo: filterStack.trailingAnchor),
+            chipScrollView.heightAnchor.constraint(equalToConstant: 24),
+            chipStack.topAnchor.constraint(equalTo: chipClip.topAnchor),
+            chipStack.bottomAnchor.constraint(equalTo: chipClip.bottomAnchor),
+            chipStack.leadingAnchor.constraint(equalTo: chipClip.leadingAnchor),
+            filterSummaryRow.leadingAnchor.constraint(equalTo: filterStack.leadingAnchor),
+            filterSummaryRow.trailingAnchor.constraint(equalTo: filterStack.trailingAnchor),
+        ])
+
         NSLayoutConstraint.activate([
             navBar.topAnchor.constraint(equalTo: view.safeAreaLayoutGuide.topAnchor),
             navBar.leadingAnchor.constraint(equalTo: view.safeAreaLayoutGuide.leadingAnchor),
@@ -213,6 +306,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         gearButton.bezelStyle = .accessoryBarAction
         gearButton.image = NSImage(systemSymbolName: "gear", accessibilityDescription: "Settings")
         gearButton.isBordered = false
+        gearButton.toolTip = "Settings"
         gearButton.contentTintColor = Theme.primaryText
         gearButton.target = self
         gearButton.action = #selector(settingsButtonClicked)
@@ -231,6 +325,18 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         addProjectButton.translatesAutoresizingMaskIntoConstraints = false
         footerBar.addSubview(addProjectButton)
 
+        manifestIssuesButton.bezelStyle = .accessoryBarAction
+        manifestIssuesButton.image = NSImage(systemSymbolName: "exclamationmark.triangle.fill", accessibilityDescription: "Manifest problems")
+        manifestIssuesButton.imagePosition = .imageLeading
+        manifestIssuesButton.font = .systemFont(ofSize: 11)
+        manifestIssuesButton.isBordered = false
+        manifestIssuesButton.contentTintColor = .systemYellow
+        manifestIssuesButton.target = self
+        manifestIssuesButton.action = #selector(manifestIssuesButtonClicked)
+        manifestIssuesButton.isHidden = true
+        manifestIssuesButton.translatesAutoresizingMaskIntoConstraints = false
+        footerBar.addSubview(manifestIssuesButton)
+
         let shortcutLabel = NSTextField(labelWithString: "\u{2318}O")
         shortcutLabel.font = .systemFont(ofSize: 10)
         shortcutLabel.textColor = .tertiaryLabelColor
@@ -240,7 +346,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         view.addSubview(footerBar)
 
         NSLayoutConstraint.activate([
-            scrollView.topAnchor.constraint(equalTo: navBar.bottomAnchor),
+            scrollView.topAnchor.constraint(equalTo: filterStack.bottomAnchor, constant: 4),
             scrollView.leadingAnchor.constraint(equalTo: view.safeAreaLayoutGuide.leadingAnchor),
             scrollView.trailingAnchor.constraint(equalTo: view.safeAreaLayoutGuide.trailingAnchor),
             scrollView.bottomAnchor.constraint(equalTo: footerBar.topAnchor),
@@ -257,6 +363,9 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
             gearButton.leadingAnchor.constraint(equalTo: footerBar.leadingAnchor, constant: 8),
             gearButton.centerYAnchor.constraint(equalTo: footerBar.centerYAnchor),
 
+            manifestIssuesButton.leadingAnchor.constraint(equalTo: gearButton.trailingAnchor, constant: 6),
+            manifestIssuesButton.centerYAnchor.constraint(equalTo: footerBar.centerYAnchor),
+
             addProjectButton.trailingAnchor.constraint(equalTo: shortcutLabel.leadingAnchor, constant: -4),
             addProjectButton.centerYAnchor.constraint(equalTo: footerBar.centerYAnchor),
 
@@ -288,6 +397,12 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         agentConfigRow.isSelected = (tab == .agentConfig)
     }
 
+    /// Highlight a tab as if clicked, without calling its `onXClicked` callback.
+    func showTab(_ tab: SidebarTab) {
+        outlineView.deselectAll(nil)
+        selectTab(tab)
+    }
+
     private func deselectAllTabs() {
         activeTab = nil
         dashboardRow.isSelected = false
@@ -335,6 +450,134 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         onAddProject?()
     }
 
+    // MARK: - Filter Chips
+
+    /// Rebuild the type chips when the manifests' agent types change. With fewer than
+    /// two types there's nothing to pick between, so the row collapses and any filter
+    /// is dropped along with it.
+    private func updateFilterChips() {
+        let types = SidebarFilter.agentTypes(in: projectWorktrees.values.flatMap { $0 })
+        var updated = filter
+        updated.keepAgentTypes(in: types)
+        if types.count < 2 {
+            updated = SidebarFilter()
+        }
+        if types != chipAgentTypes {
+            chipAgentTypes = types
+            for view in chipStack.arrangedSubviews {
+                chipStack.removeArrangedSubview(view)
+                view.removeFromSuperview()
+            }
+            for type in types {
+                let title = AgentVariant.allVariants.first { $0.id == type }?.displayName ?? type
+                chipStack.addArrangedSubview(makeFilterChip(title: title, identifier: "type:\(type)"))
+            }
+            for status in SidebarFilter.statusChips {
+                chipStack.addArrangedSubview(makeFilterChip(title: SidebarFilter.title(for: status).capitalized, identifier: "status:\(status.rawValue)"))
+            }
+        }
+        chipScrollView.isHidden = types.count < 2
+        // Called before the refresh builds its tree, which picks up the change
+        filter = updated
+        updateFilterControls()
+    }
+
+    private func makeFilterChip(title: String, identifier: String) -> NSButton {
+        let chip = NSButton(title: title, target: self, action: #selector(filterChipClicked(_:)))
+        chip.setButtonType(.pushOnPushOff)
+        chip.bezelStyle = .recessed
+        chip.controlSize = .small
+        chip.font = .systemFont(ofSize: 11)
+        chip.identifier = NSUserInterfaceItemIdentifier(identifier)
+        chip.setAccessibilityLabel("Show only \(title) agents")
+        return chip
+    }
+
+    @objc private func filterChipClicked(_ sender: NSButton) {
+        guard let identifier = sender.identifier?.rawValue else { return }
+        var updated = filter
+        if identifier.hasPrefix("type:") {
+            updated.toggle(agentType: String(identifier.dropFirst("type:".count)))
+        } else if let status = AgentStatus(rawValue: String(identifier.dropFirst("status:".count))) {
+            updated.toggle(status: status)
+        }
+        setFilter(updated)
+    }
+
+    @objc private func clearFilterClicked() {
+        setFilter(SidebarFilter())
+    }
+
+    /// Apply a new filter to the rows now, the same way a refresh applies new data.
+    private func setFilter(_ newFilter: SidebarFilter) {
+        guard newFilter != filter else { return }
+        filter = newFilter
+        updateFilterControls()
+        guard hasPerformedInitialLoad else { return }
+        suppressSelectionCallback = true
+        applyTreeDiff(from: projectNodes, to: buildTree())
+        suppressSelectionCallback = false
+    }
+
+    /// Sync chip states and the "Filtered: …" line with `filter`.
+    private func updateFilterControls() {
+        for case let chip as NSButton in chipStack.arrangedSubviews {
+            let identifier = chip.identifier?.rawValue ?? ""
+            let isOn: Bool
+            if identifier.hasPrefix("type:") {
+                isOn = filter.agentTypes.contains(String(identifier.dropFirst("type:".count)))
+            } else {
+                isOn = AgentStatus(rawValue: String(identifier.dropFirst("status:".count))).map(filter.statuses.contains) ?? false
+            }
+            chip.state = isOn ? .on : .off
+        }
+        filterSummaryLabel.stringValue = "Filtered: \(filter.summary) —"
+        filterSummaryRow.isHidden = !filter.isActive
+    }
+
+    // MARK: - Manifest Issues
+
+    private func updateManifestIssuesButton() {
+        let count = manifestIssues.values.reduce(0) { $0 + $1.count }
+        manifestIssuesButton.isHidden = count == 0
+        manifestIssuesButton.title = "\(count) unreadable"
+        manifestIssuesButton.toolTip = "\(count) manifest \(count == 1 ? "entry" : "entries") couldn't be read — click for details"
+    }
+
+    @objc private func manifestIssuesButtonClicked() {
+        var lines: [String] = []
+        for root in manifestIssues.keys.sorted() {
+            lines.append(URL(fileURLWithPath: root).lastPathComponent)
+            for issue in manifestIssues[root] ?? [] {
+                lines.append("  \(issue.path): \(issue.message)")
+            }
+        }
+        let count = manifestIssues.values.reduce(0) { $0 + $1.count }
+
+        let alert = NSAlert()
+        alert.messageText = "\(count) Manifest \(count == 1 ? "Entry" : "Entries") Couldn't Be Read"
+        alert.informativeText = "These worktrees and agents are left out of the sidebar until the manifest is fixed. Everything else is shown as usual."
+        alert.alertStyle = .warning
+
+        let scrollView = NSScrollView(frame: NSRect(x: 0, y: 0, width: 480, height: 160))
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .bezelBorder
+        let textView = NSTextView(frame: scrollView.bounds)
+        textView.isEditable = false
+        textView.isSelectable = true
+        textView.font = .monospacedSystemFont(ofSize: 11, weight: .regular)
+        textView.string = lines.joined(separator: "\n")
+        textView.autoresizingMask = [.width]
+        scrollView.documentView = textView
+        alert.accessoryView = scrollView
+
+        if let window = view.window {
+            alert.beginSheetModal(for: window)
+        } else {
+            alert.runModal()
+        }
+    }
+
     // MARK: - Refresh
 
     /// Whether the very first load has happened (uses full reloadData).
@@ -345,13 +588,45 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         syncManifestWatchers()
         scheduleSafetyTimer()
 
-        // Restart safety timer when refresh interval changes
+        // Restart safety timer when refresh interval changes; resize rows for compact mode
         settingsObserver = NotificationCenter.default.addObserver(
             forName: .appSettingsDidChange, object: nil, queue: .main
         ) { [weak self] notification in
-            guard let key = notification.userInfo?[AppSettingsManager.changedKeyUserInfoKey] as? AppSettingsKey,
-                  key == .refreshInterval else { return }
-            self?.scheduleSafetyTimer()
+            guard let self = self,
+                  let key = notification.userInfo?[AppSettingsManager.changedKeyUserInfoKey] as? AppSettingsKey else { return }
+            switch key {
+            case .refreshInterval:
+                self.scheduleSafetyTimer()
+            case .compactSidebarRows:
+                // Reloading rows in place keeps the selection and expansion state
+                let allRows = IndexSet(integersIn: 0..<self.outlineView.numberOfRows)
+                self.outlineView.reloadData(forRowIndexes: allRows, columnIndexes: IndexSet(integer: 0))
+                self.outlineView.noteHeightOfRows(withIndexesChanged: allRows)
+            default:
+                break
+            }
+        }
+
+        // Recolor status icons when theme.json changes; rows whose data didn't change
+        // would otherwise keep the old colors until their next update
+        themeObserver = NotificationCenter.default.addObserver(
+            forName: .userThemeDidChange, object: nil, queue: .main
+        ) { [weak self] _ in
+            guard let self = self else { return }
+            self.outlineView.reloadData(forRowIndexes: IndexSet(integersIn: 0..<self.outlineView.numberOfRows),
+                                        columnIndexes: IndexSet(integer: 0))
+            self.onDataRefreshed?(self.currentSelectedItem())
+        }
+
+        // Show or hide a worktree's note icon as soon as the note is saved
+        notesObserver = NotificationCenter.default.addObserver(
+            forName: .worktreeNotesDidChange, object: nil, queue: .main
+        ) { [weak self] notification in
+            guard let self = self,
+                  let worktreeId = notification.userInfo?["worktreeId"] as? String else { return }
+            for node in self.projectNodes.flatMap(\.children) where node.item.id == worktreeId {
+                self.outlineView.reloadItem(node, reloadChildren: false)
+            }
         }
     }
 
@@ -380,7 +655,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
             let ppgDir = (root as NSString).appendingPathComponent(".ppg")
             let manifestPath = (ppgDir as NSString).appendingPathComponent("manifest.json")
             let watcher = ManifestWatcher(path: manifestPath) { [weak self] in
-                self?.scheduleDebounceRefresh()
+                self?.refreshDebouncer.schedule()
             }
             manifestWatchers[root] = watcher
         }
@@ -391,36 +666,69 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         }
     }
 
-    /// Debounce rapid file changes — coalesce into a single refresh after 300ms of quiet.
-    private func scheduleDebounceRefresh() {
-        debounceWorkItem?.cancel()
-        let workItem = DispatchWorkItem { [weak self] in
-            self?.refresh()
-        }
-        debounceWorkItem = workItem
-        DispatchQueue.main.asyncAfter(deadline: .now() + 0.3, execute: workItem)
-    }
-
     func refresh() {
-        // Skip if a background refresh is already in flight — avoids piling up work
-        guard !isRefreshing else { return }
+        // One background refresh at a time; a request made meanwhile runs once it lands,
+        // so the last write of a burst is never missed.
+        guard !isRefreshing else {
+            refreshPending = true
+            return
+        }
         isRefreshing = true
 
         let openProjects = OpenProjects.shared.projects
+        let strict = AppSettingsManager.shared.strictManifestParsing
+        let pollActivity = AgentActivity.shared.needsPoll()
 
         DispatchQueue.global(qos: .utility).async { [weak self] in
             var results: [String: [WorktreeModel]] = [:]
+            var blocked: [String: Int] = [:]
+            var issues: [String: [ManifestParseIssue]] = [:]
+            var identities: [String: ManifestIdentity] = [:]
 
             for ctx in openProjects {
-                let worktrees = PPGService.shared.refreshStatus(manifestPath: ctx.manifestPath)
-                results[ctx.projectRoot] = worktrees
+                if let version = PPGService.shared.blockingManifestVersion(at: ctx.manifestPath) {
+                    blocked[ctx.projectRoot] = version
+                    continue
+                }
+                let status = PPGService.shared.loadStatus(manifestPath: ctx.manifestPath, strict: strict)
+                results[ctx.projectRoot] = status.worktrees
+                identities[ctx.projectRoot] = status.identity
+                if !status.issues.isEmpty {
+                    issues[ctx.projectRoot] = status.issues
+                }
             }
 
+            // Window output times for stall detection; only worth asking tmux while something runs
+            let anyRunning = results.values.contains { $0.contains { $0.agents.contains { $0.status == .running } } }
+            let activity = pollActivity && anyRunning ? PPGService.shared.tmuxWindowActivity() : nil
+
             DispatchQueue.main.async {
                 guard let self = self else { return }
                 self.isRefreshing = false
 
+                for root in blocked.keys {
+                    results[root] = self.projectWorktrees[root] ?? []
+                }
+                for ctx in openProjects where blocked[ctx.projectRoot] == nil {
+                    // Reported before the old worktrees are replaced, so the selection can still be traced to its project
+                    if let identity = identities[ctx.projectRoot], ctx.adoptManifestIdentity(identity) {
+                        self.onProjectReinitialized?(ctx)
+                    }
+                    ctx.statusHistory.record(results[ctx.projectRoot] ?? [])
+                    ctx.worktreeNotes.prune(existing: Set((results[ctx.projectRoot] ?? []).map(\.id)))
+                }
+                self.blockedManifestVersions = blocked
+                self.manifestIssues = issues
+                self.updateManifestIssuesButton()
                 self.projectWorktrees = results
+                self.updateFilterChips()
+                let allAgents = results.values.flatMap { $0.flatMap(\.agents) }
+                if let activity {
+                    AgentActivity.shared.record(activity, agents: allAgents)
+                }
+                let stalled = Set(allAgents.filter { AgentActivity.shared.stalledSince($0) != nil }.map(\.id))
+                let stallChanges = stalled.symmetricDifference(self.stalledAgentIds)
+                self.stalledAgentIds = stalled
                 let newTree = self.buildTree()
 
                 if !self.hasPerformedInitialLoad {
@@ -436,8 +744,19 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
                     self.suppressSelectionCallback = false
                 }
 
+                // The stalled marker isn't part of a row's content signature, so the diff
+                // leaves rows whose manifest data is unchanged alone
+                if !stallChanges.isEmpty {
+                    self.reloadRows(forAgentIds: stallChanges)
+                }
+
                 let currentItem = self.currentSelectedItem()
                 self.onDataRefreshed?(currentItem)
+
+                if self.refreshPending {
+                    self.refreshPending = false
+                    self.refreshDebouncer.schedule()
+                }
             }
         }
     }
@@ -449,26 +768,33 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
     }
 
     /// Build a fresh tree from current data without mutating `projectNodes`.
-    private func buildTree() -> [SidebarNode] {
+    /// While `filter` is active only matching agents are listed, under their worktrees;
+    /// worktrees without a match and the user's own terminals are left out.
+    private func buildTree(filter: SidebarFilter? = nil) -> [SidebarNode] {
+        let filter = filter ?? self.filter
         var result: [SidebarNode] = []
 
         for ctx in OpenProjects.shared.projects {
             let projectNode = SidebarNode(.project(ctx))
 
             // Master-level dashboard entries (agents + terminals without a parent worktree)
-            for entry in ctx.dashboardSession.entriesForMaster() {
-                projectNode.children.append(SidebarNode(.terminal(entry)))
+            if !filter.isActive {
+                for entry in ctx.dashboardSession.entriesForMaster() {
+                    projectNode.children.append(SidebarNode(.terminal(entry)))
+                }
             }
 
             // Worktrees from manifest
             let worktrees = projectWorktrees[ctx.projectRoot] ?? []
             for wt in worktrees {
+                let agents = filter.isActive ? wt.agents.filter(filter.matches) : wt.agents
+                if filter.isActive && agents.isEmpty { continue }
                 let wtNode = SidebarNode(.worktree(wt))
 
                 // Group agents by tmux window using Dictionary
                 var windowGroups: [String: [AgentModel]] = [:]
                 var agentOrder: [String] = []  // preserve first-seen order
-                for agent in wt.agents {
+                for agent in agents {
                     let target = agent.tmuxTarget
                     let windowKey: String
                     if let dotIndex = target.lastIndex(of: ".") {
@@ -490,8 +816,10 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
                     }
                 }
 
-                for entry in ctx.dashboardSession.entriesForWorktree(wt.id) {
-                    wtNode.children.append(SidebarNode(.terminal(entry)))
+                if !filter.isActive {
+                    for entry in ctx.dashboardSession.entriesForWorktree(wt.id) {
+                        wtNode.children.append(SidebarNode(.terminal(entry)))
+                    }
                 }
                 projectNode.children.append(wtNode)
             }
@@ -502,6 +830,24 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         return result
     }
 
+    /// Redraw the rows showing any of `agentIds`, alone or in a split group.
+    private func reloadRows(forAgentIds agentIds: Set<String>) {
+        var rows = IndexSet()
+        for row in 0..<outlineView.numberOfRows {
+            guard let node = outlineView.item(atRow: row) as? SidebarNode else { continue }
+            switch node.item {
+            case .agent(let agent) where agentIds.contains(agent.id):
+                rows.insert(row)
+            case .agentGroup(let agents, _) where agents.contains(where: { agentIds.contains($0.id) }):
+                rows.insert(row)
+            default:
+                break
+            }
+        }
+        guard !rows.isEmpty else { return }
+        outlineView.reloadData(forRowIndexes: rows, columnIndexes: IndexSet(integer: 0))
+    }
+
     // MARK: - Incremental Diff
 
     /// Compare old and new tree, apply minimal NSOutlineView mutations.
@@ -605,6 +951,13 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
             // Only reload the cell view when visible content changed
             if oldSig != newNode.item.contentSignature {
                 outlineView.reloadItem(oldNode, reloadChildren: false)
+                // A worktree row is taller while it shows a branch, which may have just appeared or gone
+                if case .worktree = oldNode.item {
+                    let row = outlineView.row(forItem: oldNode)
+                    if row >= 0 {
+                        outlineView.noteHeightOfRows(withIndexesChanged: IndexSet(integer: row))
+                    }
+                }
             }
 
             // Recurse into children for expandable items
@@ -625,10 +978,15 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
     deinit {
         safetyTimer?.invalidate()
-        debounceWorkItem?.cancel()
         if let settingsObserver {
             NotificationCenter.default.removeObserver(settingsObserver)
         }
+        if let themeObserver {
+            NotificationCenter.default.removeObserver(themeObserver)
+        }
+        if let notesObserver {
+            NotificationCenter.default.removeObserver(notesObserver)
+        }
         for watcher in manifestWatchers.values {
             watcher.stop()
         }
@@ -653,14 +1011,10 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
             }
             return nil
         case .agent(let ag):
+            guard let root = agentIndex.find(ag.id)?.projectRoot else { return nil }
             for node in projectNodes {
-                if case .project(let ctx) = node.item {
-                    let worktrees = projectWorktrees[ctx.projectRoot] ?? []
-                    for w in worktrees {
-                        if w.agents.contains(where: { $0.id == ag.id }) {
-                            return ctx
-                        }
-                    }
+                if case .project(let ctx) = node.item, ctx.projectRoot == root {
+                    return ctx
                 }
             }
             return nil
@@ -711,6 +1065,45 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         return false
     }
 
+    /// Select the item with `id` even if its project or worktree row is collapsed,
+    /// expanding the rows above it. An agent that shares a window with others is
+    /// reached through its group row.
+    /// An item hidden by the filter clears the filter first.
+    @discardableResult
+    func revealItem(byId id: String) -> Bool {
+        if nodePath(to: id, in: projectNodes) == nil, filter.isActive, nodePath(to: id, in: buildTree(filter: SidebarFilter())) != nil {
+            setFilter(SidebarFilter())
+        }
+        guard let nodes = nodePath(to: id, in: projectNodes), let target = nodes.last else { return false }
+        for ancestor in nodes.dropLast() {
+            outlineView.expandItem(ancestor)
+        }
+        let row = outlineView.row(forItem: target)
+        guard row >= 0 else { return false }
+        outlineView.selectRowIndexes(IndexSet(integer: row), byExtendingSelection: false)
+        outlineView.scrollRowToVisible(row)
+        onItemSelected?(target.item)
+        return true
+    }
+
+    /// Whether `revealItem(byId:)` would find the item, even if the filter hides it.
+    func containsItem(byId id: String) -> Bool {
+        nodePath(to: id, in: projectNodes) != nil
+            || (filter.isActive && nodePath(to: id, in: buildTree(filter: SidebarFilter())) != nil)
+    }
+
+    /// Nodes from a project row down to the item with `id`, or nil if it isn't in the tree.
+    private func nodePath(to id: String, in nodes: [SidebarNode]) -> [SidebarNode]? {
+        for node in nodes {
+            if node.item.id == id { return [node] }
+            if case .agentGroup(let agents, _) = node.item, agents.contains(where: { $0.id == id }) {
+                return [node]
+            }
+            if let rest = nodePath(to: id, in: node.children) { return [node] + rest }
+        }
+        return nil
+    }
+
     func selectedWorktreeId() -> String? {
         let row = outlineView.selectedRow
         guard row >= 0, let node = outlineView.item(atRow: row) as? SidebarNode else { return nil }
@@ -718,21 +1111,10 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         case .project: return nil
         case .worktree(let wt): return wt.id
         case .agent(let ag):
-            // Find parent worktree across all projects
-            for (_, worktrees) in projectWorktrees {
-                for wt in worktrees where wt.agents.contains(where: { $0.id == ag.id }) {
-                    return wt.id
-                }
-            }
-            return nil
+            return agentIndex.worktree(ofAgent: ag.id)?.id
         case .agentGroup(let agents, _):
             guard let firstAgent = agents.first else { return nil }
-            for (_, worktrees) in projectWorktrees {
-                for wt in worktrees where wt.agents.contains(where: { $0.id == firstAgent.id }) {
-                    return wt.id
-                }
-            }
-            return nil
+            return agentIndex.worktree(ofAgent: firstAgent.id)?.id
         case .terminal(let entry): return entry.parentWorktreeId
         }
     }
@@ -848,14 +1230,23 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
     }
 
     func outlineView(_ outlineView: NSOutlineView, heightOfRowByItem item: Any) -> CGFloat {
+        if let node = item as? SidebarNode, case .worktree(let wt) = node.item, Self.showsBranch(wt) {
+            return 34
+        }
         return 24
     }
 
-    private func makeInlineAddButton(action: Selector) -> NSButton {
+    /// Whether a worktree row gets the branch as a second line.
+    private static func showsBranch(_ worktree: WorktreeModel) -> Bool {
+        !worktree.branch.isEmpty && !AppSettingsManager.shared.compactSidebarRows
+    }
+
+    private func makeInlineAddButton(action: Selector, toolTip: String) -> NSButton {
         let button = NSButton()
         button.setButtonType(.momentaryPushIn)
         button.isBordered = false
-        button.image = NSImage(systemSymbolName: "plus", accessibilityDescription: "Add")
+        button.image = NSImage(systemSymbolName: "plus", accessibilityDescription: toolTip)
+        button.toolTip = toolTip
         button.imageScaling = .scaleProportionallyDown
         button.contentTintColor = Theme.primaryText
         button.target = self
@@ -875,12 +1266,13 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         name.font = .boldSystemFont(ofSize: 13)
 
         // Inline "+" button
-        let addBtn = makeInlineAddButton(action: #selector(projectAddButtonClicked(_:)))
+        let addBtn = makeInlineAddButton(action: #selector(projectAddButtonClicked(_:)), toolTip: "New in \(name.stringValue)…")
         addBtn.tag = OpenProjects.shared.indexOf(root: ctx.projectRoot) ?? 0
 
         stack.addArrangedSubview(name)
         stack.addArrangedSubview(NSView()) // spacer
         stack.addArrangedSubview(addBtn)
+        cell.setAccessibilityLabel(AccessibilityLabels.project(name: name.stringValue, worktreeCount: worktrees(for: ctx).count))
 
         cell.addSubview(stack)
         NSLayoutConstraint.activate([
@@ -900,6 +1292,8 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
         let name = NSTextField(labelWithString: worktree.name)
         name.font = .systemFont(ofSize: 13)
+        name.lineBreakMode = .byTruncatingTail
+        name.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
 
         // Show agent count badge when collapsed
         let isCollapsed = !outlineView.isItemExpanded(node)
@@ -911,11 +1305,39 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         badge.setContentHuggingPriority(.required, for: .horizontal)
 
         // Inline "+" button for adding agents/terminals to this worktree
-        let addBtn = makeInlineAddButton(action: #selector(worktreeAddButtonClicked(_:)))
+        let addBtn = makeInlineAddButton(action: #selector(worktreeAddButtonClicked(_:)), toolTip: "New in \(worktree.name)…")
         addBtn.identifier = NSUserInterfaceItemIdentifier(worktree.id)
+        cell.setAccessibilityLabel(AccessibilityLabels.worktree(worktree))
+
+        let titleRow = NSStackView(views: [name, badge])
+        titleRow.orientation = .horizontal
+        titleRow.spacing = 6
+        if let note = projectContext(for: .worktree(worktree))?.worktreeNotes.note(for: worktree.id) {
+            let noteIcon = NSImageView(image: NSImage(systemSymbolName: "note.text", accessibilityDescription: "Has note")!)
+            noteIcon.contentTintColor = .secondaryLabelColor
+            noteIcon.symbolConfiguration = NSImage.SymbolConfiguration(pointSize: 10, weight: .regular)
+            noteIcon.setContentHuggingPriority(.required, for: .horizontal)
+            noteIcon.toolTip = note.preview
+            titleRow.addArrangedSubview(noteIcon)
+        }
 
-        stack.addArrangedSubview(name)
-        stack.addArrangedSubview(badge)
+        // Branch underneath, cut in the middle so its distinctive end stays visible
+        let textStack = NSStackView(views: [titleRow])
+        textStack.orientation = .vertical
+        textStack.alignment = .leading
+        textStack.spacing = 0
+        textStack.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+        if Self.showsBranch(worktree) {
+            let branch = NSTextField(labelWithString: worktree.branch)
+            branch.font = .systemFont(ofSize: 10)
+            branch.textColor = .tertiaryLabelColor
+            branch.lineBreakMode = .byTruncatingMiddle
+            branch.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+            branch.toolTip = worktree.branch
+            textStack.addArrangedSubview(branch)
+        }
+
+        stack.addArrangedSubview(textStack)
         stack.addArrangedSubview(NSView()) // spacer
         stack.addArrangedSubview(addBtn)
 
@@ -935,7 +1357,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         stack.spacing = 5
         stack.translatesAutoresizingMaskIntoConstraints = false
 
-        let statusDesc = "Agent \(agent.status.rawValue)"
+        let statusDesc = "Agent \(agent.statusLabel)"
         let icon = NSImageView(image: NSImage(systemSymbolName: "circle.fill", accessibilityDescription: statusDesc)!)
         icon.contentTintColor = Theme.statusColor(for: agent.status)
         icon.symbolConfiguration = NSImage.SymbolConfiguration(pointSize: 6, weight: .regular)
@@ -946,11 +1368,33 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
         let displayName = agent.name.isEmpty ? agent.id : agent.name
         let label = NSTextField(labelWithString: "\(displayName) — \(agent.agentType)")
-        cell.setAccessibilityLabel("\(displayName), \(agent.agentType), \(agent.status.rawValue)")
         label.font = .systemFont(ofSize: 12)
+        label.lineBreakMode = .byTruncatingTail
+        label.setContentCompressionResistancePriority(.defaultLow, for: .horizontal)
+
+        // Status in words too, so it doesn't rest on the dot's color alone
+        let statusText = NSTextField(labelWithString: agent.statusLabel)
+        statusText.font = .systemFont(ofSize: 11)
+        statusText.textColor = .secondaryLabelColor
+        statusText.setContentHuggingPriority(.required, for: .horizontal)
+
+        // Amber ring for a running agent that has gone quiet; its status stays as ppg reports it
+        let stalledSince = AgentActivity.shared.stalledSince(agent)
+        if let stalledSince {
+            icon.wantsLayer = true
+            icon.layer?.borderColor = NSColor.systemOrange.cgColor
+            icon.layer?.borderWidth = 1.5
+            icon.layer?.cornerRadius = 4
+            statusText.stringValue = "\(agent.statusLabel), stalled"
+            statusText.textColor = .systemOrange
+            cell.toolTip = "Last output \(humanizeRelative(stalledSince))"
+        }
+        cell.setAccessibilityLabel(AccessibilityLabels.agent(agent, stalled: stalledSince != nil))
 
         stack.addArrangedSubview(icon)
         stack.addArrangedSubview(label)
+        stack.addArrangedSubview(NSView()) // spacer
+        stack.addArrangedSubview(statusText)
 
         cell.addSubview(stack)
         NSLayoutConstraint.activate([
@@ -978,6 +1422,11 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
         let label = NSTextField(labelWithString: "\(agents.count) agents (split)")
         label.font = .systemFont(ofSize: 12)
+        cell.toolTip = agents.map {
+            let stalled = AgentActivity.shared.stalledSince($0) != nil ? ", stalled" : ""
+            return "\($0.name.isEmpty ? $0.id : $0.name) — \($0.statusLabel)\(stalled)"
+        }.joined(separator: "\n")
+        cell.setAccessibilityLabel(AccessibilityLabels.agentGroup(agents))
 
         stack.addArrangedSubview(icon)
         stack.addArrangedSubview(label)
@@ -1020,6 +1469,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 
         let label = NSTextField(labelWithString: entry.label)
         label.font = .systemFont(ofSize: 12)
+        cell.setAccessibilityLabel(AccessibilityLabels.sessionEntry(label: entry.label, isAgent: entry.kind == .agent))
 
         stack.addArrangedSubview(icon)
         stack.addArrangedSubview(label)
@@ -1082,11 +1532,19 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         case .project:
             contextClickedNode = node
             menu.addItem(withTitle: "Close Project", action: #selector(contextCloseProject(_:)), keyEquivalent: "").target = self
-        case .worktree:
+        case .worktree(let wt):
             contextClickedNode = node
-            menu.addItem(withTitle: "Delete Worktree", action: #selector(contextDeleteWorktree(_:)), keyEquivalent: "").target = self
+            if !BatchRestart.restartable(wt.agents).isEmpty {
+                menu.addItem(withTitle: "Restart Exited Agents…", action: #selector(contextRestartExitedAgents(_:)), keyEquivalent: "").target = self
+            }
+            menu.addItem(withTitle: "Kill Agents…", action: #selector(contextKillWorktreeAgents(_:)), keyEquivalent: "").target = self
+            menu.addItem(.separator())
+            menu.addItem(withTitle: "Delete Worktree…", action: #selector(contextDeleteWorktree(_:)), keyEquivalent: "").target = self
         case .agent:
             contextClickedNode = node
+            menu.addItem(withTitle: "View Logs…", action: #selector(contextViewLogs(_:)), keyEquivalent: "").target = self
+            menu.addItem(withTitle: "Restart with New Prompt…", action: #selector(contextRestartWithNewPrompt(_:)), keyEquivalent: "").target = self
+            menu.addItem(.separator())
             menu.addItem(withTitle: "Rename…", action: #selector(contextRename(_:)), keyEquivalent: "").target = self
             menu.addItem(withTitle: "Delete", action: #selector(contextDelete(_:)), keyEquivalent: "").target = self
         case .agentGroup:
@@ -1100,12 +1558,44 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
         }
     }
 
+    @objc private func contextViewLogs(_ sender: Any) {
+        guard let node = contextClickedNode, case .agent(let agent) = node.item else { return }
+        guard let ctx = projectContext(for: node.item) else { return }
+        onShowAgentLogs?(ctx, agent)
+    }
+
+    @objc private func contextRestartWithNewPrompt(_ sender: Any) {
+        guard let node = contextClickedNode, case .agent(let agent) = node.item else { return }
+        guard let ctx = projectContext(for: node.item) else { return }
+        onRestartAgentWithNewPrompt?(ctx, agent)
+    }
+
     @objc private func contextCloseProject(_ sender: Any) {
         guard let node = contextClickedNode, case .project(let ctx) = node.item else { return }
         OpenProjects.shared.remove(root: ctx.projectRoot)
         refresh()
     }
 
+    @objc private func contextRestartExitedAgents(_ sender: Any) {
+        guard let node = contextClickedNode, case .worktree(let wt) = node.item else { return }
+        guard let ctx = projectContext(for: node.item) else { return }
+        onRestartExitedAgents?(ctx, wt)
+    }
+
+    @objc private func contextKillWorktreeAgents(_ sender: Any) {
+        guard let node = contextClickedNode, case .worktree(let wt) = node.item else { return }
+        guard let ctx = projectContext(for: node.item) else { return }
+
+        let alert = NSAlert()
+        alert.messageText = "Kill agents in \"\(wt.name)\"?"
+        alert.informativeText = "This stops every agent in the worktree. The worktree, its branch, and any uncommitted changes are kept."
+        alert.alertStyle = .warning
+        alert.addButton(withTitle: "Kill Agents")
+        alert.addButton(withTitle: "Cancel")
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+        onKillWorktreeAgents?(ctx, wt.id)
+    }
+
     @objc private func contextDeleteWorktree(_ sender: Any) {
         guard let node = contextClickedNode, case .worktree(let wt) = node.item else { return }
         guard let ctx = projectContext(for: node.item) else { return }
@@ -1235,7 +1725,7 @@ class SidebarViewController: NSViewController, NSOutlineViewDataSource, NSOutlin
 /// Watches a single file for .write events using GCD's DispatchSource (FSEvents under the hood).
 /// Calls `onChange` on the main queue whenever the file is modified.
 /// All state mutations must happen on the main thread.
-private class ManifestWatcher {
+class ManifestWatcher {
     private var source: DispatchSourceFileSystemObject?
     private var fileDescriptor: Int32 = -1
     private let path: String
@@ -0,0 +1,28 @@
+import AppKit
+
+/// Keeps one dashboard running per user. A second launch (e.g. `ppg ui` from another
+/// project) hands its project to the running instance and exits, instead of starting
+/// a second copy that refreshes and attaches to everything again.
+enum SingleInstance {
+    /// Posted by a second launch; `object` is the project root it was given, if any.
+    static let openProjectNotification = Notification.Name("com.2wit.PPG-CLI.openProject")
+
+    /// If another instance is running, forward `config`'s project to it, bring it to
+    /// the front, and return true so the caller can exit.
+    static func forwardToRunningInstance(_ config: LaunchConfig) -> Bool {
+        guard let bundleId = Bundle.main.bundleIdentifier,
+              let existing = NSRunningApplication.runningApplications(withBundleIdentifier: bundleId)
+                .first(where: { $0 != .current }) else { return false }
+
+        if !config.projectRoot.isEmpty {
+            DistributedNotificationCenter.default().postNotificationName(
+                openProjectNotification,
+                object: config.projectRoot,
+                userInfo: nil,
+                deliverImmediately: true
+            )
+        }
+        existing.activate()
+        return true
+    }
+}
@@ -0,0 +1,188 @@
+import Foundation
+
+/// One agent status change seen between two manifest refreshes.
+nonisolated struct StatusTransition: Codable, Equatable {
+    let agentId: String
+    let worktreeId: String
+    /// Raw manifest status strings, so statuses from a newer CLI are kept as written.
+    let from: String
+    let to: String
+    /// Exit code the agent had after the change; absent in files written before it was kept.
+    let exitCode: Int?
+    let at: Date
+
+    /// `to` read the way the manifest is, so `exited` with a non-zero code is a failure.
+    var toStatus: AgentStatus { AgentStatus(manifestStatus: to, exitCode: exitCode) }
+}
+
+/// An agent the app started through ppg, and what it asked for. The manifest only
+/// keeps the resulting prompt and type, and `ppg restart --json` reports the new
+/// window and session once, so this is the only record of how an agent came about.
+nonisolated struct SpawnRecord: Codable, Equatable {
+    let agentId: String
+    let worktreeId: String
+    /// The agent this one replaced.
+    let replacedAgentId: String?
+    /// Agent type asked for; nil kept the replaced agent's.
+    let requestedAgentType: String?
+    /// Prompt asked for; nil reused the replaced agent's.
+    let requestedPrompt: String?
+    /// The ppg command line as run, so later edits to agent settings don't rewrite it.
+    let command: String
+    /// Agents started together, e.g. by Restart Exited Agents; 1 for a single restart.
+    let batchSize: Int
+    let tmuxTarget: String?
+    let sessionId: String?
+    let at: Date
+
+    /// The record of restarting `agent` with `request`, from `ppg <args>`'s response.
+    init(restarting agent: AgentModel, worktreeId: String, request: RestartRequest, args: String,
+         response: RestartRequest.Response, batchSize: Int = 1, at date: Date = Date()) {
+        self.agentId = response.newAgentId
+        self.worktreeId = response.worktreeId ?? worktreeId
+        self.replacedAgentId = agent.id
+        self.requestedAgentType = request.agent
+        self.requestedPrompt = request.prompt
+        self.command = "ppg \(args)"
+        self.batchSize = batchSize
+        self.tmuxTarget = response.tmuxTarget
+        self.sessionId = response.sessionId
+        self.at = date
+    }
+}
+
+/// Recent agent status transitions for one project, derived by comparing each
+/// refresh against the last statuses seen. The manifest only holds current status,
+/// so this is the only record of when an agent went from running to failed.
+///
+/// Fed from the sidebar refresh, which both the manifest watcher and the timer go
+/// through. Kept in `.ppg/status-history.json` (newest `capacity` entries) together
+/// with the last seen statuses, so changes made while the app was closed are picked
+/// up as transitions on the next launch.
+///
+/// Agents the app starts are recorded here too, as `spawns`, capped the same way.
+final class StatusHistory {
+    static let capacity = 500
+
+    private(set) var transitions: [StatusTransition] = []
+    private(set) var spawns: [SpawnRecord] = []
+    private var lastStatuses: [String: String] = [:]
+    private let path: String?
+    private let ioQueue = DispatchQueue(label: "ppg.status-history.io", qos: .utility)
+
+    nonisolated struct Stored: Codable, Equatable {
+        var transitions: [StatusTransition]
+        var lastStatuses: [String: String]
+        /// Absent in files written before spawns were recorded.
+        var spawns: [SpawnRecord]?
+    }
+
+    /// `path` nil keeps history in memory only.
+    init(path: String?) {
+        self.path = path
+        loadFromDisk()
+    }
+
+    convenience init(projectRoot: String) {
+        guard !projectRoot.isEmpty, projectRoot != "/" else {
+            self.init(path: nil)
+            return
+        }
+        let ppgDir = (projectRoot as NSString).appendingPathComponent(".ppg")
+        self.init(path: (ppgDir as NSString).appendingPathComponent("status-history.json"))
+    }
+
+    /// Compare `worktrees` with the previous refresh and append a transition for every
+    /// agent whose status changed. Agents seen for the first time aren't transitions.
+    /// An empty refresh (usually a manifest caught mid-write) is skipped so the
+    /// statuses it would forget don't miss their next change.
+    func record(_ worktrees: [WorktreeModel], at date: Date = Date()) {
+        guard !worktrees.isEmpty else { return }
+        var current: [String: String] = [:]
+        var added: [StatusTransition] = []
+        for worktree in worktrees {
+            for agent in worktree.agents {
+                current[agent.id] = agent.rawStatus
+                if let previous = lastStatuses[agent.id], previous != agent.rawStatus {
+                    added.append(StatusTransition(agentId: agent.id, worktreeId: worktree.id, from: previous, to: agent.rawStatus,
+                                                  exitCode: agent.exitCode, at: date))
+                }
+            }
+        }
+        guard current != lastStatuses else { return }
+
+        lastStatuses = current
+        transitions.append(contentsOf: added)
+        if transitions.count > Self.capacity {
+            transitions.removeFirst(transitions.count - Self.capacity)
+        }
+        saveToDisk()
+    }
+
+    /// Keep how an agent was started, for its info popover and the status report.
+    func recordSpawn(_ spawn: SpawnRecord) {
+        spawns.append(spawn)
+        if spawns.count > Self.capacity {
+            spawns.removeFirst(spawns.count - Self.capacity)
+        }
+        saveToDisk()
+    }
+
+    // MARK: - Queries
+
+    func spawn(forAgent agentId: String) -> SpawnRecord? {
+        spawns.last { $0.agentId == agentId }
+    }
+
+    func transitions(forWorktree worktreeId: String) -> [StatusTransition] {
+        transitions.filter { $0.worktreeId == worktreeId }
+    }
+
+    func transitions(forAgent agentId: String) -> [StatusTransition] {
+        transitions.filter { $0.agentId == agentId }
+    }
+
+    /// Agents that moved to failed at or after `date`, oldest first.
+    func recentFailures(since date: Date) -> [StatusTransition] {
+        transitions.filter { $0.toStatus == .failed && $0.at >= date }
+    }
+
+    // MARK: - Persistence
+
+    nonisolated static func encode(_ stored: Stored) throws -> Data {
+        let encoder = JSONEncoder()
+        encoder.dateEncodingStrategy = .iso8601
+        return try encoder.encode(stored)
+    }
+
+    nonisolated static func decode(_ data: Data) -> Stored? {
+        let decoder = JSONDecoder()
+        decoder.dateDecodingStrategy = .iso8601
+        return try? decoder.decode(Stored.self, from: data)
+    }
+
+    /// Wait for writes already queued to reach the file.
+    func flush() {
+        ioQueue.sync {}
+    }
+
+    private func saveToDisk() {
+        guard let path else { return }
+        let stored = Stored(transitions: transitions, lastStatuses: lastStatuses, spawns: spawns)
+        ioQueue.async {
+            do {
+                try Self.encode(stored).write(to: URL(fileURLWithPath: path), options: .atomic)
+            } catch {
+                // Non-fatal — history restarts from the next refresh
+            }
+        }
+    }
+
+    private func loadFromDisk() {
+        guard let path, let data = FileManager.default.contents(atPath: path),
+              let stored = Self.decode(data) else { return }
+        transitions = Array(stored.transitions.suffix(Self.capacity))
+        spawns = Array((stored.spawns ?? []).suffix(Self.capacity))
+        lastStatuses = stored.lastStatuses
+    }
+}
@@ -0,0 +1,250 @@
+import Foundation
+import UniformTypeIdentifiers
+
+/// Point-in-time summary of the open projects, exported from App ▸ Export Report…
+/// Built from each project's manifest plus the dashboard's commit counts, so rendering
+/// never touches disk, git or the clock.
+nonisolated struct StatusReport: Encodable, Equatable {
+    enum Format: String, CaseIterable {
+        case markdown
+        case json
+
+        var displayName: String {
+            switch self {
+            case .markdown: return "Markdown"
+            case .json: return "JSON"
+            }
+        }
+
+        var fileExtension: String {
+            switch self {
+            case .markdown: return "md"
+            case .json: return "json"
+            }
+        }
+
+        var contentType: UTType {
+            switch self {
+            case .markdown: return UTType(filenameExtension: "md") ?? .plainText
+            case .json: return .json
+            }
+        }
+    }
+
+    struct Project: Encodable, Equatable {
+        let name: String
+        let root: String
+        let sessionName: String
+        /// Commits across all branches dated today, from the dashboard heatmap data.
+        let commitsToday: Int
+        let worktrees: [Worktree]
+    }
+
+    struct Worktree: Encodable, Equatable {
+        let id: String
+        let name: String
+        let branch: String
+        let status: String
+        let prUrl: String?
+        let agents: [Agent]
+    }
+
+    struct Agent: Encodable, Equatable {
+        let id: String
+        let name: String
+        let agentType: String
+        let status: String
+        let exitCode: Int?
+        /// Start to finish, or start to `generatedAt` for agents still running.
+        let runtimeSeconds: Int?
+        /// Set for agents the app started, e.g. by restarting another one.
+        let spawn: Spawn?
+    }
+
+    /// How the app started an agent, from the project's status history.
+    struct Spawn: Encodable, Equatable {
+        /// ISO 8601, UTC.
+        let at: String
+        let replacedAgentId: String?
+        let requestedAgentType: String?
+        let requestedPrompt: String?
+        let command: String
+        let batchSize: Int
+        let tmuxTarget: String?
+        let sessionId: String?
+
+        init(_ record: SpawnRecord) {
+            at = ISO8601DateFormatter().string(from: record.at)
+            replacedAgentId = record.replacedAgentId
+            requestedAgentType = record.requestedAgentType
+            requestedPrompt = record.requestedPrompt
+            command = record.command
+            batchSize = record.batchSize
+            tmuxTarget = record.tmuxTarget
+            sessionId = record.sessionId
+        }
+    }
+
+    /// ISO 8601, UTC.
+    let generatedAt: String
+    let projects: [Project]
+
+    /// Worktrees in creation order and agents in start order, as the sidebar lists them.
+    /// `spawns` is keyed by agent ID.
+    init(projects: [(manifest: ManifestModel, commitsToday: Int)], spawns: [String: SpawnRecord] = [:], generatedAt: Date) {
+        self.generatedAt = ISO8601DateFormatter().string(from: generatedAt)
+        self.projects = projects.map { manifest, commitsToday in
+            let worktrees = manifest.worktrees.values.sorted {
+                PPGService.precedes((parseISO8601($0.createdAt), $0.createdAt, $0.id), (parseISO8601($1.createdAt), $1.createdAt, $1.id))
+            }
+            return Project(
+                name: URL(fileURLWithPath: manifest.projectRoot).lastPathComponent,
+                root: manifest.projectRoot,
+                sessionName: manifest.sessionName,
+                commitsToday: commitsToday,
+                worktrees: worktrees.map { Self.worktree($0, spawns: spawns, now: generatedAt) }
+            )
+        }
+    }
+
+    private static func worktree(_ entry: WorktreeEntryModel, spawns: [String: SpawnRecord], now: Date) -> Worktree {
+        let agents = entry.agents.values.sorted {
+            PPGService.precedes((parseISO8601($0.startedAt), $0.startedAt, $0.id), (parseISO8601($1.startedAt), $1.startedAt, $1.id))
+        }
+        var prUrl: String?
+        if case .string(let url)? = entry.extra["prUrl"], !url.isEmpty {
+            prUrl = url
+        }
+        return Worktree(
+            id: entry.id,
+            name: entry.name,
+            branch: entry.branch,
+            status: entry.status,
+            prUrl: prUrl,
+            agents: agents.map { agent in
+                Agent(
+                    id: agent.id,
+                    name: agent.name.isEmpty ? agent.id : agent.name,
+                    agentType: agent.agentType,
+                    status: agent.status,
+                    exitCode: agent.exitCode,
+                    runtimeSeconds: runtime(of: agent, now: now),
+                    spawn: spawns[agent.id].map(Spawn.init)
+                )
+            }
+        )
+    }
+
+    /// Nil when the start time can't be parsed, or when an agent that isn't live has
+    /// no completion time (its runtime is unknown, not still growing). An idle agent has
+    /// already exited back to the shell, so it doesn't count as live.
+    private static func runtime(of agent: AgentEntryModel, now: Date) -> Int? {
+        guard let started = parseISO8601(agent.startedAt) else { return nil }
+        if let completedAt = agent.completedAt {
+            return parseISO8601(completedAt).map { max(0, Int($0.timeIntervalSince(started))) }
+        }
+        let live: Set<AgentStatus> = [.spawning, .running]
+        guard live.contains(AgentStatus(manifestStatus: agent.status, exitCode: agent.exitCode)) else { return nil }
+        return max(0, Int(now.timeIntervalSince(started)))
+    }
+
+    // MARK: - Rendering
+
+    func rendered(as format: Format) -> String {
+        switch format {
+        case .markdown: return markdown()
+        case .json: return json()
+        }
+    }
+
+    func markdown() -> String {
+        var lines = ["# PPG Status Report", "", "Generated \(generatedAt)"]
+        for project in projects {
+            lines += [
+                "",
+                "## \(project.name)",
+                "",
+                "- **Root:** \(project.root)",
+                "- **tmux session:** \(project.sessionName)",
+                "- **Commits today:** \(project.commitsToday)",
+            ]
+            if project.worktrees.isEmpty {
+                lines += ["", "No worktrees."]
+            }
+            for worktree in project.worktrees {
+                lines += [
+                    "",
+                    "### \(worktree.name)",
+                    "",
+                    "- **Branch:** \(worktree.branch)",
+                    "- **Status:** \(worktree.status)",
+                    "- **PR:** \(worktree.prUrl ?? "none")",
+                    "",
+                ]
+                guard !worktree.agents.isEmpty else {
+                    lines.append("No agents.")
+                    continue
+                }
+                lines += ["| Agent | Type | Status | Exit code | Runtime |", "| --- | --- | --- | --- | --- |"]
+                lines += worktree.agents.map { agent in
+                    let cells = [
+                        agent.name,
+                        agent.agentType,
+                        agent.status,
+                        agent.exitCode.map(String.init) ?? "—",
+                        agent.runtimeSeconds.map { formatDuration(TimeInterval($0)) } ?? "—",
+                    ]
+                    return "| " + cells.map(Self.tableCell).joined(separator: " | ") + " |"
+                }
+                let spawned = worktree.agents.filter { $0.spawn != nil }
+                if !spawned.isEmpty {
+                    lines += ["", "Spawned from the app:", ""]
+                    lines += spawned.compactMap { agent in
+                        guard let spawn = agent.spawn else { return nil }
+                        var line = "- **\(agent.name)** at \(spawn.at)"
+                        if let replaced = spawn.replacedAgentId { line += ", restarting \(replaced)" }
+                        return line + ": `\(spawn.command)`"
+                    }
+                }
+            }
+        }
+        return lines.joined(separator: "\n") + "\n"
+    }
+
+    func json() -> String {
+        let encoder = JSONEncoder()
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys, .withoutEscapingSlashes]
+        guard let data = try? encoder.encode(self) else { return "{}\n" }
+        return String(decoding: data, as: UTF8.self) + "\n"
+    }
+
+    private static func tableCell(_ text: String) -> String {
+        text.replacingOccurrences(of: "|", with: "\\|").replacingOccurrences(of: "\n", with: " ")
+    }
+}
+
+extension StatusReport {
+    /// Read each project's manifest and today's commit count off the main thread, then
+    /// call `completion` on main. Projects whose manifest can't be read are left out.
+    static func collect(projects: [(root: String, manifestPath: String)], spawns: [String: SpawnRecord] = [:],
+                        completion: @escaping @MainActor (StatusReport) -> Void) {
+        DispatchQueue.global(qos: .userInitiated).async {
+            let now = Date()
+            let formatter = DateFormatter()
+            formatter.dateFormat = "yyyy-MM-dd"
+            let today = formatter.string(from: now)
+
+            var inputs: [(manifest: ManifestModel, commitsToday: Int)] = []
+            for project in projects {
+                guard let manifest = PPGService.shared.readManifest(at: project.manifestPath) else { continue }
+                let heatmap = HomeDashboardView.fetchHeatmapData(projectRoot: project.root)
+                inputs.append((manifest, heatmap.commitsByDate[today, default: 0]))
+            }
+
+            let report = StatusReport(projects: inputs, spawns: spawns, generatedAt: now)
+            DispatchQueue.main.async {
+                completion(report)
+            }
+        }
+    }
+}
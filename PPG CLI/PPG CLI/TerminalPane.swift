@@ -9,12 +9,24 @@ class TerminalPane: NSView {
     private(set) var terminalView: ScrollableTerminalView?
     private var processStarted = false
     private var terminalInstalled = false
+    /// Shown over the top of the scrollback once the agent has exited.
+    private var exitBanner: ExitSummaryBanner?
+    var isShowingExitBanner: Bool { exitBanner != nil }
+    /// When this pane first saw the agent exit — the runtime end when the manifest has no completedAt.
+    private var exitObservedAt: Date?
+    /// Set when the agent's tmux window was already gone at attach time.
+    private var windowMissing = false
+    /// When the running agent last printed anything, while it counts as stalled.
+    private var stalledSince: Date?
+    var onShowLogs: ((AgentModel) -> Void)?
+    var onRestart: ((AgentModel) -> Void)?
+    var onRestartWithNewPrompt: ((AgentModel) -> Void)?
 
     init(agent: AgentModel, sessionName: String) {
         self.agent = agent
         self.sessionName = sessionName
         let displayName = agent.name.isEmpty ? agent.id : agent.name
-        self.label = NSTextField(labelWithString: "\(displayName) — \(agent.status.rawValue)")
+        self.label = NSTextField(labelWithString: "\(displayName) — \(agent.statusLabel)")
         super.init(frame: .zero)
         setupUI()
     }
@@ -30,6 +42,9 @@ class TerminalPane: NSView {
         layer?.shadowOffset = .zero
 
         label.isHidden = true
+        setAccessibilityElement(true)
+        setAccessibilityRole(.group)
+        setAccessibilityLabel(AccessibilityLabels.agentPane(name: agent.name.isEmpty ? agent.id : agent.name, status: agent.statusLabel))
     }
 
     /// Create and install the terminal view on demand (first time only).
@@ -41,7 +56,8 @@ class TerminalPane: NSView {
         tv.wantsLayer = true
         tv.layer?.masksToBounds = true
         tv.translatesAutoresizingMaskIntoConstraints = false
-        addSubview(tv)
+        // Keep an exit banner added before the terminal existed on top of it
+        addSubview(tv, positioned: .below, relativeTo: exitBanner)
 
         NSLayoutConstraint.activate([
             tv.topAnchor.constraint(equalTo: topAnchor),
@@ -73,25 +89,35 @@ class TerminalPane: NSView {
         // never reflowed even after a later SIGWINCH resize.
         if let tv = terminalView, !processStarted && tv.bounds.width > 1 {
             processStarted = true
-            startTmux()
+            startAfterPreflight()
+        }
+    }
+
+    /// Attach only if the agent's tmux window still exists. A missing window (tmux
+    /// restarted, session killed) gets a banner instead of a pane full of tmux errors.
+    private func startAfterPreflight() {
+        PPGService.shared.checkTmuxTarget(agent.tmuxTarget) { [weak self] exists in
+            guard let self else { return }
+            guard self.window != nil else {
+                // Removed while checking; try again the next time it's shown
+                self.processStarted = false
+                return
+            }
+            guard exists == false else {
+                self.startTmux()
+                return
+            }
+            self.windowMissing = true
+            if self.exitBanner == nil { self.showMissingWindowBanner() }
         }
     }
 
     func startTmux() {
         let target = agent.tmuxTarget
 
-        // Parse session name and window spec from the tmux target.
-        // Format is typically "session:window" or "session:window.pane".
-        let tmuxSession: String
-        let windowSpec: String?
-        if let colonIdx = target.firstIndex(of: ":") {
-            tmuxSession = String(target[..<colonIdx])
-            let after = String(target[target.index(after: colonIdx)...])
-            windowSpec = after.isEmpty ? nil : after
-        } else {
-            tmuxSession = target
-            windowSpec = nil
-        }
+        // Format is "session", "session:window", or "session:window.pane".
+        let parsed = TmuxTarget(parsing: target)
+        let tmuxSession = parsed.session
 
         // Use a grouped session so this client gets independent current-window
         // tracking.  Without this, all TerminalPanes sharing the same tmux session
@@ -107,14 +133,22 @@ class TerminalPane: NSView {
         var cmd = "if [ -x /usr/libexec/path_helper ]; then eval $(/usr/libexec/path_helper -s); fi; "
         cmd += "[ -f ~/.zprofile ] && source ~/.zprofile; "
         cmd += "[ -f ~/.zshrc ] && source ~/.zshrc; "
-        cmd += "tmux set-option -t \(shellEscape(target)) status off 2>/dev/null; "
-        cmd += "exec tmux new-session -t \(shellEscape(tmuxSession)) -s \(shellEscape(viewSession))"
-        cmd += " \\; set-option destroy-unattached on"
-        cmd += " \\; set-option status off"
-        cmd += " \\; set-option mouse on"
-        if let win = windowSpec {
-            cmd += " \\; select-window -t :\(shellEscape(win))"
+        cmd += TmuxCommand("set-option").option("-t", target).arguments("status", "off").shellString + " 2>/dev/null; "
+
+        var attach = TmuxCommand("new-session").option("-t", tmuxSession).option("-s", viewSession)
+            .then("set-option").arguments("destroy-unattached", "on")
+            .then("set-option").arguments("status", "off")
+            .thenEnablingMouse(features: PPGService.shared.tmuxFeatures)
+        if let win = parsed.window {
+            attach = attach.then("select-window").option("-t", ":" + win)
+            // Several agents can share a window; focus this agent's pane so typing
+            // reaches it, and make sure input isn't mirrored to its neighbours.
+            if let pane = parsed.pane {
+                attach = attach.then("set-window-option").arguments("synchronize-panes", "off")
+                    .then("select-pane").option("-t", ":\(win).\(pane)")
+            }
         }
+        cmd += "exec " + attach.shellString
 
         // Internal tmux boot command uses Bourne syntax — always run under /bin/zsh
         // regardless of the user's shell preference (which is for interactive terminals).
@@ -131,10 +165,135 @@ class TerminalPane: NSView {
         layer?.backgroundColor = Theme.terminalBackground.resolvedCGColor(for: effectiveAppearance)
     }
 
-    func updateStatus(_ status: AgentStatus) {
+    /// `stalledSince` is when a running agent last printed anything, once it has been
+    /// quiet long enough to count as stalled (see `AgentActivity`).
+    func updateStatus(_ status: AgentStatus, rawStatus: String? = nil, exitCode: Int? = nil, completedAt: Date? = nil, stalledSince: Date? = nil) {
         let displayName = agent.name.isEmpty ? agent.id : agent.name
-        label.stringValue = "\(displayName) — \(status.rawValue)"
+        var statusText = AgentModel.statusLabel(status: status, rawStatus: rawStatus ?? "?")
+        if stalledSince != nil { statusText += ", stalled" }
+        label.stringValue = "\(displayName) — \(statusText)"
         label.textColor = Theme.statusColor(for: status)
+        setAccessibilityLabel(AccessibilityLabels.agentPane(name: displayName, status: statusText))
+        self.stalledSince = stalledSince
+        updateExitBanner(status: status, exitCode: exitCode, completedAt: completedAt)
+    }
+
+    // MARK: - Agent Info
+
+    /// First line of the prompt, shortened for a tooltip. Nil for agents without a prompt.
+    static func promptSummary(_ prompt: String, maxLength: Int = 100) -> String? {
+        guard let firstLine = prompt.split(whereSeparator: \.isNewline)
+            .lazy.map({ $0.trimmingCharacters(in: .whitespaces) })
+            .first(where: { !$0.isEmpty }) else { return nil }
+        guard firstLine.count > maxLength else { return firstLine }
+        return String(firstLine.prefix(maxLength - 1)) + "…"
+    }
+
+    var promptSummary: String? { Self.promptSummary(agent.prompt) }
+
+    func showInfoPopover(relativeTo anchor: NSView) {
+        let popover = NSPopover()
+        popover.behavior = .transient
+        let agentId = agent.id
+        let spawn = OpenProjects.shared.projects.lazy.compactMap { $0.statusHistory.spawn(forAgent: agentId) }.first
+        popover.contentViewController = AgentInfoViewController(agent: agent, spawn: spawn)
+        popover.show(relativeTo: anchor.bounds, of: anchor, preferredEdge: .maxY)
+    }
+
+    // MARK: - Exit Summary
+
+    /// Banner headline for an agent that has stopped, or nil while it is still alive.
+    /// An exit code means the agent's process ended on its own; without one, a
+    /// lost agent's tmux window was closed outside ppg.
+    static func exitHeadline(status: AgentStatus, exitCode: Int?) -> (text: String, color: NSColor)? {
+        switch status {
+        case .spawning, .running, .waiting, .unknown:
+            return nil
+        case .completed, .failed, .killed, .lost:
+            break
+        }
+        if let code = exitCode {
+            return ("Exited with code \(code)", code == 0 ? .systemGreen : .systemRed)
+        }
+        switch status {
+        case .completed: return ("Completed", Theme.statusColor(for: .completed))
+        case .failed: return ("Failed", Theme.statusColor(for: .failed))
+        case .killed: return ("Killed", Theme.statusColor(for: .killed))
+        default: return ("Gone — its tmux window was closed outside ppg", Theme.statusColor(for: .lost))
+        }
+    }
+
+    private func updateExitBanner(status: AgentStatus, exitCode: Int?, completedAt: Date?) {
+        guard let headline = Self.exitHeadline(status: status, exitCode: exitCode) else {
+            exitObservedAt = nil
+            if windowMissing {
+                showMissingWindowBanner()
+            } else if let stalledSince {
+                showStalledBanner(since: stalledSince)
+            } else {
+                exitBanner?.removeFromSuperview()
+                exitBanner = nil
+            }
+            return
+        }
+
+        if exitObservedAt == nil { exitObservedAt = Date() }
+        let endedAt = completedAt ?? exitObservedAt ?? Date()
+        let runtime = agent.startedDate.map { "Ran for " + formatDuration(endedAt.timeIntervalSince($0)) }
+
+        let banner = exitBanner ?? makeExitBanner()
+        banner.configure(headline: headline.text, color: headline.color, detail: runtime)
+        banner.setRestartHidden(!hasBannerActions)
+    }
+
+    private func showMissingWindowBanner() {
+        let banner = exitBanner ?? makeExitBanner()
+        banner.configure(
+            headline: "tmux window missing",
+            color: Theme.statusColor(for: .lost),
+            detail: "\(agent.tmuxTarget) no longer exists — tmux may have restarted"
+        )
+        banner.setRestartHidden(!hasBannerActions)
+    }
+
+    private func showStalledBanner(since: Date) {
+        let banner = exitBanner ?? makeExitBanner()
+        banner.configure(
+            headline: "Last output \(humanizeRelative(since))",
+            color: .systemOrange,
+            detail: "Still running — it may be stuck or waiting for input"
+        )
+        // `ppg restart` would kill the live agent without asking
+        banner.setRestartHidden(true)
+    }
+
+    /// Only real manifest agents can be restarted or have their logs fetched by ID.
+    private var hasBannerActions: Bool { onShowLogs != nil || onRestart != nil }
+
+    private func makeExitBanner() -> ExitSummaryBanner {
+        let banner = ExitSummaryBanner()
+        banner.setActionsHidden(!hasBannerActions)
+        banner.onShowLogs = { [weak self] in
+            guard let self else { return }
+            self.onShowLogs?(self.agent)
+        }
+        banner.onRestart = { [weak self] in
+            guard let self else { return }
+            self.onRestart?(self.agent)
+        }
+        banner.onRestartWithNewPrompt = { [weak self] in
+            guard let self else { return }
+            self.onRestartWithNewPrompt?(self.agent)
+        }
+        banner.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(banner, positioned: .above, relativeTo: terminalView)
+        NSLayoutConstraint.activate([
+            banner.topAnchor.constraint(equalTo: topAnchor, constant: 8),
+            banner.leadingAnchor.constraint(equalTo: leadingAnchor, constant: 16),
+            banner.trailingAnchor.constraint(equalTo: trailingAnchor, constant: -16),
+        ])
+        exitBanner = banner
+        return banner
     }
 
     /// Explicit cleanup — tears down the terminal view (timer, monitor, process).
@@ -147,3 +306,229 @@ class TerminalPane: NSView {
         tearDown()
     }
 }
+
+// MARK: - Exit Summary Banner
+
+/// Strip pinned to the top of an exited agent's pane. Only covers its own height,
+/// so the scrollback underneath stays visible and scrollable.
+final class ExitSummaryBanner: NSView {
+    var onShowLogs: (() -> Void)?
+    var onRestart: (() -> Void)?
+    var onRestartWithNewPrompt: (() -> Void)?
+
+    private let statusDot = NSView()
+    private let headlineLabel = NSTextField(labelWithString: "")
+    private let detailLabel = NSTextField(labelWithString: "")
+    private let logsButton = NSButton(title: "Show Last 200 Lines", target: nil, action: nil)
+    private let restartButton = NSButton(title: "Restart", target: nil, action: nil)
+    private let newPromptButton = NSButton(title: "Restart with New Prompt…", target: nil, action: nil)
+
+    override init(frame frameRect: NSRect) {
+        super.init(frame: frameRect)
+        setupUI()
+    }
+
+    required init?(coder: NSCoder) { fatalError() }
+
+    private func setupUI() {
+        wantsLayer = true
+        setAccessibilityElement(true)
+        setAccessibilityRole(.group)
+        layer?.cornerRadius = 8
+        layer?.borderWidth = 1
+        applyColors()
+
+        statusDot.wantsLayer = true
+        statusDot.layer?.cornerRadius = 4
+        statusDot.translatesAutoresizingMaskIntoConstraints = false
+        NSLayoutConstraint.activate([
+            statusDot.widthAnchor.constraint(equalToConstant: 8),
+            statusDot.heightAnchor.constraint(equalToConstant: 8),
+        ])
+
+        headlineLabel.font = .systemFont(ofSize: 12, weight: .semibold)
+        detailLabel.font = .systemFont(ofSize: 12)
+        detailLabel.textColor = .secondaryLabelColor
+
+        for button in [logsButton, restartButton, newPromptButton] {
+            button.bezelStyle = .rounded
+            button.controlSize = .small
+            button.font = .systemFont(ofSize: 11)
+            button.target = self
+        }
+        logsButton.action = #selector(logsClicked)
+        restartButton.action = #selector(restartClicked)
+        newPromptButton.action = #selector(newPromptClicked)
+
+        let spacer = NSView()
+        spacer.setContentHuggingPriority(.defaultLow, for: .horizontal)
+
+        let stack = NSStackView(views: [statusDot, headlineLabel, detailLabel, spacer, logsButton, restartButton, newPromptButton])
+        stack.orientation = .horizontal
+        stack.alignment = .centerY
+        stack.spacing = 8
+        stack.edgeInsets = NSEdgeInsets(top: 6, left: 10, bottom: 6, right: 8)
+        stack.translatesAutoresizingMaskIntoConstraints = false
+        addSubview(stack)
+        NSLayoutConstraint.activate([
+            stack.topAnchor.constraint(equalTo: topAnchor),
+            stack.leadingAnchor.constraint(equalTo: leadingAnchor),
+            stack.trailingAnchor.constraint(equalTo: trailingAnchor),
+            stack.bottomAnchor.constraint(equalTo: bottomAnchor),
+        ])
+    }
+
+    func configure(headline: String, color: NSColor, detail: String?) {
+        headlineLabel.stringValue = headline
+        headlineLabel.textColor = color
+        statusDot.layer?.backgroundColor = color.cgColor
+        detailLabel.stringValue = detail ?? ""
+        detailLabel.isHidden = detail == nil
+        setAccessibilityLabel([headline, detail].compactMap { $0 }.joined(separator: ", "))
+    }
+
+    func setActionsHidden(_ hidden: Bool) {
+        logsButton.isHidden = hidden
+        restartButton.isHidden = hidden
+        newPromptButton.isHidden = hidden
+    }
+
+    func setRestartHidden(_ hidden: Bool) {
+        restartButton.isHidden = hidden
+        newPromptButton.isHidden = hidden
+    }
+
+    private func applyColors() {
+        layer?.backgroundColor = Theme.paneOverlayBackground.resolvedCGColor(for: effectiveAppearance)
+        layer?.borderColor = Theme.paneOverlayBorder.resolvedCGColor(for: effectiveAppearance)
+    }
+
+    override func viewDidChangeEffectiveAppearance() {
+        super.viewDidChangeEffectiveAppearance()
+        applyColors()
+    }
+
+    @objc private func logsClicked() { onShowLogs?() }
+    @objc private func restartClicked() { onRestart?() }
+    @objc private func newPromptClicked() { onRestartWithNewPrompt?() }
+}
+
+// MARK: - Agent Info Popover
+
+/// Popover content describing the agent behind a pane: its full prompt (copyable, for
+/// reuse in a respawn), type, start time, and tmux target, plus how the app spawned it
+/// when it did.
+final class AgentInfoViewController: NSViewController {
+    private let agent: AgentModel
+    private let spawn: SpawnRecord?
+
+    init(agent: AgentModel, spawn: SpawnRecord? = nil) {
+        self.agent = agent
+        self.spawn = spawn
+        super.init(nibName: nil, bundle: nil)
+    }
+
+    required init?(coder: NSCoder) { fatalError() }
+
+    override func loadView() {
+        let titleLabel = NSTextField(labelWithString: agent.name.isEmpty ? agent.id : agent.name)
+        titleLabel.font = .systemFont(ofSize: 13, weight: .semibold)
+
+        var rows: [[NSView]] = [
+            [detailKey("Type"), detailValue(agent.agentType)],
+            [detailKey("Started"), startedValue()],
+            [detailKey("tmux"), detailValue(agent.tmuxTarget)],
+        ]
+        if let usage = agent.usage {
+            rows.append([detailKey("Usage"), UsageChip(usage: usage)])
+        }
+        if let spawn {
+            rows.append([detailKey("Spawned"), spawnedValue(spawn)])
+            let command = detailValue(spawn.command)
+            command.toolTip = spawn.command
+            rows.append([detailKey("Command"), command])
+        }
+        let details = NSGridView(views: rows)
+        details.rowSpacing = 4
+        details.columnSpacing = 8
+
+        // Plain-text view: prompts are shown verbatim (never interpreted) and wrapped.
+        let scrollView = NSScrollView(frame: NSRect(x: 0, y: 0, width: 420, height: 220))
+        scrollView.hasVerticalScroller = true
+        scrollView.borderType = .bezelBorder
+        scrollView.translatesAutoresizingMaskIntoConstraints = false
+        // Sized to the scroll view up front; a zero-sized text view can lay out nothing
+        let textView = NSTextView(frame: NSRect(origin: .zero, size: scrollView.contentSize))
+        textView.isEditable = false
+        textView.isSelectable = true
+        textView.isRichText = false
+        textView.font = .monospacedSystemFont(ofSize: 11, weight: .regular)
+        textView.string = agent.prompt.isEmpty ? "(no prompt)" : agent.prompt
+        textView.minSize = NSSize(width: 0, height: scrollView.contentSize.height)
+        textView.maxSize = NSSize(width: CGFloat.greatestFiniteMagnitude, height: CGFloat.greatestFiniteMagnitude)
+        textView.isVerticallyResizable = true
+        textView.isHorizontallyResizable = false
+        textView.autoresizingMask = [.width]
+        textView.textContainer?.containerSize = NSSize(width: scrollView.contentSize.width, height: CGFloat.greatestFiniteMagnitude)
+        textView.textContainer?.widthTracksTextView = true
+        scrollView.documentView = textView
+
+        let copyButton = NSButton(title: "Copy Prompt", target: self, action: #selector(copyPrompt))
+        copyButton.bezelStyle = .rounded
+        copyButton.controlSize = .small
+        copyButton.isEnabled = !agent.prompt.isEmpty
+
+        let stack = NSStackView(views: [titleLabel, details, scrollView, copyButton])
+        stack.orientation = .vertical
+        stack.alignment = .leading
+        stack.spacing = 8
+        stack.edgeInsets = NSEdgeInsets(top: 12, left: 12, bottom: 12, right: 12)
+        NSLayoutConstraint.activate([
+            scrollView.widthAnchor.constraint(equalToConstant: scrollView.frame.width),
+            scrollView.heightAnchor.constraint(equalToConstant: scrollView.frame.height),
+        ])
+        view = stack
+    }
+
+    private func detailKey(_ text: String) -> NSTextField {
+        let label = NSTextField(labelWithString: text)
+        label.font = .systemFont(ofSize: 11)
+        label.textColor = .secondaryLabelColor
+        return label
+    }
+
+    private func detailValue(_ text: String) -> NSTextField {
+        let label = NSTextField(labelWithString: text.isEmpty ? "—" : text)
+        label.font = .systemFont(ofSize: 11)
+        label.lineBreakMode = .byTruncatingMiddle
+        label.isSelectable = true
+        return label
+    }
+
+    private func startedValue() -> NSTextField {
+        let label = detailValue(agent.startedDate.map { humanizeRelative($0) } ?? agent.startedAt)
+        if agent.startedDate != nil {
+            label.toolTip = agent.startedAt
+        }
+        return label
+    }
+
+    /// "5 minutes ago · restart of ag-1a2b3c4d", with the batch size for Restart Exited Agents.
+    private func spawnedValue(_ spawn: SpawnRecord) -> NSTextField {
+        var parts = [humanizeRelative(spawn.at)]
+        if let replaced = spawn.replacedAgentId {
+            parts.append("restart of \(replaced)")
+        }
+        if spawn.batchSize > 1 {
+            parts.append("1 of \(spawn.batchSize)")
+        }
+        let label = detailValue(parts.joined(separator: " · "))
+        label.toolTip = DateFormatter.localizedString(from: spawn.at, dateStyle: .medium, timeStyle: .medium)
+        return label
+    }
+
+    @objc private func copyPrompt() {
+        NSPasteboard.general.clearContents()
+        NSPasteboard.general.setString(agent.prompt, forType: .string)
+    }
+}
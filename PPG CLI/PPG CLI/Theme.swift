@@ -64,13 +64,17 @@ enum Theme {
     static let agentRowBackground = adaptive(dark: (0.14, 0.14, 0.15, 1.0), light: (0.98, 0.98, 0.98, 1.0))
 
     // MARK: - Status Color
+    /// The user's `theme.json` color for `status` if it sets one, else the built-in color.
     static func statusColor(for status: AgentStatus) -> NSColor {
+        if let custom = UserTheme.shared.overrides.statusColors[status] {
+            return custom.nsColor
+        }
         switch status {
         case .running: return .systemGreen
         case .completed: return .systemBlue
         case .failed: return .systemRed
         case .killed: return .systemOrange
-        case .lost, .waiting: return .systemGray
+        case .lost, .waiting, .unknown: return .systemGray
         case .spawning: return .systemYellow
         }
     }
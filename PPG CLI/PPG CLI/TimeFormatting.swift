@@ -0,0 +1,84 @@
+import Foundation
+
+/// Parse a manifest timestamp. The CLI writes `Date.toISOString()` output, which
+/// carries fractional seconds; older entries and hand edits may not.
+nonisolated func parseISO8601(_ string: String) -> Date? {
+    ISO8601Formatters.fractionalSeconds.date(from: string) ?? ISO8601Formatters.wholeSeconds.date(from: string)
+}
+
+/// Made once, since every refresh parses each agent's timestamps. ISO8601DateFormatter
+/// is thread-safe, and these are never reconfigured after creation.
+nonisolated private enum ISO8601Formatters {
+    nonisolated(unsafe) static let fractionalSeconds: ISO8601DateFormatter = {
+        let formatter = ISO8601DateFormatter()
+        formatter.formatOptions = [.withInternetDateTime, .withFractionalSeconds]
+        return formatter
+    }()
+    nonisolated(unsafe) static let wholeSeconds: ISO8601DateFormatter = {
+        let formatter = ISO8601DateFormatter()
+        formatter.formatOptions = [.withInternetDateTime]
+        return formatter
+    }()
+}
+
+/// Chronological order for manifest timestamps, given each one's parsed and raw form.
+/// Timestamps that don't parse sort after those that do, by raw string among themselves,
+/// so one bad entry doesn't scramble the rest.
+nonisolated func timestampPrecedes(_ lhs: (date: Date?, raw: String), _ rhs: (date: Date?, raw: String)) -> Bool {
+    switch (lhs.date, rhs.date) {
+    case let (left?, right?):
+        return left != right ? left < right : lhs.raw < rhs.raw
+    case (.some, nil):
+        return true
+    case (nil, .some):
+        return false
+    case (nil, nil):
+        return lhs.raw < rhs.raw
+    }
+}
+
+/// Render `date` relative to `now`: "just now", "5 minutes ago", "3 hours ago", "2 days ago".
+/// Dates slightly in the future (clock skew between machines) read as "just now".
+nonisolated func humanizeRelative(_ date: Date, now: Date = Date()) -> String {
+    let seconds = Int(now.timeIntervalSince(date))
+    if seconds < 60 { return "just now" }
+
+    let minutes = seconds / 60
+    if minutes < 60 { return plural(minutes, "minute") + " ago" }
+
+    let hours = minutes / 60
+    if hours < 24 { return plural(hours, "hour") + " ago" }
+
+    let days = hours / 24
+    if days < 30 { return plural(days, "day") + " ago" }
+
+    let months = days / 30
+    if months < 12 { return plural(months, "month") + " ago" }
+
+    // 360–364 days are 12 months but not yet 365 days; call that a year
+    return plural(max(1, days / 365), "year") + " ago"
+}
+
+/// Relative form of an ISO 8601 manifest timestamp, or nil if it can't be parsed.
+nonisolated func humanizeRelative(iso string: String, now: Date = Date()) -> String? {
+    parseISO8601(string).map { humanizeRelative($0, now: now) }
+}
+
+/// Compact elapsed time: "45s", "12m", "1h 12m", "2d 3h".
+nonisolated func formatDuration(_ interval: TimeInterval) -> String {
+    let seconds = max(0, Int(interval))
+    if seconds < 60 { return "\(seconds)s" }
+
+    let minutes = seconds / 60
+    if minutes < 60 { return "\(minutes)m" }
+
+    let hours = minutes / 60
+    if hours < 24 { return minutes % 60 == 0 ? "\(hours)h" : "\(hours)h \(minutes % 60)m" }
+
+    let days = hours / 24
+    return hours % 24 == 0 ? "\(days)d" : "\(days)d \(hours % 24)h"
+}
+
+nonisolated private func plural(_ count: Int, _ unit: String) -> String {
+    "\(count) \(unit)\(count == 1 ? "" : "s")"
+}
@@ -0,0 +1,165 @@
+import AppKit
+
+extension Notification.Name {
+    static let userThemeDidChange = Notification.Name("PPGUserThemeDidChange")
+}
+
+/// Colors set in the user's `theme.json`, e.g. a colorblind-friendly status palette:
+///
+///     {
+///       "status.running": "#0072B2",
+///       "status.failed": { "dark": "#FF8A80", "light": "#B00020" }
+///     }
+///
+/// A value is `#RRGGBB`, `#RRGGBBAA`, or a `dark`/`light` pair of those.
+nonisolated struct ThemeOverrides: Equatable {
+    struct RGBA: Equatable {
+        var red: Double
+        var green: Double
+        var blue: Double
+        var alpha: Double
+    }
+
+    struct Color: Equatable {
+        var dark: RGBA
+        var light: RGBA
+
+        var nsColor: NSColor {
+            NSColor(name: nil) { appearance in
+                let c = appearance.isDark ? dark : light
+                return NSColor(srgbRed: c.red, green: c.green, blue: c.blue, alpha: c.alpha)
+            }
+        }
+    }
+
+    enum ParseError: LocalizedError, Equatable {
+        case invalidJSON(String)
+        case notAnObject
+        case unknownKey(String)
+        case invalidColor(key: String)
+
+        var errorDescription: String? {
+            switch self {
+            case .invalidJSON(let detail):
+                return "theme.json isn't valid JSON: \(detail)"
+            case .notAnObject:
+                return "theme.json must be a JSON object of color keys."
+            case .unknownKey(let key):
+                return "theme.json: unknown key \"\(key)\". Diagnostics lists the keys you can set."
+            case .invalidColor(let key):
+                return "theme.json: \"\(key)\" must be \"#RRGGBB\", \"#RRGGBBAA\" or {\"dark\": …, \"light\": …}."
+            }
+        }
+    }
+
+    /// Every key the file can set, one per agent status.
+    static let keys: [String] = AgentStatus.allCases.map { "status.\($0.rawValue)" }
+
+    private(set) var statusColors: [AgentStatus: Color] = [:]
+
+    init() {}
+
+    static func parse(_ data: Data) throws -> ThemeOverrides {
+        let json: Any
+        do {
+            json = try JSONSerialization.jsonObject(with: data)
+        } catch {
+            // Foundation's message carries the line and column, e.g. "… around line 3, column 5."
+            let detail = (error as NSError).userInfo[NSDebugDescriptionErrorKey] as? String
+            throw ParseError.invalidJSON(detail ?? error.localizedDescription)
+        }
+        guard let object = json as? [String: Any] else { throw ParseError.notAnObject }
+
+        var overrides = ThemeOverrides()
+        for key in object.keys.sorted() {
+            guard key.hasPrefix("status."),
+                  let status = AgentStatus(rawValue: String(key.dropFirst("status.".count))) else {
+                throw ParseError.unknownKey(key)
+            }
+            guard let color = color(from: object[key]) else { throw ParseError.invalidColor(key: key) }
+            overrides.statusColors[status] = color
+        }
+        return overrides
+    }
+
+    private static func color(from value: Any?) -> Color? {
+        if let hex = value as? String {
+            return parseHex(hex).map { Color(dark: $0, light: $0) }
+        }
+        guard let pair = value as? [String: Any], pair.count == 2,
+              let dark = (pair["dark"] as? String).flatMap(parseHex),
+              let light = (pair["light"] as? String).flatMap(parseHex) else { return nil }
+        return Color(dark: dark, light: light)
+    }
+
+    /// "#RRGGBB" or "#RRGGBBAA", case-insensitive.
+    static func parseHex(_ text: String) -> RGBA? {
+        guard text.hasPrefix("#") else { return nil }
+        let digits = text.dropFirst()
+        guard digits.count == 6 || digits.count == 8, digits.allSatisfy(\.isHexDigit),
+              let value = UInt32(digits, radix: 16) else { return nil }
+        let rgba = digits.count == 6 ? value << 8 | 0xFF : value
+        func channel(_ shift: UInt32) -> Double { Double(rgba >> shift & 0xFF) / 255 }
+        return RGBA(red: channel(24), green: channel(16), blue: channel(8), alpha: channel(0))
+    }
+}
+
+/// Loads `theme.json` at launch and re-reads it whenever it's saved or the app comes
+/// back to the front, which also picks up a file created while the app was running.
+/// A file that can't be used is reported once and the previous colors stay in effect.
+final class UserTheme {
+    static let shared = UserTheme()
+    static let fileURL = FileManager.default.homeDirectoryForCurrentUser
+        .appendingPathComponent("Library/Application Support/PPG CLI/theme.json")
+
+    private(set) var overrides = ThemeOverrides()
+    /// Why the file was last rejected; nil while it loads cleanly or doesn't exist.
+    private(set) var loadError: String?
+    /// Called with the message each time the file goes from usable to unusable.
+    var onLoadError: ((String) -> Void)?
+
+    private var watcher: ManifestWatcher?
+    private var activationObserver: NSObjectProtocol?
+
+    private init() {}
+
+    func start() {
+        guard activationObserver == nil else { return }
+        reload()
+        activationObserver = NotificationCenter.default.addObserver(
+            forName: NSApplication.didBecomeActiveNotification, object: nil, queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                self?.reload()
+            }
+        }
+    }
+
+    func reload() {
+        if let watcher {
+            watcher.retry()
+        } else {
+            watcher = ManifestWatcher(path: Self.fileURL.path) { [weak self] in
+                self?.reload()
+            }
+        }
+
+        var loaded = ThemeOverrides()
+        if let data = try? Data(contentsOf: Self.fileURL) {
+            do {
+                loaded = try ThemeOverrides.parse(data)
+            } catch {
+                let message = error.localizedDescription
+                if message != loadError {
+                    loadError = message
+                    onLoadError?(message)
+                }
+                return
+            }
+        }
+        loadError = nil
+        guard loaded != overrides else { return }
+        overrides = loaded
+        NotificationCenter.default.post(name: .userThemeDidChange, object: nil)
+    }
+}
@@ -0,0 +1,105 @@
+import Foundation
+
+/// Pull a dotted version ("0.3.3") out of the ppg CLI's `--version` output. The last
+/// line's first version is used, so numbers printed by shell startup scripts before it
+/// aren't picked up; a bare number on its own isn't trusted as a version. tmux has its
+/// own parser, `TmuxVersion.parse`.
+nonisolated func extractVersion(from output: String) -> String? {
+    guard let lastLine = output.split(whereSeparator: \.isNewline)
+        .last(where: { !$0.trimmingCharacters(in: .whitespaces).isEmpty }) else { return nil }
+    return lastLine.range(of: #"[0-9]+(?:\.[0-9]+)+[a-z]?"#, options: .regularExpression).map { String(lastLine[$0]) }
+}
+
+/// Numeric components of a version, ignoring letter suffixes ("3.3a" → [3, 3]).
+nonisolated func versionComponents(_ version: String) -> [Int]? {
+    let parts = version.split(separator: ".")
+    guard !parts.isEmpty else { return nil }
+    var ints: [Int] = []
+    for part in parts {
+        let numericPrefix = part.prefix { $0.isNumber }
+        guard !numericPrefix.isEmpty, let value = Int(numericPrefix) else { return nil }
+        ints.append(value)
+    }
+    return ints
+}
+
+/// Whether `version` is at least `minimum`, or nil if either can't be parsed.
+/// Missing components count as zero, so "3" equals "3.0".
+nonisolated func isVersion(_ version: String, atLeast minimum: String) -> Bool? {
+    guard let lhs = versionComponents(version),
+          let rhs = versionComponents(minimum) else { return nil }
+    let count = max(lhs.count, rhs.count)
+    for idx in 0..<count {
+        let l = idx < lhs.count ? lhs[idx] : 0
+        let r = idx < rhs.count ? rhs[idx] : 0
+        if l != r { return l > r }
+    }
+    return true
+}
+
+// MARK: - tmux
+
+/// A tmux version from `tmux -V`: a release ("tmux 3.3a", "tmux 2.9") or a development
+/// build ("tmux next-3.4"), which is counted as the release it leads up to. "tmux master"
+/// and other builds without a number don't parse.
+nonisolated struct TmuxVersion: Comparable, CustomStringConvertible {
+    let major: Int
+    let minor: Int
+    /// Letter of a patch release, e.g. "a" in 3.3a; empty otherwise.
+    let patch: String
+    let isDevelopment: Bool
+
+    init(major: Int, minor: Int, patch: String = "", isDevelopment: Bool = false) {
+        self.major = major
+        self.minor = minor
+        self.patch = patch
+        self.isDevelopment = isDevelopment
+    }
+
+    static func parse(_ output: String) -> TmuxVersion? {
+        let pattern = #"tmux\s+(next-)?([0-9]+)\.([0-9]+)([a-z]?)"#
+        guard let regex = try? NSRegularExpression(pattern: pattern),
+              let match = regex.firstMatch(in: output, range: NSRange(output.startIndex..., in: output)) else { return nil }
+        func group(_ index: Int) -> String? {
+            Range(match.range(at: index), in: output).map { String(output[$0]) }
+        }
+        guard let major = group(2).flatMap(Int.init), let minor = group(3).flatMap(Int.init) else { return nil }
+        return TmuxVersion(major: major, minor: minor, patch: group(4) ?? "", isDevelopment: group(1) != nil)
+    }
+
+    func isAtLeast(_ major: Int, _ minor: Int) -> Bool {
+        (self.major, self.minor) >= (major, minor)
+    }
+
+    var description: String {
+        isDevelopment ? "next-\(major).\(minor)" : "\(major).\(minor)\(patch)"
+    }
+
+    static func < (lhs: TmuxVersion, rhs: TmuxVersion) -> Bool {
+        (lhs.major, lhs.minor, lhs.patch) < (rhs.major, rhs.minor, rhs.patch)
+    }
+}
+
+/// What the installed tmux supports, for commands whose syntax changed between releases.
+/// An unknown version is assumed to be current.
+nonisolated struct TmuxFeatures: Equatable {
+    /// `mouse` replaced `mode-mouse` and the `mouse-select-*`/`mouse-resize-pane` options in 2.1.
+    let hasMouseOption: Bool
+    /// `resize-window` arrived in 2.9.
+    let hasResizeWindow: Bool
+    /// Codex's input box styling needs `PPGService.minimumTmuxVersionForCodexInputTheme`.
+    let supportsCodexInputTheme: Bool
+
+    init(version: TmuxVersion?) {
+        hasMouseOption = version?.isAtLeast(2, 1) ?? true
+        hasResizeWindow = version?.isAtLeast(2, 9) ?? true
+        supportsCodexInputTheme = version.flatMap {
+            isVersion("\($0.major).\($0.minor)", atLeast: PPGService.minimumTmuxVersionForCodexInputTheme)
+        } ?? true
+    }
+
+    /// Session options that together turn the mouse on.
+    var mouseOptionNames: [String] {
+        hasMouseOption ? ["mouse"] : ["mode-mouse", "mouse-select-pane", "mouse-resize-pane", "mouse-select-window"]
+    }
+}
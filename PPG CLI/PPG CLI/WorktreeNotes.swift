@@ -0,0 +1,153 @@
+import Foundation
+
+/// A free-form note the user keeps against a worktree ("waiting on review").
+/// Cleared notes stay as blank entries until pruned, so a merge with an older
+/// copy of the file doesn't bring them back.
+nonisolated struct WorktreeNote: Codable, Equatable {
+    var text: String
+    var editedAt: Date
+    /// When the worktree was first seen missing from the manifest; nil while it exists.
+    var missingSince: Date?
+
+    var isBlank: Bool { text.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty }
+
+    /// First non-blank line, for tooltips.
+    var preview: String {
+        text.components(separatedBy: .newlines)
+            .map { $0.trimmingCharacters(in: .whitespaces) }
+            .first { !$0.isEmpty } ?? ""
+    }
+}
+
+/// Notes per worktree ID for one project, kept in `.ppg/worktree-notes.json`. These
+/// are the user's own and never go into the manifest, which the CLI rewrites.
+///
+/// Notes outlive their worktree for `retention` so a worktree cleaned up by mistake
+/// and restored keeps its note; after that they're dropped on the next refresh.
+final class WorktreeNotes {
+    static let retention: TimeInterval = 30 * 24 * 60 * 60
+
+    private(set) var notes: [String: WorktreeNote] = [:]
+    private let path: String?
+    private let ioQueue = DispatchQueue(label: "ppg.worktree-notes.io", qos: .utility)
+
+    /// `path` nil keeps notes in memory only.
+    init(path: String?) {
+        self.path = path
+        if let path { notes = Self.load(path: path) }
+    }
+
+    convenience init(projectRoot: String) {
+        guard !projectRoot.isEmpty, projectRoot != "/" else {
+            self.init(path: nil)
+            return
+        }
+        let ppgDir = (projectRoot as NSString).appendingPathComponent(".ppg")
+        self.init(path: (ppgDir as NSString).appendingPathComponent("worktree-notes.json"))
+    }
+
+    /// The note for a worktree, or nil if it has none.
+    func note(for worktreeId: String) -> WorktreeNote? {
+        guard let note = notes[worktreeId], !note.isBlank else { return nil }
+        return note
+    }
+
+    /// Replace a worktree's note; blank text clears it.
+    func setText(_ text: String, for worktreeId: String, at date: Date = Date()) {
+        let hadNote = note(for: worktreeId) != nil
+        let existing = notes[worktreeId]
+        guard existing?.text != text, hadNote || !text.isEmpty else { return }
+        notes[worktreeId] = WorktreeNote(text: text, editedAt: date, missingSince: existing?.missingSince)
+        saveToDisk()
+        NotificationCenter.default.post(name: .worktreeNotesDidChange, object: self, userInfo: ["worktreeId": worktreeId])
+    }
+
+    /// Track which noted worktrees are gone from the manifest and drop notes missing
+    /// for longer than `retention`. Cleared notes have nothing left to keep and go
+    /// once they're older than that, whether or not the worktree exists. An empty
+    /// refresh (usually a manifest caught mid-write) is skipped.
+    func prune(existing worktreeIds: Set<String>, at date: Date = Date()) {
+        guard !worktreeIds.isEmpty else { return }
+        var updated = notes
+        for (id, var note) in notes {
+            if note.isBlank && date.timeIntervalSince(note.editedAt) > Self.retention {
+                updated[id] = nil
+            } else if worktreeIds.contains(id) {
+                note.missingSince = nil
+                updated[id] = note
+            } else if let since = note.missingSince {
+                if date.timeIntervalSince(since) > Self.retention { updated[id] = nil }
+            } else {
+                note.missingSince = date
+                updated[id] = note
+            }
+        }
+        guard updated != notes else { return }
+        let dropped = notes.filter { updated[$0.key] == nil }
+        notes = updated
+        saveToDisk(dropping: dropped)
+    }
+
+    /// Combine two copies of the notes, keeping the later edit of each worktree's note.
+    /// On a tie `ours` wins. The earlier missing-since date is kept so a note's
+    /// retention isn't reset by a stale copy.
+    nonisolated static func merge(_ ours: [String: WorktreeNote], _ theirs: [String: WorktreeNote]) -> [String: WorktreeNote] {
+        ours.merging(theirs) { mine, other in
+            var winner = other.editedAt > mine.editedAt ? other : mine
+            switch (mine.missingSince, other.missingSince) {
+            case let (a?, b?): winner.missingSince = min(a, b)
+            default: winner.missingSince = mine.missingSince ?? other.missingSince
+            }
+            return winner
+        }
+    }
+
+    // MARK: - Persistence
+
+    nonisolated private struct Stored: Codable {
+        var notes: [String: WorktreeNote]
+    }
+
+    /// Notes in the file at `path`; empty if it's missing or unreadable.
+    nonisolated static func load(path: String) -> [String: WorktreeNote] {
+        guard let data = FileManager.default.contents(atPath: path) else { return [:] }
+        let decoder = JSONDecoder()
+        decoder.dateDecodingStrategy = .iso8601
+        return (try? decoder.decode(Stored.self, from: data))?.notes ?? [:]
+    }
+
+    nonisolated static func save(_ notes: [String: WorktreeNote], path: String) throws {
+        let encoder = JSONEncoder()
+        encoder.dateEncodingStrategy = .iso8601
+        encoder.outputFormatting = [.prettyPrinted, .sortedKeys]
+        try encoder.encode(Stored(notes: notes)).write(to: URL(fileURLWithPath: path), options: .atomic)
+    }
+
+    /// Write the notes, merged with whatever is on disk so edits made from another
+    /// copy of the app (a debug build next to the release one) aren't overwritten.
+    /// Notes pruned here are then taken back out, unless the file has a later edit.
+    private func saveToDisk(dropping dropped: [String: WorktreeNote] = [:]) {
+        guard let path else { return }
+        let notes = notes
+        ioQueue.async {
+            do {
+                var merged = Self.merge(notes, Self.load(path: path))
+                for (id, note) in dropped where merged[id].map({ $0.editedAt <= note.editedAt }) == true {
+                    merged[id] = nil
+                }
+                try Self.save(merged, path: path)
+            } catch {
+                // Non-fatal — the note is kept in memory and written with the next edit
+            }
+        }
+    }
+
+    /// Wait for writes already queued to reach the file.
+    func flush() {
+        ioQueue.sync {}
+    }
+}
+
+extension Notification.Name {
+    static let worktreeNotesDidChange = Notification.Name("PPGWorktreeNotesDidChange")
+}
@@ -1,10 +1,32 @@
 import AppKit
 
-let config = LaunchConfig.parse(CommandLine.arguments)
+CrashReporter.install()
+
+let config: LaunchConfig
+do {
+    switch try LaunchConfig.parseCommand(CommandLine.arguments) {
+    case .run(let parsed):
+        config = parsed
+    case .help:
+        print(LaunchConfig.usage)
+        exit(0)
+    case .version:
+        print(Bundle.main.infoDictionary?["CFBundleShortVersionString"] as? String ?? "unknown")
+        exit(0)
+    }
+} catch {
+    FileHandle.standardError.write(Data("error: \(error)\n\n\(LaunchConfig.usage)\n".utf8))
+    exit(64)  // EX_USAGE
+}
+// As the unit test host, run alongside an open copy of the app instead of handing off to it
+let isTestHost = ProcessInfo.processInfo.environment["XCTestConfigurationFilePath"] != nil
+if !isTestHost, SingleInstance.forwardToRunningInstance(config) {
+    exit(0)
+}
 LaunchConfig.shared = config
 ProjectState.shared.loadFromLaunchConfig(config)
 
-let app = NSApplication.shared
+let app = PPGApplication.shared
 let delegate = AppDelegate()
 MainActor.assumeIsolated {
     app.delegate = delegate
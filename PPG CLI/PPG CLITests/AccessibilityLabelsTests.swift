@@ -0,0 +1,50 @@
+import XCTest
+@testable import PPG_CLI
+
+final class AccessibilityLabelsTests: XCTestCase {
+
+    private func agent(_ id: String, name: String = "", status: AgentStatus = .running, rawStatus: String? = nil) -> AgentModel {
+        AgentModel(id: id, name: name, agentType: "claude", status: status, rawStatus: rawStatus, tmuxTarget: "s:1", prompt: "", startedAt: "")
+    }
+
+    // MARK: - Sidebar rows
+
+    func testProjectRowCountsWorktrees() {
+        XCTAssertEqual(AccessibilityLabels.project(name: "app", worktreeCount: 1), "Project app, 1 worktree")
+        XCTAssertEqual(AccessibilityLabels.project(name: "app", worktreeCount: 0), "Project app, 0 worktrees")
+    }
+
+    func testWorktreeRowReadsNameStatusAndAgentCount() {
+        let worktree = WorktreeModel(id: "wt-1", name: "auth-fix", path: "/tmp/auth-fix", branch: "ppg/auth-fix", status: "active",
+                                     tmuxWindow: "s:1", agents: [agent("ag-1"), agent("ag-2"), agent("ag-3")])
+        XCTAssertEqual(AccessibilityLabels.worktree(worktree), "Worktree auth-fix, active, 3 agents")
+    }
+
+    func testAgentRowFallsBackToIdAndSpellsOutUnknownStatuses() {
+        XCTAssertEqual(AccessibilityLabels.agent(agent("ag-1", name: "claude-1")), "Agent claude-1, claude, running")
+        XCTAssertEqual(AccessibilityLabels.agent(agent("ag-2", status: .unknown, rawStatus: "paused")), "Agent ag-2, claude, unknown (paused)")
+        XCTAssertEqual(AccessibilityLabels.agent(agent("ag-3", name: "claude-3"), stalled: true), "Agent claude-3, claude, running, stalled")
+    }
+
+    func testAgentGroupListsEveryMembersStatus() {
+        let label = AccessibilityLabels.agentGroup([agent("ag-1", name: "a"), agent("ag-2", name: "b", status: .failed)])
+        XCTAssertEqual(label, "Split of 2 agents: a, running; b, failed")
+    }
+
+    func testSessionEntriesSayWhetherTheyAreAgentsOrTerminals() {
+        XCTAssertEqual(AccessibilityLabels.sessionEntry(label: "build", isAgent: false), "Terminal build")
+        XCTAssertEqual(AccessibilityLabels.sessionEntry(label: "claude", isAgent: true), "Agent session claude")
+    }
+
+    // MARK: - Panes and cards
+
+    func testAgentPaneNamesTheStatus() {
+        XCTAssertEqual(AccessibilityLabels.agentPane(name: "claude-1", status: "failed"), "Agent claude-1 terminal, failed")
+    }
+
+    func testAgentCountsReadAsWords() {
+        XCTAssertEqual(AccessibilityLabels.agentCounts(AgentStatusCounts()), "No agents")
+        XCTAssertEqual(AccessibilityLabels.agentCounts(AgentStatusCounts(running: 2, failed: 1)), "2 running, 1 failed")
+        XCTAssertEqual(AccessibilityLabels.agentCounts(AgentStatusCounts(running: 2, stalled: 1)), "2 running, 1 stalled")
+    }
+}
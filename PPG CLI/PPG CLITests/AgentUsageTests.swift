@@ -0,0 +1,69 @@
+import XCTest
+@testable import PPG_CLI
+
+final class AgentUsageTests: XCTestCase {
+
+    private let us = Locale(identifier: "en_US")
+
+    private func agent(_ extra: [String: ManifestValue]) -> AgentModel {
+        AgentModel(id: "ag-1", name: "claude-1", agentType: "claude", status: .running, tmuxTarget: "", prompt: "", startedAt: "", extra: extra)
+    }
+
+    // MARK: - Formatting
+
+    func testCostUsesThousandsSeparatorsAndCents() {
+        XCTAssertEqual(formatCost(1234.5, locale: us), "$1,234.50")
+        XCTAssertEqual(formatCost(0.42, locale: us), "$0.42")
+    }
+
+    func testSubCentCostIsNotShownAsFree() {
+        XCTAssertEqual(formatCost(0.0004, locale: us), "<$0.01")
+        XCTAssertEqual(formatCost(0, locale: us), "$0.00")
+    }
+
+    func testTokenCountUsesThousandsSeparators() {
+        XCTAssertEqual(formatTokenCount(18_204, locale: us), "18,204 tokens")
+        XCTAssertEqual(formatTokenCount(1, locale: us), "1 token")
+    }
+
+    func testTextLeavesOutMissingParts() {
+        XCTAssertEqual(AgentUsage(tokens: 1200, costUsd: 0.5).text(locale: us), "$0.50 · 1,200 tokens")
+        XCTAssertEqual(AgentUsage(tokens: 1200, costUsd: nil).text(locale: us), "1,200 tokens")
+        XCTAssertEqual(AgentUsage(tokens: nil, costUsd: 0.5).text(locale: us), "$0.50")
+    }
+
+    // MARK: - Totals
+
+    func testTotalSumsEachFieldOverAgentsThatReportIt() {
+        let total = AgentUsage.total(of: [
+            AgentUsage(tokens: 100, costUsd: 0.25),
+            nil,
+            AgentUsage(tokens: nil, costUsd: 0.5),
+        ])
+        XCTAssertEqual(total, AgentUsage(tokens: 100, costUsd: 0.75))
+    }
+
+    func testTotalIsNilWhenNothingIsReported() {
+        XCTAssertNil(AgentUsage.total(of: [nil, nil]))
+        XCTAssertNil(AgentUsage.total(of: []))
+    }
+
+    // MARK: - Manifest Fields
+
+    func testAccessorsReadExtraFields() {
+        let usage = agent(["tokensUsed": .number(18_204), "costUsd": .number(1.24)]).usage
+        XCTAssertEqual(usage, AgentUsage(tokens: 18_204, costUsd: 1.24))
+    }
+
+    func testMissingOrMalformedFieldsReadNil() {
+        XCTAssertNil(agent([:]).usage)
+        XCTAssertNil(agent(["costUsd": .string("1.24"), "tokensUsed": .number(-5)]).usage)
+    }
+
+    func testHugeTokenCountClampsInsteadOfTrapping() {
+        XCTAssertEqual(agent(["tokensUsed": .number(1e20)]).tokensUsed, .max)
+        XCTAssertEqual(agent(["tokensUsed": .number(1_200.7)]).tokensUsed, 1_200)
+        let total = AgentUsage.total(of: [AgentUsage(tokens: .max, costUsd: nil), AgentUsage(tokens: 10, costUsd: nil)])
+        XCTAssertEqual(total?.tokens, .max)
+    }
+}
@@ -24,4 +24,110 @@ final class AppSettingsManagerTests: XCTestCase {
     func testDefaultTerminalFontSize() {
         XCTAssertEqual(AppSettingsManager.defaultTerminalFontSize, 13.0)
     }
+
+    func testDefaultMaxVisiblePanesFillsTheGrid() {
+        XCTAssertEqual(AppSettingsManager.defaultMaxVisiblePanes, PaneSplitNode.maxLeaves)
+        XCTAssertEqual(AppSettingsManager.maxVisiblePanesRange, 1...6)
+    }
+
+    func testDefaultMinPaneWidth() {
+        XCTAssertEqual(AppSettingsManager.defaultMinPaneWidth, 480)
+        XCTAssertTrue(AppSettingsManager.minPaneWidthRange.contains(AppSettingsManager.defaultMinPaneWidth))
+    }
+
+    func testAutoOpenSpawnedAgentsIsOptIn() {
+        XCTAssertFalse(AppSettingsManager.defaultAutoOpenSpawnedAgents)
+    }
+
+    // MARK: - Migration
+
+    @MainActor
+    func testSettingsFromBeforeTerminalAndPaneKeysStillLoad() {
+        let suite = "AppSettingsManagerTests-\(UUID().uuidString)"
+        let defaults = UserDefaults(suiteName: suite)!
+        addTeardownBlock { defaults.removePersistentDomain(forName: suite) }
+        // An older install only ever wrote the settings it knew about
+        defaults.set("/bin/bash", forKey: "PPGShell")
+        defaults.set(20000, forKey: "PPGHistoryLimit")
+        let settings = AppSettingsManager(defaults: defaults)
+
+        XCTAssertEqual(settings.shell, "/bin/bash")
+        XCTAssertEqual(settings.historyLimit, 20000)
+        XCTAssertEqual(settings.autoOpenSpawnedAgents, AppSettingsManager.defaultAutoOpenSpawnedAgents)
+        XCTAssertEqual(settings.maxVisiblePanes, AppSettingsManager.defaultMaxVisiblePanes)
+
+        settings.autoOpenSpawnedAgents = true
+        settings.maxVisiblePanes = 3
+        let reloaded = AppSettingsManager(defaults: defaults)
+        XCTAssertTrue(reloaded.autoOpenSpawnedAgents)
+        XCTAssertEqual(reloaded.maxVisiblePanes, 3)
+    }
+
+    func testSidebarShowsBranchesByDefault() {
+        XCTAssertFalse(AppSettingsManager.defaultCompactSidebarRows)
+    }
+
+    func testDefaultLaunchShowsDashboard() {
+        XCTAssertFalse(AppSettingsManager.defaultRestoreLastSelection)
+    }
+
+    func testSetupWaitsForContinueByDefault() {
+        XCTAssertFalse(AppSettingsManager.defaultAutoContinueSetup)
+    }
+
+    func testClosingTheWindowQuitsByDefault() {
+        XCTAssertFalse(AppSettingsManager.defaultKeepRunningInMenuBar)
+    }
+
+    func testQuitWithRunningAgentsAsksByDefault() {
+        XCTAssertTrue(AppSettingsManager.defaultConfirmQuitWithRunningAgents)
+    }
+
+    // MARK: - Groups
+
+    func testTerminalAndPaneGroupIncludesPaneSettings() {
+        let keys = AppSettingsManager.terminalAndPaneKeys
+        XCTAssertTrue(keys.contains(.historyLimit))
+        XCTAssertTrue(keys.contains(.maxVisiblePanes))
+        XCTAssertTrue(keys.contains(.minPaneWidth))
+        XCTAssertFalse(keys.contains(.autoOpenSpawnedAgents))
+    }
+
+    // MARK: - Reset
+
+    func testAppearanceAndTerminalResetLeavesBehaviorSettingsAlone() {
+        let keys = AppSettingsManager.appearanceAndTerminalKeys
+        XCTAssertTrue(keys.contains(.terminalFont))
+        XCTAssertTrue(keys.contains(.appearanceMode))
+        XCTAssertTrue(keys.contains(.compactSidebarRows))
+        XCTAssertTrue(keys.contains(.maxVisiblePanes))
+        XCTAssertTrue(keys.contains(.minPaneWidth))
+        XCTAssertFalse(keys.contains(.autoOpenSpawnedAgents))
+        XCTAssertFalse(keys.contains(.restoreLastSelection))
+    }
+
+    @MainActor
+    func testResetRemovesOnlyTheGivenKeys() {
+        let suite = "AppSettingsManagerTests-\(UUID().uuidString)"
+        let defaults = UserDefaults(suiteName: suite)!
+        addTeardownBlock { defaults.removePersistentDomain(forName: suite) }
+        let settings = AppSettingsManager(defaults: defaults)
+        settings.terminalFontName = "Monaco"
+        settings.shell = "/bin/bash"
+        settings.maxVisiblePanes = 2
+        settings.minPaneWidth = 800
+        settings.autoOpenSpawnedAgents = true
+        settings.restoreLastSelection = true
+
+        settings.reset(AppSettingsManager.appearanceAndTerminalKeys)
+
+        XCTAssertNil(defaults.object(forKey: "PPGTerminalFont"))
+        XCTAssertNil(defaults.object(forKey: "PPGShell"))
+        XCTAssertEqual(settings.terminalFontName, AppSettingsManager.defaultTerminalFont)
+        XCTAssertEqual(settings.shell, AppSettingsManager.defaultShell)
+        XCTAssertEqual(settings.maxVisiblePanes, AppSettingsManager.defaultMaxVisiblePanes)
+        XCTAssertEqual(settings.minPaneWidth, AppSettingsManager.defaultMinPaneWidth)
+        XCTAssertEqual(defaults.object(forKey: "PPGAutoOpenSpawnedAgents") as? Bool, true)
+        XCTAssertEqual(defaults.object(forKey: "PPGRestoreLastSelection") as? Bool, true)
+    }
 }
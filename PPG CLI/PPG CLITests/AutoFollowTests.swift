@@ -0,0 +1,66 @@
+import XCTest
+@testable import PPG_CLI
+
+final class AutoFollowTests: XCTestCase {
+
+    private let start = Date(timeIntervalSince1970: 1_700_000_000)
+
+    private func at(_ seconds: TimeInterval) -> Date {
+        start.addingTimeInterval(seconds)
+    }
+
+    // MARK: - Switching
+
+    func testSwitchesToTheNewestOutput() {
+        var follow = AutoFollow()
+        let target = follow.nextEntry(lastOutput: ["ag-1": at(-10), "ag-2": at(-2)], current: "ag-1", now: start)
+        XCTAssertEqual(target, "ag-2")
+        XCTAssertEqual(follow.lastSwitch, start)
+    }
+
+    func testStaysWhenTheCurrentEntryIsAsRecent() {
+        var follow = AutoFollow()
+        XCTAssertNil(follow.nextEntry(lastOutput: ["ag-1": at(-2), "ag-2": at(-2)], current: "ag-1", now: start))
+        XCTAssertNil(follow.nextEntry(lastOutput: ["ag-1": at(-1), "ag-2": at(-2)], current: "ag-1", now: start))
+        XCTAssertNil(follow.lastSwitch)
+    }
+
+    func testEqualTimesGoToTheLowerId() {
+        var follow = AutoFollow()
+        XCTAssertEqual(follow.nextEntry(lastOutput: ["ag-2": at(-2), "ag-1": at(-2)], current: nil, now: start), "ag-1")
+    }
+
+    func testSwitchesAtMostEverySwitchInterval() {
+        var follow = AutoFollow()
+        XCTAssertEqual(follow.nextEntry(lastOutput: ["ag-1": at(-5), "ag-2": at(-1)], current: "ag-1", now: start), "ag-2")
+        let soon = at(AutoFollow.switchInterval - 1)
+        XCTAssertNil(follow.nextEntry(lastOutput: ["ag-1": soon, "ag-2": at(-1)], current: "ag-2", now: soon))
+        let later = at(AutoFollow.switchInterval)
+        XCTAssertEqual(follow.nextEntry(lastOutput: ["ag-1": later, "ag-2": at(-1)], current: "ag-2", now: later), "ag-1")
+    }
+
+    func testNoOutputTimesMeansNoSwitch() {
+        var follow = AutoFollow()
+        XCTAssertNil(follow.nextEntry(lastOutput: [:], current: "ag-1", now: start))
+    }
+
+    // MARK: - Pausing
+
+    func testPauseHoldsSwitchesBackAndCountsDown() {
+        var follow = AutoFollow()
+        follow.pause(at: start)
+        XCTAssertEqual(follow.pauseRemaining(at: at(0.5)), 60)
+        XCTAssertEqual(follow.pauseRemaining(at: at(59.5)), 1)
+        XCTAssertNil(follow.nextEntry(lastOutput: ["ag-2": at(30)], current: "ag-1", now: at(30)))
+
+        XCTAssertNil(follow.pauseRemaining(at: at(AutoFollow.pauseDuration)))
+        XCTAssertEqual(follow.nextEntry(lastOutput: ["ag-2": at(30)], current: "ag-1", now: at(AutoFollow.pauseDuration)), "ag-2")
+    }
+
+    func testInteractingAgainRestartsThePause() {
+        var follow = AutoFollow()
+        follow.pause(at: start)
+        follow.pause(at: at(50))
+        XCTAssertEqual(follow.pauseRemaining(at: at(100)), 10)
+    }
+}
@@ -0,0 +1,76 @@
+import XCTest
+@testable import PPG_CLI
+
+final class CommandFailureTests: XCTestCase {
+
+    private let occurredAt = Date(timeIntervalSince1970: 1_700_000_000)
+
+    private func failure(stdout: String = "", stderr: String, exitCode: Int32 = 1) -> CommandFailure {
+        CommandFailure(args: "kill --worktree wt-1 --json",
+                       result: PPGService.CommandResult(exitCode: exitCode, stdout: stdout, stderr: stderr),
+                       at: occurredAt)
+    }
+
+    // MARK: - Parsing
+
+    func testJSONErrorGivesMessageAndCode() {
+        let f = failure(stderr: #"{"error":"Worktree not found: wt-1","code":"WORKTREE_NOT_FOUND"}"# + "\n")
+        XCTAssertEqual(f.message, "Worktree not found: wt-1")
+        XCTAssertEqual(f.code, "WORKTREE_NOT_FOUND")
+        XCTAssertEqual(f.extraOutput, "")
+        XCTAssertFalse(f.needsDetails)
+        XCTAssertEqual(f.summary, "Worktree not found: wt-1")
+    }
+
+    func testUnknownCodeIsDropped() {
+        XCTAssertNil(failure(stderr: #"{"error":"boom","code":"UNKNOWN"}"#).code)
+    }
+
+    func testOutputBeforeTheJSONErrorIsKept() {
+        let f = failure(stderr: "CONFLICT (content): Merge conflict in a.ts\nCONFLICT (content): Merge conflict in b.ts\n" +
+                        #"{"error":"Merge failed","code":"MERGE_FAILED"}"#)
+        XCTAssertEqual(f.message, "Merge failed")
+        XCTAssertEqual(f.extraOutput, "CONFLICT (content): Merge conflict in a.ts\nCONFLICT (content): Merge conflict in b.ts")
+        XCTAssertTrue(f.needsDetails)
+    }
+
+    func testPlainStderrIsTheMessage() {
+        let f = failure(stderr: "zsh: command not found: ppg\n", exitCode: 127)
+        XCTAssertEqual(f.message, "zsh: command not found: ppg")
+        XCTAssertNil(f.code)
+    }
+
+    func testStdoutIsUsedWhenStderrIsEmpty() {
+        XCTAssertEqual(failure(stdout: "not json", stderr: "", exitCode: 0).message, "not json")
+    }
+
+    // MARK: - Summary
+
+    func testLongMessagesAreCutAndNeedDetails() {
+        let long = String(repeating: "x", count: CommandFailure.summaryLimit + 30)
+        let f = failure(stderr: long)
+        XCTAssertTrue(f.needsDetails)
+        XCTAssertEqual(f.summary.count, CommandFailure.summaryLimit)
+        XCTAssertTrue(f.summary.hasSuffix("…"))
+    }
+
+    func testMultiLineMessagesSummarizeToTheFirstLine() {
+        let f = failure(stderr: "pre-commit hook failed\nlint: 3 errors")
+        XCTAssertTrue(f.needsDetails)
+        XCTAssertEqual(f.summary, "pre-commit hook failed")
+    }
+
+    func testEmptyOutputFallsBackToTheExitCode() {
+        XCTAssertEqual(failure(stderr: "", exitCode: 2).summary, "ppg exited with code 2.")
+    }
+
+    // MARK: - Details
+
+    func testDetailsCarryCommandExitCodeAndTime() {
+        let f = failure(stderr: "hook output\n" + #"{"error":"Merge failed","code":"MERGE_FAILED"}"#)
+        let details = f.detailsText()
+        XCTAssertTrue(details.hasPrefix("Command: ppg kill --worktree wt-1 --json\nExit code: 1\nError code: MERGE_FAILED\nTime: 2023-11-14T22:13:20Z\n"), details)
+        XCTAssertTrue(details.contains("\nMerge failed\n"))
+        XCTAssertTrue(details.contains("── Output ──\nhook output"))
+    }
+}
@@ -140,3 +140,161 @@ final class ContentViewControllerTests: XCTestCase {
         XCTAssertNil(vc.currentEntry)
     }
 }
+
+@MainActor
+final class WorktreeDetailBranchChangesTests: XCTestCase {
+    func testParseNumstatReadsCountsAndPaths() {
+        let output = "12\t3\tsrc/app.ts\n0\t7\tREADME.md\n"
+        let files = WorktreeDetailView.parseNumstat(output)
+        XCTAssertEqual(files.count, 2)
+        XCTAssertEqual(files[0].filename, "src/app.ts")
+        XCTAssertEqual(files[0].added, 12)
+        XCTAssertEqual(files[0].removed, 3)
+        XCTAssertEqual(files[1].filename, "README.md")
+        XCTAssertEqual(files[1].removed, 7)
+    }
+
+    func testParseNumstatTreatsBinaryFilesAsZero() {
+        let files = WorktreeDetailView.parseNumstat("-\t-\tassets/logo.png\n")
+        XCTAssertEqual(files.count, 1)
+        XCTAssertEqual(files[0].added, 0)
+        XCTAssertEqual(files[0].removed, 0)
+    }
+
+    func testParseNumstatKeepsTabsInPath() {
+        let files = WorktreeDetailView.parseNumstat("1\t1\tdir/odd\tname.txt")
+        XCTAssertEqual(files.first?.filename, "dir/odd\tname.txt")
+    }
+
+    func testSummaryTotalsAcrossFiles() {
+        let changes = WorktreeDetailView.BranchChanges(
+            baseBranch: "main",
+            files: WorktreeDetailView.parseNumstat("10\t2\ta.swift\n5\t1\tb.swift")
+        )
+        XCTAssertEqual(changes.totalAdded, 15)
+        XCTAssertEqual(changes.totalRemoved, 3)
+        XCTAssertEqual(changes.summary, "2 files changed, +15 −3 vs main")
+    }
+
+    func testSummaryWithNoChanges() {
+        let changes = WorktreeDetailView.BranchChanges(baseBranch: "main", files: [])
+        XCTAssertEqual(changes.summary, "No changes vs main")
+    }
+
+    func testMissingBaseBranchIsAnErrorNotAnEmptyBranch() {
+        let failed = PPGService.CommandResult(exitCode: 128, stdout: "",
+                                              stderr: "fatal: ambiguous argument 'main...HEAD': unknown revision or path not in the working tree.\nUse '--' to separate paths\n")
+        let changes = WorktreeDetailView.branchChanges(baseBranch: "main", diff: failed, log: failed)
+        XCTAssertEqual(changes.error, "fatal: ambiguous argument 'main...HEAD': unknown revision or path not in the working tree.")
+        XCTAssertTrue(changes.summary.hasPrefix("Couldn't compare with main: fatal:"))
+        XCTAssertNotEqual(changes.commitsSummary, "No commits yet — the agent hasn't committed anything")
+    }
+
+    func testFailedLogWithoutStderrReportsExitCode() {
+        let diff = PPGService.CommandResult(exitCode: 0, stdout: "1\t0\ta.swift", stderr: "")
+        let log = PPGService.CommandResult(exitCode: 1, stdout: "", stderr: "")
+        XCTAssertEqual(WorktreeDetailView.branchChanges(baseBranch: "main", diff: diff, log: log).error, "git exited with code 1")
+    }
+
+    func testSuccessfulResultsHaveNoError() {
+        let diff = PPGService.CommandResult(exitCode: 0, stdout: "1\t0\ta.swift", stderr: "")
+        let log = PPGService.CommandResult(exitCode: 0, stdout: "", stderr: "")
+        let changes = WorktreeDetailView.branchChanges(baseBranch: "main", diff: diff, log: log)
+        XCTAssertNil(changes.error)
+        XCTAssertEqual(changes.summary, "1 file changed, +1 −0 vs main")
+    }
+
+    func testParseCommitLogReadsNullDelimitedFields() {
+        let output = "abc1234\0Add parser\0Ada\02 hours ago\01700000000\ndef5678\0Initial\0Bob\03 days ago\01699000000\n"
+        let commits = HomeDashboardView.parseCommitLog(output)
+        XCTAssertEqual(commits.count, 2)
+        XCTAssertEqual(commits[0].hash, "abc1234")
+        XCTAssertEqual(commits[0].message, "Add parser")
+        XCTAssertEqual(commits[0].author, "Ada")
+        XCTAssertEqual(commits[0].relativeTime, "2 hours ago")
+        XCTAssertEqual(commits[1].timestamp, 1699000000)
+    }
+
+    func testCommitsSummary() {
+        var changes = WorktreeDetailView.BranchChanges(baseBranch: "main", files: [])
+        XCTAssertEqual(changes.commitsSummary, "No commits yet — the agent hasn't committed anything")
+
+        changes.commits = HomeDashboardView.parseCommitLog("abc1234\0Fix\0Ada\0now\01700000000")
+        XCTAssertEqual(changes.commitsSummary, "1 commit ahead of main")
+    }
+}
+
+@MainActor
+final class WorktreeNameValidationTests: XCTestCase {
+    func testAcceptsOrdinaryName() {
+        XCTAssertNil(WorktreeDetailView.validateWorktreeName("auth-refactor"))
+    }
+
+    func testRejectsEmptyOrWhitespace() {
+        XCTAssertNotNil(WorktreeDetailView.validateWorktreeName(""))
+        XCTAssertNotNil(WorktreeDetailView.validateWorktreeName("   "))
+    }
+
+    func testLengthLimitIs64() {
+        XCTAssertNil(WorktreeDetailView.validateWorktreeName(String(repeating: "a", count: 64)))
+        XCTAssertNotNil(WorktreeDetailView.validateWorktreeName(String(repeating: "a", count: 65)))
+    }
+
+    func testRejectsSlashes() {
+        XCTAssertNotNil(WorktreeDetailView.validateWorktreeName("feature/login"))
+    }
+}
+
+@MainActor
+final class WorktreeDetailAdvancedTests: XCTestCase {
+    func testAdvancedEntriesListTmuxIdentifiers() {
+        let agent = AgentModel(
+            id: "ag-1", name: "claude-1", agentType: "claude", status: .running,
+            tmuxTarget: "ppg:2.0", prompt: "", startedAt: "", sessionId: "sess-123"
+        )
+        let wt = WorktreeModel(
+            id: "wt-1", name: "fox", path: "/tmp/fox", branch: "ppg/fox",
+            status: "active", tmuxWindow: "ppg:2", agents: [agent]
+        )
+        let entries = WorktreeDetailView.advancedEntries(for: wt, sessionName: "ppg")
+        XCTAssertEqual(entries.map(\.label), ["Session", "Window", "claude-1 target", "claude-1 session ID", "claude-1 attach", "Attach"])
+        XCTAssertEqual(entries[1].value, "ppg:2")
+        XCTAssertEqual(entries[4].value, tmuxAttachPaneShellCommand(target: "ppg:2.0", unsynchronizePanes: true))
+        XCTAssertEqual(entries[5].value, tmuxAttachShellCommand(target: "ppg:2"))
+    }
+
+    func testAdvancedEntriesListUnrecognizedManifestFields() {
+        let agent = AgentModel(
+            id: "ag-1", name: "claude-1", agentType: "claude", status: .running,
+            tmuxTarget: "ppg:2.0", prompt: "", startedAt: "", extra: ["model": .string("opus"), "tokens": .number(1200)]
+        )
+        let wt = WorktreeModel(
+            id: "wt-1", name: "fox", path: "/tmp/fox", branch: "ppg/fox",
+            status: "active", tmuxWindow: "", extra: ["cost": .number(0.42)], agents: [agent]
+        )
+        let entries = WorktreeDetailView.advancedEntries(for: wt, sessionName: "ppg")
+        XCTAssertEqual(entries.map(\.label), ["Session", "cost", "claude-1 target", "claude-1 attach", "claude-1 model", "claude-1 tokens"])
+        XCTAssertEqual(entries[1].value, "0.42")
+        XCTAssertEqual(entries[5].value, "1200")
+    }
+
+    func testAdvancedEntriesLeaveUsageToItsOwnRow() {
+        let agent = AgentModel(
+            id: "ag-1", name: "claude-1", agentType: "claude", status: .running,
+            tmuxTarget: "", prompt: "", startedAt: "", extra: ["costUsd": .number(0.42), "tokensUsed": .number(1200)]
+        )
+        let wt = WorktreeModel(
+            id: "wt-1", name: "fox", path: "/tmp/fox", branch: "ppg/fox",
+            status: "active", tmuxWindow: "", agents: [agent]
+        )
+        XCTAssertEqual(WorktreeDetailView.advancedEntries(for: wt, sessionName: "ppg").map(\.label), ["Session"])
+    }
+
+    func testAdvancedEntriesOmitEmptyValues() {
+        let wt = WorktreeModel(
+            id: "__project__", name: "root", path: "/tmp", branch: "main",
+            status: "active", tmuxWindow: "", agents: []
+        )
+        XCTAssertTrue(WorktreeDetailView.advancedEntries(for: wt, sessionName: "").isEmpty)
+    }
+}
@@ -0,0 +1,24 @@
+import XCTest
+@testable import PPG_CLI
+
+final class CrashReporterTests: XCTestCase {
+
+    func testReportIncludesExceptionAndBacktrace() {
+        let report = CrashReporter.report(
+            name: "NSRangeException",
+            reason: "index 3 beyond bounds",
+            callStack: ["0 CoreFoundation __exceptionPreprocess", "1 libobjc.A.dylib objc_exception_throw"],
+            date: Date(timeIntervalSince1970: 1_700_000_000)
+        )
+        XCTAssertTrue(report.contains("Date: 2023-11-14T22:13:20Z"))
+        XCTAssertTrue(report.contains("Exception: NSRangeException"))
+        XCTAssertTrue(report.contains("Reason: index 3 beyond bounds"))
+        XCTAssertTrue(report.contains("  1 libobjc.A.dylib objc_exception_throw"))
+    }
+
+    func testMissingReasonAndBacktraceAreStated() {
+        let report = CrashReporter.report(name: "Custom", reason: nil, callStack: [], date: Date())
+        XCTAssertTrue(report.contains("Reason: none"))
+        XCTAssertTrue(report.contains("Backtrace:\n  unavailable"))
+    }
+}
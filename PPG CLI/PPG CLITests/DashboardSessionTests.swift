@@ -197,6 +197,31 @@ final class DashboardSessionTests: XCTestCase {
 
         try? FileManager.default.removeItem(atPath: dir)
     }
+
+    // MARK: - Grid Layouts
+
+    func testGridOverflowSurvivesReload() {
+        let dir = NSTemporaryDirectory() + "ppg-test-overflow-\(UUID().uuidString)"
+        try? FileManager.default.createDirectory(atPath: dir + "/.ppg", withIntermediateDirectories: true)
+        let s = DashboardSession(projectRoot: dir)
+
+        var layout = GridLayoutNode.split(direction: "vertical", ratio: 0.5,
+                                          first: .leaf(entryId: "ag-1"), second: .leaf(entryId: "ag-2"))
+        layout.overflowEntryIds = ["ag-3", "ag-4"]
+        s.saveGridLayout(ownerEntryId: "ag-1", layout: layout)
+        s.flushToDisk()
+        s.reloadFromDisk()
+
+        XCTAssertEqual(s.gridLayout(forOwnerEntryId: "ag-1")?.overflowEntryIds, ["ag-3", "ag-4"])
+        try? FileManager.default.removeItem(atPath: dir)
+    }
+
+    func testLayoutSavedBeforeOverflowHasNone() throws {
+        let json = #"{"direction":"vertical","ratio":0.5,"children":[{"entryId":"ag-1"},{"entryId":"ag-2"}]}"#
+        let layout = try JSONDecoder().decode(GridLayoutNode.self, from: Data(json.utf8))
+        XCTAssertNil(layout.overflowEntryIds)
+        XCTAssertEqual(layout.children?.count, 2)
+    }
 }
 
 /// Minimal Codable wrapper matching DashboardSession.SessionData for test decoding.
@@ -0,0 +1,84 @@
+import XCTest
+@testable import PPG_CLI
+
+final class DebouncerTests: XCTestCase {
+
+    /// Clock and scheduler for a `Debouncer`, advanced by hand.
+    private final class ManualClock {
+        var now = Date(timeIntervalSince1970: 1_000_000)
+        private var pending: [(due: Date, item: DispatchWorkItem)] = []
+
+        func schedule(after wait: TimeInterval, _ item: DispatchWorkItem) {
+            pending.append((now.addingTimeInterval(wait), item))
+        }
+
+        /// Move the clock forward, running scheduled work that falls due in order.
+        func advance(by seconds: TimeInterval) {
+            let end = now.addingTimeInterval(seconds)
+            while let next = pending.enumerated().filter({ $0.element.due <= end }).min(by: { $0.element.due < $1.element.due }) {
+                pending.remove(at: next.offset)
+                now = max(now, next.element.due)
+                if !next.element.item.isCancelled { next.element.item.perform() }
+            }
+            now = end
+        }
+    }
+
+    private func makeDebouncer(delay: TimeInterval, maxDelay: TimeInterval, clock: ManualClock,
+                               action: @escaping () -> Void) -> Debouncer {
+        Debouncer(delay: delay, maxDelay: maxDelay, now: { clock.now },
+                  scheduler: { wait, item in clock.schedule(after: wait, item) }, action: action)
+    }
+
+    func testBurstRunsOnceAfterTheLastCall() {
+        let clock = ManualClock()
+        var runs = 0
+        let debouncer = makeDebouncer(delay: 0.15, maxDelay: 1, clock: clock) { runs += 1 }
+        for _ in 0..<5 {
+            debouncer.schedule()
+            clock.advance(by: 0.03)
+        }
+        XCTAssertEqual(runs, 0)
+        clock.advance(by: 0.14)
+        XCTAssertEqual(runs, 1)
+        clock.advance(by: 1)
+        XCTAssertEqual(runs, 1)
+    }
+
+    func testLoneCallRunsAfterTheDelay() {
+        let clock = ManualClock()
+        var runs = 0
+        let debouncer = makeDebouncer(delay: 0.15, maxDelay: 1, clock: clock) { runs += 1 }
+        debouncer.schedule()
+        clock.advance(by: 0.14)
+        XCTAssertEqual(runs, 0)
+        clock.advance(by: 0.02)
+        XCTAssertEqual(runs, 1)
+    }
+
+    func testSteadyStreamStillRunsByTheMaxDelay() {
+        let clock = ManualClock()
+        var runs = 0
+        let debouncer = makeDebouncer(delay: 0.15, maxDelay: 0.25, clock: clock) { runs += 1 }
+        for _ in 0..<3 {
+            debouncer.schedule()
+            clock.advance(by: 0.1)
+        }
+        XCTAssertEqual(runs, 1, "a call every 0.1s still runs once the burst is 0.25s old")
+        for _ in 0..<3 {
+            debouncer.schedule()
+            clock.advance(by: 0.1)
+        }
+        XCTAssertEqual(runs, 2)
+    }
+
+    func testCancelDropsThePendingRun() {
+        let clock = ManualClock()
+        var runs = 0
+        let debouncer = makeDebouncer(delay: 0.1, maxDelay: 1, clock: clock) { runs += 1 }
+        debouncer.schedule()
+        debouncer.cancel()
+        clock.advance(by: 1)
+        XCTAssertEqual(runs, 0)
+    }
+}
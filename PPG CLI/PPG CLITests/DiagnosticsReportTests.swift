@@ -0,0 +1,65 @@
+import XCTest
+@testable import PPG_CLI
+
+final class DiagnosticsReportTests: XCTestCase {
+
+    func testMissingFieldsReadUnknown() {
+        let markdown = DiagnosticsReport().markdown()
+        XCTAssertTrue(markdown.contains("- **App version:** unknown"))
+        XCTAssertTrue(markdown.contains("- **Running agents:** unknown"))
+    }
+
+    func testFilledFieldsAreRendered() {
+        var report = DiagnosticsReport()
+        report.tmuxVersion = "3.4"
+        report.openProjects = 2
+        let markdown = report.markdown()
+        XCTAssertTrue(markdown.contains("- **tmux:** 3.4"))
+        XCTAssertTrue(markdown.contains("- **Open projects:** 2"))
+    }
+
+    func testUnsupportedCLIVersionIsFlagged() {
+        var report = DiagnosticsReport()
+        report.ppgVersion = "0.1.0"
+        report.ppgSupported = false
+        XCTAssertTrue(report.markdown().contains("0.1.0 (needs ≥ \(PPGService.minimumCLIVersion))"))
+    }
+
+    func testThemeSectionShowsFileStateAndKeys() {
+        var report = DiagnosticsReport()
+        report.themePath = "/Users/me/theme.json"
+        report.themeFileExists = true
+        XCTAssertTrue(report.markdown().contains("- **File:** /Users/me/theme.json (loaded)"))
+        XCTAssertTrue(report.markdown().contains("`status.running`"))
+
+        report.themeError = "bad color"
+        XCTAssertTrue(report.markdown().contains("(not applied: bad color)"))
+    }
+
+    // MARK: - Doctor
+
+    func testDoctorSectionReportsUnknownWithoutTmux() {
+        XCTAssertTrue(DiagnosticsReport().markdown().contains("- **Orphaned manifest entries:** unknown"))
+    }
+
+    func testOrphanedEntriesListMissingWindowsAndLiveAgents() {
+        let snapshot = TmuxSnapshot(windows: ["ppg-app": [TmuxSnapshot.Window(index: "1", name: "fox")]])
+        let agents = [
+            AgentModel(id: "ag-1", name: "claude-1", agentType: "claude", status: .running, tmuxTarget: "ppg-app:2.0", prompt: "", startedAt: ""),
+            AgentModel(id: "ag-2", name: "claude-2", agentType: "claude", status: .completed, tmuxTarget: "ppg-app:2.1", prompt: "", startedAt: ""),
+        ]
+        let worktrees = [
+            WorktreeModel(id: "wt-1", name: "fox", path: "/p", branch: "b", status: "active", tmuxWindow: "ppg-app:1", agents: []),
+            WorktreeModel(id: "wt-2", name: "owl", path: "/p", branch: "b", status: "active", tmuxWindow: "ppg-app:2", agents: agents),
+        ]
+        let entries = DiagnosticsReport.orphanedEntries(in: ["/src/app": worktrees], snapshot: snapshot)
+        XCTAssertEqual(entries, [
+            "app / owl: window ppg-app:2 is missing",
+            "app / owl / claude-1: running agent's window ppg-app:2.0 is missing",
+        ])
+
+        var report = DiagnosticsReport()
+        report.orphanedEntries = entries
+        XCTAssertTrue(report.markdown().contains("  - app / owl: window ppg-app:2 is missing"))
+    }
+}
@@ -0,0 +1,56 @@
+import XCTest
+@testable import PPG_CLI
+
+final class ExternalTerminalTests: XCTestCase {
+
+    private let command = "tmux attach-session -t 'ppg' \\; select-window -t 'ppg:2'"
+
+    // MARK: - Launch Arguments
+
+    func testTerminalIsScriptedWithEscapedCommand() {
+        let launch = ExternalTerminal.terminal.launchArguments(command: command, appPath: "/System/Applications/Utilities/Terminal.app")
+        XCTAssertEqual(launch.executable, "/usr/bin/osascript")
+        XCTAssertEqual(launch.arguments, [
+            "-e", "tell application \"Terminal\" to do script \"tmux attach-session -t 'ppg' \\\\; select-window -t 'ppg:2'\"",
+            "-e", "tell application \"Terminal\" to activate",
+        ])
+    }
+
+    func testITermWritesCommandIntoNewWindow() {
+        let launch = ExternalTerminal.iTerm.launchArguments(command: "echo \"hi\"", appPath: "/Applications/iTerm.app")
+        XCTAssertEqual(launch.executable, "/usr/bin/osascript")
+        XCTAssertTrue(launch.arguments.contains("tell current session of newWindow to write text \"echo \\\"hi\\\"\""))
+    }
+
+    private var commandThenShell: String { "\(command); exec /bin/zsh -l" }
+
+    func testGhosttyAndAlacrittyUseDashE() {
+        for terminal in [ExternalTerminal.ghostty, .alacritty] {
+            let launch = terminal.launchArguments(command: command, appPath: "/Applications/X.app")
+            XCTAssertEqual(launch.executable, "/usr/bin/open")
+            XCTAssertEqual(launch.arguments, ["-na", "/Applications/X.app", "--args", "-e", "/bin/zsh", "-lc", commandThenShell])
+        }
+    }
+
+    func testKittyTakesProgramDirectly() {
+        let launch = ExternalTerminal.kitty.launchArguments(command: command, appPath: "/Applications/kitty.app")
+        XCTAssertEqual(launch.arguments, ["-na", "/Applications/kitty.app", "--args", "/bin/zsh", "-lc", commandThenShell])
+    }
+
+    func testWezTermUsesStartSubcommand() {
+        let launch = ExternalTerminal.wezTerm.launchArguments(command: command, appPath: "/Applications/WezTerm.app")
+        XCTAssertEqual(launch.arguments, ["-na", "/Applications/WezTerm.app", "--args", "start", "--", "/bin/zsh", "-lc", commandThenShell])
+    }
+
+    // MARK: - Detection
+
+    func testUnknownOrEmptyPreferenceFallsBackToTerminal() {
+        XCTAssertEqual(ExternalTerminal.detect(preferred: ""), .terminal)
+        XCTAssertEqual(ExternalTerminal.detect(preferred: "hyper"), .terminal)
+    }
+
+    func testPreferenceIsValidatedAsString() {
+        XCTAssertEqual(SettingsBundle.validatedValue("kitty", for: .preferredTerminal) as? String, "kitty")
+        XCTAssertNil(SettingsBundle.validatedValue(3, for: .preferredTerminal))
+    }
+}
@@ -0,0 +1,46 @@
+import XCTest
+@testable import PPG_CLI
+
+final class FleetSummaryTests: XCTestCase {
+
+    private func agent(_ id: String, _ status: AgentStatus) -> AgentModel {
+        AgentModel(id: id, name: "", agentType: "claude", status: status, tmuxTarget: "s:1", prompt: "", startedAt: "")
+    }
+
+    private func worktree(_ id: String, _ agents: [AgentModel]) -> WorktreeModel {
+        WorktreeModel(id: id, name: id, path: "/tmp/\(id)", branch: "ppg/\(id)", status: "active", tmuxWindow: "s:1", agents: agents)
+    }
+
+    func testCountsAgentsAcrossProjects() {
+        let summary = FleetSummary(projectWorktrees: [
+            "/b": [worktree("wt-2", [agent("ag-3", .spawning), agent("ag-4", .failed)])],
+            "/a": [worktree("wt-1", [agent("ag-1", .running), agent("ag-2", .completed)])],
+        ])
+        XCTAssertEqual(summary.counts, AgentStatusCounts(running: 2, completed: 1, failed: 1))
+        XCTAssertEqual(summary.text, "2 running · 1 failed")
+        XCTAssertEqual(summary.accessibilityLabel, "Agents: 2 running, 1 completed, 1 failed")
+        XCTAssertEqual(summary.failedAgents.map(\.agent.id), ["ag-4"])
+        XCTAssertEqual(summary.failedAgents.first?.projectRoot, "/b")
+    }
+
+    func testFailedPartIsLeftOutUntilSomethingFails() {
+        let summary = FleetSummary(projectWorktrees: ["/a": [worktree("wt-1", [agent("ag-1", .completed)])]])
+        XCTAssertEqual(summary.text, "0 running")
+        XCTAssertTrue(summary.failedAgents.isEmpty)
+    }
+
+    func testManifestExitedWithNonZeroCodeCountsAsFailed() throws {
+        let json = #"{"id":"ag-1","name":"","agentType":"claude","status":"exited","exitCode":1,"tmuxTarget":"s:1","prompt":"","startedAt":""}"#
+        let failed = AgentModel(from: try JSONDecoder().decode(AgentEntryModel.self, from: Data(json.utf8)))
+        let clean = AgentModel(from: try JSONDecoder().decode(AgentEntryModel.self, from: Data(json.replacingOccurrences(of: #""exitCode":1"#, with: #""exitCode":0"#).utf8)))
+        let summary = FleetSummary(projectWorktrees: ["/a": [worktree("wt-1", [failed, clean])]])
+        XCTAssertEqual(summary.text, "0 running · 1 failed")
+        XCTAssertEqual(summary.failedAgents.map(\.agent.id), ["ag-1"])
+    }
+
+    func testEmptyFleetReadsAsNoAgents() {
+        let summary = FleetSummary(projectWorktrees: [:])
+        XCTAssertEqual(summary.text, "0 running")
+        XCTAssertEqual(summary.accessibilityLabel, "No agents")
+    }
+}
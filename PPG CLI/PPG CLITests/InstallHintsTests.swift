@@ -0,0 +1,33 @@
+import XCTest
+@testable import PPG_CLI
+
+final class InstallHintsTests: XCTestCase {
+
+    // MARK: - tmux
+
+    func testHomebrewOnly() {
+        XCTAssertEqual(tmuxInstallHints(available: [.homebrew, .npm]), ["brew install tmux"])
+    }
+
+    func testMacPortsOnly() {
+        XCTAssertEqual(tmuxInstallHints(available: [.macports]), ["sudo port install tmux"])
+    }
+
+    func testNoSystemManagerListsEveryOption() {
+        XCTAssertEqual(tmuxInstallHints(available: [.npm]), ["brew install tmux", "sudo port install tmux"])
+    }
+
+    // MARK: - ppg
+
+    func testNpmPreferredWhenBothPresent() {
+        XCTAssertEqual(ppgInstallHints(available: [.npm, .pnpm]), ["npm install -g pure-point-guard", "pnpm add -g pure-point-guard"])
+    }
+
+    func testPnpmOnly() {
+        XCTAssertEqual(ppgInstallHints(available: [.pnpm]), ["pnpm add -g pure-point-guard"])
+    }
+
+    func testNoNodeManagerListsBoth() {
+        XCTAssertEqual(ppgInstallHints(available: []).count, 2)
+    }
+}
@@ -0,0 +1,154 @@
+import XCTest
+@testable import PPG_CLI
+
+final class LogViewerTests: XCTestCase {
+
+    private let received = Date(timeIntervalSince1970: 1_700_000_000)
+
+    // MARK: - splitLines
+
+    func testSplitKeepsBlankLinesBetweenOutput() {
+        XCTAssertEqual(LogBuffer.splitLines("a\nb\n"), ["a", "b"])
+        XCTAssertEqual(LogBuffer.splitLines("a\n\nb"), ["a", "", "b"])
+    }
+
+    func testSplitEmptyOutputHasNoLines() {
+        XCTAssertEqual(LogBuffer.splitLines(""), [])
+    }
+
+    func testSplitDropsBlankRowsBelowTheCursor() {
+        XCTAssertEqual(LogBuffer.splitLines("a\n\nb\n\n   \n"), ["a", "", "b"])
+    }
+
+    // MARK: - newLines
+
+    func testUnchangedCaptureHasNoNewLines() {
+        XCTAssertEqual(LogBuffer.newLines(since: ["a", "b"], in: ["a", "b"]), [])
+    }
+
+    func testLinesAddedBelowAreNew() {
+        XCTAssertEqual(LogBuffer.newLines(since: ["a", "b"], in: ["a", "b", "c"]), ["c"])
+    }
+
+    func testScrolledWindowYieldsLinesPastTheOverlap() {
+        XCTAssertEqual(LogBuffer.newLines(since: ["a", "b", "c"], in: ["b", "c", "d"]), ["d"])
+        XCTAssertEqual(LogBuffer.newLines(since: ["a", "b", "c"], in: ["c", "d", "e"]), ["d", "e"])
+    }
+
+    func testRepeatedLinesUseTheLongestOverlap() {
+        XCTAssertEqual(LogBuffer.newLines(since: ["x", "x", "x"], in: ["x", "x", "x", "y"]), ["y"])
+    }
+
+    func testCapturesThatDontOverlapAreNil() {
+        XCTAssertNil(LogBuffer.newLines(since: ["a", "b"], in: ["c", "d"]))
+        XCTAssertEqual(LogBuffer.newLines(since: [], in: ["a"]), ["a"])
+    }
+
+    // MARK: - merge / append
+
+    func testMergeKeepsArrivalTimeOfLinesAlreadyShown() {
+        let later = received.addingTimeInterval(2)
+        var buffer = LogBuffer()
+        buffer.replace(with: "a\nb\n", at: received)
+        XCTAssertEqual(buffer.merge(capture: "b\nc\n", at: later), .appended([LogBuffer.Line(text: "c", receivedAt: later)]))
+        XCTAssertEqual(buffer.lines.map(\.text), ["a", "b", "c"])
+        XCTAssertEqual(buffer.lines.map(\.receivedAt), [received, received, later])
+    }
+
+    func testMergeWithoutOverlapReplaces() {
+        var buffer = LogBuffer()
+        buffer.replace(with: "a\nb", at: received)
+        XCTAssertEqual(buffer.merge(capture: "cleared", at: received), .replaced)
+        XCTAssertEqual(buffer.lines.map(\.text), ["cleared"])
+    }
+
+    func testAppendPastTheCapDropsTheOldestLines() {
+        var buffer = LogBuffer()
+        buffer.append(Array(repeating: "old", count: LogBuffer.maxLines), at: received)
+        XCTAssertEqual(buffer.append(["new"], at: received), .replaced)
+        XCTAssertEqual(buffer.lines.count, LogBuffer.maxLines * 3 / 4)
+        XCTAssertEqual(buffer.lines.last?.text, "new")
+    }
+
+    // MARK: - LineSplitter
+
+    func testSplitterHoldsBackPartialLines() {
+        var splitter = LineSplitter()
+        XCTAssertEqual(splitter.feed(Data("one\ntw".utf8)), ["one"])
+        XCTAssertEqual(splitter.feed(Data("o\nthree\n".utf8)), ["two", "three"])
+        XCTAssertEqual(splitter.feed(Data()), [])
+    }
+
+    func testSplitterReassemblesMultibyteCharacters() {
+        var splitter = LineSplitter()
+        let bytes = Array("✓ done\n".utf8)
+        XCTAssertEqual(splitter.feed(Data(bytes[..<2])), [])
+        XCTAssertEqual(splitter.feed(Data(bytes[2...])), ["✓ done"])
+    }
+
+    func testFlushHandsOverTheLastPartialLineOnce() {
+        var splitter = LineSplitter()
+        XCTAssertEqual(splitter.feed(Data("one\n$ ".utf8)), ["one"])
+        XCTAssertEqual(splitter.flush(), "$ ")
+        XCTAssertNil(splitter.flush())
+    }
+
+    // MARK: - LineBatch
+
+    func testBatchAsksForOneFlushUntilTaken() {
+        var batch = LineBatch()
+        XCTAssertFalse(batch.add([]))
+        XCTAssertTrue(batch.add(["one"]))
+        XCTAssertFalse(batch.add(["two", "three"]))
+        XCTAssertEqual(batch.take(), ["one", "two", "three"])
+        XCTAssertEqual(batch.take(), [])
+        XCTAssertTrue(batch.add(["four"]))
+    }
+
+    // MARK: - rendered
+
+    func testRenderedWithoutTimestampsIsTheOutput() {
+        var buffer = LogBuffer()
+        buffer.replace(with: "first\nsecond\n", at: received)
+        XCTAssertEqual(buffer.lines.map(\.receivedAt), [received, received])
+        XCTAssertEqual(buffer.rendered(timestamps: false), "first\nsecond")
+    }
+
+    func testRenderedWithTimestampsPrefixesEachLine() {
+        var buffer = LogBuffer()
+        buffer.replace(with: "first\nsecond", at: received)
+        let lines = buffer.rendered(timestamps: true).components(separatedBy: "\n")
+        XCTAssertEqual(lines.count, 2)
+        for (line, text) in zip(lines, ["first", "second"]) {
+            XCTAssertNotNil(line.range(of: #"^\d\d:\d\d:\d\d  "#, options: .regularExpression), line)
+            XCTAssertTrue(line.hasSuffix(text))
+        }
+    }
+
+    func testReplaceDiscardsPreviousCapture() {
+        var buffer = LogBuffer()
+        buffer.replace(with: "old\n", at: received)
+        buffer.replace(with: "new\n", at: received.addingTimeInterval(5))
+        XCTAssertEqual(buffer.lines, [LogBuffer.Line(text: "new", receivedAt: received.addingTimeInterval(5))])
+    }
+
+    // MARK: - Ending
+
+    private func manifestAgent(status: String, exitCode: Int? = nil) -> AgentModel {
+        AgentModel(from: AgentEntryModel(id: "ag-1", name: "claude", agentType: "claude", status: status, tmuxTarget: "s:1",
+                                         prompt: "", startedAt: "", completedAt: nil, exitCode: exitCode, error: nil, sessionId: nil))
+    }
+
+    @MainActor
+    func testManifestExitIdleAndGoneEndFollowing() {
+        XCTAssertNotNil(LogViewerWindowController.endReason(for: manifestAgent(status: "exited", exitCode: 0)))
+        XCTAssertEqual(LogViewerWindowController.endReason(for: manifestAgent(status: "exited", exitCode: 1)), "Exited with code 1")
+        XCTAssertNotNil(LogViewerWindowController.endReason(for: manifestAgent(status: "gone")))
+        XCTAssertEqual(LogViewerWindowController.endReason(for: manifestAgent(status: "idle")), "Completed")
+    }
+
+    @MainActor
+    func testRunningAgentKeepsFollowing() {
+        XCTAssertNil(LogViewerWindowController.endReason(for: manifestAgent(status: "running")))
+    }
+}
@@ -0,0 +1,57 @@
+import XCTest
+@testable import PPG_CLI
+
+@MainActor
+final class NavigationHistoryTests: XCTestCase {
+
+    func testBackAndForwardWalkVisits() {
+        var history = NavigationHistory()
+        history.visit(.item("ag-1"))
+        history.visit(.tab(.dashboard))
+        history.visit(.item("wt-1"))
+
+        XCTAssertEqual(history.goBack(), .tab(.dashboard))
+        XCTAssertEqual(history.goBack(), .item("ag-1"))
+        XCTAssertNil(history.goBack())
+        XCTAssertEqual(history.current, .item("ag-1"))
+
+        XCTAssertEqual(history.goForward(), .tab(.dashboard))
+        XCTAssertEqual(history.goForward(), .item("wt-1"))
+        XCTAssertNil(history.goForward())
+    }
+
+    func testVisitAfterGoingBackDropsForwardEntries() {
+        var history = NavigationHistory()
+        history.visit(.item("ag-1"))
+        history.visit(.item("ag-2"))
+        _ = history.goBack()
+        history.visit(.item("ag-3"))
+        XCTAssertFalse(history.canGoForward)
+        XCTAssertEqual(history.backStack, [.item("ag-1")])
+    }
+
+    func testReselectingTheCurrentEntryIsNotAVisit() {
+        var history = NavigationHistory()
+        history.visit(.item("ag-1"))
+        history.visit(.item("ag-1"))
+        XCTAssertFalse(history.canGoBack)
+    }
+
+    func testUnavailableEntriesAreSkippedAndDropped() {
+        var history = NavigationHistory()
+        history.visit(.item("ag-1"))
+        history.visit(.item("gone"))
+        history.visit(.item("ag-2"))
+        XCTAssertEqual(history.goBack(where: { $0 != .item("gone") }), .item("ag-1"))
+        XCTAssertEqual(history.forwardStack, [.item("ag-2")])
+    }
+
+    func testBackStackIsCapped() {
+        var history = NavigationHistory()
+        for index in 0...NavigationHistory.capacity + 10 {
+            history.visit(.item("ag-\(index)"))
+        }
+        XCTAssertEqual(history.backStack.count, NavigationHistory.capacity)
+        XCTAssertEqual(history.backStack.first, .item("ag-10"))
+    }
+}
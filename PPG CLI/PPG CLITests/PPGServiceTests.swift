@@ -67,6 +67,100 @@ final class PPGServiceTests: XCTestCase {
         XCTAssertNil(PPGService.shared.readManifest())
     }
 
+    func testUnsupportedManifestVersionIsRefused() {
+        let path = writeFixture(validJSON.replacingOccurrences(of: "\"version\": 1", with: "\"version\": 5"))
+        XCTAssertEqual(PPGService.shared.blockingManifestVersion(at: path), 5)
+        XCTAssertNil(PPGService.shared.readManifest(at: path))
+    }
+
+    func testIgnoringVersionReadsUnsupportedManifest() {
+        let path = writeFixture(validJSON.replacingOccurrences(of: "\"version\": 1", with: "\"version\": 5"))
+        PPGService.shared.ignoresManifestVersion = true
+        defer { PPGService.shared.ignoresManifestVersion = false }
+        XCTAssertNil(PPGService.shared.blockingManifestVersion(at: path))
+        XCTAssertEqual(PPGService.shared.readManifest(at: path)?.version, 5)
+    }
+
+    func testSupportedManifestVersionIsNotBlocking() {
+        let path = writeFixture(validJSON)
+        XCTAssertNil(PPGService.shared.blockingManifestVersion(at: path))
+        XCTAssertNil(PPGService.shared.blockingManifestVersion(at: "/nonexistent/path.json"))
+    }
+
+    // MARK: - Lenient Parsing
+
+    private let partlyBrokenJSON = """
+    {
+      "version": 1, "projectRoot": "/tmp", "sessionName": "s",
+      "worktrees": {
+        "wt-good": {
+          "id": "wt-good", "name": "good", "path": "/p", "branch": "b", "baseBranch": "main",
+          "status": "active", "tmuxWindow": "s:1",
+          "agents": {
+            "ag-ok": {
+              "id": "ag-ok", "name": "ok", "agentType": "claude", "status": "running",
+              "tmuxTarget": "s:1.0", "prompt": "x", "startedAt": "2026-02-23T12:00:00Z"
+            },
+            "ag-bad": {
+              "id": "ag-bad", "name": "bad", "agentType": "claude", "status": "running",
+              "tmuxTarget": "s:1.1", "prompt": "x", "startedAt": "2026-02-23T12:00:00Z",
+              "exitCode": "zero"
+            }
+          },
+          "createdAt": "2026-02-23T11:00:00Z"
+        },
+        "wt-bad": {
+          "id": "wt-bad", "name": "bad", "path": "/p2", "baseBranch": "main",
+          "status": "active", "tmuxWindow": "s:2", "agents": {},
+          "createdAt": "2026-02-23T11:00:00Z"
+        }
+      },
+      "createdAt": "t", "updatedAt": "t"
+    }
+    """
+
+    func testLenientReadSalvagesReadableEntries() throws {
+        let path = writeFixture(partlyBrokenJSON)
+        let (manifest, issues) = PPGService.shared.readManifestLeniently(at: path)
+        let unwrapped = try XCTUnwrap(manifest)
+        XCTAssertEqual(Array(unwrapped.worktrees.keys), ["wt-good"])
+        XCTAssertEqual(Array(unwrapped.worktrees["wt-good"]!.agents.keys), ["ag-ok"])
+        XCTAssertEqual(issues, [
+            ManifestParseIssue(path: "worktrees.wt-bad", message: "Missing \"branch\"."),
+            ManifestParseIssue(path: "worktrees.wt-good.agents.ag-bad", message: "\"exitCode\" has the wrong type."),
+        ])
+    }
+
+    func testStrictReadDropsWholeManifest() {
+        let path = writeFixture(partlyBrokenJSON)
+        let (manifest, issues) = PPGService.shared.readManifestLeniently(at: path, strict: true)
+        XCTAssertNil(manifest)
+        XCTAssertEqual(issues.count, 1)
+        XCTAssertEqual(issues.first?.path, "manifest")
+    }
+
+    func testLoadStatusReportsIssuesAlongsideWorktrees() {
+        let path = writeFixture(partlyBrokenJSON)
+        let status = PPGService.shared.loadStatus(manifestPath: path)
+        XCTAssertEqual(status.worktrees.map(\.id), ["wt-good"])
+        XCTAssertEqual(status.worktrees.first?.agents.map(\.id), ["ag-ok"])
+        XCTAssertEqual(status.issues.count, 2)
+    }
+
+    func testLoadStatusReportsManifestIdentity() {
+        let path = writeFixture(validJSON)
+        XCTAssertEqual(PPGService.shared.loadStatus(manifestPath: path).identity,
+                       ManifestIdentity(sessionName: "ppg-test", createdAt: "2026-02-23T10:00:00Z"))
+        XCTAssertNil(PPGService.shared.loadStatus(manifestPath: writeFixture("{ not valid json }")).identity)
+    }
+
+    func testBrokenTopLevelFieldFailsWholeManifest() {
+        let path = writeFixture(validJSON.replacingOccurrences(of: "\"sessionName\": \"ppg-test\"", with: "\"sessionName\": 7"))
+        let (manifest, issues) = PPGService.shared.readManifestLeniently(at: path)
+        XCTAssertNil(manifest)
+        XCTAssertEqual(issues.map(\.path), ["manifest"])
+    }
+
     func testRefreshStatusReturnsSortedWorktrees() {
         let json = """
         {
@@ -128,6 +222,74 @@ final class PPGServiceTests: XCTestCase {
         XCTAssertEqual(worktrees[0].agents[1].id, "ag-b")
     }
 
+    func testRefreshStatusSortsMixedTimestampFormatsChronologically() {
+        // The CLI writes toISOString() (fractional seconds); older entries have none.
+        // As strings, "12:00:00Z" sorts after "12:00:00.500Z" even though it's earlier.
+        let json = """
+        {
+          "version": 1, "projectRoot": "/tmp", "sessionName": "s",
+          "worktrees": {
+            "wt-1": {
+              "id": "wt-1", "name": "w", "path": "/p", "branch": "b", "baseBranch": "main",
+              "status": "active", "tmuxWindow": "s:1",
+              "agents": {
+                "ag-late": {
+                  "id": "ag-late", "name": "late", "agentType": "claude", "status": "running",
+                  "tmuxTarget": "s:1.1", "prompt": "x",
+                  "startedAt": "2026-02-23T12:00:00.500Z"
+                },
+                "ag-early": {
+                  "id": "ag-early", "name": "early", "agentType": "claude", "status": "running",
+                  "tmuxTarget": "s:1.0", "prompt": "y",
+                  "startedAt": "2026-02-23T12:00:00Z"
+                },
+                "ag-bad": {
+                  "id": "ag-bad", "name": "bad", "agentType": "claude", "status": "running",
+                  "tmuxTarget": "s:1.2", "prompt": "z",
+                  "startedAt": "yesterday"
+                }
+              },
+              "createdAt": "2026-02-23T11:59:00.000Z"
+            }
+          },
+          "createdAt": "t", "updatedAt": "t"
+        }
+        """
+        let path = writeFixture(json)
+        LaunchConfig.shared = LaunchConfig(manifestPath: path, sessionName: "s", projectName: "test", projectRoot: "")
+        let agents = PPGService.shared.refreshStatus()[0].agents
+        XCTAssertEqual(agents.map(\.id), ["ag-early", "ag-late", "ag-bad"])
+        XCTAssertNil(agents[2].startedDate)
+        XCTAssertEqual(agents[2].startedAt, "yesterday")
+    }
+
+    func testRefreshStatusOrdersSameInstantEntriesById() {
+        let agents = ["ag-c", "ag-a", "ag-b"].map { id in
+            """
+            "\(id)": {
+              "id": "\(id)", "name": "\(id)", "agentType": "claude", "status": "running",
+              "tmuxTarget": "s:1", "prompt": "x", "startedAt": "2026-02-23T12:00:00.000Z"
+            }
+            """
+        }.joined(separator: ",\n")
+        let json = """
+        {
+          "version": 1, "projectRoot": "/tmp", "sessionName": "s",
+          "worktrees": {
+            "wt-1": {
+              "id": "wt-1", "name": "w", "path": "/p", "branch": "b", "baseBranch": "main",
+              "status": "active", "tmuxWindow": "s:1", "agents": { \(agents) },
+              "createdAt": "t"
+            }
+          },
+          "createdAt": "t", "updatedAt": "t"
+        }
+        """
+        let path = writeFixture(json)
+        LaunchConfig.shared = LaunchConfig(manifestPath: path, sessionName: "s", projectName: "test", projectRoot: "")
+        XCTAssertEqual(PPGService.shared.refreshStatus()[0].agents.map(\.id), ["ag-a", "ag-b", "ag-c"])
+    }
+
     func testRefreshStatusReturnsEmptyWhenMissing() {
         LaunchConfig.shared = LaunchConfig(manifestPath: "/nonexistent", sessionName: "", projectName: "", projectRoot: "")
         XCTAssertEqual(PPGService.shared.refreshStatus().count, 0)
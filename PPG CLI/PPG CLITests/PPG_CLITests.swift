@@ -71,6 +71,58 @@ final class ManifestModelTests: XCTestCase {
     }
 }
 
+final class ManifestExtraFieldTests: XCTestCase {
+    let json = """
+    {
+      "version": 1, "projectRoot": "/tmp", "sessionName": "s", "owner": {"team": "infra"},
+      "worktrees": {
+        "wt-1": {
+          "id": "wt-1", "name": "a", "path": "/p", "branch": "b", "baseBranch": "main",
+          "status": "active", "tmuxWindow": "s:1", "labels": ["x", "y"],
+          "agents": {
+            "ag-1": {
+              "id": "ag-1", "name": "c", "agentType": "claude", "status": "running",
+              "tmuxTarget": "s:1", "prompt": "x", "startedAt": "t",
+              "model": "opus", "costUsd": 0.42, "cached": true, "parent": null
+            }
+          },
+          "createdAt": "t"
+        }
+      },
+      "createdAt": "t", "updatedAt": "t"
+    }
+    """.data(using: .utf8)!
+
+    func testUnknownFieldsAreKept() throws {
+        let manifest = try JSONDecoder().decode(ManifestModel.self, from: json)
+        XCTAssertEqual(manifest.extra["owner"], .object(["team": .string("infra")]))
+        let wt = try XCTUnwrap(manifest.worktrees["wt-1"])
+        XCTAssertEqual(wt.extra["labels"], .array([.string("x"), .string("y")]))
+        let agent = try XCTUnwrap(wt.agents["ag-1"])
+        XCTAssertEqual(agent.extra.string("model"), "opus")
+        XCTAssertEqual(agent.extra.number("costUsd"), 0.42)
+        XCTAssertEqual(agent.extra["cached"], .bool(true))
+        XCTAssertEqual(agent.extra["parent"], .null)
+        XCTAssertNil(agent.extra["prompt"])
+    }
+
+    func testUnknownFieldsSurviveRoundTrip() throws {
+        let manifest = try JSONDecoder().decode(ManifestModel.self, from: json)
+        let reencoded = try JSONDecoder().decode(ManifestModel.self, from: JSONEncoder().encode(manifest))
+        XCTAssertEqual(reencoded.extra, manifest.extra)
+        let agent = try XCTUnwrap(reencoded.worktrees["wt-1"]?.agents["ag-1"])
+        XCTAssertEqual(agent.extra, manifest.worktrees["wt-1"]?.agents["ag-1"]?.extra)
+        XCTAssertEqual(agent.prompt, "x")
+        XCTAssertNil(agent.completedAt)
+    }
+
+    func testDisplayStrings() {
+        XCTAssertEqual(ManifestValue.number(3).displayString, "3")
+        XCTAssertEqual(ManifestValue.number(2.5).displayString, "2.5")
+        XCTAssertEqual(ManifestValue.object(["b": .bool(false), "a": .null]).displayString, #"{"a":null,"b":false}"#)
+    }
+}
+
 final class AgentModelTests: XCTestCase {
     func testStatusMappingFromEntry() {
         let entry = AgentEntryModel(
@@ -82,14 +134,80 @@ final class AgentModelTests: XCTestCase {
         XCTAssertEqual(model.status, AgentStatus.running)
     }
 
-    func testUnknownStatusMapsToLost() {
+    func testUnrecognizedStatusIsKeptAsUnknown() {
+        let entry = AgentEntryModel(
+            id: "ag-1", name: "c", agentType: "claude", status: "paused",
+            tmuxTarget: "s:1", prompt: "x", startedAt: "t",
+            completedAt: nil, exitCode: nil, error: nil, sessionId: nil
+        )
+        let model = AgentModel(from: entry)
+        XCTAssertEqual(model.status, AgentStatus.unknown)
+        XCTAssertEqual(model.rawStatus, "paused")
+        XCTAssertEqual(model.statusLabel, "unknown (paused)")
+    }
+
+    private func model(status: String, exitCode: Int? = nil) -> AgentModel {
+        AgentModel(from: AgentEntryModel(
+            id: "ag-1", name: "c", agentType: "claude", status: status,
+            tmuxTarget: "s:1", prompt: "x", startedAt: "t",
+            completedAt: nil, exitCode: exitCode, error: nil, sessionId: nil
+        ))
+    }
+
+    func testCLIIdleHasFinished() {
+        let agent = model(status: "idle")
+        XCTAssertEqual(agent.status, .completed)
+        XCTAssertEqual(agent.rawStatus, "idle")
+        XCTAssertEqual(TerminalPane.exitHeadline(status: agent.status, exitCode: nil)?.text, "Completed")
+    }
+
+    func testCLIExitedWithZeroCodeIsCompleted() {
+        XCTAssertEqual(model(status: "exited", exitCode: 0).status, .completed)
+        XCTAssertEqual(model(status: "exited").status, .completed)
+    }
+
+    func testCLIExitedWithNonZeroCodeIsFailed() {
+        let agent = model(status: "exited", exitCode: 1)
+        XCTAssertEqual(agent.status, .failed)
+        XCTAssertEqual(agent.statusLabel, "failed")
+        XCTAssertEqual(TerminalPane.exitHeadline(status: agent.status, exitCode: agent.exitCode)?.text, "Exited with code 1")
+    }
+
+    func testCLIGoneIsLost() {
+        let agent = model(status: "gone")
+        XCTAssertEqual(agent.status, .lost)
+        XCTAssertNotNil(TerminalPane.exitHeadline(status: agent.status, exitCode: nil))
+    }
+
+    func testKnownStatusLabelIsRawValue() {
+        let model = AgentModel(id: "ag-1", name: "c", agentType: "claude", status: .failed, tmuxTarget: "s:1", prompt: "x", startedAt: "t")
+        XCTAssertEqual(model.rawStatus, "failed")
+        XCTAssertEqual(model.statusLabel, "failed")
+    }
+
+    func testTimestampsAreParsedAndRawValuesKept() throws {
         let entry = AgentEntryModel(
-            id: "ag-1", name: "c", agentType: "claude", status: "unknown_status",
+            id: "ag-1", name: "c", agentType: "claude", status: "completed",
+            tmuxTarget: "s:1", prompt: "x", startedAt: "2026-02-23T12:00:00.250Z",
+            completedAt: "2026-02-23T12:30:00Z", exitCode: 0, error: nil, sessionId: nil
+        )
+        let model = AgentModel(from: entry)
+        let started = try XCTUnwrap(model.startedDate)
+        let completed = try XCTUnwrap(model.completedDate)
+        XCTAssertEqual(completed.timeIntervalSince(started), 1799.75, accuracy: 0.001)
+        XCTAssertEqual(model.startedAt, "2026-02-23T12:00:00.250Z")
+    }
+
+    func testUnparseableTimestampLeavesDateNil() {
+        let entry = AgentEntryModel(
+            id: "ag-1", name: "c", agentType: "claude", status: "running",
             tmuxTarget: "s:1", prompt: "x", startedAt: "t",
             completedAt: nil, exitCode: nil, error: nil, sessionId: nil
         )
         let model = AgentModel(from: entry)
-        XCTAssertEqual(model.status, AgentStatus.lost)
+        XCTAssertNil(model.startedDate)
+        XCTAssertNil(model.completedDate)
+        XCTAssertEqual(model.startedAt, "t")
     }
 }
 
@@ -137,4 +255,61 @@ final class LaunchConfigTests: XCTestCase {
         XCTAssertEqual(config.projectName, "root")
     }
 
+    func testMissingValueIsAnError() {
+        XCTAssertThrowsError(try LaunchConfig.parseCommand(["app", "--manifest-path"]))
+        XCTAssertThrowsError(try LaunchConfig.parseCommand(["app", "--manifest-path", "--session-name", "ppg"]))
+    }
+
+    func testUnknownOptionIsAnError() {
+        XCTAssertThrowsError(try LaunchConfig.parseCommand(["app", "--manifset-path", "/foo/.ppg/manifest.json"])) { error in
+            XCTAssertEqual("\(error)", "unknown option --manifset-path")
+        }
+    }
+
+    func testSystemArgumentsArePassedThrough() throws {
+        let command = try LaunchConfig.parseCommand(["app", "-NSDocumentRevisionsDebugMode", "YES", "--session-name", "ppg-foo"])
+        guard case .run(let config) = command else { return XCTFail("expected a run command") }
+        XCTAssertEqual(config.sessionName, "ppg-foo")
+    }
+
+    func testHelpAndVersion() throws {
+        guard case .help = try LaunchConfig.parseCommand(["app", "--help"]) else { return XCTFail("expected help") }
+        guard case .version = try LaunchConfig.parseCommand(["app", "--version"]) else { return XCTFail("expected version") }
+    }
+}
+
+final class AgentIndexTests: XCTestCase {
+    /// 50 worktrees × 10 agents across two projects.
+    private func makeProjects() -> [String: [WorktreeModel]] {
+        var projects: [String: [WorktreeModel]] = [:]
+        for w in 0..<50 {
+            let agents = (0..<10).map { a in
+                AgentModel(id: "ag-\(w)-\(a)", name: "a\(a)", agentType: "claude", status: .running,
+                           tmuxTarget: "s:\(w).\(a)", prompt: "", startedAt: "")
+            }
+            let wt = WorktreeModel(id: "wt-\(w)", name: "w\(w)", path: "/p/\(w)", branch: "b", status: "active",
+                                   tmuxWindow: "s:\(w)", agents: agents)
+            projects[w % 2 == 0 ? "/even" : "/odd", default: []].append(wt)
+        }
+        return projects
+    }
+
+    func testIndexesEveryAgent() {
+        XCTAssertEqual(AgentIndex(projectWorktrees: makeProjects()).count, 500)
+    }
+
+    func testFindReturnsOwningProjectAndWorktree() throws {
+        let index = AgentIndex(projectWorktrees: makeProjects())
+        let location = try XCTUnwrap(index.find("ag-37-4"))
+        XCTAssertEqual(location.projectRoot, "/odd")
+        XCTAssertEqual(location.worktree.id, "wt-37")
+        XCTAssertEqual(location.agent.tmuxTarget, "s:37.4")
+        XCTAssertEqual(index.worktree(ofAgent: "ag-12-0")?.id, "wt-12")
+    }
+
+    func testUnknownAgentIsNil() {
+        let index = AgentIndex(projectWorktrees: makeProjects())
+        XCTAssertNil(index.find("ag-missing"))
+        XCTAssertNil(AgentIndex().worktree(ofAgent: "ag-0-0"))
+    }
 }
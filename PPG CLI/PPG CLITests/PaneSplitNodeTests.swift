@@ -494,6 +494,455 @@ final class PaneSplitNodeTests: XCTestCase {
     }
 }
 
+// MARK: - PaneGridController Shape
+
+@MainActor
+final class PaneGridShapeTests: XCTestCase {
+    func testNarrowWindowStacksPanes() {
+        let shape = PaneGridController.gridShape(paneCount: 2, availableWidth: 700, minPaneWidth: 480)
+        XCTAssertEqual(shape.columns, 1)
+        XCTAssertEqual(shape.rows, 2)
+    }
+
+    func testWideWindowUsesOneRow() {
+        let shape = PaneGridController.gridShape(paneCount: 3, availableWidth: 2400, minPaneWidth: 480)
+        XCTAssertEqual(shape.columns, 3)
+        XCTAssertEqual(shape.rows, 1)
+    }
+
+    func testColumnsGrowWhenTwoRowsCannotHoldPanes() {
+        let shape = PaneGridController.gridShape(paneCount: 6, availableWidth: 500, minPaneWidth: 480)
+        XCTAssertEqual(shape.columns, 3)
+        XCTAssertEqual(shape.rows, 2)
+    }
+
+    func testWiderMinimumPaneWidthUsesFewerColumns() {
+        XCTAssertEqual(PaneGridController.gridShape(paneCount: 3, availableWidth: 1800, minPaneWidth: 480).columns, 3)
+        XCTAssertEqual(PaneGridController.gridShape(paneCount: 3, availableWidth: 1800, minPaneWidth: 900).columns, 2)
+    }
+
+    func testColumnsStopAtThreeOnUltrawideWindows() {
+        XCTAssertEqual(PaneGridController.gridShape(paneCount: 4, availableWidth: 5000, minPaneWidth: 480).columns, 3)
+    }
+
+    func testPaneCountIsCappedAtGridLimit() {
+        let shape = PaneGridController.gridShape(paneCount: 9, availableWidth: 3000, minPaneWidth: 480)
+        XCTAssertEqual(shape.columns * shape.rows, PaneSplitNode.maxLeaves)
+    }
+
+    // MARK: - Reflow
+
+    private func leaves(_ ids: String...) -> [PaneSplitNode] {
+        ids.map { .leaf(id: $0, entry: nil) }
+    }
+
+    func testGridPlacesLeavesRowByRow() throws {
+        let grid = try XCTUnwrap(PaneSplitNode.grid(of: leaves("a", "b", "c", "d", "e"), columns: 3))
+        XCTAssertEqual(grid.allLeafIds(), ["a", "b", "c", "d", "e"])
+        XCTAssertEqual(grid.rowCount, 2)
+        XCTAssertEqual(grid.rowForLeaf(id: "a")?.columnsInRow, 3)
+        XCTAssertEqual(grid.rowForLeaf(id: "e")?.columnsInRow, 2)
+        XCTAssertEqual(grid.gridColumns, 3)
+    }
+
+    func testHandMadeLayoutHasNoGridColumns() {
+        let node = PaneSplitNode.split(
+            direction: .vertical,
+            first: .split(direction: .horizontal, first: .leaf(id: "a", entry: nil), second: .leaf(id: "b", entry: nil), ratio: 0.5),
+            second: .leaf(id: "c", entry: nil),
+            ratio: 0.5
+        )
+        XCTAssertNil(node.gridColumns)
+    }
+
+    func testReflowMovesPanesIntoFewerColumnsAndKeepsOrder() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.minPaneWidth = 480
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        let ids = grid.root.allLeafIds()
+        let focused = grid.focusedLeafId
+        XCTAssertEqual(grid.root.gridColumns, 3)
+
+        XCTAssertTrue(grid.reflow(availableWidth: 700))
+        XCTAssertEqual(grid.root.gridColumns, 2)
+        XCTAssertEqual(grid.root.allLeafIds(), ids)
+        XCTAssertEqual(grid.focusedLeafId, focused)
+        XCTAssertFalse(grid.reflow(availableWidth: 700), "same width, same columns")
+
+        XCTAssertTrue(grid.reflow(availableWidth: 2400))
+        XCTAssertEqual(grid.root.gridColumns, 3)
+    }
+
+    func testNewMinPaneWidthReflowsTheGrid() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.view.frame = NSRect(x: 0, y: 0, width: 1800, height: 600)
+        grid.minPaneWidth = 480
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        grid.reflowsOnResize = true
+
+        grid.minPaneWidth = 900
+        XCTAssertEqual(grid.root.gridColumns, 2, "1800pt holds only two 900pt panes")
+    }
+
+    func testReflowLeavesHandMadeLayoutAlone() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        XCTAssertTrue(grid.splitFocusedPane(direction: .horizontal))
+        XCTAssertFalse(grid.reflow(availableWidth: 2400))
+    }
+}
+
+// MARK: - PaneGridController Zoom
+
+@MainActor
+final class PaneGridZoomTests: XCTestCase {
+    private func makeTwoPaneGrid() -> PaneGridController {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        return grid
+    }
+
+    func testToggleZoomMaximizesFocusedPaneAndRestores() {
+        let grid = makeTwoPaneGrid()
+        let focused = grid.focusedLeafId
+
+        grid.toggleZoom()
+        XCTAssertEqual(grid.zoomedLeafId, focused)
+        XCTAssertEqual(grid.root.leafCount, 2, "zooming must not change the layout tree")
+
+        grid.toggleZoom()
+        XCTAssertNil(grid.zoomedLeafId)
+    }
+
+    func testToggleZoomIgnoredWithSinglePane() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.toggleZoom()
+        XCTAssertNil(grid.zoomedLeafId)
+    }
+
+    func testSplittingWhileZoomedRestoresLayout() {
+        let grid = makeTwoPaneGrid()
+        grid.toggleZoom()
+        XCTAssertTrue(grid.splitFocusedPane(direction: .horizontal))
+        XCTAssertNil(grid.zoomedLeafId)
+        XCTAssertEqual(grid.root.leafCount, 3)
+    }
+}
+
+// MARK: - PaneGridController Focus
+
+@MainActor
+final class PaneGridFocusTests: XCTestCase {
+    func testMoveFocusWrapsAtEdges() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        let first = grid.focusedLeafId
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        let second = grid.focusedLeafId
+
+        grid.moveFocus(.vertical, forward: true)
+        XCTAssertEqual(grid.focusedLeafId, first, "moving right from the last pane wraps to the first")
+
+        grid.moveFocus(.vertical, forward: false)
+        XCTAssertEqual(grid.focusedLeafId, second, "moving left from the first pane wraps to the last")
+    }
+
+    func testFocusPaneByIndex() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        let leafIds = grid.root.allLeafIds()
+
+        grid.focusPane(at: 0)
+        XCTAssertEqual(grid.focusedLeafId, leafIds[0])
+
+        grid.focusPane(at: 5)
+        XCTAssertEqual(grid.focusedLeafId, leafIds[0], "out-of-range index is ignored")
+    }
+
+    func testSwapPanesExchangesEntriesAndKeepsLayout() {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        let session = DashboardSession(projectRoot: "/tmp/test")
+        let termA = session.addTerminal(parentWorktreeId: nil, workingDir: "/tmp")
+        let termB = session.addTerminal(parentWorktreeId: nil, workingDir: "/tmp")
+
+        grid.fillFocusedPane(with: .sessionEntry(termA, sessionName: "test"))
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        grid.fillFocusedPane(with: .sessionEntry(termB, sessionName: "test"))
+        let leafIds = grid.root.allLeafIds()
+
+        var swapped = false
+        grid.onPanesSwapped = { swapped = true }
+        grid.swapPanes(leafIds[0], leafIds[1])
+
+        XCTAssertTrue(swapped)
+        XCTAssertEqual(grid.root.allLeafIds(), leafIds)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, termB.id)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[1])?.id, termA.id)
+        XCTAssertEqual(grid.focusedLeafId, leafIds[1], "focus follows the dragged pane")
+    }
+}
+
+// MARK: - PaneGridController Detach
+
+@MainActor
+final class PaneGridDetachTests: XCTestCase {
+    private var providedViews: [String: NSView] = [:]
+    private var providerCalls = 0
+
+    /// Two panes with terminals; the provider hands out one view per entry and counts calls.
+    private func makeGrid() -> (grid: PaneGridController, termA: TabEntry) {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.terminalViewProvider = { [unowned self] entry in
+            providerCalls += 1
+            let view = NSView()
+            providedViews[entry.id] = view
+            return view
+        }
+        let session = DashboardSession(projectRoot: "/tmp/test")
+        let termA = TabEntry.sessionEntry(session.addTerminal(parentWorktreeId: nil, workingDir: "/tmp"), sessionName: "test")
+        let termB = TabEntry.sessionEntry(session.addTerminal(parentWorktreeId: nil, workingDir: "/tmp"), sessionName: "test")
+        grid.fillFocusedPane(with: termA)
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        grid.fillFocusedPane(with: termB)
+        return (grid, termA)
+    }
+
+    func testReattachReturnsTheSameTerminalToItsSlot() throws {
+        let (grid, termA) = makeGrid()
+        let leafIds = grid.root.allLeafIds()
+        let termView = try XCTUnwrap(providedViews[termA.id])
+        let cell = try XCTUnwrap(grid.cellViews[leafIds[0]])
+        let callsBefore = providerCalls
+
+        grid.detachPane(leafIds[0])
+        XCTAssertTrue(grid.isDetached(leafIds[0]))
+        XCTAssertFalse(termView.isDescendant(of: cell), "the terminal moves to its own window")
+        XCTAssertNotNil(termView.window)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, termA.id, "the slot keeps its entry while detached")
+
+        grid.reattachPane(leafIds[0])
+        XCTAssertFalse(grid.isDetached(leafIds[0]))
+        XCTAssertTrue(termView.isDescendant(of: cell), "the same terminal comes back to the same cell")
+        XCTAssertEqual(providerCalls, callsBefore, "no new terminal session is started")
+        XCTAssertEqual(grid.root.allLeafIds(), leafIds)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, termA.id)
+        XCTAssertEqual(grid.focusedLeafId, leafIds[0])
+    }
+
+    func testClosingTheDetachedWindowReattaches() throws {
+        let (grid, termA) = makeGrid()
+        let leafIds = grid.root.allLeafIds()
+        let termView = try XCTUnwrap(providedViews[termA.id])
+        let callsBefore = providerCalls
+
+        grid.detachPane(leafIds[0])
+        try XCTUnwrap(termView.window).close()
+
+        XCTAssertFalse(grid.isDetached(leafIds[0]))
+        XCTAssertTrue(termView.isDescendant(of: try XCTUnwrap(grid.cellViews[leafIds[0]])))
+        XCTAssertEqual(providerCalls, callsBefore)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, termA.id)
+    }
+}
+
+// MARK: - PaneGridController Overflow
+
+@MainActor
+final class PaneGridOverflowTests: XCTestCase {
+    private var providedIds: [String] = []
+    private var terminatedViews: [NSView] = []
+    private var viewsById: [String: NSView] = [:]
+
+    private func agentEntry(_ id: String) -> TabEntry {
+        .manifestAgent(AgentModel(id: id, name: id, agentType: "claude", status: .running, tmuxTarget: "s:1", prompt: "", startedAt: ""), sessionName: "s")
+    }
+
+    /// Fill a grid with as many entries as fit; the rest go to the overflow.
+    private func makeFullGrid(with entries: [TabEntry]) -> PaneGridController {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.terminalViewProvider = { [unowned self] entry in
+            providedIds.append(entry.id)
+            let view = NSView()
+            viewsById[entry.id] = view
+            return view
+        }
+        grid.terminalTerminator = { [unowned self] view in terminatedViews.append(view) }
+        // Same order as opening a worktree: bottom row first, then the top row
+        var remaining = entries[...]
+        grid.fillFocusedPane(with: remaining.removeFirst())
+        let topLeaf = grid.focusedLeafId
+        for direction: SplitDirection in [.horizontal, .vertical, .vertical] where !remaining.isEmpty {
+            guard grid.splitFocusedPane(direction: direction) else { break }
+            grid.fillFocusedPane(with: remaining.removeFirst())
+        }
+        grid.setFocus(topLeaf)
+        while !remaining.isEmpty, grid.splitFocusedPane(direction: .vertical) {
+            grid.fillFocusedPane(with: remaining.removeFirst())
+        }
+        grid.setOverflowEntries(entries.filter { !grid.containsEntry(id: $0.id) })
+        return grid
+    }
+
+    private func overflowButtonTitle(_ grid: PaneGridController) -> String? {
+        grid.view.subviews.compactMap { $0 as? NSButton }.first { $0.title.hasSuffix("more") }?.title
+    }
+
+    func testEntriesPastCapacityGoToOverflowWithoutTerminals() {
+        let entries = (1...8).map { agentEntry("ag-\($0)") }
+        let grid = makeFullGrid(with: entries)
+
+        XCTAssertEqual(grid.root.leafCount, PaneSplitNode.maxLeaves)
+        XCTAssertEqual(grid.overflowEntries.map(\.id), ["ag-7", "ag-8"])
+        XCTAssertEqual(overflowButtonTitle(grid), "+2 more")
+        XCTAssertFalse(providedIds.contains("ag-7") || providedIds.contains("ag-8"), "hidden agents get no terminal")
+        XCTAssertEqual(grid.layoutNode().overflowEntryIds, ["ag-7", "ag-8"])
+    }
+
+    func testNoOverflowChipWhenEverythingFits() {
+        let grid = makeFullGrid(with: (1...2).map { agentEntry("ag-\($0)") })
+        XCTAssertTrue(grid.overflowEntries.isEmpty)
+        XCTAssertNil(overflowButtonTitle(grid))
+        XCTAssertNil(grid.layoutNode().overflowEntryIds)
+    }
+
+    func testSwappingInAnOverflowEntryMovesTheDisplacedOneOut() throws {
+        let entries = (1...8).map { agentEntry("ag-\($0)") }
+        let grid = makeFullGrid(with: entries)
+        let leafIds = grid.root.allLeafIds()
+        let displacedView = try XCTUnwrap(viewsById["ag-1"])
+        grid.setFocus(leafIds[0])
+        var swapped = false
+        grid.onPanesSwapped = { swapped = true }
+
+        grid.showOverflowEntry(at: 0)
+
+        XCTAssertTrue(swapped)
+        XCTAssertEqual(grid.root.allLeafIds(), leafIds, "the swap keeps the layout")
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, "ag-7")
+        XCTAssertEqual(grid.overflowEntries.map(\.id), ["ag-8", "ag-1"])
+        XCTAssertEqual(overflowButtonTitle(grid), "+2 more")
+        XCTAssertEqual(providedIds.last, "ag-7")
+        XCTAssertTrue(terminatedViews.contains { $0 === displacedView }, "the displaced agent's terminal is closed")
+    }
+}
+
+// MARK: - PaneGridController Eviction
+
+@MainActor
+final class PaneGridEvictionTests: XCTestCase {
+    private func agentEntry(_ id: String, status: AgentStatus = .running) -> TabEntry {
+        .manifestAgent(AgentModel(id: id, name: id, agentType: "claude", status: status, tmuxTarget: "s:1", prompt: "", startedAt: ""), sessionName: "s")
+    }
+
+    private func makeGrid(with entries: [TabEntry]) -> PaneGridController {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        for (index, entry) in entries.enumerated() {
+            if index > 0 { XCTAssertTrue(grid.splitFocusedPane(direction: .vertical)) }
+            grid.fillFocusedPane(with: entry)
+        }
+        return grid
+    }
+
+    func testEntriesMissingFromTheManifestAreEvicted() {
+        let grid = makeGrid(with: [agentEntry("ag-1"), agentEntry("ag-2")])
+        let leafIds = grid.root.allLeafIds()
+
+        XCTAssertTrue(grid.evictEntries(notIn: ["ag-2"]))
+        XCTAssertNil(grid.root.entry(forLeafId: leafIds[0]))
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[1])?.id, "ag-2")
+        XCTAssertEqual(grid.root.allLeafIds(), leafIds, "the emptied pane keeps its place")
+        XCTAssertFalse(grid.evictEntries(notIn: ["ag-2"]))
+    }
+
+    func testGoneAgentShowsEndedOverlayThenIsEvicted() {
+        let grid = makeGrid(with: [agentEntry("ag-1", status: .lost), agentEntry("ag-2")])
+        grid.endedOverlayDuration = 0.05
+        let leafIds = grid.root.allLeafIds()
+
+        XCTAssertEqual(grid.endEntries(agentIds: ["ag-1"]), ["ag-1"])
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, "ag-1", "the pane stays while the overlay shows")
+        XCTAssertEqual(grid.cellViews[leafIds[0]]?.isShowingEndedOverlay, true)
+        XCTAssertEqual(grid.endEntries(agentIds: ["ag-1"]), [], "an ending pane isn't scheduled twice")
+
+        let evicted = expectation(description: "evicted")
+        DispatchQueue.main.asyncAfter(deadline: .now() + 0.2) { evicted.fulfill() }
+        wait(for: [evicted], timeout: 1)
+        XCTAssertNil(grid.root.entry(forLeafId: leafIds[0]))
+        XCTAssertEqual(grid.cellViews[leafIds[0]]?.isShowingEndedOverlay, false)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[1])?.id, "ag-2")
+    }
+
+    /// A grid whose panes are real `TerminalPane`s, first agent gone from tmux.
+    private func makeTerminalPaneGrid() -> PaneGridController {
+        let grid = PaneGridController()
+        grid.loadViewIfNeeded()
+        grid.terminalViewProvider = { entry in
+            guard case .manifestAgent(let agent, let sessionName) = entry else { return NSView() }
+            let pane = TerminalPane(agent: agent, sessionName: sessionName)
+            pane.onRestart = { _ in }
+            pane.updateStatus(agent.status)
+            return pane
+        }
+        grid.fillFocusedPane(with: agentEntry("ag-1", status: .lost))
+        XCTAssertTrue(grid.splitFocusedPane(direction: .vertical))
+        grid.fillFocusedPane(with: agentEntry("ag-2"))
+        grid.endedOverlayDuration = 0.05
+        return grid
+    }
+
+    private func waitPastEndedOverlay() {
+        let waited = expectation(description: "waited")
+        DispatchQueue.main.asyncAfter(deadline: .now() + 0.2) { waited.fulfill() }
+        wait(for: [waited], timeout: 1)
+    }
+
+    func testGoneTerminalPaneIsEvictedDespiteItsExitBanner() {
+        let grid = makeTerminalPaneGrid()
+        let leafIds = grid.root.allLeafIds()
+        XCTAssertEqual(grid.cellViews[leafIds[0]]?.isShowingExitBanner, true)
+
+        XCTAssertEqual(grid.endEntries(agentIds: ["ag-1"]), ["ag-1"])
+        waitPastEndedOverlay()
+        XCTAssertNil(grid.root.entry(forLeafId: leafIds[0]))
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[1])?.id, "ag-2")
+    }
+
+    func testGonePaneWithPendingRestartIsReplacedInPlace() {
+        let grid = makeTerminalPaneGrid()
+        grid.hasPendingRestart = { $0 == "ag-1" }
+        let leafIds = grid.root.allLeafIds()
+
+        XCTAssertEqual(grid.endEntries(agentIds: ["ag-1"]), ["ag-1"])
+        waitPastEndedOverlay()
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, "ag-1", "the pane waits for the replacement")
+        XCTAssertEqual(grid.cellViews[leafIds[0]]?.isShowingEndedOverlay, false)
+
+        XCTAssertTrue(grid.replaceEntry(id: "ag-1", with: agentEntry("ag-3")))
+        XCTAssertEqual(grid.root.allLeafIds(), leafIds)
+        XCTAssertEqual(grid.root.entry(forLeafId: leafIds[0])?.id, "ag-3", "the restart's agent takes the same pane")
+        XCTAssertEqual(grid.cellViews[leafIds[0]]?.isShowingExitBanner, false)
+    }
+
+    func testGroupEndsOnlyWhenEveryAgentHas() {
+        let agents = ["ag-1", "ag-2"].map { AgentModel(id: $0, name: $0, agentType: "claude", status: .lost, tmuxTarget: "s:1", prompt: "", startedAt: "") }
+        let group = TabEntry.agentGroup(agents, "s:1", sessionName: "s")
+        XCTAssertFalse(PaneGridController.hasEnded(group, endedAgentIds: ["ag-1"]))
+        XCTAssertTrue(PaneGridController.hasEnded(group, endedAgentIds: ["ag-1", "ag-2"]))
+    }
+}
+
 // MARK: - SplitDirection Equatable (for test assertions)
 
 extension SplitDirection: Equatable {}
@@ -0,0 +1,51 @@
+import XCTest
+@testable import PPG_CLI
+
+@MainActor
+final class ProjectContextTests: XCTestCase {
+
+    // MARK: - Manifest identity
+
+    func testFirstManifestOnlyPicksUpItsSessionName() {
+        let ctx = ProjectContext(projectRoot: "/tmp/ppg-no-manifest-\(UUID().uuidString)")
+        XCTAssertNil(ctx.manifestIdentity)
+        XCTAssertEqual(ctx.sessionName, "ppg")
+        XCTAssertFalse(ctx.adoptManifestIdentity(ManifestIdentity(sessionName: "ppg-app", createdAt: "2026-01-01T00:00:00Z")))
+        XCTAssertEqual(ctx.sessionName, "ppg-app")
+    }
+
+    func testReinitIsReportedOnce() {
+        let ctx = ProjectContext(projectRoot: "/tmp/ppg-no-manifest-\(UUID().uuidString)")
+        let first = ManifestIdentity(sessionName: "ppg-app", createdAt: "2026-01-01T00:00:00Z")
+        let second = ManifestIdentity(sessionName: "ppg-app", createdAt: "2026-02-01T00:00:00Z")
+        ctx.adoptManifestIdentity(first)
+        XCTAssertFalse(ctx.adoptManifestIdentity(first))
+        XCTAssertTrue(ctx.adoptManifestIdentity(second))
+        XCTAssertFalse(ctx.adoptManifestIdentity(second))
+        XCTAssertEqual(ctx.manifestIdentity, second)
+    }
+
+    // MARK: - defaultAgent
+
+    func testDefaultAgentIsReadFromTopLevelKey() {
+        let config = """
+        sessionName: ppg
+        defaultAgent: codex
+        agents:
+          claude:
+            name: claude
+        """
+        XCTAssertEqual(ProjectContext.defaultAgent(inConfig: config), "codex")
+    }
+
+    func testDefaultAgentIsUnquotedAndDropsComments() {
+        XCTAssertEqual(ProjectContext.defaultAgent(inConfig: #"defaultAgent: "opencode"  # fast one"#), "opencode")
+        XCTAssertEqual(ProjectContext.defaultAgent(inConfig: "defaultAgent: 'claude'"), "claude")
+    }
+
+    func testNestedOrMissingDefaultAgentIsIgnored() {
+        XCTAssertNil(ProjectContext.defaultAgent(inConfig: "agents:\n  x:\n    defaultAgent: codex"))
+        XCTAssertNil(ProjectContext.defaultAgent(inConfig: "defaultAgent:"))
+        XCTAssertNil(ProjectContext.defaultAgent(inConfig: ""))
+    }
+}
@@ -0,0 +1,116 @@
+import XCTest
+@testable import PPG_CLI
+
+final class QuickSwitcherTests: XCTestCase {
+
+    private let now = Date(timeIntervalSince1970: 1_700_000_000)
+
+    private func agent(_ id: String, name: String = "claude", type: String = "claude", status: AgentStatus = .running) -> AgentModel {
+        AgentModel(id: id, name: name, agentType: type, status: status, tmuxTarget: "s:1", prompt: "x", startedAt: "t")
+    }
+
+    private func worktree(_ id: String, name: String, agents: [AgentModel] = []) -> WorktreeModel {
+        WorktreeModel(id: id, name: name, path: "/tmp/\(name)", branch: "ppg/\(name)", status: "active", tmuxWindow: "s:1", agents: agents)
+    }
+
+    // MARK: - FuzzyMatcher
+
+    func testSubsequenceMatchesAndOthersDont() {
+        XCTAssertNotNil(FuzzyMatcher.score("fxlg", in: "fix-login"))
+        XCTAssertNil(FuzzyMatcher.score("lgf", in: "fix-login"))
+        XCTAssertEqual(FuzzyMatcher.score("", in: "anything"), 0)
+    }
+
+    func testMatchingIsCaseInsensitive() {
+        XCTAssertNotNil(FuzzyMatcher.score("FIX", in: "fix-login"))
+    }
+
+    func testWordStartsOutrankMidWordMatches() {
+        let wordStarts = FuzzyMatcher.score("fl", in: "fix-login")!
+        let midWord = FuzzyMatcher.score("fl", in: "buffalo")!
+        XCTAssertGreaterThan(wordStarts, midWord)
+    }
+
+    func testConsecutiveRunsOutrankScatteredMatches() {
+        let run = FuzzyMatcher.score("log", in: "xx-login")!
+        let scattered = FuzzyMatcher.score("log", in: "xx-lxoxg")!
+        XCTAssertGreaterThan(run, scattered)
+    }
+
+    // MARK: - items
+
+    func testItemsListWorktreesFollowedByTheirAgents() {
+        let items = QuickSwitcherItem.items(projectWorktrees: [
+            (root: "/p/app", worktrees: [worktree("wt-1", name: "auth", agents: [agent("ag-1", status: .failed)])]),
+        ])
+        XCTAssertEqual(items.map(\.id), ["wt-1", "ag-1"])
+        XCTAssertEqual(items[1].kind, .agent(.failed))
+        XCTAssertEqual(items[1].subtitle, "auth · claude · failed")
+        XCTAssertTrue(items[1].searchText.contains("ppg/auth"))
+    }
+
+    func testProjectNameAppearsOnlyWithSeveralProjects() {
+        let single = QuickSwitcherItem.items(projectWorktrees: [(root: "/p/app", worktrees: [worktree("wt-1", name: "auth")])])
+        XCTAssertEqual(single[0].subtitle, "ppg/auth")
+
+        let several = QuickSwitcherItem.items(projectWorktrees: [
+            (root: "/p/app", worktrees: [worktree("wt-1", name: "auth")]),
+            (root: "/p/site", worktrees: []),
+        ])
+        XCTAssertEqual(several[0].subtitle, "app · ppg/auth")
+    }
+
+    // MARK: - rank
+
+    func testRankFiltersToMatches() {
+        let items = QuickSwitcherItem.items(projectWorktrees: [(root: "/p/app", worktrees: [
+            worktree("wt-1", name: "auth", agents: [agent("ag-1", type: "codex")]),
+            worktree("wt-2", name: "billing"),
+        ])])
+        XCTAssertEqual(QuickSwitcherItem.rank(items, query: "bill", frecency: { _ in 0 }).map(\.id), ["wt-2"])
+        XCTAssertEqual(QuickSwitcherItem.rank(items, query: "codex", frecency: { _ in 0 }).map(\.id), ["ag-1"])
+    }
+
+    func testEmptyQueryOrdersByFrecencyThenSidebarOrder() {
+        let items = QuickSwitcherItem.items(projectWorktrees: [(root: "/p/app", worktrees: [
+            worktree("wt-1", name: "auth"), worktree("wt-2", name: "billing"), worktree("wt-3", name: "search"),
+        ])])
+        let ranked = QuickSwitcherItem.rank(items, query: "", frecency: { $0 == "wt-3" ? 100 : 0 })
+        XCTAssertEqual(ranked.map(\.id), ["wt-3", "wt-1", "wt-2"])
+    }
+
+    func testFrecencyBreaksTiesBetweenEqualMatches() {
+        let items = QuickSwitcherItem.items(projectWorktrees: [(root: "/p/app", worktrees: [
+            worktree("wt-1", name: "auth", agents: [agent("ag-1")]),
+            worktree("wt-2", name: "auth2", agents: [agent("ag-2")]),
+        ])])
+        let ranked = QuickSwitcherItem.rank(items, query: "claude", frecency: { $0 == "ag-2" ? 100 : 0 })
+        XCTAssertEqual(ranked.prefix(2).map(\.id), ["ag-2", "ag-1"])
+    }
+
+    // MARK: - QuickSwitcherFrecency
+
+    private func makeFrecency() -> QuickSwitcherFrecency {
+        let suite = "QuickSwitcherTests-\(UUID().uuidString)"
+        let defaults = UserDefaults(suiteName: suite)!
+        addTeardownBlock { defaults.removePersistentDomain(forName: suite) }
+        return QuickSwitcherFrecency(defaults: defaults)
+    }
+
+    func testRecentJumpsWeighMoreThanOldOnes() {
+        let frecency = makeFrecency()
+        frecency.recordVisit("recent", at: now.addingTimeInterval(-60))
+        frecency.recordVisit("old", at: now.addingTimeInterval(-60 * 24 * 3600))
+        XCTAssertEqual(frecency.score(for: "recent", now: now), 100)
+        XCTAssertEqual(frecency.score(for: "old", now: now), 10)
+        XCTAssertEqual(frecency.score(for: "never", now: now), 0)
+    }
+
+    func testVisitsPerItemAreCapped() {
+        let frecency = makeFrecency()
+        for _ in 0..<(QuickSwitcherFrecency.maxVisits + 5) {
+            frecency.recordVisit("busy", at: now)
+        }
+        XCTAssertEqual(frecency.score(for: "busy", now: now), Double(QuickSwitcherFrecency.maxVisits) * 100)
+    }
+}
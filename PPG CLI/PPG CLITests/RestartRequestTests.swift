@@ -0,0 +1,68 @@
+import XCTest
+@testable import PPG_CLI
+
+final class RestartRequestTests: XCTestCase {
+
+    func testPlainRestartKeepsPromptAndType() {
+        XCTAssertEqual(RestartRequest().arguments(agentId: "ag-1"), "restart 'ag-1' --json")
+    }
+
+    func testEditedPromptAndTypeArePassedEscaped() {
+        let request = RestartRequest(prompt: "don't touch the migrations", agent: "codex")
+        XCTAssertEqual(
+            request.arguments(agentId: "ag-1"),
+            "restart 'ag-1' --prompt 'don'\\''t touch the migrations' --agent 'codex' --json"
+        )
+    }
+
+    func testEditedPromptIsTrimmed() {
+        XCTAssertEqual(RestartRequest.edited(prompt: "  fix it\n\n", agent: nil), RestartRequest(prompt: "fix it", agent: nil))
+    }
+
+    func testBlankPromptIsRejected() {
+        XCTAssertNil(RestartRequest.edited(prompt: " \n\t", agent: "codex"))
+    }
+
+    func testNewAgentIdIsReadFromJSONOutput() {
+        XCTAssertEqual(RestartRequest.newAgentId(fromJSON: #"{"oldAgent":{"id":"ag-1"},"newAgent":{"id":"ag-2"}}"#), "ag-2")
+        XCTAssertNil(RestartRequest.newAgentId(fromJSON: "Restarted ag-1"))
+    }
+
+    func testResponseKeepsTheNewWindowAndSession() {
+        let output = #"{"success":true,"oldAgentId":"ag-1","newAgent":{"id":"ag-2","tmuxTarget":"ppg-app:3","sessionId":"s-9","worktreeId":"wt-1"}}"#
+        XCTAssertEqual(RestartRequest.response(fromJSON: output),
+                       RestartRequest.Response(newAgentId: "ag-2", tmuxTarget: "ppg-app:3", sessionId: "s-9", worktreeId: "wt-1"))
+    }
+
+    // MARK: - BatchRestart
+
+    func testOnlyExitedAgentsAreRestartable() {
+        let agents = AgentStatus.allCases.map {
+            AgentModel(id: "ag-\($0.rawValue)", name: "", agentType: "claude", status: $0, tmuxTarget: "", prompt: "", startedAt: "")
+        }
+        XCTAssertEqual(BatchRestart.restartable(agents).map(\.status), [.completed, .failed, .killed, .lost])
+    }
+
+    func testManifestAgentsThatStoppedAreRestartable() {
+        let agents = [("ag-1", "running", nil), ("ag-2", "idle", nil), ("ag-3", "exited", 0), ("ag-4", "exited", 1), ("ag-5", "gone", nil)].map {
+            AgentModel(from: AgentEntryModel(id: $0.0, name: "", agentType: "claude", status: $0.1, tmuxTarget: "", prompt: "",
+                                             startedAt: "", completedAt: nil, exitCode: $0.2, error: nil, sessionId: nil))
+        }
+        XCTAssertEqual(BatchRestart.restartable(agents).map(\.id), ["ag-2", "ag-3", "ag-4", "ag-5"])
+    }
+
+    func testSummaryCountsFailures() {
+        var batch = BatchRestart(total: 4)
+        for _ in 0..<3 { batch.recordSuccess() }
+        XCTAssertFalse(batch.isFinished)
+        batch.recordFailure(name: "claude-2", CommandFailure(args: "restart ag-2 --json", result: PPGService.CommandResult(exitCode: 1, stdout: "", stderr: "boom")))
+        XCTAssertTrue(batch.isFinished)
+        XCTAssertEqual(batch.summary, "Restarted 3 of 4 agents — 1 failed")
+    }
+
+    func testSummaryWithoutFailures() {
+        var batch = BatchRestart(total: 1)
+        batch.recordSuccess()
+        XCTAssertEqual(batch.summary, "Restarted 1 agent")
+    }
+}
@@ -0,0 +1,85 @@
+import XCTest
+@testable import PPG_CLI
+
+@MainActor
+final class SettingsBundleTests: XCTestCase {
+
+    private func data(_ json: String) -> Data {
+        json.data(using: .utf8)!
+    }
+
+    // MARK: - Decoding
+
+    func testRejectsNonJSON() {
+        XCTAssertThrowsError(try SettingsBundle.decode(data("not json"))) { error in
+            XCTAssertTrue(error.localizedDescription.contains("JSON"))
+        }
+    }
+
+    func testRejectsMissingVersion() {
+        XCTAssertThrowsError(try SettingsBundle.decode(data(#"{"settings": {}}"#))) { error in
+            XCTAssertTrue(error.localizedDescription.contains("version"))
+        }
+    }
+
+    func testRejectsBundleFromNewerVersion() {
+        let json = #"{"version": \#(SettingsBundle.formatVersion + 1), "settings": {}}"#
+        XCTAssertThrowsError(try SettingsBundle.decode(data(json))) { error in
+            XCTAssertTrue(error.localizedDescription.contains("newer version"))
+        }
+    }
+
+    func testRejectsMalformedShortcut() {
+        let json = #"{"version": 1, "settings": {}, "shortcuts": {"refresh": {"key": ""}}}"#
+        XCTAssertThrowsError(try SettingsBundle.decode(data(json))) { error in
+            XCTAssertTrue(error.localizedDescription.contains("shortcuts.refresh"))
+        }
+    }
+
+    func testRoundTripKeepsUnknownTopLevelFields() throws {
+        let json = #"{"version": 1, "settings": {"shell": "/bin/bash"}, "profiles": [{"name": "work"}]}"#
+        let bundle = try SettingsBundle.decode(data(json))
+        let reread = try SettingsBundle.decode(bundle.encoded())
+        XCTAssertEqual(reread.settings["shell"] as? String, "/bin/bash")
+        XCTAssertNotNil(reread.extraFields["profiles"])
+    }
+
+    // MARK: - Import Plan
+
+    func testPlanRejectsOutOfRangeValue() {
+        let bundle = SettingsBundle(settings: ["maxVisiblePanes": AppSettingsManager.maxVisiblePanesRange.upperBound + 1], shortcuts: [:])
+        XCTAssertThrowsError(try bundle.importPlan()) { error in
+            XCTAssertTrue(error.localizedDescription.contains("settings.maxVisiblePanes"))
+        }
+    }
+
+    func testPlanSetsAsideUnknownSettingsAndShortcuts() throws {
+        let bundle = SettingsBundle(
+            settings: ["historyLimit": 1000, "futureOption": true],
+            shortcuts: ["futureAction": StoredBinding(keyEquivalent: "j", modifiers: NSEvent.ModifierFlags.command.rawValue)]
+        )
+        let plan = try bundle.importPlan()
+        XCTAssertEqual(plan.settings[.historyLimit] as? Int, 1000)
+        XCTAssertEqual((plan.preservedFields["settings"] as? [String: Any])?["futureOption"] as? Bool, true)
+        XCTAssertNotNil((plan.preservedFields["shortcuts"] as? [String: Any])?["futureAction"])
+    }
+
+    func testPlanRejectsDuplicateShortcuts() {
+        // ⌘R is Refresh's default
+        let bundle = SettingsBundle(
+            settings: [:],
+            shortcuts: ["closeEntry": StoredBinding(keyEquivalent: "r", modifiers: NSEvent.ModifierFlags.command.rawValue)]
+        )
+        XCTAssertThrowsError(try bundle.importPlan())
+    }
+
+    func testAppearanceModeIsNormalized() {
+        XCTAssertEqual(SettingsBundle.validatedValue("dark", for: .appearanceMode) as? AppearanceMode, .dark)
+        XCTAssertNil(SettingsBundle.validatedValue("sepia", for: .appearanceMode))
+    }
+
+    func testAgentCommandsRejectBlankCommands() {
+        XCTAssertNotNil(SettingsBundle.validatedValue(["claude": "claude --model sonnet"], for: .agentCommands))
+        XCTAssertNil(SettingsBundle.validatedValue(["codex": "  "], for: .agentCommands))
+    }
+}
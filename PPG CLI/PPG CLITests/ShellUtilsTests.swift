@@ -13,6 +13,25 @@ final class ShellUtilsTests: XCTestCase {
         XCTAssertEqual(shellEscape("it's"), "'it'\\''s'")
     }
 
+    func testShellEscapeKeepsMetacharactersInsideQuotes() {
+        XCTAssertEqual(shellEscape("fix;rm -rf $HOME `x`"), "'fix;rm -rf $HOME `x`'")
+        XCTAssertEqual(shellEscape(""), "''")
+    }
+
+    func testShellEscapeUsesANSIQuotingForControlCharacters() {
+        XCTAssertEqual(shellEscape("a\nb\tc"), "$'a\\nb\\tc'")
+        XCTAssertEqual(shellEscape("it's\n"), "$'it\\'s\\n'")
+        XCTAssertEqual(shellEscape("back\\slash\u{1B}"), "$'back\\\\slash\\x1b'")
+    }
+
+    func testShellEscapeDropsNUL() {
+        XCTAssertEqual(shellEscape("a\0b"), "'ab'")
+    }
+
+    func testShellEscapeKeepsUnicode() {
+        XCTAssertEqual(shellEscape("修正-ü 🚀"), "'修正-ü 🚀'")
+    }
+
     // MARK: - shellProfileScript
 
     func testZshProfileScript() {
@@ -43,4 +62,150 @@ final class ShellUtilsTests: XCTestCase {
         let script = shellProfileScript(for: "/usr/local/bin/zsh")
         XCTAssertTrue(script.contains(".zshrc"), "should match on basename, not full path")
     }
+
+    // MARK: - tmuxAttachShellCommand
+
+    func testAttachCommandSelectsWindow() {
+        XCTAssertEqual(
+            tmuxAttachShellCommand(target: "ppg-abc:3"),
+            "tmux attach-session -t 'ppg-abc' \\; select-window -t 'ppg-abc:3'"
+        )
+    }
+
+    func testAttachCommandForBareSession() {
+        XCTAssertEqual(tmuxAttachShellCommand(target: "ppg"), "tmux attach-session -t 'ppg'")
+    }
+
+    // MARK: - TmuxTarget
+
+    func testParsesNumericWindowAndPane() {
+        XCTAssertEqual(TmuxTarget(parsing: "ppg-proj:2.1"), TmuxTarget(session: "ppg-proj", window: "2", pane: "1"))
+    }
+
+    func testParsesNamedWindowWithPane() {
+        let target = TmuxTarget(parsing: "ppg-proj:auth-fix.0")
+        XCTAssertEqual(target, TmuxTarget(session: "ppg-proj", window: "auth-fix", pane: "0"))
+        XCTAssertEqual(target.windowTarget, "ppg-proj:auth-fix")
+        XCTAssertEqual(target.paneTarget, "ppg-proj:auth-fix.0")
+    }
+
+    func testDottedWindowNameWithoutPaneIsKeptWhole() {
+        XCTAssertEqual(TmuxTarget(parsing: "ppg:v1.x"), TmuxTarget(session: "ppg", window: "v1.x"))
+        XCTAssertEqual(TmuxTarget(parsing: "ppg:v1.2.3"), TmuxTarget(session: "ppg", window: "v1.2", pane: "3"))
+    }
+
+    func testParsesWindowOnlyAndBareSession() {
+        XCTAssertEqual(TmuxTarget(parsing: "ppg:3"), TmuxTarget(session: "ppg", window: "3"))
+        XCTAssertEqual(TmuxTarget(parsing: "ppg:"), TmuxTarget(session: "ppg"))
+        XCTAssertEqual(TmuxTarget(parsing: "ppg"), TmuxTarget(session: "ppg"))
+    }
+
+    // MARK: - tmuxAttachPaneShellCommand
+
+    func testPaneAttachSelectsWindowThenPane() {
+        XCTAssertEqual(
+            tmuxAttachPaneShellCommand(target: "ppg-proj:2.1"),
+            "tmux attach-session -t 'ppg-proj' \\; select-window -t 'ppg-proj:2' \\; select-pane -t 'ppg-proj:2.1'"
+        )
+    }
+
+    func testPaneAttachCanTurnOffSynchronizePanes() {
+        XCTAssertEqual(
+            tmuxAttachPaneShellCommand(target: "ppg-proj:auth-fix.0", unsynchronizePanes: true),
+            "tmux attach-session -t 'ppg-proj' \\; select-window -t 'ppg-proj:auth-fix'"
+                + " \\; set-window-option -t 'ppg-proj:auth-fix' 'synchronize-panes' 'off'"
+                + " \\; select-pane -t 'ppg-proj:auth-fix.0'"
+        )
+    }
+
+    func testPaneAttachWithoutWindowFallsBackToSession() {
+        XCTAssertEqual(tmuxAttachPaneShellCommand(target: "ppg"), tmuxAttachShellCommand(target: "ppg"))
+    }
+
+    // MARK: - TmuxCommand
+
+    func testArgvSeparatesCommandsWithLoneSemicolon() {
+        let command = TmuxCommand("attach-session").option("-t", "ppg")
+            .then("select-window").option("-t", "ppg:fix;rm")
+        XCTAssertEqual(command.argv, ["tmux", "attach-session", "-t", "ppg", ";", "select-window", "-t", "ppg:fix;rm"])
+    }
+
+    func testTrailingSemicolonInDataIsEscaped() {
+        let command = TmuxCommand("select-window").option("-t", "ppg:fix;")
+        XCTAssertEqual(command.argv, ["tmux", "select-window", "-t", "ppg:fix\\;"])
+        XCTAssertEqual(command.shellString, "tmux select-window -t 'ppg:fix\\;'")
+    }
+
+    func testQuotesInNamesStayInsideOneWord() {
+        let command = TmuxCommand("new-window").option("-n", "it's \"quoted\"")
+        XCTAssertEqual(command.argv, ["tmux", "new-window", "-n", "it's \"quoted\""])
+        XCTAssertEqual(command.shellString, "tmux new-window -n 'it'\\''s \"quoted\"'")
+    }
+
+    func testLeadingDashValueStaysAnOptionValue() {
+        let command = TmuxCommand("kill-window").option("-t", "-t")
+        XCTAssertEqual(command.argv, ["tmux", "kill-window", "-t", "-t"])
+    }
+
+    func testLeadingDashPositionalEndsOptionsFirst() {
+        let command = TmuxCommand("send-keys").option("-t", "ppg:1").flag("-l").arguments("-t evil\n")
+        XCTAssertEqual(command.argv, ["tmux", "send-keys", "-t", "ppg:1", "-l", "--", "-t evil\n"])
+        XCTAssertEqual(command.shellString, "tmux send-keys -t 'ppg:1' -l -- $'-t evil\\n'")
+    }
+
+    func testPlainPositionalsHaveNoOptionTerminator() {
+        let command = TmuxCommand("set-option").option("-t", "ppg").arguments("mouse", "on")
+        XCTAssertEqual(command.argv, ["tmux", "set-option", "-t", "ppg", "mouse", "on"])
+    }
+
+    func testUnicodeNamesPassThrough() {
+        let command = TmuxCommand("select-window").option("-t", "ppg:修正")
+        XCTAssertEqual(command.argv.last, "ppg:修正")
+        XCTAssertEqual(command.shellString, "tmux select-window -t 'ppg:修正'")
+    }
+
+    func testAttachCommandQuotesAdversarialWindowName() {
+        XCTAssertEqual(
+            tmuxAttachShellCommand(target: "ppg:fix;rm"),
+            "tmux attach-session -t 'ppg' \\; select-window -t 'ppg:fix;rm'"
+        )
+    }
+
+    // MARK: - TmuxSnapshot
+
+    func testSnapshotParsesListWindowsOutput() {
+        let stdout = "ppg-app\t0\tzsh\nppg-app\t2\tauth-fix\nscratch\t1\tname\twith tab\n"
+        let snapshot = TmuxSnapshot.parse(exitCode: 0, stdout: stdout, stderr: "")
+        XCTAssertEqual(snapshot?.sessions, ["ppg-app", "scratch"])
+        XCTAssertEqual(snapshot?.windows["scratch"], [TmuxSnapshot.Window(index: "1", name: "name\twith tab")])
+    }
+
+    func testSnapshotMatchesWindowByIndexOrName() {
+        let snapshot = TmuxSnapshot.parse(exitCode: 0, stdout: "ppg-app\t2\tauth-fix\n", stderr: "")!
+        XCTAssertTrue(snapshot.contains(TmuxTarget(parsing: "ppg-app:2.1")))
+        XCTAssertTrue(snapshot.contains(TmuxTarget(parsing: "ppg-app:auth-fix.0")))
+        XCTAssertTrue(snapshot.contains(TmuxTarget(parsing: "ppg-app")))
+        XCTAssertFalse(snapshot.containsWindow(session: "ppg-app", window: "3"))
+        XCTAssertFalse(snapshot.contains(TmuxTarget(parsing: "ppg-other:2")))
+    }
+
+    func testNoServerRunningIsAnEmptySnapshot() {
+        let snapshot = TmuxSnapshot.parse(exitCode: 1, stdout: "", stderr: "no server running on /private/tmp/tmux-501/default\n")
+        XCTAssertEqual(snapshot, TmuxSnapshot(windows: [:]))
+        let stale = TmuxSnapshot.parse(exitCode: 1, stdout: "", stderr: "error connecting to /private/tmp/tmux-501/default (No such file or directory)\n")
+        XCTAssertEqual(stale?.sessions, [])
+    }
+
+    func testOtherFailuresAreUnknown() {
+        XCTAssertNil(TmuxSnapshot.parse(exitCode: 127, stdout: "", stderr: "zsh:1: command not found: tmux\n"))
+    }
+
+    func testEnablingMouseUsesLegacyNamesOnOldTmux() {
+        let legacy = TmuxFeatures(version: TmuxVersion(major: 1, minor: 9))
+        let command = TmuxCommand("has-session").option("-t", "ppg").thenEnablingMouse(target: "ppg", features: legacy)
+        XCTAssertEqual(command.argv.filter { $0.contains("mouse") }, ["mode-mouse", "mouse-select-pane", "mouse-resize-pane", "mouse-select-window"])
+
+        let current = TmuxCommand("has-session").thenEnablingMouse(features: TmuxFeatures(version: nil))
+        XCTAssertEqual(current.argv, ["tmux", "has-session", ";", "set-option", "mouse", "on"])
+    }
 }
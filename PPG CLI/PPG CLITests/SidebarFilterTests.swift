@@ -0,0 +1,100 @@
+import XCTest
+@testable import PPG_CLI
+
+final class SidebarFilterTests: XCTestCase {
+
+    private func agent(_ id: String, type: String = "claude", status: AgentStatus = .running) -> AgentModel {
+        AgentModel(id: id, name: id, agentType: type, status: status, tmuxTarget: "s:1", prompt: "", startedAt: "")
+    }
+
+    // MARK: - Matching
+
+    func testEmptyFilterMatchesEverything() {
+        let filter = SidebarFilter()
+        XCTAssertFalse(filter.isActive)
+        XCTAssertTrue(filter.matches(agent("ag-1", type: "codex", status: .lost)))
+    }
+
+    func testChipsOfOneKindWiden() {
+        var filter = SidebarFilter()
+        filter.toggle(agentType: "claude")
+        filter.toggle(agentType: "codex")
+        XCTAssertTrue(filter.matches(agent("ag-1", type: "claude")))
+        XCTAssertTrue(filter.matches(agent("ag-2", type: "codex")))
+        XCTAssertFalse(filter.matches(agent("ag-3", type: "opencode")))
+    }
+
+    func testTypeAndStatusNarrowEachOther() {
+        var filter = SidebarFilter()
+        filter.toggle(agentType: "claude")
+        filter.toggle(status: .failed)
+        XCTAssertTrue(filter.matches(agent("ag-1", type: "claude", status: .failed)))
+        XCTAssertFalse(filter.matches(agent("ag-2", type: "claude", status: .running)))
+        XCTAssertFalse(filter.matches(agent("ag-3", type: "codex", status: .failed)))
+    }
+
+    func testTogglingTwiceTurnsAChipOff() {
+        var filter = SidebarFilter()
+        filter.toggle(status: .failed)
+        filter.toggle(status: .failed)
+        XCTAssertEqual(filter, SidebarFilter())
+    }
+
+    // MARK: - Summary
+
+    func testSummaryListsTypesThenStatusesInChipOrder() {
+        var filter = SidebarFilter()
+        filter.toggle(status: .lost)
+        filter.toggle(agentType: "codex")
+        filter.toggle(status: .running)
+        filter.toggle(agentType: "claude")
+        XCTAssertEqual(filter.summary, "claude · codex · running · gone")
+    }
+
+    // MARK: - Manifest Statuses
+
+    private func manifestAgent(_ id: String, status: String, exitCode: Int? = nil) -> AgentModel {
+        AgentModel(from: AgentEntryModel(id: id, name: id, agentType: "claude", status: status, tmuxTarget: "s:1", prompt: "",
+                                         startedAt: "", completedAt: nil, exitCode: exitCode, error: nil, sessionId: nil))
+    }
+
+    func testEveryCLIStatusHasAChip() {
+        let agents = [manifestAgent("ag-1", status: "running"), manifestAgent("ag-2", status: "idle"),
+                      manifestAgent("ag-3", status: "exited", exitCode: 0), manifestAgent("ag-4", status: "exited", exitCode: 1),
+                      manifestAgent("ag-5", status: "gone")]
+        let expected: [AgentStatus: [String]] = [.running: ["ag-1"], .completed: ["ag-2", "ag-3"], .failed: ["ag-4"], .lost: ["ag-5"]]
+        for status in SidebarFilter.statusChips {
+            var filter = SidebarFilter()
+            filter.toggle(status: status)
+            XCTAssertEqual(agents.filter(filter.matches).map(\.id), expected[status], "chip \(status)")
+        }
+    }
+
+    func testFailedChipKeepsOnlyNonZeroExits() {
+        var filter = SidebarFilter()
+        filter.toggle(status: .failed)
+        XCTAssertTrue(filter.matches(manifestAgent("ag-1", status: "exited", exitCode: 2)))
+        XCTAssertFalse(filter.matches(manifestAgent("ag-2", status: "exited", exitCode: 0)))
+        XCTAssertFalse(filter.matches(manifestAgent("ag-3", status: "running")))
+    }
+
+    // MARK: - Agent Types
+
+    func testAgentTypesAreDistinctAndSorted() {
+        let worktrees = [
+            WorktreeModel(id: "wt-1", name: "a", path: "/tmp/a", branch: "ppg/a", status: "active",
+                          tmuxWindow: "s:1", agents: [agent("ag-1", type: "codex"), agent("ag-2")]),
+            WorktreeModel(id: "wt-2", name: "b", path: "/tmp/b", branch: "ppg/b", status: "active",
+                          tmuxWindow: "s:2", agents: [agent("ag-3"), agent("ag-4", type: "")]),
+        ]
+        XCTAssertEqual(SidebarFilter.agentTypes(in: worktrees), ["claude", "codex"])
+    }
+
+    func testTypesThatWentAwayAreDropped() {
+        var filter = SidebarFilter()
+        filter.toggle(agentType: "codex")
+        filter.toggle(agentType: "claude")
+        filter.keepAgentTypes(in: ["claude", "opencode"])
+        XCTAssertEqual(filter.agentTypes, ["claude"])
+    }
+}
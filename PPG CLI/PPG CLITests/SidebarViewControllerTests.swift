@@ -64,6 +64,12 @@ final class SidebarViewControllerTests: XCTestCase {
         XCTAssertEqual(agent.id, "ag-xyz")
     }
 
+    func testProjectIdIsStableAcrossLaunches() {
+        // Persisted as the last selection, so it can't depend on per-process hash seeds
+        let project = SidebarItem.project(ProjectContext(projectRoot: "/tmp/test-proj"))
+        XCTAssertEqual(project.id, "project:/tmp/test-proj")
+    }
+
     // MARK: - Status color
 
     func testStatusColorMapping() {
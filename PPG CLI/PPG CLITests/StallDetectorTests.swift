@@ -0,0 +1,72 @@
+import XCTest
+@testable import PPG_CLI
+
+final class StallDetectorTests: XCTestCase {
+
+    private let start = Date(timeIntervalSince1970: 1_700_000_000)
+    private let threshold: TimeInterval = 15 * 60
+
+    private func agent(_ id: String, status: AgentStatus = .running, target: String = "ppg-app:2.0") -> AgentModel {
+        AgentModel(id: id, name: "", agentType: "claude", status: status, tmuxTarget: target, prompt: "", startedAt: "")
+    }
+
+    private func activity(_ windows: [String: Date]) -> TmuxWindowActivity {
+        TmuxWindowActivity(lastOutput: windows)
+    }
+
+    // MARK: - Threshold
+
+    func testRunningAgentStallsOnceQuietForTheThreshold() {
+        var detector = StallDetector()
+        detector.recordOutput(agentId: "ag-1", at: start)
+        XCTAssertNil(detector.stalledSince(agentId: "ag-1", status: .running, threshold: threshold, now: start.addingTimeInterval(threshold - 1)))
+        XCTAssertEqual(detector.stalledSince(agentId: "ag-1", status: .running, threshold: threshold, now: start.addingTimeInterval(threshold)), start)
+    }
+
+    func testNewOutputClearsTheMarkerImmediately() {
+        var detector = StallDetector()
+        detector.recordOutput(agentId: "ag-1", at: start)
+        let later = start.addingTimeInterval(threshold + 60)
+        XCTAssertNotNil(detector.stalledSince(agentId: "ag-1", status: .running, threshold: threshold, now: later))
+
+        detector.recordOutput(agentId: "ag-1", at: later)
+        XCTAssertNil(detector.stalledSince(agentId: "ag-1", status: .running, threshold: threshold, now: later))
+    }
+
+    func testOlderOutputTimesAreIgnored() {
+        var detector = StallDetector()
+        detector.recordOutput(agentId: "ag-1", at: start)
+        detector.recordOutput(agentId: "ag-1", at: start.addingTimeInterval(-600))
+        XCTAssertEqual(detector.lastOutput["ag-1"], start)
+    }
+
+    func testOnlyRunningAgentsWithKnownOutputStall() {
+        var detector = StallDetector()
+        detector.recordOutput(agentId: "ag-1", at: start)
+        let later = start.addingTimeInterval(threshold * 2)
+        for status in AgentStatus.allCases where status != .running {
+            XCTAssertNil(detector.stalledSince(agentId: "ag-1", status: status, threshold: threshold, now: later), "\(status)")
+        }
+        XCTAssertNil(detector.stalledSince(agentId: "ag-2", status: .running, threshold: threshold, now: later))
+    }
+
+    // MARK: - tmux activity
+
+    func testRecordTakesEachAgentsWindowAndForgetsRemovedAgents() {
+        var detector = StallDetector()
+        detector.recordOutput(agentId: "gone", at: start)
+        let windowTime = start.addingTimeInterval(120)
+        detector.record(activity(["ppg-app:2": windowTime]), agents: [agent("ag-1"), agent("ag-2", target: "ppg-app:9.0")])
+        XCTAssertEqual(detector.lastOutput, ["ag-1": windowTime])
+    }
+
+    func testWindowActivityParsesIndexAndNameKeys() {
+        let stdout = "ppg-app\t2\t1700000000\tauth-fix\nppg-app\t3\tnot-a-time\tbroken\n"
+        let parsed = TmuxWindowActivity.parse(exitCode: 0, stdout: stdout)
+        XCTAssertEqual(parsed?.lastOutput(of: TmuxTarget(parsing: "ppg-app:2.1")), start)
+        XCTAssertEqual(parsed?.lastOutput(of: TmuxTarget(parsing: "ppg-app:auth-fix")), start)
+        XCTAssertNil(parsed?.lastOutput(of: TmuxTarget(parsing: "ppg-app:3")))
+        XCTAssertNil(parsed?.lastOutput(of: TmuxTarget(parsing: "ppg-app")))
+        XCTAssertNil(TmuxWindowActivity.parse(exitCode: 1, stdout: ""))
+    }
+}
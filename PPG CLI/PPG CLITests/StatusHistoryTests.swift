@@ -0,0 +1,166 @@
+import XCTest
+@testable import PPG_CLI
+
+@MainActor
+final class StatusHistoryTests: XCTestCase {
+    private var tempDir: URL!
+
+    override func setUp() {
+        super.setUp()
+        tempDir = FileManager.default.temporaryDirectory.appendingPathComponent(UUID().uuidString)
+        try? FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+    }
+
+    override func tearDown() {
+        try? FileManager.default.removeItem(at: tempDir)
+        super.tearDown()
+    }
+
+    private func worktree(_ id: String, _ agents: [(String, AgentStatus)]) -> WorktreeModel {
+        WorktreeModel(id: id, name: id, path: "/p", branch: "b", status: "active", tmuxWindow: "s:1", agents: agents.map {
+            AgentModel(id: $0.0, name: $0.0, agentType: "claude", status: $0.1, tmuxTarget: "s:1", prompt: "", startedAt: "")
+        })
+    }
+
+    func testFirstSightingIsNotATransition() {
+        let history = StatusHistory(path: nil)
+        history.record([worktree("wt-1", [("ag-1", .running)])])
+        XCTAssertTrue(history.transitions.isEmpty)
+    }
+
+    func testStatusChangeIsRecorded() {
+        let history = StatusHistory(path: nil)
+        let at = Date(timeIntervalSince1970: 1_700_000_000)
+        history.record([worktree("wt-1", [("ag-1", .running), ("ag-2", .running)])])
+        history.record([worktree("wt-1", [("ag-1", .failed), ("ag-2", .running)])], at: at)
+        XCTAssertEqual(history.transitions, [
+            StatusTransition(agentId: "ag-1", worktreeId: "wt-1", from: "running", to: "failed", exitCode: nil, at: at),
+        ])
+        XCTAssertEqual(history.transitions(forWorktree: "wt-1").count, 1)
+        XCTAssertTrue(history.transitions(forWorktree: "wt-2").isEmpty)
+    }
+
+    func testRecentFailuresFiltersByStatusAndDate() {
+        let history = StatusHistory(path: nil)
+        let early = Date(timeIntervalSince1970: 1_000)
+        let late = Date(timeIntervalSince1970: 2_000)
+        history.record([worktree("wt-1", [("ag-1", .running), ("ag-2", .running)])])
+        history.record([worktree("wt-1", [("ag-1", .failed), ("ag-2", .running)])], at: early)
+        history.record([worktree("wt-1", [("ag-1", .failed), ("ag-2", .completed)])], at: late)
+        XCTAssertEqual(history.recentFailures(since: early).map(\.agentId), ["ag-1"])
+        XCTAssertTrue(history.recentFailures(since: late).isEmpty)
+    }
+
+    private func manifestWorktree(_ agents: [(String, String, Int?)]) -> WorktreeModel {
+        WorktreeModel(id: "wt-1", name: "wt-1", path: "/p", branch: "b", status: "active", tmuxWindow: "s:1", agents: agents.map {
+            AgentModel(from: AgentEntryModel(id: $0.0, name: $0.0, agentType: "claude", status: $0.1, tmuxTarget: "s:1", prompt: "",
+                                             startedAt: "", completedAt: nil, exitCode: $0.2, error: nil, sessionId: nil))
+        })
+    }
+
+    func testManifestExitWithNonZeroCodeIsAFailure() {
+        let history = StatusHistory(path: nil)
+        let at = Date(timeIntervalSince1970: 1_000)
+        history.record([manifestWorktree([("ag-1", "running", nil), ("ag-2", "running", nil), ("ag-3", "running", nil)])])
+        history.record([manifestWorktree([("ag-1", "exited", 1), ("ag-2", "exited", 0), ("ag-3", "gone", nil)])], at: at)
+        XCTAssertEqual(history.transitions.map(\.to), ["exited", "exited", "gone"])
+        XCTAssertEqual(history.transitions.first?.exitCode, 1)
+        XCTAssertEqual(history.recentFailures(since: at).map(\.agentId), ["ag-1"])
+    }
+
+    func testTransitionWithoutExitCodeStillDecodes() throws {
+        let json = #"{"transitions":[{"agentId":"ag-1","worktreeId":"wt-1","from":"running","to":"exited","at":"2024-01-01T00:00:00Z"}],"lastStatuses":{}}"#
+        let stored = try XCTUnwrap(StatusHistory.decode(Data(json.utf8)))
+        XCTAssertNil(stored.transitions.first?.exitCode)
+        XCTAssertEqual(stored.transitions.first?.toStatus, .completed)
+    }
+
+    func testEmptyRefreshKeepsLastStatuses() {
+        let history = StatusHistory(path: nil)
+        history.record([worktree("wt-1", [("ag-1", .running)])])
+        history.record([])
+        history.record([worktree("wt-1", [("ag-1", .completed)])])
+        XCTAssertEqual(history.transitions.map(\.to), ["completed"])
+    }
+
+    func testStoredRoundTrip() throws {
+        let at = Date(timeIntervalSince1970: 1_700_000_000)
+        let stored = StatusHistory.Stored(transitions: [
+            StatusTransition(agentId: "ag-1", worktreeId: "wt-1", from: "running", to: "failed", exitCode: 1, at: at),
+        ], lastStatuses: ["ag-1": "failed"])
+        XCTAssertEqual(StatusHistory.decode(try StatusHistory.encode(stored)), stored)
+    }
+
+    // MARK: - Spawns
+
+    private func spawn(_ agentId: String, replacing oldId: String = "ag-0", at: Date = Date(timeIntervalSince1970: 1_700_000_000)) -> SpawnRecord {
+        let old = AgentModel(id: oldId, name: "", agentType: "claude", status: .failed, tmuxTarget: "s:1", prompt: "Fix login", startedAt: "")
+        let response = RestartRequest.Response(newAgentId: agentId, tmuxTarget: "s:2", sessionId: "sid-1", worktreeId: "wt-1")
+        return SpawnRecord(restarting: old, worktreeId: "wt-1", request: RestartRequest(prompt: "Fix login, again", agent: "codex"),
+                           args: "restart ag-0 --json", response: response, at: at)
+    }
+
+    func testSpawnKeepsRequestAndResponse() {
+        let record = spawn("ag-1")
+        XCTAssertEqual(record.replacedAgentId, "ag-0")
+        XCTAssertEqual(record.requestedPrompt, "Fix login, again")
+        XCTAssertEqual(record.requestedAgentType, "codex")
+        XCTAssertEqual(record.command, "ppg restart ag-0 --json")
+        XCTAssertEqual(record.tmuxTarget, "s:2")
+        XCTAssertEqual(record.sessionId, "sid-1")
+        XCTAssertEqual(record.batchSize, 1)
+    }
+
+    func testSpawnIsFoundByNewAgent() {
+        let history = StatusHistory(path: nil)
+        history.recordSpawn(spawn("ag-1"))
+        XCTAssertEqual(history.spawn(forAgent: "ag-1")?.replacedAgentId, "ag-0")
+        XCTAssertNil(history.spawn(forAgent: "ag-0"))
+    }
+
+    func testSpawnsAreCapped() {
+        let history = StatusHistory(path: nil)
+        for index in 0...StatusHistory.capacity {
+            history.recordSpawn(spawn("ag-\(index)"))
+        }
+        XCTAssertEqual(history.spawns.count, StatusHistory.capacity)
+        XCTAssertNil(history.spawn(forAgent: "ag-0"))
+    }
+
+    func testSpawnsRoundTrip() throws {
+        let stored = StatusHistory.Stored(transitions: [], lastStatuses: ["ag-1": "running"], spawns: [spawn("ag-1")])
+        XCTAssertEqual(StatusHistory.decode(try StatusHistory.encode(stored)), stored)
+    }
+
+    func testFileWithoutSpawnsStillLoads() {
+        let json = #"{"transitions":[{"agentId":"ag-1","worktreeId":"wt-1","from":"running","to":"failed","at":"2023-11-14T22:13:20Z"}],"lastStatuses":{"ag-1":"failed"}}"#
+        let stored = StatusHistory.decode(Data(json.utf8))
+        XCTAssertEqual(stored?.transitions.map(\.to), ["failed"])
+        XCTAssertNil(stored?.spawns)
+    }
+
+    // MARK: - Persistence
+
+    func testHistorySurvivesReload() {
+        let path = tempDir.appendingPathComponent("status-history.json").path
+        let history = StatusHistory(path: path)
+        history.record([worktree("wt-1", [("ag-1", .running)])])
+        history.record([worktree("wt-1", [("ag-1", .killed)])])
+        history.flush()
+
+        // A change made while the app was closed shows up on the next refresh
+        let reloaded = StatusHistory(path: path)
+        XCTAssertEqual(reloaded.transitions.map(\.to), ["killed"])
+        reloaded.record([worktree("wt-1", [("ag-1", .lost)])])
+        XCTAssertEqual(reloaded.transitions.map(\.to), ["killed", "lost"])
+    }
+
+    func testSpawnsSurviveReload() {
+        let path = tempDir.appendingPathComponent("status-history.json").path
+        let history = StatusHistory(path: path)
+        history.recordSpawn(spawn("ag-1"))
+        history.flush()
+
+        XCTAssertEqual(StatusHistory(path: path).spawns, [spawn("ag-1")])
+    }
+}
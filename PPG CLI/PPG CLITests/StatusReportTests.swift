@@ -0,0 +1,176 @@
+import XCTest
+@testable import PPG_CLI
+
+final class StatusReportTests: XCTestCase {
+
+    private let generatedAt = parseISO8601("2026-10-16T12:00:00Z")!
+
+    private let fixtureManifest = """
+    {
+      "version": 1,
+      "projectRoot": "/Users/dev/app",
+      "sessionName": "ppg-app",
+      "createdAt": "2026-10-01T08:00:00.000Z",
+      "updatedAt": "2026-10-16T11:30:00.000Z",
+      "worktrees": {
+        "wt-2": {
+          "id": "wt-2", "name": "search", "path": "/Users/dev/app/.worktrees/wt-2",
+          "branch": "ppg/search", "baseBranch": "main", "status": "active", "tmuxWindow": "ppg-app:2",
+          "createdAt": "2026-10-16T09:00:00.000Z", "agents": {}
+        },
+        "wt-1": {
+          "id": "wt-1", "name": "auth", "path": "/Users/dev/app/.worktrees/wt-1",
+          "branch": "ppg/auth", "baseBranch": "main", "status": "merged", "tmuxWindow": "ppg-app:1",
+          "createdAt": "2026-10-16T08:00:00.000Z", "prUrl": "https://github.com/acme/app/pull/42",
+          "agents": {
+            "ag-2": {
+              "id": "ag-2", "name": "claude-2", "agentType": "codex", "status": "running",
+              "tmuxTarget": "ppg-app:1.1", "prompt": "Add tests", "startedAt": "2026-10-16T11:30:00.000Z"
+            },
+            "ag-1": {
+              "id": "ag-1", "name": "claude-1", "agentType": "claude", "status": "failed",
+              "tmuxTarget": "ppg-app:1.0", "prompt": "Fix login", "startedAt": "2026-10-16T08:05:00.000Z",
+              "completedAt": "2026-10-16T09:17:00.000Z", "exitCode": 1
+            },
+            "ag-3": {
+              "id": "ag-3", "name": "claude-3", "agentType": "claude", "status": "idle",
+              "tmuxTarget": "ppg-app:1.2", "prompt": "Update docs", "startedAt": "2026-10-16T11:45:00.000Z"
+            }
+          }
+        }
+      }
+    }
+    """
+
+    private let goldenMarkdown = """
+    # PPG Status Report
+
+    Generated 2026-10-16T12:00:00Z
+
+    ## app
+
+    - **Root:** /Users/dev/app
+    - **tmux session:** ppg-app
+    - **Commits today:** 7
+
+    ### auth
+
+    - **Branch:** ppg/auth
+    - **Status:** merged
+    - **PR:** https://github.com/acme/app/pull/42
+
+    | Agent | Type | Status | Exit code | Runtime |
+    | --- | --- | --- | --- | --- |
+    | claude-1 | claude | failed | 1 | 1h 12m |
+    | claude-2 | codex | running | — | 30m |
+    | claude-3 | claude | idle | — | — |
+
+    ### search
+
+    - **Branch:** ppg/search
+    - **Status:** active
+    - **PR:** none
+
+    No agents.
+
+    """
+
+    private func fixtureReport() throws -> StatusReport {
+        let manifest = try JSONDecoder().decode(ManifestModel.self, from: Data(fixtureManifest.utf8))
+        return StatusReport(projects: [(manifest: manifest, commitsToday: 7)], generatedAt: generatedAt)
+    }
+
+    // MARK: - Markdown
+
+    func testMarkdownMatchesGolden() throws {
+        XCTAssertEqual(try fixtureReport().markdown(), goldenMarkdown)
+    }
+
+    func testProjectWithoutWorktreesSaysSo() {
+        let manifest = ManifestModel(version: 1, projectRoot: "/p/empty", sessionName: "ppg-empty", worktrees: [:], createdAt: "", updatedAt: "")
+        let report = StatusReport(projects: [(manifest: manifest, commitsToday: 0)], generatedAt: generatedAt)
+        XCTAssertTrue(report.markdown().hasSuffix("- **Commits today:** 0\n\nNo worktrees.\n"))
+    }
+
+    func testTableCellsEscapePipes() {
+        let agent = AgentEntryModel(id: "ag-1", name: "a|b", agentType: "claude", status: "completed", tmuxTarget: "", prompt: "",
+                                    startedAt: "", completedAt: nil, exitCode: 0, error: nil, sessionId: nil)
+        let worktree = WorktreeEntryModel(id: "wt-1", name: "x", path: "", branch: "b", baseBranch: "main", status: "active",
+                                          tmuxWindow: "", agents: ["ag-1": agent], createdAt: "", mergedAt: nil)
+        let manifest = ManifestModel(version: 1, projectRoot: "/p/app", sessionName: "s", worktrees: ["wt-1": worktree], createdAt: "", updatedAt: "")
+        let markdown = StatusReport(projects: [(manifest: manifest, commitsToday: 0)], generatedAt: generatedAt).markdown()
+        XCTAssertTrue(markdown.contains("| a\\|b | claude | completed | 0 | — |"), markdown)
+    }
+
+    func testTiedTimestampsAreOrderedByID() {
+        let agents = ["ag-c", "ag-a", "ag-b"].map {
+            AgentEntryModel(id: $0, name: $0, agentType: "claude", status: "running", tmuxTarget: "", prompt: "",
+                            startedAt: "2026-10-16T10:00:00.000Z", completedAt: nil, exitCode: nil, error: nil, sessionId: nil)
+        }
+        let worktrees = ["wt-b", "wt-a"].map {
+            WorktreeEntryModel(id: $0, name: $0, path: "", branch: "b", baseBranch: "main", status: "active", tmuxWindow: "",
+                               agents: Dictionary(uniqueKeysWithValues: agents.map { ($0.id, $0) }),
+                               createdAt: "2026-10-16T09:00:00.000Z", mergedAt: nil)
+        }
+        let manifest = ManifestModel(version: 1, projectRoot: "/p/app", sessionName: "s",
+                                     worktrees: Dictionary(uniqueKeysWithValues: worktrees.map { ($0.id, $0) }), createdAt: "", updatedAt: "")
+        let report = StatusReport(projects: [(manifest: manifest, commitsToday: 0)], generatedAt: generatedAt)
+
+        let project = report.projects[0]
+        XCTAssertEqual(project.worktrees.map(\.id), ["wt-a", "wt-b"])
+        XCTAssertEqual(project.worktrees[0].agents.map(\.id), ["ag-a", "ag-b", "ag-c"])
+    }
+
+    // MARK: - JSON
+
+    func testJSONCarriesTheSameFacts() throws {
+        let json = try fixtureReport().json()
+        let object = try XCTUnwrap(JSONSerialization.jsonObject(with: Data(json.utf8)) as? [String: Any])
+        XCTAssertEqual(object["generatedAt"] as? String, "2026-10-16T12:00:00Z")
+
+        let project = try XCTUnwrap((object["projects"] as? [[String: Any]])?.first)
+        XCTAssertEqual(project["commitsToday"] as? Int, 7)
+        let worktrees = try XCTUnwrap(project["worktrees"] as? [[String: Any]])
+        XCTAssertEqual(worktrees.map { $0["name"] as? String }, ["auth", "search"])
+        XCTAssertEqual(worktrees[0]["prUrl"] as? String, "https://github.com/acme/app/pull/42")
+
+        let agents = try XCTUnwrap(worktrees[0]["agents"] as? [[String: Any]])
+        XCTAssertEqual(agents.map { $0["runtimeSeconds"] as? Int }, [72 * 60, 30 * 60, nil])
+        XCTAssertEqual(agents[0]["exitCode"] as? Int, 1)
+    }
+
+    // MARK: - Spawns
+
+    private func spawnedReport() throws -> StatusReport {
+        let manifest = try JSONDecoder().decode(ManifestModel.self, from: Data(fixtureManifest.utf8))
+        let old = AgentModel(id: "ag-0", name: "", agentType: "codex", status: .failed, tmuxTarget: "", prompt: "", startedAt: "")
+        let spawn = SpawnRecord(restarting: old, worktreeId: "wt-1", request: RestartRequest(), args: "restart ag-0 --json",
+                                response: RestartRequest.Response(newAgentId: "ag-2", tmuxTarget: "ppg-app:1.1", sessionId: "s-1", worktreeId: "wt-1"),
+                                at: parseISO8601("2026-10-16T11:30:00Z")!)
+        return StatusReport(projects: [(manifest: manifest, commitsToday: 7)], spawns: ["ag-2": spawn], generatedAt: generatedAt)
+    }
+
+    func testJSONIncludesSpawnMetadata() throws {
+        let object = try XCTUnwrap(JSONSerialization.jsonObject(with: Data(try spawnedReport().json().utf8)) as? [String: Any])
+        let project = try XCTUnwrap((object["projects"] as? [[String: Any]])?.first)
+        let agents = try XCTUnwrap((project["worktrees"] as? [[String: Any]])?.first?["agents"] as? [[String: Any]])
+        XCTAssertNil(agents[0]["spawn"])
+        let spawn = try XCTUnwrap(agents[1]["spawn"] as? [String: Any])
+        XCTAssertEqual(spawn["at"] as? String, "2026-10-16T11:30:00Z")
+        XCTAssertEqual(spawn["replacedAgentId"] as? String, "ag-0")
+        XCTAssertEqual(spawn["command"] as? String, "ppg restart ag-0 --json")
+        XCTAssertEqual(spawn["sessionId"] as? String, "s-1")
+    }
+
+    func testMarkdownListsSpawnedAgents() throws {
+        XCTAssertTrue(try spawnedReport().markdown().contains(
+            "Spawned from the app:\n\n- **claude-2** at 2026-10-16T11:30:00Z, restarting ag-0: `ppg restart ag-0 --json`\n"
+        ))
+    }
+
+    func testJSONIsStableAcrossRenders() throws {
+        let report = try fixtureReport()
+        XCTAssertEqual(report.json(), report.json())
+        XCTAssertEqual(report.rendered(as: .markdown), report.markdown())
+    }
+}
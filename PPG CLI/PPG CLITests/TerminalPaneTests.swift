@@ -43,4 +43,53 @@ final class TerminalPaneTests: XCTestCase {
         // which sets allowMouseReporting = false.
         XCTAssertNil(pane.terminalView)
     }
+
+    // MARK: - Exit Summary
+
+    func testNoExitHeadlineWhileAlive() {
+        XCTAssertNil(TerminalPane.exitHeadline(status: .running, exitCode: nil))
+        XCTAssertNil(TerminalPane.exitHeadline(status: .spawning, exitCode: nil))
+        XCTAssertNil(TerminalPane.exitHeadline(status: .unknown, exitCode: nil))
+    }
+
+    func testExitCodeColorsHeadline() {
+        let clean = TerminalPane.exitHeadline(status: .completed, exitCode: 0)
+        XCTAssertEqual(clean?.text, "Exited with code 0")
+        XCTAssertEqual(clean?.color, .systemGreen)
+
+        let crashed = TerminalPane.exitHeadline(status: .failed, exitCode: 2)
+        XCTAssertEqual(crashed?.text, "Exited with code 2")
+        XCTAssertEqual(crashed?.color, .systemRed)
+    }
+
+    func testLostWithoutExitCodeReadsAsClosedExternally() {
+        let headline = TerminalPane.exitHeadline(status: .lost, exitCode: nil)
+        XCTAssertTrue(headline?.text.hasPrefix("Gone") ?? false)
+    }
+
+    func testExitedStatusAddsBannerAndRunningRemovesIt() {
+        let pane = TerminalPane(agent: makeAgent(), sessionName: "test")
+        pane.updateStatus(.completed, exitCode: 0)
+        XCTAssertTrue(pane.subviews.contains { $0 is ExitSummaryBanner })
+
+        pane.updateStatus(.running)
+        XCTAssertFalse(pane.subviews.contains { $0 is ExitSummaryBanner })
+    }
+
+    // MARK: - Prompt Summary
+
+    func testPromptSummaryUsesFirstNonEmptyLine() {
+        XCTAssertEqual(TerminalPane.promptSummary("\n  Fix the login bug  \nDetails follow"), "Fix the login bug")
+    }
+
+    func testPromptSummaryNilForEmptyPrompt() {
+        XCTAssertNil(TerminalPane.promptSummary(""))
+        XCTAssertNil(TerminalPane.promptSummary("\n   \n"))
+    }
+
+    func testPromptSummaryEllipsizesLongLines() {
+        let summary = TerminalPane.promptSummary(String(repeating: "a", count: 500), maxLength: 20)
+        XCTAssertEqual(summary?.count, 20)
+        XCTAssertTrue(summary?.hasSuffix("…") ?? false)
+    }
 }
@@ -0,0 +1,63 @@
+import XCTest
+@testable import PPG_CLI
+
+final class ThemeOverridesTests: XCTestCase {
+
+    private func parse(_ json: String) throws -> ThemeOverrides {
+        try ThemeOverrides.parse(Data(json.utf8))
+    }
+
+    // MARK: - Colors
+
+    func testHexColorsParseWithAndWithoutAlpha() {
+        XCTAssertEqual(ThemeOverrides.parseHex("#FF8000"), ThemeOverrides.RGBA(red: 1, green: 128.0 / 255, blue: 0, alpha: 1))
+        XCTAssertEqual(ThemeOverrides.parseHex("#ff800080")?.alpha, 128.0 / 255)
+        XCTAssertNil(ThemeOverrides.parseHex("FF8000"))
+        XCTAssertNil(ThemeOverrides.parseHex("#FF80"))
+        XCTAssertNil(ThemeOverrides.parseHex("#+F8000"))
+        XCTAssertNil(ThemeOverrides.parseHex("#GG8000"))
+    }
+
+    func testStatusColorsAcceptOneColorOrADarkLightPair() throws {
+        let overrides = try parse(#"{"status.running": "#0072B2", "status.failed": {"dark": "#FF8A80", "light": "#B00020"}}"#)
+        let blue = ThemeOverrides.parseHex("#0072B2")!
+        XCTAssertEqual(overrides.statusColors[.running], ThemeOverrides.Color(dark: blue, light: blue))
+        XCTAssertEqual(overrides.statusColors[.failed]?.dark, ThemeOverrides.parseHex("#FF8A80"))
+        XCTAssertEqual(overrides.statusColors[.failed]?.light, ThemeOverrides.parseHex("#B00020"))
+        XCTAssertNil(overrides.statusColors[.completed])
+    }
+
+    func testEmptyObjectOverridesNothing() throws {
+        XCTAssertEqual(try parse("{}"), ThemeOverrides())
+    }
+
+    // MARK: - Errors
+
+    func testUnknownKeysAreRejected() {
+        XCTAssertThrowsError(try parse(#"{"status.runing": "#0072B2"}"#)) { error in
+            XCTAssertEqual(error as? ThemeOverrides.ParseError, .unknownKey("status.runing"))
+        }
+    }
+
+    func testBadColorsNameTheirKey() {
+        XCTAssertThrowsError(try parse(#"{"status.killed": {"dark": "#000000"}}"#)) { error in
+            XCTAssertEqual(error as? ThemeOverrides.ParseError, .invalidColor(key: "status.killed"))
+        }
+    }
+
+    func testSyntaxErrorsAreReportedAsInvalidJSON() {
+        XCTAssertThrowsError(try parse("{\n  \"status.running\": \"#0072B2\",\n}x")) { error in
+            guard case .invalidJSON = error as? ThemeOverrides.ParseError else {
+                return XCTFail("expected invalidJSON, got \(error)")
+            }
+        }
+        XCTAssertThrowsError(try parse("[]")) { error in
+            XCTAssertEqual(error as? ThemeOverrides.ParseError, .notAnObject)
+        }
+    }
+
+    func testKeysCoverEveryStatus() {
+        XCTAssertEqual(ThemeOverrides.keys.count, AgentStatus.allCases.count)
+        XCTAssertTrue(ThemeOverrides.keys.contains("status.running"))
+    }
+}
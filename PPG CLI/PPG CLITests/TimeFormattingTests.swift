@@ -0,0 +1,93 @@
+import XCTest
+@testable import PPG_CLI
+
+final class TimeFormattingTests: XCTestCase {
+
+    private let now = Date(timeIntervalSince1970: 1_700_000_000)
+
+    // MARK: - parseISO8601
+
+    func testParsesFractionalSeconds() {
+        let date = parseISO8601("2024-06-02T17:45:12.003Z")
+        XCTAssertNotNil(date)
+        XCTAssertEqual(date!.timeIntervalSince1970, 1_717_350_312.003, accuracy: 0.001)
+    }
+
+    func testParsesWholeSeconds() {
+        XCTAssertEqual(parseISO8601("2026-02-23T12:00:00Z")?.timeIntervalSince1970, 1_771_848_000)
+    }
+
+    func testRejectsGarbage() {
+        XCTAssertNil(parseISO8601("t"))
+        XCTAssertNil(parseISO8601(""))
+    }
+
+    // MARK: - timestampPrecedes
+
+    func testParsedTimestampsCompareByDate() {
+        let whole = (parseISO8601("2026-02-23T12:00:00Z"), "2026-02-23T12:00:00Z")
+        let fractional = (parseISO8601("2026-02-23T12:00:00.500Z"), "2026-02-23T12:00:00.500Z")
+        XCTAssertTrue(timestampPrecedes(whole, fractional))
+        XCTAssertFalse(timestampPrecedes(fractional, whole))
+    }
+
+    func testUnparseableTimestampsSortLast() {
+        let valid = (parseISO8601("2026-02-23T12:00:00Z"), "2026-02-23T12:00:00Z")
+        XCTAssertTrue(timestampPrecedes(valid, (nil, "a")))
+        XCTAssertFalse(timestampPrecedes((nil, "a"), valid))
+        XCTAssertTrue(timestampPrecedes((nil, "a"), (nil, "b")))
+    }
+
+    // MARK: - humanizeRelative
+
+    func testUnderAMinuteIsJustNow() {
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-59), now: now), "just now")
+    }
+
+    func testMinuteBoundaries() {
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-60), now: now), "1 minute ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-59 * 60), now: now), "59 minutes ago")
+    }
+
+    func testHourBoundaries() {
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-3600), now: now), "1 hour ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-23 * 3600), now: now), "23 hours ago")
+    }
+
+    func testDayBoundaries() {
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-24 * 3600), now: now), "1 day ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-3 * 24 * 3600), now: now), "3 days ago")
+    }
+
+    func testMonthAndYearBoundaries() {
+        let day: TimeInterval = 24 * 3600
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-30 * day), now: now), "1 month ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-359 * day), now: now), "11 months ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-360 * day), now: now), "1 year ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-364 * day), now: now), "1 year ago")
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(-730 * day), now: now), "2 years ago")
+    }
+
+    func testFutureTimestampFromClockSkewIsJustNow() {
+        XCTAssertEqual(humanizeRelative(now.addingTimeInterval(300), now: now), "just now")
+    }
+
+    func testHumanizeISOStringReturnsNilWhenUnparseable() {
+        XCTAssertNil(humanizeRelative(iso: "", now: now))
+        XCTAssertEqual(humanizeRelative(iso: "2023-11-14T21:13:20Z", now: now), "1 hour ago")
+    }
+
+    // MARK: - formatDuration
+
+    func testDurationUnits() {
+        XCTAssertEqual(formatDuration(45), "45s")
+        XCTAssertEqual(formatDuration(12 * 60 + 30), "12m")
+        XCTAssertEqual(formatDuration(3600), "1h")
+        XCTAssertEqual(formatDuration(3600 + 12 * 60), "1h 12m")
+        XCTAssertEqual(formatDuration(2 * 86400 + 3 * 3600), "2d 3h")
+    }
+
+    func testNegativeDurationClampsToZero() {
+        XCTAssertEqual(formatDuration(-5), "0s")
+    }
+}
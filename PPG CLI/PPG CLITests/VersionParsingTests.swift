@@ -0,0 +1,81 @@
+import XCTest
+@testable import PPG_CLI
+
+final class VersionParsingTests: XCTestCase {
+
+    // MARK: - extractVersion
+
+    func testExtractsCLIVersionFromLastLine() {
+        XCTAssertEqual(extractVersion(from: "0.3.3"), "0.3.3")
+        XCTAssertEqual(extractVersion(from: "Welcome back!\n0.2.1\n"), "0.2.1")
+        XCTAssertNil(extractVersion(from: "unknown"))
+    }
+
+    // MARK: - isVersion(_:atLeast:)
+
+    func testComparesNumerically() {
+        XCTAssertEqual(isVersion("3.10", atLeast: "3.5"), true)
+        XCTAssertEqual(isVersion("2.9", atLeast: "3.0"), false)
+        XCTAssertEqual(isVersion("0.3.0", atLeast: "0.3"), true)
+    }
+
+    func testLetterSuffixIsIgnored() {
+        XCTAssertEqual(isVersion("3.5a", atLeast: "3.5"), true)
+        XCTAssertEqual(isVersion("3.3a", atLeast: "3.5"), false)
+    }
+
+    func testUnparseableVersionIsNil() {
+        XCTAssertNil(isVersion("next", atLeast: "3.0"))
+    }
+
+    // MARK: - TmuxVersion
+
+    func testParsesReleaseAndPatchLetter() {
+        XCTAssertEqual(TmuxVersion.parse("tmux 3.3a"), TmuxVersion(major: 3, minor: 3, patch: "a"))
+        XCTAssertEqual(TmuxVersion.parse("tmux 2.9")?.description, "2.9")
+    }
+
+    func testParsesDevelopmentBuildAsUpcomingRelease() {
+        let version = TmuxVersion.parse("tmux next-3.4")
+        XCTAssertEqual(version, TmuxVersion(major: 3, minor: 4, isDevelopment: true))
+        XCTAssertEqual(version?.description, "next-3.4")
+        XCTAssertEqual(version?.isAtLeast(3, 4), true)
+    }
+
+    func testSkipsShellStartupNoise() {
+        XCTAssertEqual(TmuxVersion.parse("nvm 0.39.7 loaded\ntmux 3.4"), TmuxVersion(major: 3, minor: 4))
+    }
+
+    func testUnnumberedBuildDoesNotParse() {
+        XCTAssertNil(TmuxVersion.parse("tmux master"))
+        XCTAssertNil(TmuxVersion.parse(""))
+    }
+
+    func testPatchReleasesOrderAfterTheirBase() {
+        XCTAssertLessThan(TmuxVersion(major: 3, minor: 3), TmuxVersion(major: 3, minor: 3, patch: "a"))
+        XCTAssertLessThan(TmuxVersion(major: 2, minor: 9, patch: "a"), TmuxVersion(major: 3, minor: 0))
+    }
+
+    // MARK: - TmuxFeatures
+
+    func testOldTmuxUsesLegacyMouseOptions() {
+        let features = TmuxFeatures(version: TmuxVersion(major: 2, minor: 0))
+        XCTAssertEqual(features.mouseOptionNames, ["mode-mouse", "mouse-select-pane", "mouse-resize-pane", "mouse-select-window"])
+        XCTAssertFalse(features.hasResizeWindow)
+    }
+
+    func testFeatureThresholds() {
+        let features = TmuxFeatures(version: TmuxVersion(major: 2, minor: 9))
+        XCTAssertEqual(features.mouseOptionNames, ["mouse"])
+        XCTAssertTrue(features.hasResizeWindow)
+        XCTAssertFalse(features.supportsCodexInputTheme)
+        XCTAssertTrue(TmuxFeatures(version: TmuxVersion(major: 3, minor: 5, isDevelopment: true)).supportsCodexInputTheme)
+    }
+
+    func testUnknownVersionAssumesCurrentTmux() {
+        let features = TmuxFeatures(version: nil)
+        XCTAssertTrue(features.hasMouseOption)
+        XCTAssertTrue(features.hasResizeWindow)
+        XCTAssertTrue(features.supportsCodexInputTheme)
+    }
+}
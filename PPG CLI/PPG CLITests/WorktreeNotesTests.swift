@@ -0,0 +1,144 @@
+import XCTest
+@testable import PPG_CLI
+
+@MainActor
+final class WorktreeNotesTests: XCTestCase {
+    private var tempDir: URL!
+    private let day: TimeInterval = 24 * 60 * 60
+    private let start = Date(timeIntervalSince1970: 1_700_000_000)
+
+    override func setUp() {
+        super.setUp()
+        tempDir = FileManager.default.temporaryDirectory.appendingPathComponent(UUID().uuidString)
+        try? FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+    }
+
+    override func tearDown() {
+        try? FileManager.default.removeItem(at: tempDir)
+        super.tearDown()
+    }
+
+    private var path: String { tempDir.appendingPathComponent("worktree-notes.json").path }
+
+    // MARK: - Editing
+
+    func testSetTextKeepsEditTime() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("waiting on review", for: "wt-1", at: start)
+        XCTAssertEqual(notes.note(for: "wt-1"), WorktreeNote(text: "waiting on review", editedAt: start))
+        XCTAssertNil(notes.note(for: "wt-2"))
+    }
+
+    func testBlankTextClearsTheNote() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("waiting on review", for: "wt-1", at: start)
+        notes.setText("  \n", for: "wt-1", at: start)
+        XCTAssertNil(notes.note(for: "wt-1"))
+    }
+
+    func testPreviewIsTheFirstNonBlankLine() {
+        XCTAssertEqual(WorktreeNote(text: "\n  don't merge before Friday \nsee PR", editedAt: start).preview, "don't merge before Friday")
+    }
+
+    // MARK: - Load / Save
+
+    func testSaveAndLoadRoundTrip() throws {
+        let notes = ["wt-1": WorktreeNote(text: "a\nb", editedAt: start, missingSince: start.addingTimeInterval(day))]
+        try WorktreeNotes.save(notes, path: path)
+        XCTAssertEqual(WorktreeNotes.load(path: path), notes)
+    }
+
+    func testMissingOrCorruptFileLoadsEmpty() throws {
+        XCTAssertEqual(WorktreeNotes.load(path: path), [:])
+        try Data("not json".utf8).write(to: URL(fileURLWithPath: path))
+        XCTAssertEqual(WorktreeNotes.load(path: path), [:])
+    }
+
+    func testStoreLoadsExistingFile() throws {
+        try WorktreeNotes.save(["wt-1": WorktreeNote(text: "kept", editedAt: start)], path: path)
+        XCTAssertEqual(WorktreeNotes(path: path).note(for: "wt-1")?.text, "kept")
+    }
+
+    // MARK: - Merge
+
+    func testMergeKeepsTheLaterEdit() {
+        let merged = WorktreeNotes.merge(
+            ["wt-1": WorktreeNote(text: "old", editedAt: start), "wt-2": WorktreeNote(text: "ours", editedAt: start)],
+            ["wt-1": WorktreeNote(text: "new", editedAt: start.addingTimeInterval(60)), "wt-3": WorktreeNote(text: "theirs", editedAt: start)]
+        )
+        XCTAssertEqual(merged.mapValues(\.text), ["wt-1": "new", "wt-2": "ours", "wt-3": "theirs"])
+    }
+
+    func testMergeKeepsALaterClear() {
+        let merged = WorktreeNotes.merge(
+            ["wt-1": WorktreeNote(text: "", editedAt: start.addingTimeInterval(60))],
+            ["wt-1": WorktreeNote(text: "stale", editedAt: start)]
+        )
+        XCTAssertEqual(merged["wt-1"]?.text, "")
+    }
+
+    func testMergeKeepsTheEarlierMissingDate() {
+        let merged = WorktreeNotes.merge(
+            ["wt-1": WorktreeNote(text: "a", editedAt: start.addingTimeInterval(60), missingSince: nil)],
+            ["wt-1": WorktreeNote(text: "a", editedAt: start, missingSince: start.addingTimeInterval(day))]
+        )
+        XCTAssertEqual(merged["wt-1"]?.missingSince, start.addingTimeInterval(day))
+    }
+
+    // MARK: - Pruning
+
+    func testNoteOfRemovedWorktreeIsDroppedAfterRetention() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("waiting on review", for: "wt-1", at: start)
+        notes.prune(existing: ["wt-2"], at: start)
+        notes.prune(existing: ["wt-2"], at: start.addingTimeInterval(29 * day))
+        XCTAssertNotNil(notes.note(for: "wt-1"))
+        notes.prune(existing: ["wt-2"], at: start.addingTimeInterval(31 * day))
+        XCTAssertNil(notes.notes["wt-1"])
+    }
+
+    func testWorktreeThatReappearsKeepsItsNote() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("waiting on review", for: "wt-1", at: start)
+        notes.prune(existing: ["wt-2"], at: start)
+        notes.prune(existing: ["wt-1"], at: start.addingTimeInterval(day))
+        notes.prune(existing: ["wt-2"], at: start.addingTimeInterval(20 * day))
+        notes.prune(existing: ["wt-2"], at: start.addingTimeInterval(40 * day))
+        XCTAssertNotNil(notes.note(for: "wt-1"))
+    }
+
+    func testEmptyRefreshDoesNotMarkNotesMissing() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("waiting on review", for: "wt-1", at: start)
+        notes.prune(existing: [], at: start)
+        XCTAssertNil(notes.notes["wt-1"]?.missingSince)
+    }
+
+    func testPruneKeepsNotesAnotherCopySavedSinceLoad() throws {
+        try WorktreeNotes.save([
+            "wt-1": WorktreeNote(text: "keep", editedAt: start),
+            "wt-3": WorktreeNote(text: "", editedAt: start),
+        ], path: path)
+        let notes = WorktreeNotes(path: path)
+        // Another copy of the app adds a note after this one loaded the file
+        var onDisk = WorktreeNotes.load(path: path)
+        onDisk["wt-2"] = WorktreeNote(text: "added elsewhere", editedAt: start.addingTimeInterval(day))
+        try WorktreeNotes.save(onDisk, path: path)
+
+        notes.prune(existing: ["wt-1", "wt-2"], at: start.addingTimeInterval(31 * day))
+        notes.flush()
+
+        let saved = WorktreeNotes.load(path: path)
+        XCTAssertEqual(saved["wt-1"]?.text, "keep")
+        XCTAssertEqual(saved["wt-2"]?.text, "added elsewhere")
+        XCTAssertNil(saved["wt-3"], "the cleared note prune dropped stays dropped")
+    }
+
+    func testOldClearedNotesAreDropped() {
+        let notes = WorktreeNotes(path: nil)
+        notes.setText("done", for: "wt-1", at: start)
+        notes.setText("", for: "wt-1", at: start)
+        notes.prune(existing: ["wt-1"], at: start.addingTimeInterval(31 * day))
+        XCTAssertNil(notes.notes["wt-1"])
+    }
+}
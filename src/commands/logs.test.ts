@@ -0,0 +1,74 @@
+import { describe, test, expect } from 'vitest';
+import { captureWindow, newLinesSince, trimTrailingBlankLines } from './logs.js';
+
+describe('newLinesSince', () => {
+  test('given an unchanged capture, should return nothing', () => {
+    expect(newLinesSince(['a', 'b', 'c'], ['a', 'b', 'c'])).toEqual([]);
+  });
+
+  test('given lines appended below, should return only those', () => {
+    expect(newLinesSince(['a', 'b'], ['a', 'b', 'c', 'd'])).toEqual(['c', 'd']);
+  });
+
+  test('given a full window that scrolled, should return the lines past the overlap', () => {
+    expect(newLinesSince(['a', 'b', 'c'], ['b', 'c', 'd'])).toEqual(['d']);
+    expect(newLinesSince(['a', 'b', 'c'], ['c', 'd', 'e'])).toEqual(['d', 'e']);
+  });
+
+  test('given repeated lines, should prefer the longest overlap', () => {
+    expect(newLinesSince(['x', 'x', 'x'], ['x', 'x', 'x', 'y'])).toEqual(['y']);
+  });
+
+  test('given no overlap, should return the whole current capture', () => {
+    expect(newLinesSince(['a', 'b'], ['c', 'd'])).toEqual(['c', 'd']);
+  });
+
+  test('given a last line redrawn in place, should return only the redrawn line', () => {
+    expect(newLinesSince(['a', 'b', 'working |'], ['a', 'b', 'working /'])).toEqual(['working /']);
+  });
+
+  test('given output above a fixed input box, should return the new output and the box', () => {
+    const previous = ['a', 'b', '> ', 'status'];
+    const current = ['a', 'b', 'c', 'd', '> ', 'status'];
+    expect(newLinesSince(previous, current)).toEqual(['c', 'd', '> ', 'status']);
+  });
+
+  test('given no previous capture, should return everything', () => {
+    expect(newLinesSince([], ['a'])).toEqual(['a']);
+  });
+
+  test('given more lines than the window scrolled by, should return all of them from the widened capture', () => {
+    const previous = ['a', 'b', 'c'];
+    const window = captureWindow(3, 10, 15);
+    expect(window).toBe(8);
+    const current = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'];
+    expect(newLinesSince(previous, current)).toEqual(['d', 'e', 'f', 'g', 'h']);
+  });
+});
+
+describe('captureWindow', () => {
+  test('given nothing scrolled off the top, should keep the requested window', () => {
+    expect(captureWindow(100, undefined, 40)).toBe(100);
+    expect(captureWindow(100, 40, 40)).toBe(100);
+    expect(captureWindow(undefined, 40, 40)).toBeUndefined();
+  });
+
+  test('given output scrolled into history, should reach back that much further', () => {
+    expect(captureWindow(100, 40, 250)).toBe(310);
+    expect(captureWindow(undefined, 40, 45)).toBe(5);
+  });
+
+  test('given a cleared history, should keep the requested window', () => {
+    expect(captureWindow(100, 500, 0)).toBe(100);
+  });
+});
+
+describe('trimTrailingBlankLines', () => {
+  test('given blank rows below the output, should drop them', () => {
+    expect(trimTrailingBlankLines(['a', '', 'b', '', '  '])).toEqual(['a', '', 'b']);
+  });
+
+  test('given only blank rows, should return an empty list', () => {
+    expect(trimTrailingBlankLines(['', ''])).toEqual([]);
+  });
+});
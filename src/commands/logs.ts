@@ -24,25 +24,19 @@ export async function logsCommand(agentId: string, options: LogsOptions): Promis
 
   if (options.follow) {
     // Poll mode
-    let lastOutput = '';
+    let lastLines: string[] | undefined;
+    let lastHistory: number | undefined;
     const interval = setInterval(async () => {
       try {
-        const content = await tmux.capturePane(agent.tmuxTarget, lines);
-        if (content !== lastOutput) {
-          // Find new lines
-          if (lastOutput) {
-            const oldLines = lastOutput.split('\n');
-            const newLines = content.split('\n');
-            // Output only lines that are new
-            const diff = newLines.slice(oldLines.length);
-            if (diff.length > 0) {
-              process.stdout.write(diff.join('\n') + '\n');
-            }
-          } else {
-            process.stdout.write(content + '\n');
-          }
-          lastOutput = content;
+        const history = await tmux.getHistorySize(agent.tmuxTarget);
+        const content = await tmux.capturePane(agent.tmuxTarget, captureWindow(lines, lastHistory, history));
+        const current = trimTrailingBlankLines(content.split('\n'));
+        const fresh = lastLines ? newLinesSince(lastLines, current) : current;
+        if (fresh.length > 0) {
+          process.stdout.write(fresh.join('\n') + '\n');
         }
+        lastLines = current;
+        lastHistory = history;
       } catch {
         clearInterval(interval);
         outputError(new Error('Pane no longer available'), options.json ?? false);
@@ -73,3 +67,54 @@ export async function logsCommand(agentId: string, options: LogsOptions): Promis
     }
   }
 }
+
+/**
+ * How many lines above the visible screen to capture on a follow poll. When output
+ * scrolled off the top since the last poll (the pane's history grew), the capture
+ * reaches back that much further, so it still starts where the last one did and no
+ * line that scrolled by in between is skipped. Once the history is at tmux's
+ * history-limit it stops growing, and only the usual window is captured.
+ */
+export function captureWindow(
+  lines: number | undefined,
+  previousHistory: number | undefined,
+  history: number,
+): number | undefined {
+  const scrolled = previousHistory === undefined ? 0 : Math.max(0, history - previousHistory);
+  return scrolled === 0 ? lines : (lines ?? 0) + scrolled;
+}
+
+/**
+ * Lines of `current` that weren't in `previous`, for two captures of the same pane.
+ *
+ * A capture slides as output scrolls, so the start of `current` overlaps the end of
+ * `previous`; `captureWindow` widens it to keep that true however much scrolled by.
+ * Uses the longest such overlap. With none, the bottom of the pane was redrawn: a TUI
+ * with a fixed input box or status line, a spinner, or a cleared screen. Everything
+ * below the lines both captures start with is returned then, which is the whole of
+ * `current` when they share no first line.
+ */
+export function newLinesSince(previous: string[], current: string[]): string[] {
+  for (let start = 0; start < previous.length; start++) {
+    const overlap = previous.length - start;
+    if (overlap > current.length) continue;
+    let matches = true;
+    for (let i = 0; i < overlap; i++) {
+      if (previous[start + i] !== current[i]) {
+        matches = false;
+        break;
+      }
+    }
+    if (matches) return current.slice(overlap);
+  }
+  let shared = 0;
+  while (shared < previous.length && shared < current.length && previous[shared] === current[shared]) shared++;
+  return current.slice(shared);
+}
+
+/** Drop the empty rows below the cursor that capture-pane includes for the visible screen. */
+export function trimTrailingBlankLines(lines: string[]): string[] {
+  let end = lines.length;
+  while (end > 0 && lines[end - 1].trim() === '') end--;
+  return lines.slice(0, end);
+}
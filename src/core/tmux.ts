@@ -112,6 +112,12 @@ export async function capturePane(target: string, lines?: number): Promise<strin
   return result.stdout;
 }
 
+/** Lines in a pane's scrollback, i.e. how much output has scrolled off its top. */
+export async function getHistorySize(target: string): Promise<number> {
+  const result = await execa('tmux', ['display-message', '-t', target, '-p', '#{history_size}'], execaEnv);
+  return parseInt(result.stdout.trim(), 10) || 0;
+}
+
 export async function killPane(target: string): Promise<void> {
   try {
     await execa('tmux', ['kill-pane', '-t', target], execaEnv);